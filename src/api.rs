@@ -0,0 +1,920 @@
+//! ## BriteVerify API Abstraction
+//!
+//! [`BriteVerifyApi`](BriteVerifyApi) describes the operations
+//! exposed by the BriteVerify API independently of how they're
+//! actually carried out. [`BriteVerifyClient`](BriteVerifyClient)
+//! implements it by talking to the real API, and
+//! [`InMemoryBriteVerifyApi`](InMemoryBriteVerifyApi) implements
+//! it entirely in memory so that applications can inject scripted
+//! behavior into code that depends on the API.
+//
+// Standard Library Imports
+use std::{
+    collections::{BTreeMap, VecDeque},
+    fmt,
+    future::Future,
+    sync::{Arc, Mutex, MutexGuard},
+    time::Duration,
+};
+
+// Third-Party Imports
+use chrono::{NaiveDate, Utc};
+
+// Crate-Level Imports
+use crate::{errors::BriteVerifyClientError, types, BriteVerifyClient};
+
+// <editor-fold desc="// Constants ...">
+
+type ApiResult<T> = Result<T, BriteVerifyClientError>;
+
+pub(crate) type Verifier =
+    dyn Fn(&types::VerificationRequest) -> types::VerificationResponse + Send + Sync;
+
+// </editor-fold desc="// Constants ...">
+
+// <editor-fold desc="// BriteVerifyApi ...">
+
+/// The complete set of operations offered by the BriteVerify API.
+///
+/// Code written against `BriteVerifyApi` instead of directly against
+/// [`BriteVerifyClient`](BriteVerifyClient) can be exercised without
+/// network access by swapping in an alternate implementation (such as
+/// [`InMemoryBriteVerifyApi`](InMemoryBriteVerifyApi)).
+///
+/// #### Example
+/// ```no_run
+/// # use briteverify_rs::{BriteVerifyApi, BriteVerifyClient, InMemoryBriteVerifyApi};
+/// #
+/// async fn has_credits<Api: BriteVerifyApi>(api: &Api) -> bool {
+///     api.get_account_balance()
+///         .await
+///         .map_or(false, |balance| balance.credits > 0)
+/// }
+///
+/// # async fn doc() -> anyhow::Result<()> {
+/// assert!(has_credits(&BriteVerifyClient::new("YOUR API KEY")?).await);
+/// assert!(!has_credits(&InMemoryBriteVerifyApi::new().credits(0)).await);
+/// # Ok(())
+/// # }
+/// ```
+pub trait BriteVerifyApi: Send + Sync {
+    /// Get the current account credit balance
+    /// [[ref](https://docs.briteverify.com/#07beceb3-2961-4d5b-93a4-9cfeb30f42fa)]
+    fn get_account_balance(
+        &self,
+    ) -> impl Future<Output = ApiResult<types::AccountCreditBalance>> + Send;
+
+    /// Perform a single-transaction verification of
+    /// the supplied request's email address, phone
+    /// number, and / or street address
+    /// [[ref](https://docs.briteverify.com/#a7246384-e91e-48a9-8aed-7b71cb74dd42)]
+    fn verify(
+        &self,
+        request: types::VerificationRequest,
+    ) -> impl Future<Output = ApiResult<types::VerificationResponse>> + Send;
+
+    /// Retrieve the bulk verification lists created within
+    /// the last 7 calendar days, filtered by the specified
+    /// criteria
+    /// [[ref](https://docs.briteverify.com/#0b5a2a7a-4062-4327-ab0a-4675592e3cd6)]
+    fn get_filtered_lists(
+        &self,
+        page: Option<u32>,
+        date: Option<NaiveDate>,
+        state: Option<types::BatchState>,
+        ext_id: Option<&str>,
+    ) -> impl Future<Output = ApiResult<types::GetListStatesResponse>> + Send;
+
+    /// Create a new bulk verification list with the supplied
+    /// records and (optionally) queue it for immediate processing
+    /// [[ref](https://docs.briteverify.com/#38b4c9eb-31b1-4b8e-9295-a783d8043bc1)]
+    fn create_list(
+        &self,
+        contacts: Vec<types::VerificationRequest>,
+        auto_start: bool,
+    ) -> impl Future<Output = ApiResult<types::CreateListResponse>> + Send;
+
+    /// Append records to the specified bulk verification list
+    /// and (optionally) queue it for immediate processing
+    /// [[ref](https://docs.briteverify.com/#38b4c9eb-31b1-4b8e-9295-a783d8043bc1:~:text=POST%20Verify%20List%20%2D%20Append%20Records)]
+    fn update_list(
+        &self,
+        list_id: &str,
+        contacts: Vec<types::VerificationRequest>,
+        auto_start: bool,
+    ) -> impl Future<Output = ApiResult<types::UpdateListResponse>> + Send;
+
+    /// Retrieve the current "state" of the specified
+    /// bulk verification list
+    /// [[ref](https://docs.briteverify.com/#b09c09dc-e11e-44a8-b53d-9f1fd9c6792d)]
+    fn get_list_by_id(
+        &self,
+        list_id: &str,
+    ) -> impl Future<Output = ApiResult<types::VerificationListState>> + Send;
+
+    /// Retrieve the current "state" of a bulk verification
+    /// list tied to an externally supplied identifier
+    /// [[ref](https://docs.briteverify.com/#b09c09dc-e11e-44a8-b53d-9f1fd9c6792d)]
+    fn get_list_by_external_id(
+        &self,
+        list_id: &str,
+        external_id: &str,
+    ) -> impl Future<Output = ApiResult<types::VerificationListState>> + Send;
+
+    /// Delete the specified bulk verification list
+    /// [[ref](https://docs.briteverify.com/#6c9b9c05-a4a0-435e-a064-af7d9476719d)]
+    fn delete_list_by_id(
+        &self,
+        list_id: &str,
+    ) -> impl Future<Output = ApiResult<types::DeleteListResponse>> + Send;
+
+    /// Abandon the specified unprocessed bulk verification list
+    /// [[ref](https://docs.briteverify.com/#6c9b9c05-a4a0-435e-a064-af7d9476719d)]
+    fn terminate_list_by_id(
+        &self,
+        list_id: &str,
+    ) -> impl Future<Output = ApiResult<types::UpdateListResponse>> + Send;
+
+    /// Queue the specified open bulk verification list for processing
+    /// [[ref](https://docs.briteverify.com/#0a0cc29d-6d9f-4b0d-9aa5-4166775a8831)]
+    fn queue_list_for_processing(
+        &self,
+        list_id: &str,
+    ) -> impl Future<Output = ApiResult<types::UpdateListResponse>> + Send;
+
+    /// Retrieve the complete set of results
+    /// for the specified bulk verification list
+    /// [[ref](https://docs.briteverify.com/#0a0cc29d-6d9f-4b0d-9aa5-4166775a8831)]
+    fn get_results_by_list_id(
+        &self,
+        list_id: &str,
+    ) -> impl Future<Output = ApiResult<Vec<types::BulkVerificationResult>>> + Send;
+
+    /// Retrieve the complete, unfiltered list of all bulk
+    /// verification lists created within the last 7 calendar days
+    /// [[ref](https://docs.briteverify.com/#0b5a2a7a-4062-4327-ab0a-4675592e3cd6)]
+    fn get_lists(&self) -> impl Future<Output = ApiResult<types::GetListStatesResponse>> + Send {
+        self.get_filtered_lists(None, None, None, None)
+    }
+
+    /// Verify a single email address
+    /// [[ref](https://docs.briteverify.com/#e5dd413b-6b2a-4b0c-b501-9c3a18e4d8f4)]
+    fn verify_email(
+        &self,
+        email: &str,
+    ) -> impl Future<Output = ApiResult<types::EmailVerificationArray>> + Send {
+        let request = types::VerificationRequest::builder().email(email).build();
+
+        async move {
+            let response = self.verify(request?).await?;
+
+            match response.email {
+                Some(data) => Ok(data),
+                None => Err(BriteVerifyClientError::MismatchedVerificationResponse(
+                    Box::new(response),
+                )),
+            }
+        }
+    }
+
+    /// Verify a single phone number
+    /// [[ref](https://docs.briteverify.com/#86e335f4-d1b2-4902-9051-4506a48a6b94)]
+    fn verify_phone_number(
+        &self,
+        phone: &str,
+    ) -> impl Future<Output = ApiResult<types::PhoneNumberVerificationArray>> + Send {
+        let request = types::VerificationRequest::builder().phone(phone).build();
+
+        async move {
+            let response = self.verify(request?).await?;
+
+            match response.phone {
+                Some(data) => Ok(data),
+                None => Err(BriteVerifyClientError::MismatchedVerificationResponse(
+                    Box::new(response),
+                )),
+            }
+        }
+    }
+
+    /// Verify a single street address
+    /// [[ref](https://docs.briteverify.com/#f588d8d3-8250-4a8a-9e58-f89c81af6bed)]
+    fn verify_street_address(
+        &self,
+        address: types::StreetAddressArray,
+    ) -> impl Future<Output = ApiResult<types::AddressVerificationArray>> + Send {
+        let request = types::VerificationRequest {
+            address: Some(address),
+            ..types::VerificationRequest::default()
+        };
+
+        async move {
+            let response = self.verify(request).await?;
+
+            match response.address {
+                Some(data) => Ok(data),
+                None => Err(BriteVerifyClientError::MismatchedVerificationResponse(
+                    Box::new(response),
+                )),
+            }
+        }
+    }
+}
+
+// </editor-fold desc="// BriteVerifyApi ...">
+
+// <editor-fold desc="// BriteVerifyClient ...">
+
+impl BriteVerifyApi for BriteVerifyClient {
+    async fn get_account_balance(&self) -> ApiResult<types::AccountCreditBalance> {
+        BriteVerifyClient::get_account_balance(self).await
+    }
+
+    async fn verify(
+        &self,
+        request: types::VerificationRequest,
+    ) -> ApiResult<types::VerificationResponse> {
+        Ok(self._verify(&request).await?.into_inner())
+    }
+
+    async fn get_filtered_lists(
+        &self,
+        page: Option<u32>,
+        date: Option<NaiveDate>,
+        state: Option<types::BatchState>,
+        ext_id: Option<&str>,
+    ) -> ApiResult<types::GetListStatesResponse> {
+        BriteVerifyClient::get_filtered_lists(self, page, date, state, ext_id).await
+    }
+
+    async fn create_list(
+        &self,
+        contacts: Vec<types::VerificationRequest>,
+        auto_start: bool,
+    ) -> ApiResult<types::CreateListResponse> {
+        let contacts = Some(contacts).filter(|contacts| !contacts.is_empty());
+
+        BriteVerifyClient::create_list(self, contacts, auto_start).await
+    }
+
+    async fn update_list(
+        &self,
+        list_id: &str,
+        contacts: Vec<types::VerificationRequest>,
+        auto_start: bool,
+    ) -> ApiResult<types::UpdateListResponse> {
+        BriteVerifyClient::update_list(self, list_id, contacts, auto_start).await
+    }
+
+    async fn get_list_by_id(&self, list_id: &str) -> ApiResult<types::VerificationListState> {
+        BriteVerifyClient::get_list_by_id(self, list_id).await
+    }
+
+    async fn get_list_by_external_id(
+        &self,
+        list_id: &str,
+        external_id: &str,
+    ) -> ApiResult<types::VerificationListState> {
+        BriteVerifyClient::get_list_by_external_id(self, list_id, external_id).await
+    }
+
+    async fn delete_list_by_id(&self, list_id: &str) -> ApiResult<types::DeleteListResponse> {
+        BriteVerifyClient::delete_list_by_id(self, list_id).await
+    }
+
+    async fn terminate_list_by_id(&self, list_id: &str) -> ApiResult<types::UpdateListResponse> {
+        BriteVerifyClient::terminate_list_by_id(self, list_id).await
+    }
+
+    async fn queue_list_for_processing(
+        &self,
+        list_id: &str,
+    ) -> ApiResult<types::UpdateListResponse> {
+        BriteVerifyClient::queue_list_for_processing(self, list_id).await
+    }
+
+    async fn get_results_by_list_id(
+        &self,
+        list_id: &str,
+    ) -> ApiResult<Vec<types::BulkVerificationResult>> {
+        BriteVerifyClient::get_results_by_list_id(self, list_id).await
+    }
+}
+
+// </editor-fold desc="// BriteVerifyClient ...">
+
+// <editor-fold desc="// InMemoryBriteVerifyApi ...">
+
+/// [internal-implementation]
+/// A bulk verification list held by an
+/// [`InMemoryBriteVerifyApi`](InMemoryBriteVerifyApi)
+#[derive(Clone, Debug)]
+struct InMemoryList {
+    state: types::VerificationListState,
+    contacts: Vec<types::VerificationRequest>,
+}
+
+/// [internal-implementation]
+/// The mutable state of an
+/// [`InMemoryBriteVerifyApi`](InMemoryBriteVerifyApi)
+#[derive(Debug, Default)]
+struct InMemoryState {
+    balance: types::AccountCreditBalance,
+    lists: BTreeMap<String, InMemoryList>,
+    errors: VecDeque<BriteVerifyClientError>,
+    calls: Vec<String>,
+    next_id: u64,
+}
+
+/// An in-memory implementation of [`BriteVerifyApi`](BriteVerifyApi)
+/// whose behavior is scripted by the caller.
+///
+/// Single-transaction verifications are answered by a user-supplied
+/// "verifier" function (which reports every requested value as valid
+/// by default). Bulk verification lists are held in memory and follow
+/// the same general lifecycle as lists held by the real API, with
+/// queued lists being verified (using the same verifier) immediately.
+///
+/// Every call is recorded, and errors can be queued up to be returned
+/// (in order) by the next calls made.
+///
+/// #### Example
+/// ```no_run
+/// # use briteverify_rs::{
+/// #     BriteVerifyApi,
+/// #     InMemoryBriteVerifyApi,
+/// #     errors::BriteVerifyClientError,
+/// #     types::{VerificationRequest, VerificationResponse, VerificationStatus},
+/// # };
+/// #
+/// # async fn doc() -> anyhow::Result<()> {
+/// let api = InMemoryBriteVerifyApi::new()
+///     .credits(100)
+///     .verifier(|request: &VerificationRequest| {
+///         let mut response = InMemoryBriteVerifyApi::valid_response(request);
+///
+///         if let Some(email) = response.email.as_mut() {
+///             email.status = VerificationStatus::Invalid;
+///         }
+///
+///         response
+///     });
+///
+/// api.fail_next(BriteVerifyClientError::InvalidApiKey);
+///
+/// assert!(api.verify_email("test@example.com").await.is_err());
+/// assert_eq!(
+///     VerificationStatus::Invalid,
+///     api.verify_email("test@example.com").await?.status,
+/// );
+/// assert_eq!(vec!["verify", "verify"], api.calls());
+/// # Ok(())
+/// # }
+/// ```
+pub struct InMemoryBriteVerifyApi {
+    state: Mutex<InMemoryState>,
+    verifier: Arc<Verifier>,
+}
+
+impl fmt::Debug for InMemoryBriteVerifyApi {
+    #[cfg_attr(tarpaulin, coverage(off))]
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter
+            .debug_struct("InMemoryBriteVerifyApi")
+            .field("state", &self.state)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Default for InMemoryBriteVerifyApi {
+    fn default() -> Self {
+        Self {
+            state: Mutex::new(InMemoryState::default()),
+            verifier: Arc::new(Self::valid_response),
+        }
+    }
+}
+
+impl InMemoryBriteVerifyApi {
+    /// Create a new `InMemoryBriteVerifyApi` with
+    /// no credits, no lists, and a verifier that
+    /// reports every requested value as valid
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the reported number of available credits
+    pub fn credits(self, value: u32) -> Self {
+        self._state().balance.credits = value;
+        self
+    }
+
+    /// Set the reported number of credits in reserve
+    pub fn credits_in_reserve(self, value: u32) -> Self {
+        self._state().balance.credits_in_reserve = value;
+        self
+    }
+
+    /// Set the function used to answer single-transaction
+    /// verification requests and to verify the records of
+    /// bulk verification lists
+    pub fn verifier<Func>(mut self, func: Func) -> Self
+    where
+        Func:
+            Fn(&types::VerificationRequest) -> types::VerificationResponse + Send + Sync + 'static,
+    {
+        self.verifier = Arc::new(func);
+        self
+    }
+
+    /// Add an extant bulk verification list with
+    /// the supplied state and records
+    pub fn list(
+        self,
+        state: types::VerificationListState,
+        contacts: Vec<types::VerificationRequest>,
+    ) -> Self {
+        self._state()
+            .lists
+            .insert(state.id.clone(), InMemoryList { state, contacts });
+        self
+    }
+
+    /// Queue an error to be returned by the next call
+    /// (errors are returned in the order they were queued)
+    pub fn fail_next(&self, error: BriteVerifyClientError) {
+        self._state().errors.push_back(error);
+    }
+
+    /// Change the reported number of available credits
+    pub fn set_credits(&self, value: u32) {
+        let mut state = self._state();
+
+        state.balance.credits = value;
+        state.balance.recorded_on = Utc::now();
+    }
+
+    /// The names of the [`BriteVerifyApi`](BriteVerifyApi)
+    /// methods called so far, in the order they were called
+    pub fn calls(&self) -> Vec<String> {
+        self._state().calls.clone()
+    }
+
+    /// A response reporting each of the supplied
+    /// request's values as valid
+    pub fn valid_response(request: &types::VerificationRequest) -> types::VerificationResponse {
+        types::VerificationResponse {
+            email: request.email.as_ref().map(|email| {
+                let (account, domain) = email.rsplit_once('@').unwrap_or((email, ""));
+
+                types::EmailVerificationArray {
+                    address: email.clone(),
+                    account: account.to_string(),
+                    domain: domain.to_string(),
+                    status: types::VerificationStatus::Valid,
+                    connected: None,
+                    disposable: false,
+                    role_address: false,
+                    error_code: None,
+                    error: None,
+                }
+            }),
+            phone: request
+                .phone
+                .as_ref()
+                .map(|phone| types::PhoneNumberVerificationArray {
+                    number: phone.chars().filter(char::is_ascii_digit).collect(),
+                    status: types::VerificationStatus::Valid,
+                    service_type: None,
+                    phone_location: None,
+                    errors: Vec::new(),
+                }),
+            address: request
+                .address
+                .as_ref()
+                .map(|address| types::AddressVerificationArray {
+                    address1: address.address1.clone(),
+                    address2: address.address2.clone(),
+                    city: address.city.clone(),
+                    state: address.state.clone(),
+                    zip: address.zip.clone(),
+                    status: types::VerificationStatus::Valid,
+                    corrected: false,
+                    errors: Vec::new(),
+                    secondary_status: None,
+                }),
+            duration: Duration::ZERO,
+        }
+    }
+
+    /// [internal-implementation]
+    /// Lock the instance's mutable state
+    fn _state(&self) -> MutexGuard<'_, InMemoryState> {
+        self.state
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    /// [internal-implementation]
+    /// Record a call to the named method and lock the
+    /// instance's state, or return the next queued error
+    fn _call(&self, method: &str) -> ApiResult<MutexGuard<'_, InMemoryState>> {
+        let mut state = self._state();
+
+        state.calls.push(method.to_string());
+
+        match state.errors.pop_front() {
+            Some(error) => Err(error),
+            None => Ok(state),
+        }
+    }
+
+    /// [internal-implementation]
+    /// The error returned for lists that don't exist
+    /// or can't be used for the requested operation
+    fn _list_error(
+        list_id: &str,
+        status: types::BatchState,
+        message: &str,
+    ) -> BriteVerifyClientError {
+        BriteVerifyClientError::BulkListNotFound(Box::new(types::BulkListCRUDError {
+            list_id: Some(list_id.to_string()),
+            status,
+            message: Some(message.to_string()),
+        }))
+    }
+
+    /// [internal-implementation]
+    /// Get the specified list if it exists
+    fn _list<'state>(
+        state: &'state mut InMemoryState,
+        list_id: &str,
+    ) -> ApiResult<&'state mut InMemoryList> {
+        state.lists.get_mut(list_id).ok_or_else(|| {
+            Self::_list_error(list_id, types::BatchState::NotFound, "list not found")
+        })
+    }
+
+    /// [internal-implementation]
+    /// Get the specified list if it exists
+    /// and is still accepting records
+    fn _open_list<'state>(
+        state: &'state mut InMemoryState,
+        list_id: &str,
+    ) -> ApiResult<&'state mut InMemoryList> {
+        let list = Self::_list(state, list_id)?;
+
+        if list.state.state != types::BatchState::Open {
+            return Err(Self::_list_error(
+                list_id,
+                types::BatchState::InvalidState,
+                "list is not open",
+            ));
+        }
+
+        Ok(list)
+    }
+
+    /// [internal-implementation]
+    /// Mark the supplied list as fully processed
+    fn _process(list: &mut InMemoryList) {
+        let emails = list.contacts.iter().filter(|c| c.email.is_some()).count();
+        let phones = list.contacts.iter().filter(|c| c.phone.is_some()).count();
+
+        list.state.state = types::BatchState::Complete;
+        list.state.progress = 100;
+        list.state.page_count = Some(1);
+        list.state.total_verified = list.contacts.len() as u64;
+        list.state.total_verified_emails = emails as u64;
+        list.state.total_verified_phones = phones as u64;
+        list.state.expiration_date = Some(Utc::now() + chrono::Duration::days(7));
+    }
+
+    /// [internal-implementation]
+    /// Wrap the supplied list state in a CRUD response
+    fn _crud_response(
+        list: &types::VerificationListState,
+        message: &str,
+    ) -> types::BulkListCRUDResponse {
+        types::BulkListCRUDResponse {
+            status: types::BatchState::Success,
+            message: message.to_string(),
+            list: list.clone(),
+        }
+    }
+
+    /// [internal-implementation]
+    /// Convert a single-transaction verification
+    /// response into a bulk verification result
+    pub(crate) fn _bulk_result(
+        response: types::VerificationResponse,
+    ) -> types::BulkVerificationResult {
+        types::BulkVerificationResult::Contact(types::BulkContactVerificationResult {
+            email: response
+                .email
+                .map(|email| types::bulk::BulkEmailVerificationArray {
+                    email: email.address,
+                    status: email.status,
+                    secondary_status: email.error_code.map(|code| code.to_string()),
+                }),
+            phone: response
+                .phone
+                .map(|phone| types::bulk::BulkPhoneNumberVerificationArray {
+                    phone: phone.number,
+                    status: phone.status,
+                    phone_location: None,
+                    secondary_status: phone
+                        .errors
+                        .first()
+                        .and_then(|error| error.as_str())
+                        .map(str::to_string),
+                    service_type: phone.service_type,
+                }),
+            address: response.address,
+        })
+    }
+}
+
+impl BriteVerifyApi for InMemoryBriteVerifyApi {
+    async fn get_account_balance(&self) -> ApiResult<types::AccountCreditBalance> {
+        Ok(self._call("get_account_balance")?.balance.clone())
+    }
+
+    async fn verify(
+        &self,
+        request: types::VerificationRequest,
+    ) -> ApiResult<types::VerificationResponse> {
+        drop(self._call("verify")?);
+
+        Ok((self.verifier)(&request))
+    }
+
+    async fn get_filtered_lists(
+        &self,
+        _page: Option<u32>,
+        date: Option<NaiveDate>,
+        state: Option<types::BatchState>,
+        ext_id: Option<&str>,
+    ) -> ApiResult<types::GetListStatesResponse> {
+        let lists = self
+            ._call("get_filtered_lists")?
+            .lists
+            .values()
+            .map(|list| &list.state)
+            .filter(|list| date.is_none_or(|date| list.created_at.date_naive() == date))
+            .filter(|list| state.is_none_or(|state| list.state == state))
+            .filter(|list| ext_id.is_none_or(|id| list.external_id.as_deref() == Some(id)))
+            .cloned()
+            .collect();
+
+        Ok(types::GetListStatesResponse {
+            message: None,
+            lists,
+        })
+    }
+
+    async fn create_list(
+        &self,
+        contacts: Vec<types::VerificationRequest>,
+        auto_start: bool,
+    ) -> ApiResult<types::CreateListResponse> {
+        let mut state = self._call("create_list")?;
+
+        state.next_id += 1;
+
+        let id = format!("00000000-0000-4000-8000-{:012x}", state.next_id);
+        let mut list = InMemoryList {
+            state: types::VerificationListState {
+                id: id.clone(),
+                state: types::BatchState::Open,
+                created_at: Utc::now(),
+                ..types::VerificationListState::default()
+            },
+            contacts,
+        };
+
+        if auto_start && !list.contacts.is_empty() {
+            Self::_process(&mut list);
+        }
+
+        let response = Self::_crud_response(&list.state, "created new list");
+
+        state.lists.insert(id, list);
+
+        Ok(response)
+    }
+
+    async fn update_list(
+        &self,
+        list_id: &str,
+        contacts: Vec<types::VerificationRequest>,
+        auto_start: bool,
+    ) -> ApiResult<types::UpdateListResponse> {
+        let mut state = self._call("update_list")?;
+        let list = Self::_open_list(&mut state, list_id)?;
+
+        list.contacts.extend(contacts);
+
+        if auto_start {
+            Self::_process(list);
+        }
+
+        Ok(Self::_crud_response(&list.state, "added records to list"))
+    }
+
+    async fn get_list_by_id(&self, list_id: &str) -> ApiResult<types::VerificationListState> {
+        let mut state = self._call("get_list_by_id")?;
+
+        Ok(Self::_list(&mut state, list_id)?.state.clone())
+    }
+
+    async fn get_list_by_external_id(
+        &self,
+        list_id: &str,
+        external_id: &str,
+    ) -> ApiResult<types::VerificationListState> {
+        let mut state = self._call("get_list_by_external_id")?;
+
+        match Self::_list(&mut state, list_id)? {
+            list if list.state.external_id.as_deref() == Some(external_id) => {
+                Ok(list.state.clone())
+            }
+            _ => Err(Self::_list_error(
+                list_id,
+                types::BatchState::NotFound,
+                "list not found",
+            )),
+        }
+    }
+
+    async fn delete_list_by_id(&self, list_id: &str) -> ApiResult<types::DeleteListResponse> {
+        let mut state = self._call("delete_list_by_id")?;
+        let list = Self::_list(&mut state, list_id)?;
+
+        if !matches!(
+            list.state.state,
+            types::BatchState::Prepped
+                | types::BatchState::Complete
+                | types::BatchState::Delivered
+                | types::BatchState::ImportError
+        ) {
+            return Err(Self::_list_error(
+                list_id,
+                types::BatchState::InvalidState,
+                "list cannot be deleted in its current state",
+            ));
+        }
+
+        let mut list = state
+            .lists
+            .remove(list_id)
+            .map(|list| list.state)
+            .unwrap_or_default();
+
+        list.state = types::BatchState::Deleted;
+
+        Ok(Self::_crud_response(&list, "list deleted"))
+    }
+
+    async fn terminate_list_by_id(&self, list_id: &str) -> ApiResult<types::UpdateListResponse> {
+        let mut state = self._call("terminate_list_by_id")?;
+        let list = Self::_open_list(&mut state, list_id)?;
+
+        list.state.state = types::BatchState::Terminated;
+
+        Ok(Self::_crud_response(&list.state, "list terminated"))
+    }
+
+    async fn queue_list_for_processing(
+        &self,
+        list_id: &str,
+    ) -> ApiResult<types::UpdateListResponse> {
+        let mut state = self._call("queue_list_for_processing")?;
+        let list = Self::_open_list(&mut state, list_id)?;
+
+        Self::_process(list);
+
+        Ok(Self::_crud_response(
+            &list.state,
+            "list queued for processing",
+        ))
+    }
+
+    async fn get_results_by_list_id(
+        &self,
+        list_id: &str,
+    ) -> ApiResult<Vec<types::BulkVerificationResult>> {
+        let contacts = {
+            let mut state = self._call("get_results_by_list_id")?;
+            let list = Self::_list(&mut state, list_id)?;
+
+            if list.state.page_count.is_none() {
+                return Err(BriteVerifyClientError::Other(anyhow::Error::msg(
+                    "Missing page count!",
+                )));
+            }
+
+            list.contacts.clone()
+        };
+
+        Ok(contacts
+            .iter()
+            .map(|contact| Self::_bulk_result((self.verifier)(contact)))
+            .collect())
+    }
+}
+
+// </editor-fold desc="// InMemoryBriteVerifyApi ...">
+
+// <editor-fold desc="// I/O-Free Tests ...">
+
+#[cfg(test)]
+mod tests {
+    // Third-Party Dependencies
+    use pretty_assertions::assert_eq;
+
+    // Crate-Level Imports
+    use super::{BriteVerifyApi, InMemoryBriteVerifyApi};
+    use crate::{errors::BriteVerifyClientError, types};
+
+    /// Test that `InMemoryBriteVerifyApi` answers single-transaction
+    /// verifications with its verifier and returns queued errors
+    #[rstest::rstest]
+    #[test_log::test(tokio::test)]
+    async fn test_in_memory_single_verification() {
+        let api = InMemoryBriteVerifyApi::new()
+            .credits(42)
+            .verifier(|request| {
+                let mut response = InMemoryBriteVerifyApi::valid_response(request);
+                response.phone = None;
+                response
+            });
+
+        api.fail_next(BriteVerifyClientError::InvalidApiKey);
+
+        assert!(matches!(
+            api.get_account_balance().await,
+            Err(BriteVerifyClientError::InvalidApiKey)
+        ));
+        assert_eq!(42u32, api.get_account_balance().await.unwrap().credits);
+
+        let email = api.verify_email("Someone@Example.com").await.unwrap();
+
+        assert_eq!("Someone", email.account);
+        assert_eq!("Example.com", email.domain);
+        assert_eq!(types::VerificationStatus::Valid, email.status);
+
+        assert!(matches!(
+            api.verify_phone_number("+15555555555").await,
+            Err(BriteVerifyClientError::MismatchedVerificationResponse(_))
+        ));
+        assert_eq!(
+            vec![
+                "get_account_balance",
+                "get_account_balance",
+                "verify",
+                "verify"
+            ],
+            api.calls()
+        );
+    }
+
+    /// Test that `InMemoryBriteVerifyApi` walks bulk
+    /// verification lists through their lifecycle
+    #[rstest::rstest]
+    #[test_log::test(tokio::test)]
+    async fn test_in_memory_list_lifecycle() {
+        let api = InMemoryBriteVerifyApi::new();
+        let contact = types::VerificationRequest::try_from("test@example.com").unwrap();
+
+        let created = api.create_list(vec![contact.clone()], false).await.unwrap();
+        let list_id = created.list.id.as_str();
+
+        assert_eq!(types::BatchState::Open, created.list.state);
+        assert!(api.get_results_by_list_id(list_id).await.is_err());
+        assert!(api.delete_list_by_id(list_id).await.is_err());
+
+        api.update_list(list_id, vec![contact], false)
+            .await
+            .unwrap();
+
+        let queued = api.queue_list_for_processing(list_id).await.unwrap();
+
+        assert_eq!(types::BatchState::Complete, queued.list.state);
+        assert_eq!(2, queued.list.total_verified_emails);
+        assert_eq!(2, api.get_results_by_list_id(list_id).await.unwrap().len());
+        assert!(api.terminate_list_by_id(list_id).await.is_err());
+        assert_eq!(1, api.get_lists().await.unwrap().lists.len());
+
+        let deleted = api.delete_list_by_id(list_id).await.unwrap();
+
+        assert_eq!(types::BatchState::Deleted, deleted.list.state);
+        assert!(matches!(
+            api.get_list_by_id(list_id).await,
+            Err(BriteVerifyClientError::BulkListNotFound(_))
+        ));
+        assert!(api.get_lists().await.unwrap().lists.is_empty());
+    }
+}
+
+// </editor-fold desc="// I/O-Free Tests ...">
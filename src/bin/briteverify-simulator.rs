@@ -0,0 +1,63 @@
+//! # Standalone BriteVerify API Simulator
+//!
+//! Serves a [`BriteVerifySimulator`](briteverify_rs::simulator::BriteVerifySimulator)
+//! over HTTP until interrupted. Configured via environment variables:
+//!
+//! - `BV_API_KEY` (required): the API key requests must be authorized with
+//! - `BV_SIMULATOR_ADDRESS`: the address to listen on (default `127.0.0.1:8080`)
+//! - `BV_SIMULATOR_CREDITS`: the account's starting credit balance (default `1000`)
+//! - `BV_SIMULATOR_PAGE_SIZE`: the number of results per exported page (default `50`)
+//! - `BV_SIMULATOR_LIST_TTL`: seconds until completed lists expire (default `172800`)
+//
+// Standard Library Imports
+use std::{net::SocketAddr, str::FromStr, time::Duration};
+
+// Third-Party Imports
+use anyhow::Context;
+
+// Crate-Level Imports
+use briteverify_rs::simulator::BriteVerifySimulator;
+
+/// Read and parse the named environment
+/// variable, falling back to the supplied
+/// default if it isn't set
+fn env_or<T: FromStr>(name: &str, default: T) -> anyhow::Result<T>
+where
+    T::Err: std::error::Error + Send + Sync + 'static,
+{
+    match std::env::var(name) {
+        Err(_) => Ok(default),
+        Ok(value) => value
+            .parse::<T>()
+            .with_context(|| format!("Invalid value for '{name}': {value:?}")),
+    }
+}
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    let api_key: String = std::env::var("BV_API_KEY")
+        .context("The 'BV_API_KEY' environment variable must be set for the simulator to work!")?;
+
+    let address: SocketAddr = env_or(
+        "BV_SIMULATOR_ADDRESS",
+        SocketAddr::from(([127, 0, 0, 1], 8080)),
+    )?;
+
+    let simulator = BriteVerifySimulator::new(api_key)
+        .credits(env_or("BV_SIMULATOR_CREDITS", 1000u32)?)
+        .page_size(env_or("BV_SIMULATOR_PAGE_SIZE", 50usize)?)
+        .list_ttl(Duration::from_secs(env_or(
+            "BV_SIMULATOR_LIST_TTL",
+            172_800u64,
+        )?));
+
+    let server = simulator.start_on(address).await?;
+
+    println!("BriteVerify API simulator listening on:");
+    println!("  v1: {}", server.v1_base_url());
+    println!("  v3: {}", server.v3_base_url());
+
+    tokio::signal::ctrl_c().await?;
+
+    Ok(())
+}
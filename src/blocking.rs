@@ -0,0 +1,885 @@
+//! ## Synchronous BriteVerify API Client
+//!
+//! Available with the `blocking` feature enabled.
+//!
+//! [`BlockingBriteVerifyClient`](BlockingBriteVerifyClient) exposes the same
+//! methods as the asynchronous [`BriteVerifyClient`](BriteVerifyClient), but
+//! each one blocks the calling thread until its result is available.
+//!
+//! The only exceptions are [`monitor_balance`](BriteVerifyClient::monitor_balance)
+//! and [`watch_balance`](BriteVerifyClient::watch_balance), which check the
+//! account's balance for as long as they're polled and are therefore only
+//! available on the asynchronous client.
+//!
+//! ___
+//! **NOTE:** Like [`reqwest::blocking`](https://docs.rs/reqwest/latest/reqwest/blocking/index.html),
+//! the blocking client drives its requests on an internal runtime and
+//! therefore *must not* be used from within an async context.
+//! ___
+//
+// Standard Library Imports
+use std::{fmt::Debug, sync::Arc};
+
+// Third-Party Imports
+use anyhow::{Context, Result};
+
+#[cfg(feature = "tracing")]
+use instrumentation as tracing;
+
+// Crate-Level Imports
+use crate::{
+    budget, cache, cassette, coalesce, dedupe, dry_run, errors, idempotency, jobs, ledger, sansio,
+    types, BriteVerifyClient, BriteVerifyClientBuilder, RequestOptions,
+};
+
+// <editor-fold desc="// ClientBuilder ...">
+
+impl BriteVerifyClientBuilder {
+    /// Build a custom configured [`BlockingBriteVerifyClient`](BlockingBriteVerifyClient)
+    /// instance.
+    ///
+    /// #### Example
+    /// ```no_run
+    /// # use std::time::Duration;
+    /// # use briteverify_rs::{BriteVerifyClient, BlockingBriteVerifyClient};
+    /// #
+    /// # fn doc() -> anyhow::Result<()> {
+    /// let client: BlockingBriteVerifyClient = BriteVerifyClient::builder()
+    ///     .api_key("YOUR API KEY")
+    ///     .timeout(Duration::from_secs(30))
+    ///     .build_blocking()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn build_blocking(
+        self,
+    ) -> Result<BlockingBriteVerifyClient, errors::BriteVerifyClientError> {
+        BlockingBriteVerifyClient::try_from(self.build()?)
+    }
+}
+
+// </editor-fold desc="// ClientBuilder ...">
+
+// <editor-fold desc="// Client ...">
+
+/// `briteverify-rs`'s synchronous client
+///
+/// ## Basic Usage
+/// ```no_run
+/// # use briteverify_rs::{BlockingBriteVerifyClient, types::AccountCreditBalance};
+/// #
+/// # fn doc() -> anyhow::Result<()> {
+/// let client: BlockingBriteVerifyClient = BlockingBriteVerifyClient::new("YOUR API KEY")?;
+///
+/// let balance: AccountCreditBalance = client.get_account_balance()?;
+///
+/// println!("{balance:#?}");
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+#[cfg_attr(test, visible::StructFields(pub))]
+pub struct BlockingBriteVerifyClient {
+    inner: BriteVerifyClient,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl TryFrom<BriteVerifyClient> for BlockingBriteVerifyClient {
+    type Error = errors::BriteVerifyClientError;
+
+    fn try_from(client: BriteVerifyClient) -> Result<Self, Self::Error> {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .context("Could not create a runtime for the blocking client")?;
+
+        Ok(Self {
+            inner: client,
+            runtime: Arc::new(runtime),
+        })
+    }
+}
+
+impl BlockingBriteVerifyClient {
+    // <editor-fold desc="// Constructors ... ">
+
+    /// Create a new [`BlockingBriteVerifyClient`][BlockingBriteVerifyClient] instance
+    ///
+    /// #### Example
+    /// ```no_run
+    /// # use briteverify_rs::BlockingBriteVerifyClient;
+    /// #
+    /// # fn doc() -> anyhow::Result<()> {
+    /// let client: BlockingBriteVerifyClient = BlockingBriteVerifyClient::new("YOUR API KEY")?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn new<ApiKey: ToString>(api_key: ApiKey) -> Result<Self, errors::BriteVerifyClientError> {
+        Self::builder().api_key(api_key).build_blocking()
+    }
+
+    /// Create a new [builder][BriteVerifyClientBuilder] to incrementally
+    /// build a [`BlockingBriteVerifyClient`][BlockingBriteVerifyClient]
+    /// with a customised configuration
+    ///
+    /// #### Example
+    /// ```no_run
+    /// # use briteverify_rs::{BlockingBriteVerifyClient, BriteVerifyClientBuilder};
+    /// #
+    /// # fn doc() -> anyhow::Result<()> {
+    /// let builder: BriteVerifyClientBuilder = BlockingBriteVerifyClient::builder();
+    ///
+    /// // ... call various builder methods
+    ///
+    /// let client: BlockingBriteVerifyClient = builder.build_blocking()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn builder() -> BriteVerifyClientBuilder {
+        BriteVerifyClientBuilder::new()
+    }
+
+    /// Create a copy of the client that applies the supplied
+    /// [`RequestOptions`](RequestOptions) to every request it sends.
+    ///
+    /// See [`BriteVerifyClient::with_options`](BriteVerifyClient::with_options)
+    pub fn with_options(&self, options: RequestOptions) -> Self {
+        Self {
+            inner: self.inner.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Get the transport-independent [`BriteVerifyCore`](sansio::BriteVerifyCore)
+    /// the client uses to build requests and interpret responses
+    ///
+    /// See [`BriteVerifyClient::core`](BriteVerifyClient::core)
+    pub fn core(&self) -> &sansio::BriteVerifyCore {
+        self.inner.core()
+    }
+
+    /// Get the [`Cassette`](cassette::Cassette) the client is
+    /// recording to or replaying from, if any
+    ///
+    /// See [`BriteVerifyClient::cassette`](BriteVerifyClient::cassette)
+    pub fn cassette(&self) -> Option<&cassette::Cassette> {
+        self.inner.cassette()
+    }
+
+    /// Check whether the client is running in sandbox mode
+    /// (see [`sandbox`](crate::sandbox) for details)
+    ///
+    /// See [`BriteVerifyClient::is_sandbox`](BriteVerifyClient::is_sandbox)
+    pub fn is_sandbox(&self) -> bool {
+        self.inner.is_sandbox()
+    }
+
+    /// Get the [`DryRun`](dry_run::DryRun) log of every request the
+    /// client has validated and recorded (instead of sending),
+    /// if the client is running in dry-run mode
+    ///
+    /// See [`BriteVerifyClient::dry_run`](BriteVerifyClient::dry_run)
+    pub fn dry_run(&self) -> Option<&dry_run::DryRun> {
+        self.inner.dry_run()
+    }
+
+    /// Get the client's [`Deduplicator`](dedupe::Deduplicator), if it
+    /// was built with contact deduplication enabled
+    ///
+    /// See [`BriteVerifyClient::deduplicator`](BriteVerifyClient::deduplicator)
+    pub fn deduplicator(&self) -> Option<&dedupe::Deduplicator> {
+        self.inner.deduplicator()
+    }
+
+    /// Get the client's [`BudgetGuard`](budget::BudgetGuard),
+    /// if it was built with a credit budget
+    ///
+    /// See [`BriteVerifyClient::budget_guard`](BriteVerifyClient::budget_guard)
+    pub fn budget_guard(&self) -> Option<&budget::BudgetGuard> {
+        self.inner.budget_guard()
+    }
+
+    /// Get the client's [`CreditLedger`](ledger::CreditLedger),
+    /// if it was built with one
+    ///
+    /// See [`BriteVerifyClient::credit_ledger`](BriteVerifyClient::credit_ledger)
+    pub fn credit_ledger(&self) -> Option<&ledger::CreditLedger> {
+        self.inner.credit_ledger()
+    }
+
+    /// Get the client's [`VerificationCache`](cache::VerificationCache),
+    /// if it was built with one
+    ///
+    /// See [`BriteVerifyClient::verification_cache`](BriteVerifyClient::verification_cache)
+    pub fn verification_cache(&self) -> Option<&cache::VerificationCache> {
+        self.inner.verification_cache()
+    }
+
+    /// Get the client's [`PersistentCache`](crate::store::PersistentCache),
+    /// if it was built with one
+    ///
+    /// See [`BriteVerifyClient::persistent_cache`](BriteVerifyClient::persistent_cache)
+    #[cfg(feature = "sqlite")]
+    pub fn persistent_cache(&self) -> Option<&crate::store::PersistentCache> {
+        self.inner.persistent_cache()
+    }
+
+    /// Get the client's [`IdempotentLists`](idempotency::IdempotentLists), which
+    /// record its [idempotent](crate::idempotency) list creations
+    ///
+    /// See [`BriteVerifyClient::idempotent_lists`](BriteVerifyClient::idempotent_lists)
+    pub fn idempotent_lists(&self) -> &idempotency::IdempotentLists {
+        self.inner.idempotent_lists()
+    }
+
+    /// Get the client's [`InFlightVerifications`](coalesce::InFlightVerifications),
+    /// if it was built with request coalescing enabled
+    ///
+    /// See [`BriteVerifyClient::inflight_verifications`](BriteVerifyClient::inflight_verifications)
+    pub fn inflight_verifications(&self) -> Option<&coalesce::InFlightVerifications> {
+        self.inner.inflight_verifications()
+    }
+
+    // </editor-fold desc="// Constructors ... ">
+
+    // <editor-fold desc="// Real-Time Single Transaction Endpoints ... ">
+
+    /// Get your current account credit balance
+    ///
+    /// See [`BriteVerifyClient::current_credits`](BriteVerifyClient::current_credits)
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn current_credits(&self) -> Result<u32> {
+        self.runtime.block_on(self.inner.current_credits())
+    }
+
+    /// Get the total number of credits your account currently has in reserve
+    ///
+    /// See [`BriteVerifyClient::current_credits_in_reserve`](BriteVerifyClient::current_credits_in_reserve)
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn current_credits_in_reserve(&self) -> Result<u32> {
+        self.runtime
+            .block_on(self.inner.current_credits_in_reserve())
+    }
+
+    /// Get your account credit balance, total number of credits
+    /// in reserve, and the timestamp of when your balance was
+    /// most recently recorded
+    ///
+    /// See [`BriteVerifyClient::get_account_balance`](BriteVerifyClient::get_account_balance)
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn get_account_balance(
+        &self,
+    ) -> Result<types::AccountCreditBalance, errors::BriteVerifyClientError> {
+        self.runtime.block_on(self.inner.get_account_balance())
+    }
+
+    /// Get your account credit balance along with the
+    /// [`ResponseMetadata`](types::ResponseMetadata) of
+    /// the request that retrieved it
+    ///
+    /// See [`BriteVerifyClient::get_account_balance_with_metadata`](BriteVerifyClient::get_account_balance_with_metadata)
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn get_account_balance_with_metadata(
+        &self,
+    ) -> Result<types::Response<types::AccountCreditBalance>, errors::BriteVerifyClientError> {
+        self.runtime
+            .block_on(self.inner.get_account_balance_with_metadata())
+    }
+
+    /// Verify a "complete" contact record
+    ///
+    /// See [`BriteVerifyClient::verify_contact`](BriteVerifyClient::verify_contact)
+    #[allow(clippy::too_many_arguments)]
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn verify_contact<
+        EmailAddress: ToString + Debug,
+        PhoneNumber: ToString + Debug,
+        AddressLine1: ToString + Debug,
+        AddressLine2: ToString + Debug,
+        CityName: ToString + Debug,
+        StateNameOrAbbr: ToString + Debug,
+        ZipCode: ToString + Debug,
+    >(
+        &self,
+        email: EmailAddress,
+        phone: PhoneNumber,
+        address1: AddressLine1,
+        address2: Option<AddressLine2>,
+        city: CityName,
+        state: StateNameOrAbbr,
+        zip: ZipCode,
+    ) -> Result<types::VerificationResponse, errors::BriteVerifyClientError> {
+        self.runtime.block_on(
+            self.inner
+                .verify_contact(email, phone, address1, address2, city, state, zip),
+        )
+    }
+
+    /// Verify a "complete" contact record, returning the verification
+    /// results along with the [`ResponseMetadata`](types::ResponseMetadata)
+    /// of the request that produced them
+    ///
+    /// See [`BriteVerifyClient::verify_contact_with_metadata`](BriteVerifyClient::verify_contact_with_metadata)
+    #[allow(clippy::too_many_arguments)]
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn verify_contact_with_metadata<
+        EmailAddress: ToString + Debug,
+        PhoneNumber: ToString + Debug,
+        AddressLine1: ToString + Debug,
+        AddressLine2: ToString + Debug,
+        CityName: ToString + Debug,
+        StateNameOrAbbr: ToString + Debug,
+        ZipCode: ToString + Debug,
+    >(
+        &self,
+        email: EmailAddress,
+        phone: PhoneNumber,
+        address1: AddressLine1,
+        address2: Option<AddressLine2>,
+        city: CityName,
+        state: StateNameOrAbbr,
+        zip: ZipCode,
+    ) -> Result<types::Response<types::VerificationResponse>, errors::BriteVerifyClientError> {
+        self.runtime.block_on(
+            self.inner
+                .verify_contact_with_metadata(email, phone, address1, address2, city, state, zip),
+        )
+    }
+
+    /// Verify a single email address
+    ///
+    /// See [`BriteVerifyClient::verify_email`](BriteVerifyClient::verify_email)
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn verify_email<EmailAddress: ToString + Debug>(
+        &self,
+        email: EmailAddress,
+    ) -> Result<types::EmailVerificationArray, errors::BriteVerifyClientError> {
+        self.runtime.block_on(self.inner.verify_email(email))
+    }
+
+    /// Verify a single email address, returning the verification
+    /// results along with the [`ResponseMetadata`](types::ResponseMetadata)
+    /// of the request that produced them
+    ///
+    /// See [`BriteVerifyClient::verify_email_with_metadata`](BriteVerifyClient::verify_email_with_metadata)
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn verify_email_with_metadata<EmailAddress: ToString + Debug>(
+        &self,
+        email: EmailAddress,
+    ) -> Result<types::Response<types::EmailVerificationArray>, errors::BriteVerifyClientError>
+    {
+        self.runtime
+            .block_on(self.inner.verify_email_with_metadata(email))
+    }
+
+    /// Verify a single phone number
+    ///
+    /// See [`BriteVerifyClient::verify_phone_number`](BriteVerifyClient::verify_phone_number)
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn verify_phone_number<PhoneNumber: ToString + Debug>(
+        &self,
+        phone: PhoneNumber,
+    ) -> Result<types::PhoneNumberVerificationArray, errors::BriteVerifyClientError> {
+        self.runtime.block_on(self.inner.verify_phone_number(phone))
+    }
+
+    /// Verify a single phone number, returning the verification
+    /// results along with the [`ResponseMetadata`](types::ResponseMetadata)
+    /// of the request that produced them
+    ///
+    /// See [`BriteVerifyClient::verify_phone_number_with_metadata`](BriteVerifyClient::verify_phone_number_with_metadata)
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn verify_phone_number_with_metadata<PhoneNumber: ToString + Debug>(
+        &self,
+        phone: PhoneNumber,
+    ) -> Result<types::Response<types::PhoneNumberVerificationArray>, errors::BriteVerifyClientError>
+    {
+        self.runtime
+            .block_on(self.inner.verify_phone_number_with_metadata(phone))
+    }
+
+    /// Verify a single street address
+    ///
+    /// See [`BriteVerifyClient::verify_street_address`](BriteVerifyClient::verify_street_address)
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn verify_street_address<
+        AddressLine1: ToString + Debug,
+        AddressLine2: ToString + Debug,
+        CityName: ToString + Debug,
+        StateNameOrAbbr: ToString + Debug,
+        ZipCode: ToString + Debug,
+    >(
+        &self,
+        address1: AddressLine1,
+        address2: Option<AddressLine2>,
+        city: CityName,
+        state: StateNameOrAbbr,
+        zip: ZipCode,
+    ) -> Result<types::AddressVerificationArray, errors::BriteVerifyClientError> {
+        self.runtime.block_on(
+            self.inner
+                .verify_street_address(address1, address2, city, state, zip),
+        )
+    }
+
+    /// Verify a single street address, returning the verification
+    /// results along with the [`ResponseMetadata`](types::ResponseMetadata)
+    /// of the request that produced them
+    ///
+    /// See [`BriteVerifyClient::verify_street_address_with_metadata`](BriteVerifyClient::verify_street_address_with_metadata)
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn verify_street_address_with_metadata<
+        AddressLine1: ToString + Debug,
+        AddressLine2: ToString + Debug,
+        CityName: ToString + Debug,
+        StateNameOrAbbr: ToString + Debug,
+        ZipCode: ToString + Debug,
+    >(
+        &self,
+        address1: AddressLine1,
+        address2: Option<AddressLine2>,
+        city: CityName,
+        state: StateNameOrAbbr,
+        zip: ZipCode,
+    ) -> Result<types::Response<types::AddressVerificationArray>, errors::BriteVerifyClientError>
+    {
+        self.runtime.block_on(
+            self.inner
+                .verify_street_address_with_metadata(address1, address2, city, state, zip),
+        )
+    }
+
+    // </editor-fold desc="// Real-Time Single Transaction Endpoints ... ">
+
+    // <editor-fold desc="// Bulk Verification (v3) Endpoints ... ">
+
+    /// Retrieve the complete, unfiltered list of all bulk verification
+    /// lists created within the last 7 calendar days
+    ///
+    /// See [`BriteVerifyClient::get_lists`](BriteVerifyClient::get_lists)
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn get_lists(
+        &self,
+    ) -> Result<types::GetListStatesResponse, errors::BriteVerifyClientError> {
+        self.runtime.block_on(self.inner.get_lists())
+    }
+
+    /// Retrieve the complete list of all bulk verification lists created
+    /// within the last 7 calendar days filtered by the specified criteria
+    ///
+    /// See [`BriteVerifyClient::get_filtered_lists`](BriteVerifyClient::get_filtered_lists)
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn get_filtered_lists<
+        Date: chrono::Datelike + Debug,
+        Page: Into<u32> + Debug,
+        State: Clone + Debug + Into<types::BatchState>,
+        ExternalId: std::fmt::Display + Debug,
+    >(
+        &self,
+        page: Option<Page>,
+        date: Option<Date>,
+        state: Option<State>,
+        ext_id: Option<ExternalId>,
+    ) -> Result<types::GetListStatesResponse, errors::BriteVerifyClientError> {
+        self.runtime
+            .block_on(self.inner.get_filtered_lists(page, date, state, ext_id))
+    }
+
+    /// Retrieve the complete list of all bulk verification lists created
+    /// within the last 7 calendar days filtered by the specified criteria,
+    /// along with the [`ResponseMetadata`](types::ResponseMetadata) of the
+    /// request that retrieved them
+    ///
+    /// See [`BriteVerifyClient::get_filtered_lists_with_metadata`](BriteVerifyClient::get_filtered_lists_with_metadata)
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn get_filtered_lists_with_metadata<
+        Date: chrono::Datelike + Debug,
+        Page: Into<u32> + Debug,
+        State: Clone + Debug + Into<types::BatchState>,
+        ExternalId: std::fmt::Display + Debug,
+    >(
+        &self,
+        page: Option<Page>,
+        date: Option<Date>,
+        state: Option<State>,
+        ext_id: Option<ExternalId>,
+    ) -> Result<types::Response<types::GetListStatesResponse>, errors::BriteVerifyClientError> {
+        self.runtime.block_on(
+            self.inner
+                .get_filtered_lists_with_metadata(page, date, state, ext_id),
+        )
+    }
+
+    /// Retrieve the complete list of all bulk verification lists filtered
+    /// by the specified date
+    ///
+    /// See [`BriteVerifyClient::get_lists_by_date`](BriteVerifyClient::get_lists_by_date)
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn get_lists_by_date<Date: chrono::Datelike + Debug>(
+        &self,
+        date: Date,
+    ) -> Result<types::GetListStatesResponse, errors::BriteVerifyClientError> {
+        self.runtime.block_on(self.inner.get_lists_by_date(date))
+    }
+
+    /// Retrieve the specified "page" of bulk verification lists
+    ///
+    /// See [`BriteVerifyClient::get_lists_by_page`](BriteVerifyClient::get_lists_by_page)
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn get_lists_by_page<Page: Into<u32> + Debug>(
+        &self,
+        page: Page,
+    ) -> Result<types::GetListStatesResponse, errors::BriteVerifyClientError> {
+        self.runtime.block_on(self.inner.get_lists_by_page(page))
+    }
+
+    /// Retrieve the complete list of all bulk verification lists created
+    /// within the last 7 calendar days whose status matches the specified
+    /// value
+    ///
+    /// See [`BriteVerifyClient::get_lists_by_state`](BriteVerifyClient::get_lists_by_state)
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn get_lists_by_state(
+        &self,
+        state: types::BatchState,
+    ) -> Result<types::GetListStatesResponse, errors::BriteVerifyClientError> {
+        self.runtime.block_on(self.inner.get_lists_by_state(state))
+    }
+
+    /// Create a new bulk verification list with the supplied records
+    /// and (optionally) queue it for immediate processing
+    ///
+    /// See [`BriteVerifyClient::create_list`](BriteVerifyClient::create_list)
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn create_list<
+        Contact: Into<types::VerificationRequest> + Debug,
+        ContactCollection: IntoIterator<Item = Contact> + Debug,
+    >(
+        &self,
+        contacts: Option<ContactCollection>,
+        auto_start: bool,
+    ) -> Result<types::CreateListResponse, errors::BriteVerifyClientError> {
+        self.runtime
+            .block_on(self.inner.create_list(contacts, auto_start))
+    }
+
+    /// Create a new bulk verification list with the supplied records,
+    /// (optionally) queue it for immediate processing, and return the
+    /// new list along with the [`ResponseMetadata`](types::ResponseMetadata)
+    /// of the request that created it
+    ///
+    /// See [`BriteVerifyClient::create_list_with_metadata`](BriteVerifyClient::create_list_with_metadata)
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn create_list_with_metadata<
+        Contact: Into<types::VerificationRequest> + Debug,
+        ContactCollection: IntoIterator<Item = Contact> + Debug,
+    >(
+        &self,
+        contacts: Option<ContactCollection>,
+        auto_start: bool,
+    ) -> Result<types::Response<types::CreateListResponse>, errors::BriteVerifyClientError> {
+        self.runtime
+            .block_on(self.inner.create_list_with_metadata(contacts, auto_start))
+    }
+
+    /// Create a new bulk verification list with the supplied records and
+    /// (optionally) queue it for immediate processing, unless a list was
+    /// already created for the supplied idempotency token
+    ///
+    /// See [`BriteVerifyClient::create_list_idempotent`](BriteVerifyClient::create_list_idempotent)
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn create_list_idempotent<
+        Token: ToString + Debug,
+        Contact: Into<types::VerificationRequest> + Debug,
+        ContactCollection: IntoIterator<Item = Contact> + Debug,
+    >(
+        &self,
+        token: Token,
+        contacts: ContactCollection,
+        auto_start: bool,
+    ) -> Result<types::CreateListResponse, errors::BriteVerifyClientError> {
+        self.runtime.block_on(
+            self.inner
+                .create_list_idempotent(token, contacts, auto_start),
+        )
+    }
+
+    /// Create a new bulk verification list with the supplied records (or
+    /// reuse the list already created for the supplied idempotency token),
+    /// and return it along with the [`ResponseMetadata`](types::ResponseMetadata)
+    /// of the request that created (or retrieved) it
+    ///
+    /// See [`BriteVerifyClient::create_list_idempotent_with_metadata`](BriteVerifyClient::create_list_idempotent_with_metadata)
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn create_list_idempotent_with_metadata<
+        Token: ToString + Debug,
+        Contact: Into<types::VerificationRequest> + Debug,
+        ContactCollection: IntoIterator<Item = Contact> + Debug,
+    >(
+        &self,
+        token: Token,
+        contacts: ContactCollection,
+        auto_start: bool,
+    ) -> Result<types::Response<types::CreateListResponse>, errors::BriteVerifyClientError> {
+        self.runtime.block_on(
+            self.inner
+                .create_list_idempotent_with_metadata(token, contacts, auto_start),
+        )
+    }
+
+    /// Append records to the specified bulk verification list and (optionally)
+    /// queue it for immediate processing
+    ///
+    /// See [`BriteVerifyClient::update_list`](BriteVerifyClient::update_list)
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn update_list<
+        ListId: ToString + Debug,
+        Contact: Into<types::VerificationRequest> + Debug,
+        ContactCollection: IntoIterator<Item = Contact> + Debug,
+    >(
+        &self,
+        list_id: ListId,
+        contacts: ContactCollection,
+        auto_start: bool,
+    ) -> Result<types::UpdateListResponse, errors::BriteVerifyClientError> {
+        self.runtime
+            .block_on(self.inner.update_list(list_id, contacts, auto_start))
+    }
+
+    /// Append records to the specified bulk verification list, (optionally)
+    /// queue it for immediate processing, and return the updated list along
+    /// with the [`ResponseMetadata`](types::ResponseMetadata) of the request
+    /// that updated it
+    ///
+    /// See [`BriteVerifyClient::update_list_with_metadata`](BriteVerifyClient::update_list_with_metadata)
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn update_list_with_metadata<
+        ListId: ToString + Debug,
+        Contact: Into<types::VerificationRequest> + Debug,
+        ContactCollection: IntoIterator<Item = Contact> + Debug,
+    >(
+        &self,
+        list_id: ListId,
+        contacts: ContactCollection,
+        auto_start: bool,
+    ) -> Result<types::Response<types::UpdateListResponse>, errors::BriteVerifyClientError> {
+        self.runtime.block_on(
+            self.inner
+                .update_list_with_metadata(list_id, contacts, auto_start),
+        )
+    }
+
+    /// Retrieve current "state" of the specified bulk verification list
+    ///
+    /// See [`BriteVerifyClient::get_list_by_id`](BriteVerifyClient::get_list_by_id)
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn get_list_by_id<ListId: ToString + Debug>(
+        &self,
+        list_id: ListId,
+    ) -> Result<types::VerificationListState, errors::BriteVerifyClientError> {
+        self.runtime.block_on(self.inner.get_list_by_id(list_id))
+    }
+
+    /// Retrieve current "state" of the specified bulk verification list
+    /// along with the [`ResponseMetadata`](types::ResponseMetadata) of
+    /// the request that retrieved it
+    ///
+    /// See [`BriteVerifyClient::get_list_by_id_with_metadata`](BriteVerifyClient::get_list_by_id_with_metadata)
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn get_list_by_id_with_metadata<ListId: ToString + Debug>(
+        &self,
+        list_id: ListId,
+    ) -> Result<types::Response<types::VerificationListState>, errors::BriteVerifyClientError> {
+        self.runtime
+            .block_on(self.inner.get_list_by_id_with_metadata(list_id))
+    }
+
+    /// Retrieve current "state" of a bulk verification list tied to an
+    /// externally supplied / customer-specific identifier
+    ///
+    /// See [`BriteVerifyClient::get_list_by_external_id`](BriteVerifyClient::get_list_by_external_id)
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn get_list_by_external_id<
+        ListId: ToString + Debug,
+        ExternalId: std::fmt::Display + Debug,
+    >(
+        &self,
+        list_id: ListId,
+        external_id: ExternalId,
+    ) -> Result<types::VerificationListState, errors::BriteVerifyClientError> {
+        self.runtime
+            .block_on(self.inner.get_list_by_external_id(list_id, external_id))
+    }
+
+    /// Retrieve current "state" of a bulk verification list tied to an
+    /// externally supplied / customer-specific identifier along with the
+    /// [`ResponseMetadata`](types::ResponseMetadata) of the request that
+    /// retrieved it
+    ///
+    /// See [`BriteVerifyClient::get_list_by_external_id_with_metadata`](BriteVerifyClient::get_list_by_external_id_with_metadata)
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn get_list_by_external_id_with_metadata<
+        ListId: ToString + Debug,
+        ExternalId: std::fmt::Display + Debug,
+    >(
+        &self,
+        list_id: ListId,
+        external_id: ExternalId,
+    ) -> Result<types::Response<types::VerificationListState>, errors::BriteVerifyClientError> {
+        self.runtime.block_on(
+            self.inner
+                .get_list_by_external_id_with_metadata(list_id, external_id),
+        )
+    }
+
+    /// Delete the specified batch verification list
+    ///
+    /// See [`BriteVerifyClient::delete_list_by_id`](BriteVerifyClient::delete_list_by_id)
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn delete_list_by_id<ListId: ToString + Debug>(
+        &self,
+        list_id: ListId,
+    ) -> Result<types::DeleteListResponse, errors::BriteVerifyClientError> {
+        self.runtime.block_on(self.inner.delete_list_by_id(list_id))
+    }
+
+    /// Delete the specified batch verification list, returning its final
+    /// state along with the [`ResponseMetadata`](types::ResponseMetadata)
+    /// of the request that deleted it
+    ///
+    /// See [`BriteVerifyClient::delete_list_by_id_with_metadata`](BriteVerifyClient::delete_list_by_id_with_metadata)
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn delete_list_by_id_with_metadata<ListId: ToString + Debug>(
+        &self,
+        list_id: ListId,
+    ) -> Result<types::Response<types::DeleteListResponse>, errors::BriteVerifyClientError> {
+        self.runtime
+            .block_on(self.inner.delete_list_by_id_with_metadata(list_id))
+    }
+
+    /// Abandon the specified unprocessed bulk verification list
+    ///
+    /// See [`BriteVerifyClient::terminate_list_by_id`](BriteVerifyClient::terminate_list_by_id)
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn terminate_list_by_id<ListId: ToString + Debug>(
+        &self,
+        list_id: ListId,
+    ) -> Result<types::UpdateListResponse, errors::BriteVerifyClientError> {
+        self.runtime
+            .block_on(self.inner.terminate_list_by_id(list_id))
+    }
+
+    /// Abandon the specified unprocessed bulk verification list, returning
+    /// its final state along with the [`ResponseMetadata`](types::ResponseMetadata)
+    /// of the request that abandoned it
+    ///
+    /// See [`BriteVerifyClient::terminate_list_by_id_with_metadata`](BriteVerifyClient::terminate_list_by_id_with_metadata)
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn terminate_list_by_id_with_metadata<ListId: ToString + Debug>(
+        &self,
+        list_id: ListId,
+    ) -> Result<types::Response<types::UpdateListResponse>, errors::BriteVerifyClientError> {
+        self.runtime
+            .block_on(self.inner.terminate_list_by_id_with_metadata(list_id))
+    }
+
+    /// Queue the specified (open) bulk verification list for immediate processing
+    ///
+    /// See [`BriteVerifyClient::queue_list_for_processing`](BriteVerifyClient::queue_list_for_processing)
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn queue_list_for_processing<ListId: ToString + Debug>(
+        &self,
+        list_id: ListId,
+    ) -> Result<types::UpdateListResponse, errors::BriteVerifyClientError> {
+        self.runtime
+            .block_on(self.inner.queue_list_for_processing(list_id))
+    }
+
+    /// Queue the specified (open) bulk verification list for immediate
+    /// processing, returning its updated state along with the
+    /// [`ResponseMetadata`](types::ResponseMetadata) of the request
+    /// that queued it
+    ///
+    /// See [`BriteVerifyClient::queue_list_for_processing_with_metadata`](BriteVerifyClient::queue_list_for_processing_with_metadata)
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn queue_list_for_processing_with_metadata<ListId: ToString + Debug>(
+        &self,
+        list_id: ListId,
+    ) -> Result<types::Response<types::UpdateListResponse>, errors::BriteVerifyClientError> {
+        self.runtime
+            .block_on(self.inner.queue_list_for_processing_with_metadata(list_id))
+    }
+
+    /// Get the verification results for the specified bulk verification list
+    ///
+    /// See [`BriteVerifyClient::get_results_by_list_id`](BriteVerifyClient::get_results_by_list_id)
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn get_results_by_list_id<ListId: ToString + Debug>(
+        &self,
+        list_id: ListId,
+    ) -> Result<Vec<types::BulkVerificationResult>, errors::BriteVerifyClientError> {
+        self.runtime
+            .block_on(self.inner.get_results_by_list_id(list_id))
+    }
+
+    // </editor-fold desc="// Bulk Verification (v3) Endpoints ... ">
+
+    // <editor-fold desc="// Keyed Bulk Verification Jobs ... ">
+
+    /// Create a new bulk verification job from the supplied contacts,
+    /// each paired with a caller-supplied key, and (optionally) queue
+    /// its lists for immediate processing
+    ///
+    /// See [`BriteVerifyClient::create_job`](BriteVerifyClient::create_job)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(contacts)))]
+    pub fn create_job<
+        Key,
+        Contact: Into<types::VerificationRequest>,
+        ContactCollection: IntoIterator<Item = (Key, Contact)>,
+    >(
+        &self,
+        contacts: ContactCollection,
+        auto_start: bool,
+    ) -> Result<jobs::BulkJob<Key>, errors::BriteVerifyClientError> {
+        self.runtime
+            .block_on(self.inner.create_job(contacts, auto_start))
+    }
+
+    /// Create a new bulk verification job from the supplied contacts,
+    /// each paired with a caller-supplied key, split across lists of
+    /// (at most) the specified size
+    ///
+    /// See [`BriteVerifyClient::create_job_with_list_size`](BriteVerifyClient::create_job_with_list_size)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(contacts)))]
+    pub fn create_job_with_list_size<
+        Key,
+        Contact: Into<types::VerificationRequest>,
+        ContactCollection: IntoIterator<Item = (Key, Contact)>,
+    >(
+        &self,
+        contacts: ContactCollection,
+        auto_start: bool,
+        list_size: usize,
+    ) -> Result<jobs::BulkJob<Key>, errors::BriteVerifyClientError> {
+        self.runtime.block_on(
+            self.inner
+                .create_job_with_list_size(contacts, auto_start, list_size),
+        )
+    }
+
+    /// Get the verification results of every list in the supplied
+    /// job, each paired with the key of the contact it belongs to
+    ///
+    /// See [`BriteVerifyClient::get_job_results`](BriteVerifyClient::get_job_results)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(job)))]
+    pub fn get_job_results<Key: Clone>(
+        &self,
+        job: &jobs::BulkJob<Key>,
+    ) -> Result<Vec<jobs::KeyedBulkResult<Key>>, errors::BriteVerifyClientError> {
+        self.runtime.block_on(self.inner.get_job_results(job))
+    }
+
+    // </editor-fold desc="// Keyed Bulk Verification Jobs ... ">
+}
+
+// </editor-fold desc="// Client ...">
@@ -0,0 +1,502 @@
+//! ## Credit Budget Guard
+//!
+//! A [`BriteVerifyClient`](crate::BriteVerifyClient) built with a
+//! [`credit_budget`](crate::BriteVerifyClientBuilder::credit_budget)
+//! estimates the number of credits every single-transaction verification
+//! and bulk list upload will cost (one credit per email address, phone
+//! number, and street address being verified) before sending it, and
+//! refuses to send it if:
+//!
+//! - its cost exceeds the account's live (non-reserve) credit balance,
+//!   less any configured minimum balance, in which case it's rejected with
+//!   [`InsufficientCredits`](crate::errors::BriteVerifyClientError::InsufficientCredits)
+//! - its cost, plus that of everything the client has already sent,
+//!   would exceed the budget's spend cap, in which case it's rejected with
+//!   [`SpendCapExceeded`](crate::errors::BriteVerifyClientError::SpendCapExceeded)
+//!
+//! The credits spent against the cap can be inspected (or reset, e.g.
+//! at the start of each month) via the client's
+//! [`BudgetGuard`](BudgetGuard).
+//!
+//! The live balance is fetched at most once per
+//! [`balance_ttl`](CreditBudget::balance_ttl) (30 seconds by default).
+//! In between, the guard deducts the estimated cost of every request
+//! it lets through from its copy of the balance, under the same lock
+//! that reserves credits against the spend cap, so concurrent requests
+//! can't all be approved against the same balance.
+//!
+//! ___
+//! **NOTE:** A budget is only enforced by the client (and its clones)
+//! it was built for, and the credits spent against its cap are only
+//! kept in memory. To hold several processes (or restarts) to the
+//! same cap, persist [`spent`](BudgetGuard::spent) and pass it to
+//! [`already_spent`](CreditBudget::already_spent) when building the
+//! next client. Spending by other processes is only noticed when the
+//! live balance is next refreshed.
+//! ___
+//!
+//! ## Basic Usage
+//! ```no_run
+//! # use briteverify_rs::{BriteVerifyClient, budget::CreditBudget};
+//! #
+//! # async fn doc() -> anyhow::Result<()> {
+//! let client: BriteVerifyClient = BriteVerifyClient::builder()
+//!     .api_key("YOUR API KEY")
+//!     .credit_budget(CreditBudget::new().spend_cap(10_000).minimum_balance(500))
+//!     .build()?;
+//!
+//! client.verify_email("test@example.com").await?;
+//!
+//! assert_eq!(client.budget_guard().map(|guard| guard.spent()), Some(1));
+//! # Ok(())
+//! # }
+//! ```
+//
+// Standard Library Imports
+use std::{
+    sync::{Mutex, MutexGuard},
+    time::{Duration, Instant},
+};
+
+// Crate-Level Imports
+use crate::{errors::BriteVerifyClientError, types::VerificationRequest};
+
+// <editor-fold desc="// Constants ...">
+
+/// How long the account's live credit balance
+/// is trusted for before being fetched again
+pub const DEFAULT_BALANCE_TTL: Duration = Duration::from_secs(30);
+
+// </editor-fold desc="// Constants ...">
+
+// <editor-fold desc="// Credit Estimation ...">
+
+/// Estimate the number of credits the verification of the
+/// supplied contact will cost (one per email address, phone
+/// number, and street address)
+pub fn estimate_credits(contact: &VerificationRequest) -> u64 {
+    [
+        contact.email.is_some(),
+        contact.phone.is_some(),
+        contact.address.is_some(),
+    ]
+    .into_iter()
+    .filter(|present| *present)
+    .count() as u64
+}
+
+/// Estimate the number of credits the verification
+/// of every supplied contact will cost
+pub fn estimate_list_credits<'contacts>(
+    contacts: impl IntoIterator<Item = &'contacts VerificationRequest>,
+) -> u64 {
+    contacts.into_iter().map(estimate_credits).sum()
+}
+
+// </editor-fold desc="// Credit Estimation ...">
+
+// <editor-fold desc="// CreditBudget ...">
+
+/// The limits a client's credit spending is held to
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct CreditBudget {
+    spend_cap: Option<u64>,
+    minimum_balance: u64,
+    skip_balance_check: bool,
+    balance_ttl: Option<Duration>,
+    already_spent: u64,
+}
+
+impl CreditBudget {
+    /// Create a new `CreditBudget` that only refuses
+    /// requests exceeding the live credit balance
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the total number of credits the client may
+    /// spend (until its budget guard is [reset](BudgetGuard::reset))
+    pub fn spend_cap(mut self, credits: u64) -> Self {
+        self.spend_cap = Some(credits);
+        self
+    }
+
+    /// Set the number of credits that must remain
+    /// in the account's balance after every request
+    pub fn minimum_balance(mut self, credits: u64) -> Self {
+        self.minimum_balance = credits;
+        self
+    }
+
+    /// Enable or disable checking the account's live credit
+    /// balance before every request (which costs an extra
+    /// request per [`balance_ttl`](CreditBudget::balance_ttl),
+    /// but no credits)
+    ///
+    /// ___
+    /// **NOTE:** Balance checks are `enabled` by default.
+    /// ___
+    pub fn check_balance(mut self, value: bool) -> Self {
+        self.skip_balance_check = !value;
+        self
+    }
+
+    /// Check whether the account's live credit
+    /// balance is checked before every request
+    pub fn checks_balance(&self) -> bool {
+        !self.skip_balance_check
+    }
+
+    /// Set how long the account's live credit balance is
+    /// trusted for (less the cost of every request sent
+    /// in the meantime) before being fetched again
+    ///
+    /// ___
+    /// **NOTE:** A TTL of zero fetches the live
+    /// balance before every request.
+    /// ___
+    pub fn balance_ttl(mut self, ttl: Duration) -> Self {
+        self.balance_ttl = Some(ttl);
+        self
+    }
+
+    /// Set the number of credits already spent against the
+    /// spend cap (e.g. by a previous run of the application)
+    pub fn already_spent(mut self, credits: u64) -> Self {
+        self.already_spent = credits;
+        self
+    }
+
+    /// How long the account's live credit balance is trusted for
+    pub fn balance_refresh_interval(&self) -> Duration {
+        self.balance_ttl.unwrap_or(DEFAULT_BALANCE_TTL)
+    }
+}
+
+// </editor-fold desc="// CreditBudget ...">
+
+// <editor-fold desc="// BudgetGuard ...">
+
+/// [internal-implementation]
+/// The account's live credit balance (less the cost
+/// of every request sent since it was fetched)
+#[derive(Clone, Copy, Debug)]
+struct CachedBalance {
+    credits: u64,
+    fetched: Instant,
+}
+
+/// [internal-implementation]
+/// The mutable state of a budget guard
+#[derive(Debug, Default)]
+struct GuardState {
+    spent: u64,
+    balance: Option<CachedBalance>,
+    /// How many times the cached balance has been replaced
+    generation: u64,
+}
+
+/// [internal-implementation]
+/// The credits counted as spent for a single request
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct Reservation {
+    credits: u64,
+    /// The generation of the cached balance
+    /// the credits were deducted from
+    generation: u64,
+}
+
+/// Holds a client's credit spending to its [`CreditBudget`](CreditBudget)
+#[derive(Debug)]
+pub struct BudgetGuard {
+    budget: CreditBudget,
+    state: Mutex<GuardState>,
+}
+
+impl BudgetGuard {
+    /// Create a new `BudgetGuard` for the supplied budget
+    pub fn new(budget: CreditBudget) -> Self {
+        Self {
+            budget,
+            state: Mutex::new(GuardState {
+                spent: budget.already_spent,
+                balance: None,
+                generation: 0,
+            }),
+        }
+    }
+
+    /// The budget being enforced
+    pub fn budget(&self) -> &CreditBudget {
+        &self.budget
+    }
+
+    /// The (estimated) number of credits spent
+    /// since the guard was created or last reset
+    pub fn spent(&self) -> u64 {
+        self._state().spent
+    }
+
+    /// The number of credits that may still be spent before
+    /// the spend cap is reached, if the budget has one
+    pub fn remaining(&self) -> Option<u64> {
+        self.budget
+            .spend_cap
+            .map(|cap| cap.saturating_sub(self.spent()))
+    }
+
+    /// Reset the number of credits spent to zero
+    pub fn reset(&self) {
+        self._state().spent = 0;
+    }
+
+    // <editor-fold desc="// Internal Utility Methods ... ">
+
+    /// [internal-implementation]
+    /// Lock the guard's mutable state
+    fn _state(&self) -> MutexGuard<'_, GuardState> {
+        self.state
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    /// [internal-implementation]
+    /// Check whether the account's live credit balance
+    /// needs to be fetched (again) before the next request
+    pub(crate) fn _needs_balance(&self, now: Instant) -> bool {
+        if !self.budget.checks_balance() {
+            return false;
+        }
+
+        match self._state().balance {
+            Some(balance) => {
+                now.saturating_duration_since(balance.fetched)
+                    >= self.budget.balance_refresh_interval()
+            }
+            None => true,
+        }
+    }
+
+    /// [internal-implementation]
+    /// Check that the supplied number of credits fits within
+    /// both the budget and the account's (cached) live balance,
+    /// and count them as spent if so, first replacing the cached
+    /// balance with the supplied freshly fetched one (if any)
+    pub(crate) fn _reserve(
+        &self,
+        required: u64,
+        now: Instant,
+        fetched: Option<u32>,
+    ) -> Result<Reservation, BriteVerifyClientError> {
+        let mut state = self._state();
+
+        if let Some(credits) = fetched {
+            state.balance = Some(CachedBalance {
+                credits: u64::from(credits),
+                fetched: now,
+            });
+            state.generation += 1;
+        }
+
+        let checked = state.balance.filter(|_| self.budget.checks_balance());
+
+        if let Some(balance) = checked {
+            let available = balance.credits.saturating_sub(self.budget.minimum_balance);
+
+            if required > available {
+                return Err(BriteVerifyClientError::InsufficientCredits {
+                    required,
+                    available,
+                });
+            }
+        }
+
+        if let Some(cap) = self.budget.spend_cap {
+            let remaining = cap.saturating_sub(state.spent);
+
+            if required > remaining {
+                return Err(BriteVerifyClientError::SpendCapExceeded {
+                    required,
+                    remaining,
+                });
+            }
+        }
+
+        if let Some(balance) = state.balance.as_mut().filter(|_| checked.is_some()) {
+            balance.credits -= required;
+        }
+
+        state.spent += required;
+
+        Ok(Reservation {
+            credits: required,
+            generation: state.generation,
+        })
+    }
+
+    /// [internal-implementation]
+    /// Stop counting the supplied (failed) request's credits
+    /// as spent, crediting them back to the cached balance
+    /// unless it was refreshed after they were deducted
+    /// (in which case the fresh balance already reflects
+    /// whatever the request did or didn't cost)
+    pub(crate) fn _refund(&self, reservation: Reservation) {
+        let mut state = self._state();
+
+        state.spent = state.spent.saturating_sub(reservation.credits);
+
+        if state.generation != reservation.generation {
+            return;
+        }
+
+        if let Some(balance) = state.balance.as_mut() {
+            balance.credits += reservation.credits;
+        }
+    }
+
+    // </editor-fold desc="// Internal Utility Methods ... ">
+}
+
+// </editor-fold desc="// BudgetGuard ...">
+
+// <editor-fold desc="// I/O-Free Tests ...">
+
+#[cfg(test)]
+mod tests {
+    // Standard Library Imports
+    use std::time::{Duration, Instant};
+
+    // Third-Party Dependencies
+    use pretty_assertions::assert_eq;
+
+    // Crate-Level Imports
+    use super::{BudgetGuard, CreditBudget};
+    use crate::{
+        errors::BriteVerifyClientError,
+        types::{StreetAddressArray, VerificationRequest},
+    };
+
+    /// Test that credit costs are estimated per verified value
+    #[rstest::rstest]
+    fn test_estimate_credits() {
+        let contact = VerificationRequest {
+            email: Some("test@example.com".to_string()),
+            phone: None,
+            address: Some(StreetAddressArray::from_values(
+                "123 Main St",
+                None,
+                "Any Town",
+                "CA",
+                "90210",
+            )),
+        };
+
+        assert_eq!(2, super::estimate_credits(&contact));
+        assert_eq!(
+            3,
+            super::estimate_list_credits(&[
+                contact,
+                VerificationRequest::try_from("+15555555555").unwrap()
+            ])
+        );
+    }
+
+    /// Test that requests exceeding the live balance
+    /// or the spend cap are refused
+    #[rstest::rstest]
+    fn test_reserve_credits() {
+        let now = Instant::now();
+        let guard = BudgetGuard::new(CreditBudget::new().spend_cap(10).minimum_balance(5));
+
+        assert!(matches!(
+            guard._reserve(6, now, Some(10)),
+            Err(BriteVerifyClientError::InsufficientCredits {
+                required: 6,
+                available: 5
+            })
+        ));
+
+        let reserved = guard._reserve(8, now, Some(100)).unwrap();
+
+        assert_eq!(Some(2), guard.remaining());
+        assert!(matches!(
+            guard._reserve(3, now, Some(100)),
+            Err(BriteVerifyClientError::SpendCapExceeded {
+                required: 3,
+                remaining: 2
+            })
+        ));
+
+        guard._refund(reserved);
+        guard._reserve(3, now, Some(100)).unwrap();
+
+        assert_eq!(3, guard.spent());
+
+        guard.reset();
+
+        assert_eq!(0, guard.spent());
+    }
+
+    /// Test that the live balance is cached, and that every
+    /// reserved request is deducted from the cached balance
+    #[rstest::rstest]
+    fn test_cached_balance() {
+        let now = Instant::now();
+        let guard = BudgetGuard::new(
+            CreditBudget::new()
+                .minimum_balance(1)
+                .balance_ttl(Duration::from_secs(60))
+                .already_spent(7),
+        );
+
+        assert!(guard._needs_balance(now));
+
+        let reserved = guard._reserve(2, now, Some(4)).unwrap();
+
+        assert!(!guard._needs_balance(now + Duration::from_secs(59)));
+        assert!(matches!(
+            guard._reserve(2, now, None),
+            Err(BriteVerifyClientError::InsufficientCredits {
+                required: 2,
+                available: 1
+            })
+        ));
+
+        guard._refund(reserved);
+        guard._reserve(3, now, None).unwrap();
+
+        assert_eq!(10, guard.spent());
+        assert!(guard._needs_balance(now + Duration::from_secs(60)));
+        assert!(!BudgetGuard::new(CreditBudget::new().check_balance(false))._needs_balance(now));
+    }
+
+    /// Test that refunds are only credited back to the cached
+    /// balance they were deducted from, not a refreshed one
+    #[rstest::rstest]
+    fn test_refund_after_refresh() {
+        let now = Instant::now();
+        let guard = BudgetGuard::new(CreditBudget::new());
+
+        let stale = guard._reserve(3, now, Some(5)).unwrap();
+
+        // the refreshed balance already reflects the first request
+        let fresh = guard._reserve(1, now, Some(2)).unwrap();
+
+        guard._refund(stale);
+
+        assert!(matches!(
+            guard._reserve(2, now, None),
+            Err(BriteVerifyClientError::InsufficientCredits {
+                required: 2,
+                available: 1
+            })
+        ));
+
+        guard._refund(fresh);
+        guard._reserve(2, now, None).unwrap();
+
+        assert_eq!(2, guard.spent());
+    }
+}
+
+// </editor-fold desc="// I/O-Free Tests ...">
@@ -0,0 +1,604 @@
+//! ## Single-Transaction Verification Cache
+//!
+//! A [`BriteVerifyClient`](crate::BriteVerifyClient) built with a
+//! [`verification_cache`](crate::BriteVerifyClientBuilder::verification_cache)
+//! remembers the response to every single-transaction verification it
+//! sends, and answers identical verifications from memory (without
+//! spending any credits) until the response expires.
+//!
+//! Requests are considered identical if their normalized values (see
+//! [`ContactKey`](crate::dedupe::ContactKey)) are, so `Test@Example.com`
+//! and `test@example.com` share a cached response.
+//!
+//! Each response expires after the time-to-live configured for its
+//! [`VerificationStatus`](VerificationStatus), which allows e.g. valid
+//! addresses to be cached for a day while unknown ones are re-verified
+//! after a few minutes. Responses for requests verifying more than one
+//! value expire after the shortest of their values' time-to-lives.
+//! Once the cache is full, the least recently used response is evicted.
+//!
+//! Expiry is measured with the client's [`Clock`](crate::clock::Clock).
+//!
+//! Cached responses are returned with the metadata of the exchange
+//! that originally produced them, minus anything specific to that
+//! exchange (i.e. its request id, latency, and retries), and can be
+//! told apart with [`is_cached`](crate::types::ResponseMetadata::is_cached).
+//!
+//! ## Basic Usage
+//! ```no_run
+//! # use std::time::Duration;
+//! # use briteverify_rs::{
+//! #     BriteVerifyClient,
+//! #     cache::CachePolicy,
+//! #     types::VerificationStatus,
+//! # };
+//! #
+//! # async fn doc() -> anyhow::Result<()> {
+//! let client: BriteVerifyClient = BriteVerifyClient::builder()
+//!     .api_key("YOUR API KEY")
+//!     .verification_cache(
+//!         CachePolicy::new()
+//!             .capacity(50_000)
+//!             .ttl(VerificationStatus::Valid, Duration::from_secs(7 * 24 * 3600))
+//!             .ttl(VerificationStatus::Unknown, Duration::from_secs(60)),
+//!     )
+//!     .build()?;
+//!
+//! client.verify_email("test@example.com").await?;
+//! client.verify_email("TEST@example.com").await?; // answered from the cache
+//!
+//! let stats = client.verification_cache().unwrap().stats();
+//!
+//! assert_eq!((stats.hits, stats.misses), (1, 1));
+//! # Ok(())
+//! # }
+//! ```
+//
+// Standard Library Imports
+use std::{
+    collections::{BTreeMap, HashMap},
+    sync::{Mutex, MutexGuard},
+    time::{Duration, Instant},
+};
+
+// Crate-Level Imports
+use crate::{
+    dedupe::{ContactKey, DedupePolicy},
+    types::{Response, VerificationRequest, VerificationResponse, VerificationStatus},
+};
+
+// <editor-fold desc="// Constants ...">
+
+/// The default maximum number of cached responses
+pub const DEFAULT_CACHE_CAPACITY: usize = 10_000;
+
+/// The name of the header included in
+/// every response answered from a cache
+pub(crate) const CACHE_HEADER: &str = "x-briteverify-cache";
+
+// </editor-fold desc="// Constants ...">
+
+// <editor-fold desc="// CachePolicy ...">
+
+/// How many single-transaction verification
+/// responses are cached, and for how long
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CachePolicy {
+    capacity: usize,
+    valid: Duration,
+    invalid: Duration,
+    accept_all: Duration,
+    unknown: Duration,
+}
+
+impl Default for CachePolicy {
+    fn default() -> Self {
+        Self {
+            capacity: DEFAULT_CACHE_CAPACITY,
+            valid: Duration::from_secs(24 * 3600),
+            invalid: Duration::from_secs(24 * 3600),
+            accept_all: Duration::from_secs(6 * 3600),
+            unknown: Duration::from_secs(5 * 60),
+        }
+    }
+}
+
+impl CachePolicy {
+    /// Create a new `CachePolicy` that caches up to 10,000
+    /// responses, for a day if they're `valid` or `invalid`,
+    /// six hours if they're `accept_all`, and five minutes
+    /// if they're `unknown`
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the maximum number of cached responses
+    pub fn capacity(mut self, value: usize) -> Self {
+        self.capacity = value;
+        self
+    }
+
+    /// Set how long responses with the supplied status
+    /// are cached (a duration of zero disables caching
+    /// responses with that status entirely, while one too
+    /// large to represent caches them indefinitely)
+    pub fn ttl(mut self, status: VerificationStatus, value: Duration) -> Self {
+        match status {
+            VerificationStatus::Valid => self.valid = value,
+            VerificationStatus::Invalid => self.invalid = value,
+            VerificationStatus::AcceptAll => self.accept_all = value,
+            VerificationStatus::Unknown => self.unknown = value,
+        }
+
+        self
+    }
+
+    /// How long responses with the supplied status are cached
+    pub fn ttl_for(&self, status: VerificationStatus) -> Duration {
+        match status {
+            VerificationStatus::Valid => self.valid,
+            VerificationStatus::Invalid => self.invalid,
+            VerificationStatus::AcceptAll => self.accept_all,
+            VerificationStatus::Unknown => self.unknown,
+        }
+    }
+
+    /// How long the supplied response would be cached
+    /// (i.e. the shortest time-to-live of its values)
+    pub fn ttl_of(&self, response: &VerificationResponse) -> Duration {
+        [
+            response.email.as_ref().map(|email| email.status),
+            response.phone.as_ref().map(|phone| phone.status),
+            response.address.as_ref().map(|address| address.status),
+        ]
+        .into_iter()
+        .flatten()
+        .map(|status| self.ttl_for(status))
+        .min()
+        .unwrap_or_else(|| self.ttl_for(VerificationStatus::Unknown))
+    }
+}
+
+// </editor-fold desc="// CachePolicy ...">
+
+// <editor-fold desc="// CacheStats ...">
+
+/// A snapshot of a verification cache's usage
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct CacheStats {
+    /// The number of verifications answered from the cache
+    pub hits: u64,
+    /// The number of verifications the cache couldn't answer
+    pub misses: u64,
+    /// The number of responses evicted to make room for newer ones
+    pub evictions: u64,
+    /// The number of responses discarded because they expired
+    pub expirations: u64,
+    /// The number of responses currently cached
+    pub len: usize,
+}
+
+impl CacheStats {
+    /// The fraction of verifications answered
+    /// from the cache (or zero, if there were none)
+    pub fn hit_rate(&self) -> f64 {
+        match self.hits + self.misses {
+            0 => 0.0,
+            total => self.hits as f64 / total as f64,
+        }
+    }
+}
+
+// </editor-fold desc="// CacheStats ...">
+
+// <editor-fold desc="// VerificationCache ...">
+
+/// [internal-implementation]
+/// A single cached response
+#[derive(Debug)]
+struct CacheEntry {
+    response: Response<VerificationResponse>,
+    /// When the entry expires (if ever)
+    expires: Option<Instant>,
+    used: u64,
+}
+
+/// [internal-implementation]
+/// The mutable state of a verification cache
+#[derive(Debug, Default)]
+struct CacheState {
+    entries: HashMap<ContactKey, CacheEntry>,
+    recency: BTreeMap<u64, ContactKey>,
+    tick: u64,
+    stats: CacheStats,
+}
+
+impl CacheState {
+    /// [internal-implementation]
+    /// Remove the supplied key's entry (if any)
+    fn _remove(&mut self, key: &ContactKey) -> Option<CacheEntry> {
+        let entry = self.entries.remove(key)?;
+
+        self.recency.remove(&entry.used);
+
+        Some(entry)
+    }
+
+    /// [internal-implementation]
+    /// Mark the supplied key's entry as the most recently used
+    fn _touch(&mut self, key: &ContactKey) {
+        self.tick += 1;
+
+        if let Some(entry) = self.entries.get_mut(key) {
+            self.recency.remove(&entry.used);
+            self.recency.insert(self.tick, key.clone());
+            entry.used = self.tick;
+        }
+    }
+}
+
+/// An in-memory, size-bounded cache of single-transaction
+/// verification responses, keyed by normalized request
+#[derive(Debug)]
+pub struct VerificationCache {
+    policy: CachePolicy,
+    default_region: Option<String>,
+    state: Mutex<CacheState>,
+}
+
+impl VerificationCache {
+    /// Create a new, empty cache with the supplied policy, treating
+    /// phone numbers without a country code as belonging to the
+    /// supplied region (if any)
+    pub fn new<Region: ToString>(policy: CachePolicy, default_region: Option<Region>) -> Self {
+        Self {
+            policy,
+            default_region: default_region.map(|region| region.to_string()),
+            state: Mutex::new(CacheState::default()),
+        }
+    }
+
+    /// The cache's policy
+    pub fn policy(&self) -> &CachePolicy {
+        &self.policy
+    }
+
+    /// A snapshot of the cache's usage
+    pub fn stats(&self) -> CacheStats {
+        let state = self._state();
+
+        CacheStats {
+            len: state.entries.len(),
+            ..state.stats
+        }
+    }
+
+    /// The number of responses currently
+    /// cached (including expired ones)
+    pub fn len(&self) -> usize {
+        self._state().entries.len()
+    }
+
+    /// Check whether the cache is empty
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Discard the cached response to the supplied request (if any)
+    pub fn invalidate(&self, request: &VerificationRequest) -> bool {
+        let key = self._key(request);
+
+        self._state()._remove(&key).is_some()
+    }
+
+    /// Discard every cached response (without resetting the cache's stats)
+    pub fn clear(&self) {
+        let mut state = self._state();
+
+        state.entries.clear();
+        state.recency.clear();
+    }
+
+    // <editor-fold desc="// Internal Utility Methods ... ">
+
+    /// [internal-implementation]
+    /// Lock the cache's mutable state
+    fn _state(&self) -> MutexGuard<'_, CacheState> {
+        self.state
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    /// [internal-implementation]
+    /// The key the supplied request's response is cached under
+    fn _key(&self, request: &VerificationRequest) -> ContactKey {
+        DedupePolicy::new().contact_key(request, self.default_region.as_deref())
+    }
+
+    /// [internal-implementation]
+    /// Get the cached, unexpired response to the supplied request (if any)
+    pub(crate) fn _get(
+        &self,
+        request: &VerificationRequest,
+        now: Instant,
+    ) -> Option<Response<VerificationResponse>> {
+        let key = self._key(request);
+        let mut state = self._state();
+
+        match state
+            .entries
+            .get(&key)
+            .map(|entry| entry.expires.is_none_or(|expires| expires > now))
+        {
+            Some(true) => {
+                state._touch(&key);
+                state.stats.hits += 1;
+
+                state.entries.get(&key).map(|entry| {
+                    let mut response = entry.response.clone();
+
+                    response.meta = response.meta._from_cache();
+                    response
+                })
+            }
+            Some(false) => {
+                state._remove(&key);
+                state.stats.expirations += 1;
+                state.stats.misses += 1;
+                None
+            }
+            None => {
+                state.stats.misses += 1;
+                None
+            }
+        }
+    }
+
+    /// [internal-implementation]
+    /// Cache the supplied response to the supplied request,
+    /// evicting the least recently used response if necessary
+    pub(crate) fn _insert(
+        &self,
+        request: &VerificationRequest,
+        response: &Response<VerificationResponse>,
+        now: Instant,
+    ) {
+        let ttl = self.policy.ttl_of(response);
+
+        if ttl.is_zero() || self.policy.capacity == 0 {
+            return;
+        }
+
+        let key = self._key(request);
+        let mut state = self._state();
+
+        state._remove(&key);
+
+        while state.entries.len() >= self.policy.capacity {
+            let Some((_, oldest)) = state.recency.pop_first() else {
+                break;
+            };
+
+            state.entries.remove(&oldest);
+            state.stats.evictions += 1;
+        }
+
+        state.entries.insert(
+            key.clone(),
+            CacheEntry {
+                response: response.clone(),
+                expires: now.checked_add(ttl),
+                used: 0,
+            },
+        );
+        state._touch(&key);
+    }
+
+    // </editor-fold desc="// Internal Utility Methods ... ">
+}
+
+// </editor-fold desc="// VerificationCache ...">
+
+// <editor-fold desc="// I/O-Free Tests ...">
+
+#[cfg(test)]
+mod tests {
+    // Standard Library Imports
+    use std::time::{Duration, Instant};
+
+    // Third-Party Dependencies
+    use pretty_assertions::assert_eq;
+
+    // Crate-Level Imports
+    use super::{CachePolicy, CacheStats, VerificationCache};
+    use crate::types::{
+        EmailVerificationArray, Response, ResponseMetadata, VerificationRequest,
+        VerificationResponse, VerificationStatus,
+    };
+
+    fn response(email: &str, status: VerificationStatus) -> Response<VerificationResponse> {
+        Response::new(
+            VerificationResponse {
+                email: Some(EmailVerificationArray {
+                    address: email.to_string(),
+                    account: String::new(),
+                    domain: String::new(),
+                    status,
+                    connected: None,
+                    disposable: false,
+                    role_address: false,
+                    error_code: None,
+                    error: None,
+                }),
+                phone: None,
+                address: None,
+                duration: Duration::ZERO,
+            },
+            ResponseMetadata {
+                status: http::StatusCode::OK,
+                url: "https://bpi.briteverify.com/api/v1/fullverify"
+                    .parse()
+                    .unwrap(),
+                headers: http::HeaderMap::new(),
+                latency: Duration::ZERO,
+                elapsed: Duration::ZERO,
+                retries: 0,
+            },
+        )
+    }
+
+    fn request(email: &str) -> VerificationRequest {
+        VerificationRequest::try_from(email).unwrap()
+    }
+
+    /// Test that cached responses expire after
+    /// the time-to-live for their status
+    #[rstest::rstest]
+    fn test_cache_expiry() {
+        let cache = VerificationCache::new(
+            CachePolicy::new()
+                .ttl(VerificationStatus::Valid, Duration::from_secs(600))
+                .ttl(VerificationStatus::Unknown, Duration::from_secs(60))
+                .ttl(VerificationStatus::Invalid, Duration::ZERO),
+            Option::<&str>::None,
+        );
+        let start = Instant::now();
+        let later = |secs: u64| start + Duration::from_secs(secs);
+
+        cache._insert(
+            &request("valid@example.com"),
+            &response("valid@example.com", VerificationStatus::Valid),
+            start,
+        );
+        cache._insert(
+            &request("unknown@example.com"),
+            &response("unknown@example.com", VerificationStatus::Unknown),
+            start,
+        );
+        cache._insert(
+            &request("invalid@example.com"),
+            &response("invalid@example.com", VerificationStatus::Invalid),
+            start,
+        );
+
+        assert_eq!(2, cache.len());
+        assert!(cache
+            ._get(&request("VALID@example.com"), later(59))
+            .is_some());
+        assert!(cache
+            ._get(&request("unknown@example.com"), later(59))
+            .is_some());
+        assert!(cache
+            ._get(&request("unknown@example.com"), later(60))
+            .is_none());
+        assert!(cache
+            ._get(&request("valid@example.com"), later(599))
+            .is_some());
+        assert!(cache
+            ._get(&request("invalid@example.com"), later(1))
+            .is_none());
+
+        assert_eq!(
+            CacheStats {
+                hits: 3,
+                misses: 2,
+                evictions: 0,
+                expirations: 1,
+                len: 1,
+            },
+            cache.stats()
+        );
+        assert_eq!(0.6, cache.stats().hit_rate());
+    }
+
+    /// Test that the least recently used
+    /// response is evicted once the cache is full
+    #[rstest::rstest]
+    fn test_cache_eviction() {
+        let cache = VerificationCache::new(CachePolicy::new().capacity(2), Option::<&str>::None);
+        let now = Instant::now();
+
+        for email in ["a@example.com", "b@example.com"] {
+            cache._insert(
+                &request(email),
+                &response(email, VerificationStatus::Valid),
+                now,
+            );
+        }
+
+        assert!(cache._get(&request("a@example.com"), now).is_some());
+
+        cache._insert(
+            &request("c@example.com"),
+            &response("c@example.com", VerificationStatus::Valid),
+            now,
+        );
+
+        assert!(cache._get(&request("a@example.com"), now).is_some());
+        assert!(cache._get(&request("b@example.com"), now).is_none());
+        assert!(cache._get(&request("c@example.com"), now).is_some());
+        assert_eq!(1, cache.stats().evictions);
+
+        assert!(cache.invalidate(&request("a@example.com")));
+        assert_eq!(1, cache.len());
+
+        cache.clear();
+
+        assert!(cache.is_empty());
+    }
+
+    /// Test that cached responses are marked as such, and
+    /// don't carry over their original exchange's specifics
+    #[rstest::rstest]
+    fn test_cache_hit_metadata() {
+        let cache = VerificationCache::new(CachePolicy::new(), Option::<&str>::None);
+        let now = Instant::now();
+        let mut original = response("valid@example.com", VerificationStatus::Valid);
+
+        original
+            .meta
+            .headers
+            .insert("x-request-id", http::HeaderValue::from_static("abc-123"));
+        original.meta.latency = Duration::from_millis(250);
+        original.meta.elapsed = Duration::from_secs(3);
+        original.meta.retries = 2;
+
+        cache._insert(&request("valid@example.com"), &original, now);
+
+        let cached = cache._get(&request("valid@example.com"), now).unwrap();
+
+        assert!(!original.meta.is_cached());
+        assert!(cached.meta.is_cached());
+        assert_eq!(None, cached.meta.request_id());
+        assert_eq!(Duration::ZERO, cached.meta.latency);
+        assert_eq!(Duration::ZERO, cached.meta.elapsed);
+        assert_eq!(0, cached.meta.retries);
+        assert_eq!(original.meta.status, cached.meta.status);
+    }
+
+    /// Test that responses whose time-to-live is too
+    /// large to represent are cached indefinitely
+    #[rstest::rstest]
+    fn test_cache_unbounded_ttl() {
+        let cache = VerificationCache::new(
+            CachePolicy::new().ttl(VerificationStatus::Valid, Duration::MAX),
+            Option::<&str>::None,
+        );
+        let now = Instant::now();
+
+        cache._insert(
+            &request("valid@example.com"),
+            &response("valid@example.com", VerificationStatus::Valid),
+            now,
+        );
+
+        assert!(cache
+            ._get(
+                &request("valid@example.com"),
+                now + Duration::from_secs(86_400 * 365)
+            )
+            .is_some());
+    }
+}
+
+// </editor-fold desc="// I/O-Free Tests ...">
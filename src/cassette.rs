@@ -0,0 +1,644 @@
+//! ## Record / Replay Cassettes
+//!
+//! A [`Cassette`](Cassette) captures the requests a
+//! [`BriteVerifyClient`](crate::BriteVerifyClient) sends and the
+//! responses it receives, so that a session against the real
+//! BriteVerify API can be recorded once and replayed in offline
+//! tests afterward.
+//!
+//! - In [`Record`](CassetteMode::Record) mode, every exchange is
+//!   appended to the cassette's file (one JSON object per line) as it
+//!   happens. Request headers are never recorded, sensitive response
+//!   headers are redacted, request and response bodies are passed
+//!   through the cassette's [`BodyFilter`](BodyFilter), and the
+//!   client's API key is scrubbed from everything else.
+//! - In [`Replay`](CassetteMode::Replay) mode, no requests are sent
+//!   at all. Each request is answered with the first not-yet-replayed
+//!   recorded response whose request has the same method, path, query,
+//!   and body, and requests with no such match fail with
+//!   [`UnmatchedCassetteRequest`](crate::errors::BriteVerifyClientError::UnmatchedCassetteRequest).
+//!
+//! By default, the [`BodyFilter`](BodyFilter) masks contact values
+//! (email addresses, phone numbers, and street address fields) in
+//! every JSON body, replacing each value with a token derived from
+//! it. The same value always produces the same token, so requests
+//! made during replay still match the recorded ones, but replayed
+//! responses contain the tokens rather than the original values.
+//! Use [`BodyFilter::none`](BodyFilter::none) (or a filter of your own)
+//! with [`cassette_body_filter`](crate::BriteVerifyClientBuilder::cassette_body_filter)
+//! if a replayed session depends on the original values (e.g. when
+//! matching bulk results back to the contacts that were uploaded).
+//!
+//! ## Basic Usage
+//! ```no_run
+//! # use briteverify_rs::BriteVerifyClient;
+//! #
+//! # async fn doc() -> anyhow::Result<()> {
+//! // capture a real session once ...
+//! let client: BriteVerifyClient = BriteVerifyClient::builder()
+//!     .api_key("YOUR API KEY")
+//!     .record_cassette("tests/cassettes/balance.jsonl")
+//!     .build()?;
+//!
+//! client.get_account_balance().await?;
+//!
+//! // ... then replay it without network access
+//! let client: BriteVerifyClient = BriteVerifyClient::builder()
+//!     .api_key("ANY API KEY")
+//!     .replay_cassette("tests/cassettes/balance.jsonl")
+//!     .build()?;
+//!
+//! client.get_account_balance().await?;
+//! # Ok(())
+//! # }
+//! ```
+//
+// Standard Library Imports
+use std::{
+    collections::BTreeMap,
+    fs::OpenOptions,
+    io::Write,
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex, MutexGuard},
+};
+
+// Third-Party Imports
+use anyhow::Context;
+use http::{HeaderName, HeaderValue};
+use serde_json::Value;
+
+// Crate-Level Imports
+use crate::{
+    errors::BriteVerifyClientError,
+    sansio::{HttpRequest, HttpResponse},
+    types::ResponseMetadata,
+    utils::fnv1a,
+};
+
+// <editor-fold desc="// Constants ...">
+
+/// The value recorded in place of redacted data
+const REDACTED: &str = "[REDACTED]";
+
+/// Response headers whose values are never recorded
+static SENSITIVE_HEADERS: [&str; 5] = [
+    "authorization",
+    "cookie",
+    "proxy-authorization",
+    "set-cookie",
+    "x-api-key",
+];
+
+/// Body fields holding (or containing) a contact's values
+static CONTACT_GROUPS: [&str; 5] = ["email", "phone", "address", "contacts", "results"];
+
+/// Body fields whose values are masked
+/// when they're part of a contact
+static CONTACT_FIELDS: [&str; 11] = [
+    "email", "phone", "number", "address", "account", "domain", "address1", "address2", "city",
+    "state", "zip",
+];
+
+// </editor-fold desc="// Constants ...">
+
+// <editor-fold desc="// Recorded Data ...">
+
+/// Whether a [`Cassette`](Cassette) is
+/// capturing exchanges or replaying them
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum CassetteMode {
+    /// Send requests normally and record
+    /// each exchange to the cassette's file
+    Record,
+    /// Answer requests from the cassette's
+    /// file without sending them
+    Replay,
+}
+
+/// A request captured by a [`Cassette`](Cassette)
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct RecordedRequest {
+    /// The request's HTTP method
+    pub method: String,
+    /// The request's path and query
+    pub path: String,
+    /// The request's (redacted) body
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub body: String,
+}
+
+impl RecordedRequest {
+    /// [internal-implementation]
+    /// Check if the supplied request is equivalent to this one,
+    /// comparing bodies as JSON wherever both can be parsed as such
+    fn _matches(&self, other: &Self) -> bool {
+        if self.method != other.method || self.path != other.path {
+            return false;
+        }
+
+        match (
+            serde_json::from_str::<serde_json::Value>(&self.body),
+            serde_json::from_str::<serde_json::Value>(&other.body),
+        ) {
+            (Ok(left), Ok(right)) => left == right,
+            _ => self.body == other.body,
+        }
+    }
+}
+
+/// A response captured by a [`Cassette`](Cassette)
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct RecordedResponse {
+    /// The response's HTTP status code
+    pub status: u16,
+    /// The response's (redacted) headers
+    #[serde(default)]
+    pub headers: BTreeMap<String, String>,
+    /// The response's (redacted) body
+    #[serde(default)]
+    pub body: String,
+}
+
+/// A single request / response exchange
+/// captured by a [`Cassette`](Cassette)
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct Interaction {
+    /// The request that was sent
+    pub request: RecordedRequest,
+    /// The response that was received
+    pub response: RecordedResponse,
+}
+
+/// [internal-implementation]
+/// The mutable state of a cassette
+#[derive(Debug, Default)]
+struct CassetteState {
+    interactions: Vec<Interaction>,
+    played: Vec<bool>,
+}
+
+// </editor-fold desc="// Recorded Data ...">
+
+// <editor-fold desc="// BodyFilter ...">
+
+/// A function that scrubs a (JSON) body in place
+type Scrub = dyn Fn(&mut Value) + Send + Sync;
+
+/// Scrubs sensitive values from recorded (JSON) request and response
+/// bodies before they're written to a [`Cassette`](Cassette)'s file
+///
+/// ___
+/// **NOTE:** Requests being replayed are passed through the same filter
+/// before they're matched against recorded ones, so filters must be
+/// deterministic (i.e. always replace a given value the same way).
+/// ___
+#[derive(Clone)]
+pub struct BodyFilter(Option<Arc<Scrub>>);
+
+#[cfg_attr(tarpaulin, coverage(off))]
+impl std::fmt::Debug for BodyFilter {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        formatter.debug_struct("BodyFilter").finish_non_exhaustive()
+    }
+}
+
+impl Default for BodyFilter {
+    fn default() -> Self {
+        Self::mask_contacts()
+    }
+}
+
+impl BodyFilter {
+    /// Create a new `BodyFilter` that scrubs
+    /// bodies with the supplied function
+    pub fn new<Filter: Fn(&mut Value) + Send + Sync + 'static>(filter: Filter) -> Self {
+        Self(Some(Arc::new(filter)))
+    }
+
+    /// A `BodyFilter` that records bodies verbatim
+    /// (aside from scrubbing the client's API key)
+    pub fn none() -> Self {
+        Self(None)
+    }
+
+    /// A `BodyFilter` that replaces every contact value (email address,
+    /// phone number, or street address field) with a token derived from
+    /// it (see [`mask_contacts`](mask_contacts))
+    pub fn mask_contacts() -> Self {
+        Self::new(mask_contacts)
+    }
+
+    /// [internal-implementation]
+    /// Filter the supplied body, leaving
+    /// non-JSON bodies untouched
+    fn _apply(&self, body: &str) -> String {
+        let Some(filter) = self.0.as_deref() else {
+            return body.to_string();
+        };
+
+        match serde_json::from_str::<Value>(body) {
+            Ok(mut value) => {
+                filter(&mut value);
+                value.to_string()
+            }
+            Err(_) => body.to_string(),
+        }
+    }
+}
+
+/// Replace every contact value in the supplied body with a token
+/// derived from it, so that the same value is always replaced by
+/// the same token (e.g. `test@example.com` may become
+/// `redacted-6d2f9e1c4b7a8f03@example.invalid`)
+///
+/// ___
+/// **NOTE:** Tokens are derived from (unsalted) hashes of the values
+/// they replace. They keep recorded values out of plain sight, but
+/// can't be relied on to hide values that are easily guessed.
+/// ___
+pub fn mask_contacts(body: &mut Value) {
+    _mask(body, false);
+}
+
+/// [internal-implementation]
+/// Recursively mask the contact values in the supplied
+/// body, given whether or not it's part of a contact
+fn _mask(body: &mut Value, in_contact: bool) {
+    match body {
+        Value::Array(values) => values.iter_mut().for_each(|value| _mask(value, in_contact)),
+        Value::Object(fields) => {
+            for (key, value) in fields.iter_mut() {
+                let key = key.as_str();
+
+                match value {
+                    Value::String(text)
+                        if ["email", "phone"].contains(&key)
+                            || (in_contact && CONTACT_FIELDS.contains(&key)) =>
+                    {
+                        *text = _mask_value(text);
+                    }
+                    _ => _mask(value, in_contact || CONTACT_GROUPS.contains(&key)),
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+/// [internal-implementation]
+/// Derive a stable token from the supplied value
+fn _mask_value(value: &str) -> String {
+    let hash = fnv1a(value.trim().to_lowercase().bytes());
+
+    match value.contains('@') {
+        true => format!("redacted-{hash:016x}@example.invalid"),
+        false => format!("{REDACTED}:{hash:016x}"),
+    }
+}
+
+// </editor-fold desc="// BodyFilter ...">
+
+// <editor-fold desc="// Cassette ...">
+
+/// A file of recorded BriteVerify API exchanges
+/// used by a [`BriteVerifyClient`](crate::BriteVerifyClient)
+/// to record or replay traffic
+#[derive(Debug)]
+pub struct Cassette {
+    mode: CassetteMode,
+    path: PathBuf,
+    secrets: Vec<String>,
+    filter: BodyFilter,
+    state: Mutex<CassetteState>,
+}
+
+impl Cassette {
+    /// [internal-implementation]
+    /// Create a new, empty cassette that will
+    /// record exchanges to the supplied path
+    pub(crate) fn _record(path: PathBuf, secrets: Vec<String>, filter: BodyFilter) -> Self {
+        Self {
+            mode: CassetteMode::Record,
+            path,
+            secrets: Self::_usable_secrets(secrets),
+            filter,
+            state: Mutex::new(CassetteState::default()),
+        }
+    }
+
+    /// [internal-implementation]
+    /// Load a previously recorded cassette
+    /// from the supplied path for replay
+    pub(crate) fn _replay(
+        path: PathBuf,
+        secrets: Vec<String>,
+        filter: BodyFilter,
+    ) -> Result<Self, BriteVerifyClientError> {
+        let contents = std::fs::read_to_string(&path)
+            .with_context(|| format!("Could not read cassette: {}", path.display()))?;
+
+        let interactions = contents
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(serde_json::from_str::<Interaction>)
+            .collect::<Result<Vec<_>, _>>()
+            .with_context(|| format!("Could not parse cassette: {}", path.display()))?;
+
+        Ok(Self {
+            mode: CassetteMode::Replay,
+            path,
+            secrets: Self::_usable_secrets(secrets),
+            filter,
+            state: Mutex::new(CassetteState {
+                played: vec![false; interactions.len()],
+                interactions,
+            }),
+        })
+    }
+
+    /// Whether the cassette is recording or replaying
+    pub fn mode(&self) -> CassetteMode {
+        self.mode
+    }
+
+    /// The path of the cassette's file
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// The exchanges recorded (or loaded for replay) so far
+    pub fn interactions(&self) -> Vec<Interaction> {
+        self._state().interactions.clone()
+    }
+
+    /// The number of loaded exchanges that
+    /// have not yet been replayed
+    pub fn remaining(&self) -> usize {
+        self._state()
+            .played
+            .iter()
+            .filter(|played| !**played)
+            .count()
+    }
+
+    /// [internal-implementation]
+    /// Lock the cassette's mutable state
+    fn _state(&self) -> MutexGuard<'_, CassetteState> {
+        self.state
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    /// [internal-implementation]
+    /// Discard secrets that are empty or
+    /// otherwise unsafe to scrub
+    fn _usable_secrets(secrets: Vec<String>) -> Vec<String> {
+        secrets
+            .into_iter()
+            .map(|secret| secret.trim().to_string())
+            .filter(|secret| !secret.is_empty())
+            .collect()
+    }
+
+    /// [internal-implementation]
+    /// Scrub the cassette's secrets from the supplied value
+    fn _redact(&self, value: &str) -> String {
+        self.secrets
+            .iter()
+            .fold(value.to_string(), |value, secret| {
+                value.replace(secret, REDACTED)
+            })
+    }
+
+    /// [internal-implementation]
+    /// Capture the (redacted) details of the supplied request
+    pub(crate) fn _capture(&self, request: &HttpRequest) -> RecordedRequest {
+        RecordedRequest {
+            method: request.method().to_string(),
+            path: self._redact(
+                request
+                    .uri()
+                    .path_and_query()
+                    .map_or_else(|| request.uri().path(), |path| path.as_str()),
+            ),
+            body: self._redact(&self.filter._apply(&String::from_utf8_lossy(request.body()))),
+        }
+    }
+
+    /// [internal-implementation]
+    /// Record the supplied exchange, appending
+    /// it to the cassette's file
+    pub(crate) fn _save(
+        &self,
+        request: RecordedRequest,
+        response: &HttpResponse,
+    ) -> Result<(), BriteVerifyClientError> {
+        let headers = response
+            .headers()
+            .iter()
+            .map(|(name, value)| {
+                let value = match SENSITIVE_HEADERS.contains(&name.as_str()) {
+                    true => REDACTED.to_string(),
+                    false => self._redact(&String::from_utf8_lossy(value.as_bytes())),
+                };
+
+                (name.to_string(), value)
+            })
+            .collect();
+
+        let interaction = Interaction {
+            request,
+            response: RecordedResponse {
+                status: response.status().as_u16(),
+                headers,
+                body: self._redact(
+                    &self
+                        .filter
+                        ._apply(&String::from_utf8_lossy(response.body())),
+                ),
+            },
+        };
+
+        let mut line = serde_json::to_vec(&interaction)?;
+        line.push(b'\n');
+
+        let mut state = self._state();
+
+        // the first exchange replaces any previous recording
+        let first = state.interactions.is_empty();
+
+        if first {
+            if let Some(parent) = self.path.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+                std::fs::create_dir_all(parent)
+                    .with_context(|| format!("Could not create directory: {}", parent.display()))?;
+            }
+        }
+
+        OpenOptions::new()
+            .create(true)
+            .write(true)
+            .append(!first)
+            .truncate(first)
+            .open(&self.path)
+            .and_then(|mut file| file.write_all(&line))
+            .with_context(|| format!("Could not write cassette: {}", self.path.display()))?;
+
+        state.interactions.push(interaction);
+        state.played.push(true);
+
+        Ok(())
+    }
+
+    /// [internal-implementation]
+    /// Answer the supplied request with the first matching
+    /// recorded response that hasn't been replayed yet
+    pub(crate) fn _play(
+        &self,
+        request: &HttpRequest,
+    ) -> Result<(HttpResponse, ResponseMetadata), BriteVerifyClientError> {
+        let captured = self._capture(request);
+        let mut state = self._state();
+        let CassetteState {
+            interactions,
+            played,
+        } = &mut *state;
+
+        let Some((idx, interaction)) = interactions
+            .iter()
+            .enumerate()
+            .find(|(idx, interaction)| !played[*idx] && interaction.request._matches(&captured))
+        else {
+            return Err(BriteVerifyClientError::UnmatchedCassetteRequest(format!(
+                "{} {}",
+                captured.method, captured.path
+            )));
+        };
+
+        played[idx] = true;
+
+        let mut response = http::Response::builder().status(interaction.response.status);
+
+        for (name, value) in interaction.response.headers.iter() {
+            if let (Ok(name), Ok(value)) = (
+                HeaderName::try_from(name.as_str()),
+                HeaderValue::try_from(value.as_str()),
+            ) {
+                response = response.header(name, value);
+            }
+        }
+
+        let response = response
+            .body(interaction.response.body.as_bytes().to_vec())
+            .context("Could not rebuild recorded response")?;
+
+        let meta = ResponseMetadata::from_local(request.uri(), &response)?;
+
+        Ok((response, meta))
+    }
+}
+
+// </editor-fold desc="// Cassette ...">
+
+// <editor-fold desc="// I/O-Free Tests ...">
+
+#[cfg(test)]
+mod tests {
+    // Third-Party Dependencies
+    use pretty_assertions::{assert_eq, assert_str_eq};
+    use serde_json::json;
+
+    // Crate-Level Imports
+    use super::{mask_contacts, BodyFilter, Cassette, CassetteMode, RecordedRequest};
+
+    fn request(method: &str, path: &str, body: &str) -> RecordedRequest {
+        RecordedRequest {
+            method: method.to_string(),
+            path: path.to_string(),
+            body: body.to_string(),
+        }
+    }
+
+    /// Test that recorded requests are matched on their
+    /// method, path, and (semantically compared) body
+    #[rstest::rstest]
+    fn test_recorded_request_matching() {
+        let recorded = request(
+            "POST",
+            "/api/v1/fullverify",
+            r#"{"email": "a@b.c", "phone": "1"}"#,
+        );
+
+        assert!(recorded._matches(&request(
+            "POST",
+            "/api/v1/fullverify",
+            r#"{"phone":"1","email":"a@b.c"}"#
+        )));
+        assert!(!recorded._matches(&request(
+            "POST",
+            "/api/v1/fullverify",
+            r#"{"email":"a@b.c"}"#
+        )));
+        assert!(!recorded._matches(&request(
+            "GET",
+            "/api/v1/fullverify",
+            r#"{"email": "a@b.c", "phone": "1"}"#
+        )));
+    }
+
+    /// Test that cassettes scrub their
+    /// secrets from captured requests
+    #[rstest::rstest]
+    fn test_cassette_redacts_secrets() {
+        let cassette = Cassette::_record(
+            "unused.json".into(),
+            vec!["hunter2".to_string(), "  ".to_string()],
+            BodyFilter::none(),
+        );
+
+        let captured = cassette._capture(
+            &http::Request::builder()
+                .method("GET")
+                .uri("https://example.com/api/v3/accounts/hunter2/lists?page=2")
+                .body(b"{\"key\": \"hunter2\"}".to_vec())
+                .unwrap(),
+        );
+
+        assert_eq!(CassetteMode::Record, cassette.mode());
+        assert_str_eq!("/api/v3/accounts/[REDACTED]/lists?page=2", captured.path);
+        assert_str_eq!("{\"key\": \"[REDACTED]\"}", captured.body);
+    }
+
+    /// Test that contact values are masked consistently,
+    /// while everything else is left untouched
+    #[rstest::rstest]
+    fn test_mask_contacts() {
+        let mut request = json!({
+            "email": "Test@Example.com",
+            "phone": "+15555555555",
+            "address": {"address1": "1 Main St", "city": "Springfield", "state": "IL", "zip": "62701"},
+        });
+        let mut response = json!({
+            "email": {"address": "test@example.com", "status": "valid", "disposable": false},
+            "list": {"id": "abc", "state": "open"},
+            "results": [{"phone": {"phone": "+15555555555", "status": "valid"}}],
+        });
+
+        mask_contacts(&mut request);
+        mask_contacts(&mut response);
+
+        let email = request["email"].as_str().unwrap();
+
+        assert!(email.starts_with("redacted-") && email.ends_with("@example.invalid"));
+        assert_eq!(request["email"], response["email"]["address"]);
+        assert_eq!(request["phone"], response["results"][0]["phone"]["phone"]);
+        assert!(request["address"]["state"]
+            .as_str()
+            .unwrap()
+            .starts_with("[REDACTED]:"));
+        assert_eq!(json!("valid"), response["email"]["status"]);
+        assert_eq!(json!("open"), response["list"]["state"]);
+        assert_eq!(json!("abc"), response["list"]["id"]);
+    }
+}
+
+// </editor-fold desc="// I/O-Free Tests ...">
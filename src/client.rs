@@ -3,13 +3,12 @@
 //
 // Standard Library Imports
 #[allow(unused_imports)]
-use std::{fmt::Debug, net::SocketAddr, ops::Deref, time::Duration};
+use std::{fmt::Debug, net::SocketAddr, ops::Deref, path::PathBuf, sync::Arc, time::Duration};
 
 // Third-Party Imports
 use anyhow::{Context, Result};
-use futures_timer::Delay;
 use reqwest::{
-    header::{HeaderMap, HeaderValue, AUTHORIZATION},
+    header::{HeaderMap, HeaderName, HeaderValue, AUTHORIZATION},
     StatusCode,
 };
 
@@ -18,16 +17,211 @@ use instrumentation as tracing;
 
 // Crate-Level Imports
 use crate::errors::BriteVerifyClientError;
-use crate::{errors, types, utils::ExtensibleUrl};
+use crate::{
+    budget::{BudgetGuard, CreditBudget},
+    cache::{CachePolicy, VerificationCache},
+    cassette::{BodyFilter, Cassette, CassetteMode},
+    clock::{Clock, Sleeper, Timekeeper},
+    coalesce::InFlightVerifications,
+    dedupe::{DedupePolicy, Deduplicator},
+    dry_run::{DryRun, MAX_CONTACTS_PER_LIST, MAX_CONTACTS_PER_REQUEST},
+    errors,
+    idempotency::{self, IdempotentLists},
+    jobs,
+    ledger::{CreditLedger, LedgerTags},
+    monitor, sandbox,
+    sansio::{self, V1_API_BASE_URL, V3_API_BASE_URL},
+    simulator::BriteVerifySimulator,
+    types,
+};
+
+#[cfg(feature = "sqlite")]
+use crate::store::PersistentCache;
 
 // <editor-fold desc="// Constants ...">
 
 type Nullable = Option<String>;
-static V1_API_BASE_URL: &str = "https://bpi.briteverify.com/api/v1";
-static V3_API_BASE_URL: &str = "https://bulk-api.briteverify.com/api/v3";
 
 // </editor-fold desc="// Constants ...">
 
+// <editor-fold desc="// RequestOptions ...">
+
+/// Per-call overrides for a [`BriteVerifyClient`](BriteVerifyClient)'s
+/// configured timeout, rate-limit retry behavior, and request headers.
+///
+/// Options are attached to a client with [`with_options`](BriteVerifyClient::with_options),
+/// which returns a lightweight copy of the client that shares the original's
+/// connection pool and applies the options to every request it sends.
+///
+/// ## Basic Usage
+/// ```no_run
+/// # use std::time::Duration;
+/// # use briteverify_rs::{BriteVerifyClient, RequestOptions, types::EmailVerificationArray};
+/// #
+/// # async fn doc() -> anyhow::Result<()> {
+/// # let client: BriteVerifyClient = BriteVerifyClient::new("YOUR API KEY")?;
+/// let options: RequestOptions = RequestOptions::new()
+///     .timeout(Duration::from_secs(2))
+///     .retry_enabled(false);
+///
+/// let response: EmailVerificationArray = client
+///     .with_options(options)
+///     .verify_email("test@example.com")
+///     .await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(test, visible::StructFields(pub))]
+pub struct RequestOptions {
+    timeout: Option<Duration>,
+    retry_enabled: Option<bool>,
+    headers: HeaderMap,
+    tags: LedgerTags,
+}
+
+impl RequestOptions {
+    /// Create a new, empty [`RequestOptions`](RequestOptions) instance
+    ///
+    /// #### Example
+    /// ```no_run
+    /// # use briteverify_rs::RequestOptions;
+    /// #
+    /// let options: RequestOptions = RequestOptions::new();
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Override the client's request timeout.
+    ///
+    /// The timeout is applied from when the request starts connecting until the
+    /// response body has finished, and *replaces* (rather than adds to) any timeout
+    /// configured via [`BriteVerifyClientBuilder::timeout`](BriteVerifyClientBuilder::timeout).
+    ///
+    /// #### Example
+    /// ```no_run
+    /// # use std::time::Duration;
+    /// # use briteverify_rs::RequestOptions;
+    /// #
+    /// let options: RequestOptions = RequestOptions::new()
+    ///     .timeout(Duration::from_secs(2));
+    /// ```
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Override the client's automatic rate limit handling.
+    ///
+    /// #### Example
+    /// ```no_run
+    /// # use briteverify_rs::RequestOptions;
+    /// #
+    /// let options: RequestOptions = RequestOptions::new()
+    ///     .retry_enabled(false);
+    /// ```
+    pub fn retry_enabled(mut self, value: bool) -> Self {
+        self.retry_enabled = Some(value);
+        self
+    }
+
+    /// Add a single header to every request sent with these options.
+    ///
+    /// Headers supplied here take precedence over any identically
+    /// named default headers configured on the client.
+    ///
+    /// #### Example
+    /// ```no_run
+    /// # use briteverify_rs::RequestOptions;
+    /// use reqwest::header::{HeaderName, HeaderValue};
+    ///
+    /// let options: RequestOptions = RequestOptions::new().header(
+    ///     HeaderName::from_static("x-request-id"),
+    ///     HeaderValue::from_static("signup-1234"),
+    /// );
+    /// ```
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.headers.insert(key, value);
+        self
+    }
+
+    /// Add every header in the supplied [`HeaderMap`](HeaderMap)
+    /// to every request sent with these options.
+    ///
+    /// #### Example
+    /// ```no_run
+    /// # use briteverify_rs::RequestOptions;
+    /// use reqwest::header::{HeaderMap, HeaderValue};
+    ///
+    /// let mut headers = HeaderMap::new();
+    /// headers.insert("x-request-id", HeaderValue::from_static("nightly-5678"));
+    ///
+    /// let options: RequestOptions = RequestOptions::new().headers(headers);
+    /// ```
+    pub fn headers(mut self, headers: HeaderMap) -> Self {
+        self.headers.extend(headers);
+        self
+    }
+
+    /// Attribute every request sent with these options to the supplied
+    /// tenant in the client's credit ledger (if any), without changing
+    /// the requests themselves (see [`ledger`](crate::ledger) for details)
+    ///
+    /// #### Example
+    /// ```no_run
+    /// # use briteverify_rs::RequestOptions;
+    /// #
+    /// let options: RequestOptions = RequestOptions::new().tenant("acme");
+    /// ```
+    pub fn tenant<Tenant: ToString>(mut self, tenant: Tenant) -> Self {
+        self.tags.tenant = Some(tenant.to_string());
+        self
+    }
+
+    /// Label every request sent with these options in the client's
+    /// credit ledger (if any), without changing the requests themselves
+    /// (see [`ledger`](crate::ledger) for details)
+    ///
+    /// #### Example
+    /// ```no_run
+    /// # use briteverify_rs::RequestOptions;
+    /// #
+    /// let options: RequestOptions = RequestOptions::new()
+    ///     .label("pipeline", "nightly-import")
+    ///     .label("external_id", "customer-1234");
+    /// ```
+    pub fn label<Name: ToString, Value: ToString>(mut self, name: Name, value: Value) -> Self {
+        self.tags.labels.insert(name.to_string(), value.to_string());
+        self
+    }
+
+    /// [internal-implementation]
+    /// Whether or not any overrides or tags are configured
+    fn _is_default(&self) -> bool {
+        self.timeout.is_none()
+            && self.retry_enabled.is_none()
+            && self.headers.is_empty()
+            && self.tags == LedgerTags::default()
+    }
+
+    /// [internal-implementation]
+    /// Apply the configured overrides to the supplied request
+    fn apply(&self, mut builder: reqwest::RequestBuilder) -> reqwest::RequestBuilder {
+        if let Some(timeout) = self.timeout {
+            builder = builder.timeout(timeout);
+        }
+
+        if !self.headers.is_empty() {
+            builder = builder.headers(self.headers.clone());
+        }
+
+        builder
+    }
+}
+
+// </editor-fold desc="// RequestOptions ...">
+
 // <editor-fold desc="// ClientBuilder ...">
 
 /// Helper for incrementally building a [`BriteVerifyClient`](BriteVerifyClient)
@@ -57,6 +251,23 @@ pub struct BriteVerifyClientBuilder {
     v1_base_url: url::Url,
     v3_base_url: url::Url,
     retry_enabled: bool,
+    validate_email_syntax: bool,
+    normalize_phone_numbers: bool,
+    phone_region: Option<String>,
+    normalize_addresses: bool,
+    dedupe: Option<DedupePolicy>,
+    budget: Option<CreditBudget>,
+    ledger: Option<Arc<CreditLedger>>,
+    cache: Option<CachePolicy>,
+    #[cfg(feature = "sqlite")]
+    persistent: Option<Arc<PersistentCache>>,
+    idempotency: Option<Arc<IdempotentLists>>,
+    coalesce: bool,
+    sandbox: bool,
+    dry_run: bool,
+    cassette: Option<(CassetteMode, PathBuf)>,
+    cassette_filter: BodyFilter,
+    timekeeper: Timekeeper,
     builder: reqwest::ClientBuilder,
 }
 
@@ -84,6 +295,23 @@ impl Default for BriteVerifyClientBuilder {
             v3_base_url: url::Url::parse(V3_API_BASE_URL)
                 .expect("Couldn't parse default v1 base url"),
             retry_enabled: false,
+            validate_email_syntax: false,
+            normalize_phone_numbers: false,
+            phone_region: None,
+            normalize_addresses: false,
+            dedupe: None,
+            budget: None,
+            ledger: None,
+            cache: None,
+            #[cfg(feature = "sqlite")]
+            persistent: None,
+            idempotency: None,
+            coalesce: false,
+            sandbox: false,
+            dry_run: false,
+            cassette: None,
+            cassette_filter: BodyFilter::default(),
+            timekeeper: Timekeeper::default(),
             builder: reqwest::Client::builder(),
         }
     }
@@ -127,6 +355,23 @@ impl BriteVerifyClientBuilder {
         match self.api_key {
             None => Err(errors::BriteVerifyClientError::MissingApiKey),
             Some(key) => {
+                let cassette = match self.cassette {
+                    None => None,
+                    Some((mode, path)) => {
+                        let secrets =
+                            vec![key.to_str().unwrap_or_default().replace("ApiKey: ", "")];
+
+                        Some(Arc::new(match mode {
+                            CassetteMode::Record => {
+                                Cassette::_record(path, secrets, self.cassette_filter)
+                            }
+                            CassetteMode::Replay => {
+                                Cassette::_replay(path, secrets, self.cassette_filter)?
+                            }
+                        }))
+                    }
+                };
+
                 if key.is_sensitive() {
                     let headers = HeaderMap::from_iter([(AUTHORIZATION, key)]);
                     self.builder = self.builder.default_headers(headers);
@@ -137,9 +382,39 @@ impl BriteVerifyClientBuilder {
                         .builder
                         .build()
                         .context("Could not create a usable `reqwest` client")?,
-                    v1_base_url: self.v1_base_url,
-                    v3_base_url: self.v3_base_url,
+                    // the API key is already attached to every request
+                    // via the `reqwest` client's default headers
+                    core: sansio::BriteVerifyCore::from_parts(
+                        None,
+                        self.v1_base_url,
+                        self.v3_base_url,
+                    ),
                     retry_enabled: self.retry_enabled,
+                    validate_email_syntax: self.validate_email_syntax,
+                    normalize_phone_numbers: self.normalize_phone_numbers,
+                    phone_region: self.phone_region.clone(),
+                    normalize_addresses: self.normalize_addresses,
+                    inflight: self
+                        .coalesce
+                        .then(|| Arc::new(InFlightVerifications::new(self.phone_region.clone()))),
+                    cache: self.cache.map(|policy| {
+                        Arc::new(VerificationCache::new(policy, self.phone_region.clone()))
+                    }),
+                    dedupe: self
+                        .dedupe
+                        .map(|policy| Arc::new(Deduplicator::new(policy, self.phone_region))),
+                    budget: self.budget.map(|budget| Arc::new(BudgetGuard::new(budget))),
+                    ledger: self.ledger,
+                    #[cfg(feature = "sqlite")]
+                    persistent: self.persistent,
+                    idempotency: self.idempotency.unwrap_or_default(),
+                    options: RequestOptions::default(),
+                    sandbox: self.sandbox.then(|| {
+                        BriteVerifySimulator::_unauthenticated(Arc::new(sandbox::magic_response))
+                    }),
+                    dry_run: self.dry_run.then(|| Arc::new(DryRun::_new())),
+                    cassette,
+                    timekeeper: self.timekeeper,
                 })
             }
         }
@@ -207,16 +482,20 @@ impl BriteVerifyClientBuilder {
         self
     }
 
-    /// Override the base URL for requests to the BriteVerify v1 API
-    /// [[ref](https://docs.briteverify.com/#79e00732-b734-4308-ac7f-820d62dde01f)]
+    /// Enable or disable local email address syntax validation, in
+    /// which the built client checks every email address it's asked
+    /// to verify (see [`EmailAddress`](types::EmailAddress) for details)
+    /// before sending it to the BriteVerify API
     ///
-    /// ___
-    /// **NOTE:** Unless overridden (specifically by calling [`v1_base_url`]
-    /// on a builder instance), the default value of `https://bpi.briteverify.com/api/v1`
-    /// will be used as the base url for single-transaction requests.
+    /// Single-transaction verifications of malformed addresses are
+    /// answered locally (without spending any credits) with the same
+    /// `invalid_format` result the BriteVerify API would return, while
+    /// bulk verification lists that include any malformed addresses are
+    /// rejected with [`MalformedEmailAddresses`](errors::BriteVerifyClientError::MalformedEmailAddresses)
+    /// before being uploaded.
     ///
-    /// If you set a custom url, be aware that no additional logic, formatting,
-    /// or validity checks will be applied to whatever value you specify.
+    /// ___
+    /// **NOTE:** Local validation is `disabled` by default.
     /// ___
     ///
     /// #### Example
@@ -225,39 +504,32 @@ impl BriteVerifyClientBuilder {
     /// #
     /// # fn doc() -> anyhow::Result<()> {
     /// let builder: BriteVerifyClientBuilder = BriteVerifyClientBuilder::new()
-    ///     .v1_base_url("https://my-custom-domain.net/briteverify/v1");
+    ///     .validate_email_syntax(true);
     /// # Ok(())
     /// # }
     /// ```
-    pub fn v1_base_url<URL>(mut self, url: URL) -> Self
-    where
-        URL: TryInto<url::Url>,
-        URL::Error: Into<BriteVerifyClientError>,
-    {
-        let url = url.try_into();
-
-        match url {
-            Ok(value) => {
-                self.v1_base_url = value;
-            }
-            Err(error) => {
-                self.error = Some(error.into());
-            }
-        }
-
+    pub fn validate_email_syntax(mut self, value: bool) -> Self {
+        self.validate_email_syntax = value;
         self
     }
 
-    /// Override the base URL for requests to the BriteVerify v3 API
-    /// [[ref](https://docs.briteverify.com/#382f454d-dad2-49c3-b320-c7d117fcc20a)]
+    /// Enable or disable local phone number normalization, in which
+    /// the built client normalizes every phone number it's asked to
+    /// verify to its E.164 form (see [`PhoneNumber`](types::PhoneNumber)
+    /// for details) before sending it to the BriteVerify API. Extensions
+    /// are dropped, since the API only verifies the number itself.
     ///
-    /// ___
-    /// **NOTE:** Unless overridden (specifically by calling [`v3_base_url`]
-    /// on a builder instance), the default value of `https://bulk-api.briteverify.com/api/v3`
-    /// will be used as the base url for bulk transaction requests.
+    /// Single-transaction verifications of numbers that can't be
+    /// normalized are answered locally (without spending any credits)
+    /// with the same result the BriteVerify API would return, while
+    /// bulk verification lists that include any such numbers are
+    /// rejected with [`MalformedPhoneNumbers`](errors::BriteVerifyClientError::MalformedPhoneNumbers)
+    /// before being uploaded.
     ///
-    /// If you set a custom url, be aware that no additional logic, formatting,
-    /// or validity checks will be applied to whatever value you specify.
+    /// ___
+    /// **NOTE:** Local normalization is `disabled` by default. Unless
+    /// a [default region](BriteVerifyClientBuilder::default_phone_region)
+    /// is also set, numbers must include their country code.
     /// ___
     ///
     /// #### Example
@@ -266,209 +538,684 @@ impl BriteVerifyClientBuilder {
     /// #
     /// # fn doc() -> anyhow::Result<()> {
     /// let builder: BriteVerifyClientBuilder = BriteVerifyClientBuilder::new()
-    ///     .v3_base_url("https://my-custom-domain.net/briteverify/v3");
+    ///     .normalize_phone_numbers(true)
+    ///     .default_phone_region("US");
     /// # Ok(())
     /// # }
     /// ```
-    pub fn v3_base_url<URL>(mut self, url: URL) -> Self
-    where
-        URL: TryInto<url::Url>,
-        URL::Error: Into<BriteVerifyClientError>,
-    {
-        let url = url.try_into();
+    pub fn normalize_phone_numbers(mut self, value: bool) -> Self {
+        self.normalize_phone_numbers = value;
+        self
+    }
 
-        match url {
-            Ok(value) => {
-                self.v3_base_url = value;
+    /// Set the region (an ISO 3166-1 alpha-2 code, e.g. `"US"`)
+    /// relative to which phone numbers written without a country
+    /// code are interpreted during [normalization](BriteVerifyClientBuilder::normalize_phone_numbers)
+    ///
+    /// #### Example
+    /// ```no_run
+    /// # use briteverify_rs::BriteVerifyClientBuilder;
+    /// #
+    /// # fn doc() -> anyhow::Result<()> {
+    /// let builder: BriteVerifyClientBuilder = BriteVerifyClientBuilder::new()
+    ///     .normalize_phone_numbers(true)
+    ///     .default_phone_region("GB");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn default_phone_region<Region: ToString>(mut self, region: Region) -> Self {
+        let region = region.to_string();
+
+        match types::PhoneNumber::is_supported_region(&region) {
+            true => {
+                self.phone_region = Some(region);
             }
-            Err(error) => {
-                self.error = Some(error.into());
+            false => {
+                self.error = Some(errors::BriteVerifyTypeError::UnknownPhoneRegion(region).into());
             }
         }
 
         self
     }
 
-    // Timeout options
-
-    /// Enables a request timeout.
+    /// Enable or disable local street address normalization, in which
+    /// the built client normalizes every street address it's asked to
+    /// verify (see [`types::address`](crate::types::address) for details)
+    /// before sending it to the BriteVerify API
     ///
-    /// The timeout is applied from when the request starts connecting until the
-    /// response body has finished.
+    /// Single-transaction verifications of addresses that can't be
+    /// normalized (e.g. with an unknown state or malformed ZIP code)
+    /// are answered locally (without spending any credits) with the
+    /// same result the BriteVerify API would return, while bulk
+    /// verification lists that include any such addresses are
+    /// rejected with [`MalformedAddresses`](errors::BriteVerifyClientError::MalformedAddresses)
+    /// before being uploaded.
     ///
-    /// Default is no timeout.
+    /// ___
+    /// **NOTE:** Local normalization is `disabled` by default.
+    /// ___
     ///
     /// #### Example
     /// ```no_run
-    /// # use std::time::Duration;
     /// # use briteverify_rs::BriteVerifyClientBuilder;
     /// #
     /// # fn doc() -> anyhow::Result<()> {
     /// let builder: BriteVerifyClientBuilder = BriteVerifyClientBuilder::new()
-    ///     .timeout(Duration::from_secs(5));
+    ///     .normalize_addresses(true);
     /// # Ok(())
     /// # }
     /// ```
-    #[cfg_attr(tarpaulin, coverage(off))]
-    pub fn timeout(mut self, timeout: Duration) -> Self {
-        self.builder = self.builder.timeout(timeout);
+    pub fn normalize_addresses(mut self, value: bool) -> Self {
+        self.normalize_addresses = value;
         self
     }
 
-    /// Set a timeout for only the connect phase of a `Client`.
+    /// Enable or disable the removal of duplicate contacts from bulk
+    /// verification lists before they're uploaded, using the default
+    /// [`DedupePolicy`](DedupePolicy) (see [`dedupe`](crate::dedupe)
+    /// for details)
     ///
-    /// Default is `None`.
+    /// ___
+    /// **NOTE:** Deduplication is `disabled` by default.
+    /// ___
     ///
     /// #### Example
     /// ```no_run
-    /// # use std::time::Duration;
     /// # use briteverify_rs::BriteVerifyClientBuilder;
     /// #
     /// # fn doc() -> anyhow::Result<()> {
     /// let builder: BriteVerifyClientBuilder = BriteVerifyClientBuilder::new()
-    ///     .connect_timeout(Duration::from_secs(5));
+    ///     .deduplicate_contacts(true);
     /// # Ok(())
     /// # }
     /// ```
-    #[cfg_attr(tarpaulin, coverage(off))]
-    pub fn connect_timeout(mut self, timeout: Duration) -> Self {
-        self.builder = self.builder.connect_timeout(timeout);
+    pub fn deduplicate_contacts(mut self, value: bool) -> Self {
+        self.dedupe = value.then(|| self.dedupe.unwrap_or_default());
         self
     }
 
-    /// Sets the `User-Agent` header to be used by the constructed client.
-    ///
-    /// Unless explicitly set, the `User-Agent` header will be omitted entirely
-    /// from all requests.
+    /// Enable the removal of duplicate contacts from bulk verification
+    /// lists before they're uploaded, comparing email addresses
+    /// according to the supplied [`DedupePolicy`](DedupePolicy)
     ///
     /// #### Example
     /// ```no_run
-    /// # use briteverify_rs::BriteVerifyClientBuilder;
+    /// # use briteverify_rs::{BriteVerifyClientBuilder, dedupe::DedupePolicy};
     /// #
     /// # fn doc() -> anyhow::Result<()> {
     /// let builder: BriteVerifyClientBuilder = BriteVerifyClientBuilder::new()
-    ///     .user_agent("briteverify-rs");
+    ///     .dedupe_policy(DedupePolicy::new().ignore_plus_tags(true));
     /// # Ok(())
     /// # }
     /// ```
-    #[cfg_attr(tarpaulin, coverage(off))]
-    pub fn user_agent<V>(mut self, value: V) -> BriteVerifyClientBuilder
-    where
-        V: TryInto<HeaderValue>,
-        V::Error: Into<http::Error>,
-    {
-        self.builder = self.builder.user_agent(value);
+    pub fn dedupe_policy(mut self, policy: DedupePolicy) -> Self {
+        self.dedupe = Some(policy);
         self
     }
 
-    /// Sets the default headers for every request.
-    ///
-    /// **NOTE:** [`HeaderMap`](HeaderMap)s do not enforce
-    /// uniqueness of contained key-value pairs. It is *absolutely*
-    /// possible to insert the same key more than once, either
-    /// with the same value or wildly different values. Proceed
-    /// accordingly.
+    /// Hold the built client's credit spending to the supplied
+    /// [`CreditBudget`](CreditBudget), refusing to send any
+    /// verification request or bulk list upload that doesn't
+    /// fit within it (see [`budget`](crate::budget) for details)
     ///
     /// #### Example
     /// ```no_run
-    /// # use briteverify_rs::BriteVerifyClientBuilder;
+    /// # use briteverify_rs::{BriteVerifyClientBuilder, budget::CreditBudget};
+    /// #
     /// # fn doc() -> anyhow::Result<()> {
-    /// use reqwest::header::{HeaderMap, HeaderValue, CONTENT_TYPE};
-    ///
-    /// let mut headers = HeaderMap::new();
-    /// let content_type = HeaderValue::from_static("application/json");
-    ///
-    /// headers.insert(CONTENT_TYPE, content_type);
-    ///
     /// let builder: BriteVerifyClientBuilder = BriteVerifyClientBuilder::new()
-    ///     .default_headers(headers);
+    ///     .credit_budget(CreditBudget::new().spend_cap(10_000));
     /// # Ok(())
     /// # }
     /// ```
-    #[cfg_attr(tarpaulin, coverage(off))]
-    pub fn default_headers(mut self, headers: HeaderMap) -> BriteVerifyClientBuilder {
-        self.builder = self.builder.default_headers(headers);
+    pub fn credit_budget(mut self, budget: CreditBudget) -> Self {
+        self.budget = Some(budget);
         self
     }
 
-    /// Enable auto gzip decompression by checking the `Content-Encoding` response header.
-    ///
-    /// If auto gzip decompression is turned on:
-    ///
-    /// - When sending a request and if the request's headers do not already contain
-    ///   an `Accept-Encoding` **and** `Range` values, the `Accept-Encoding` header is set to `gzip`.
-    ///   The request body is **not** automatically compressed.
-    /// - When receiving a response, if its headers contain a `Content-Encoding` value of
-    ///   `gzip`, both `Content-Encoding` and `Content-Length` are removed from the
-    ///   headers' set. The response body is automatically decompressed.
-    ///
-    /// Because `briteverify-rs` explicitly enables `reqwest`'s *gzip* feature, this option is
-    /// enabled by default.
+    /// Record the credits consumed by every verification and bulk
+    /// verification list the built client sends to the supplied
+    /// [`CreditLedger`](CreditLedger) (see [`ledger`](crate::ledger)
+    /// for details)
     ///
     /// #### Example
     /// ```no_run
-    /// # use briteverify_rs::BriteVerifyClientBuilder;
+    /// # use briteverify_rs::{BriteVerifyClientBuilder, ledger::{CreditLedger, InMemoryLedger}};
     /// #
     /// # fn doc() -> anyhow::Result<()> {
     /// let builder: BriteVerifyClientBuilder = BriteVerifyClientBuilder::new()
-    ///     .gzip(true);
+    ///     .credit_ledger(CreditLedger::new(InMemoryLedger::new()));
     /// # Ok(())
     /// # }
     /// ```
-    #[cfg_attr(tarpaulin, coverage(off))]
-    pub fn gzip(mut self, enable: bool) -> BriteVerifyClientBuilder {
-        self.builder = self.builder.gzip(enable);
+    pub fn credit_ledger(mut self, ledger: CreditLedger) -> Self {
+        self.ledger = Some(Arc::new(ledger));
         self
     }
 
-    /// Enable auto brotli decompression by checking the `Content-Encoding` response header.
-    ///
-    /// If auto brotli decompression is turned on:
-    ///
-    /// - When sending a request and if the request's headers do not already contain
-    ///   an `Accept-Encoding` **and** `Range` values, the `Accept-Encoding` header is set to `br`.
-    ///   The request body is **not** automatically compressed.
-    /// - When receiving a response, if its headers contain a `Content-Encoding` value of
-    ///   `br`, both `Content-Encoding` and `Content-Length` are removed from the
-    ///   headers' set. The response body is automatically decompressed.
-    ///
-    /// Because `briteverify-rs` explicitly enables `reqwest`'s *brotli* feature, this option is
-    /// enabled by default.
+    /// Cache the built client's single-transaction verification
+    /// responses according to the supplied [`CachePolicy`](CachePolicy),
+    /// answering repeated verifications of the same values without
+    /// sending them (see [`cache`](crate::cache) for details)
     ///
     /// #### Example
     /// ```no_run
-    /// # use briteverify_rs::BriteVerifyClientBuilder;
+    /// # use std::time::Duration;
+    /// # use briteverify_rs::{BriteVerifyClientBuilder, cache::CachePolicy};
+    /// use briteverify_rs::types::VerificationStatus;
     /// #
     /// # fn doc() -> anyhow::Result<()> {
     /// let builder: BriteVerifyClientBuilder = BriteVerifyClientBuilder::new()
-    ///     .brotli(true);
+    ///     .verification_cache(
+    ///         CachePolicy::new().ttl(VerificationStatus::Unknown, Duration::from_secs(60)),
+    ///     );
     /// # Ok(())
     /// # }
     /// ```
-    #[cfg_attr(tarpaulin, coverage(off))]
-    pub fn brotli(mut self, enable: bool) -> BriteVerifyClientBuilder {
-        self.builder = self.builder.brotli(enable);
+    pub fn verification_cache(mut self, policy: CachePolicy) -> Self {
+        self.cache = Some(policy);
         self
     }
 
-    /// Disable auto response body gzip decompression.
-    ///
-    /// This method exists even if the optional `gzip` feature is not enabled.
-    /// This can be used to ensure a `Client` doesn't use gzip decompression
-    /// even if another dependency were to enable the optional `gzip` feature.
+    /// Store the results of the built client's verifications and
+    /// bulk list exports in the supplied [`PersistentCache`](PersistentCache),
+    /// answering single-transaction verifications from it while its
+    /// results are fresh (see [`store`](crate::store) for details)
     ///
     /// #### Example
     /// ```no_run
-    /// # use briteverify_rs::BriteVerifyClientBuilder;
+    /// # use briteverify_rs::{
+    /// #     BriteVerifyClientBuilder,
+    /// #     store::{PersistentCache, SqliteResultStore},
+    /// # };
     /// #
     /// # fn doc() -> anyhow::Result<()> {
     /// let builder: BriteVerifyClientBuilder = BriteVerifyClientBuilder::new()
-    ///     .no_gzip();
+    ///     .persistent_cache(PersistentCache::new(SqliteResultStore::open("results.sqlite3")?));
     /// # Ok(())
     /// # }
     /// ```
-    #[cfg_attr(tarpaulin, coverage(off))]
-    pub fn no_gzip(mut self) -> BriteVerifyClientBuilder {
+    #[cfg(feature = "sqlite")]
+    pub fn persistent_cache(mut self, cache: PersistentCache) -> Self {
+        self.persistent = Some(Arc::new(cache));
+        self
+    }
+
+    /// Record the built client's idempotent list creations in the supplied
+    /// [`IdempotentLists`](IdempotentLists)' store, instead of in memory
+    /// (see [`idempotency`](crate::idempotency) for details)
+    ///
+    /// #### Example
+    /// ```no_run
+    /// # use briteverify_rs::{
+    /// #     BriteVerifyClientBuilder,
+    /// #     idempotency::{FileIdempotencyStore, IdempotentLists},
+    /// # };
+    /// #
+    /// # fn doc() -> anyhow::Result<()> {
+    /// let builder: BriteVerifyClientBuilder = BriteVerifyClientBuilder::new()
+    ///     .idempotent_lists(IdempotentLists::new(FileIdempotencyStore::open("lists.json")?));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn idempotent_lists(mut self, lists: IdempotentLists) -> Self {
+        self.idempotency = Some(Arc::new(lists));
+        self
+    }
+
+    /// Enable or disable in-flight request coalescing, in which concurrent
+    /// single-transaction verifications of identical (normalized) values
+    /// share a single request (see [`coalesce`](crate::coalesce) for details)
+    ///
+    /// ___
+    /// **NOTE:** Request coalescing is `disabled` by default.
+    /// ___
+    ///
+    /// #### Example
+    /// ```no_run
+    /// # use briteverify_rs::BriteVerifyClientBuilder;
+    /// #
+    /// # fn doc() -> anyhow::Result<()> {
+    /// let builder: BriteVerifyClientBuilder = BriteVerifyClientBuilder::new()
+    ///     .coalesce_requests(true);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn coalesce_requests(mut self, value: bool) -> Self {
+        self.coalesce = value;
+        self
+    }
+
+    /// Enable or disable sandbox mode, in which the built client answers
+    /// every request locally (without spending any credits) based on the
+    /// "magic" values being verified (see [`sandbox`](crate::sandbox)
+    /// for details)
+    ///
+    /// ___
+    /// **NOTE:** Sandbox mode is `disabled` by default. When enabled,
+    /// it takes precedence over any configured cassette.
+    /// ___
+    ///
+    /// #### Example
+    /// ```no_run
+    /// # use briteverify_rs::BriteVerifyClientBuilder;
+    /// #
+    /// # fn doc() -> anyhow::Result<()> {
+    /// let builder: BriteVerifyClientBuilder = BriteVerifyClientBuilder::new()
+    ///     .sandbox(true);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn sandbox(mut self, value: bool) -> Self {
+        self.sandbox = value;
+        self
+    }
+
+    /// Enable or disable dry-run mode, in which the built client
+    /// validates and records every request instead of sending it
+    /// (see [`dry_run`](crate::dry_run) for details)
+    ///
+    /// ___
+    /// **NOTE:** Dry-run mode is `disabled` by default. When enabled,
+    /// it takes precedence over both sandbox mode and any configured
+    /// cassette.
+    /// ___
+    ///
+    /// #### Example
+    /// ```no_run
+    /// # use briteverify_rs::BriteVerifyClientBuilder;
+    /// #
+    /// # fn doc() -> anyhow::Result<()> {
+    /// let builder: BriteVerifyClientBuilder = BriteVerifyClientBuilder::new()
+    ///     .dry_run(true);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn dry_run(mut self, value: bool) -> Self {
+        self.dry_run = value;
+        self
+    }
+
+    /// Set the [`Clock`](Clock) the built client will use to
+    /// measure request latency and elapsed time (see
+    /// [`clock`](crate::clock) for details)
+    ///
+    /// ___
+    /// **NOTE:** The system's monotonic clock is used by default.
+    /// ___
+    ///
+    /// #### Example
+    /// ```no_run
+    /// # use briteverify_rs::{BriteVerifyClientBuilder, clock::VirtualClock};
+    /// #
+    /// # fn doc() -> anyhow::Result<()> {
+    /// let builder: BriteVerifyClientBuilder = BriteVerifyClientBuilder::new()
+    ///     .clock(VirtualClock::new());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn clock<TimeSource: Clock + 'static>(mut self, clock: TimeSource) -> Self {
+        self.timekeeper.clock = Arc::new(clock);
+        self
+    }
+
+    /// Set the [`Sleeper`](Sleeper) the built client will use
+    /// to wait out rate limits before retrying requests (see
+    /// [`clock`](crate::clock) for details)
+    ///
+    /// ___
+    /// **NOTE:** A runtime-agnostic [`futures_timer`](futures_timer)
+    /// based sleeper is used by default.
+    /// ___
+    ///
+    /// #### Example
+    /// ```no_run
+    /// # use briteverify_rs::BriteVerifyClientBuilder;
+    /// #
+    /// # fn doc() -> anyhow::Result<()> {
+    /// let builder: BriteVerifyClientBuilder = BriteVerifyClientBuilder::new()
+    ///     .retry_enabled(true)
+    ///     .sleeper(tokio::time::sleep);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn sleeper<Sleep: Sleeper + 'static>(mut self, sleeper: Sleep) -> Self {
+        self.timekeeper.sleeper = Arc::new(sleeper);
+        self
+    }
+
+    /// Record every request the built client sends, along with the
+    /// response it receives, to a cassette file at the supplied path
+    /// (see [`cassette`](crate::cassette) for details)
+    ///
+    /// #### Example
+    /// ```no_run
+    /// # use briteverify_rs::BriteVerifyClientBuilder;
+    /// #
+    /// # fn doc() -> anyhow::Result<()> {
+    /// let builder: BriteVerifyClientBuilder = BriteVerifyClientBuilder::new()
+    ///     .record_cassette("tests/cassettes/session.jsonl");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn record_cassette<CassettePath: Into<PathBuf>>(mut self, path: CassettePath) -> Self {
+        self.cassette = Some((CassetteMode::Record, path.into()));
+        self
+    }
+
+    /// Answer every request the built client sends from the cassette
+    /// file at the supplied path instead of actually sending them
+    /// (see [`cassette`](crate::cassette) for details)
+    ///
+    /// ___
+    /// **NOTE:** The cassette file is loaded when the client is built,
+    /// so [`build`](BriteVerifyClientBuilder::build) will fail if it
+    /// doesn't exist or can't be parsed.
+    /// ___
+    ///
+    /// #### Example
+    /// ```no_run
+    /// # use briteverify_rs::BriteVerifyClientBuilder;
+    /// #
+    /// # fn doc() -> anyhow::Result<()> {
+    /// let builder: BriteVerifyClientBuilder = BriteVerifyClientBuilder::new()
+    ///     .replay_cassette("tests/cassettes/session.jsonl");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn replay_cassette<CassettePath: Into<PathBuf>>(mut self, path: CassettePath) -> Self {
+        self.cassette = Some((CassetteMode::Replay, path.into()));
+        self
+    }
+
+    /// Scrub recorded request and response bodies with the supplied
+    /// [`BodyFilter`](crate::cassette::BodyFilter) instead of the
+    /// default (which masks contact values)
+    ///
+    /// ___
+    /// **NOTE:** Cassettes must be replayed with the same filter they
+    /// were recorded with, or replayed requests won't match recorded
+    /// ones.
+    /// ___
+    ///
+    /// #### Example
+    /// ```no_run
+    /// # use briteverify_rs::{BriteVerifyClientBuilder, cassette::BodyFilter};
+    /// #
+    /// # fn doc() -> anyhow::Result<()> {
+    /// let builder: BriteVerifyClientBuilder = BriteVerifyClientBuilder::new()
+    ///     .record_cassette("tests/cassettes/session.jsonl")
+    ///     .cassette_body_filter(BodyFilter::none());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn cassette_body_filter(mut self, filter: BodyFilter) -> Self {
+        self.cassette_filter = filter;
+        self
+    }
+
+    /// Override the base URL for requests to the BriteVerify v1 API
+    /// [[ref](https://docs.briteverify.com/#79e00732-b734-4308-ac7f-820d62dde01f)]
+    ///
+    /// ___
+    /// **NOTE:** Unless overridden (specifically by calling [`v1_base_url`]
+    /// on a builder instance), the default value of `https://bpi.briteverify.com/api/v1`
+    /// will be used as the base url for single-transaction requests.
+    ///
+    /// If you set a custom url, be aware that no additional logic, formatting,
+    /// or validity checks will be applied to whatever value you specify.
+    /// ___
+    ///
+    /// #### Example
+    /// ```no_run
+    /// # use briteverify_rs::BriteVerifyClientBuilder;
+    /// #
+    /// # fn doc() -> anyhow::Result<()> {
+    /// let builder: BriteVerifyClientBuilder = BriteVerifyClientBuilder::new()
+    ///     .v1_base_url("https://my-custom-domain.net/briteverify/v1");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn v1_base_url<URL>(mut self, url: URL) -> Self
+    where
+        URL: TryInto<url::Url>,
+        URL::Error: Into<BriteVerifyClientError>,
+    {
+        let url = url.try_into();
+
+        match url {
+            Ok(value) => {
+                self.v1_base_url = value;
+            }
+            Err(error) => {
+                self.error = Some(error.into());
+            }
+        }
+
+        self
+    }
+
+    /// Override the base URL for requests to the BriteVerify v3 API
+    /// [[ref](https://docs.briteverify.com/#382f454d-dad2-49c3-b320-c7d117fcc20a)]
+    ///
+    /// ___
+    /// **NOTE:** Unless overridden (specifically by calling [`v3_base_url`]
+    /// on a builder instance), the default value of `https://bulk-api.briteverify.com/api/v3`
+    /// will be used as the base url for bulk transaction requests.
+    ///
+    /// If you set a custom url, be aware that no additional logic, formatting,
+    /// or validity checks will be applied to whatever value you specify.
+    /// ___
+    ///
+    /// #### Example
+    /// ```no_run
+    /// # use briteverify_rs::BriteVerifyClientBuilder;
+    /// #
+    /// # fn doc() -> anyhow::Result<()> {
+    /// let builder: BriteVerifyClientBuilder = BriteVerifyClientBuilder::new()
+    ///     .v3_base_url("https://my-custom-domain.net/briteverify/v3");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn v3_base_url<URL>(mut self, url: URL) -> Self
+    where
+        URL: TryInto<url::Url>,
+        URL::Error: Into<BriteVerifyClientError>,
+    {
+        let url = url.try_into();
+
+        match url {
+            Ok(value) => {
+                self.v3_base_url = value;
+            }
+            Err(error) => {
+                self.error = Some(error.into());
+            }
+        }
+
+        self
+    }
+
+    // Timeout options
+
+    /// Enables a request timeout.
+    ///
+    /// The timeout is applied from when the request starts connecting until the
+    /// response body has finished.
+    ///
+    /// Default is no timeout.
+    ///
+    /// #### Example
+    /// ```no_run
+    /// # use std::time::Duration;
+    /// # use briteverify_rs::BriteVerifyClientBuilder;
+    /// #
+    /// # fn doc() -> anyhow::Result<()> {
+    /// let builder: BriteVerifyClientBuilder = BriteVerifyClientBuilder::new()
+    ///     .timeout(Duration::from_secs(5));
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg_attr(tarpaulin, coverage(off))]
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.timeout(timeout);
+        self
+    }
+
+    /// Set a timeout for only the connect phase of a `Client`.
+    ///
+    /// Default is `None`.
+    ///
+    /// #### Example
+    /// ```no_run
+    /// # use std::time::Duration;
+    /// # use briteverify_rs::BriteVerifyClientBuilder;
+    /// #
+    /// # fn doc() -> anyhow::Result<()> {
+    /// let builder: BriteVerifyClientBuilder = BriteVerifyClientBuilder::new()
+    ///     .connect_timeout(Duration::from_secs(5));
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg_attr(tarpaulin, coverage(off))]
+    pub fn connect_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.connect_timeout(timeout);
+        self
+    }
+
+    /// Sets the `User-Agent` header to be used by the constructed client.
+    ///
+    /// Unless explicitly set, the `User-Agent` header will be omitted entirely
+    /// from all requests.
+    ///
+    /// #### Example
+    /// ```no_run
+    /// # use briteverify_rs::BriteVerifyClientBuilder;
+    /// #
+    /// # fn doc() -> anyhow::Result<()> {
+    /// let builder: BriteVerifyClientBuilder = BriteVerifyClientBuilder::new()
+    ///     .user_agent("briteverify-rs");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg_attr(tarpaulin, coverage(off))]
+    pub fn user_agent<V>(mut self, value: V) -> BriteVerifyClientBuilder
+    where
+        V: TryInto<HeaderValue>,
+        V::Error: Into<http::Error>,
+    {
+        self.builder = self.builder.user_agent(value);
+        self
+    }
+
+    /// Sets the default headers for every request.
+    ///
+    /// **NOTE:** [`HeaderMap`](HeaderMap)s do not enforce
+    /// uniqueness of contained key-value pairs. It is *absolutely*
+    /// possible to insert the same key more than once, either
+    /// with the same value or wildly different values. Proceed
+    /// accordingly.
+    ///
+    /// #### Example
+    /// ```no_run
+    /// # use briteverify_rs::BriteVerifyClientBuilder;
+    /// # fn doc() -> anyhow::Result<()> {
+    /// use reqwest::header::{HeaderMap, HeaderValue, CONTENT_TYPE};
+    ///
+    /// let mut headers = HeaderMap::new();
+    /// let content_type = HeaderValue::from_static("application/json");
+    ///
+    /// headers.insert(CONTENT_TYPE, content_type);
+    ///
+    /// let builder: BriteVerifyClientBuilder = BriteVerifyClientBuilder::new()
+    ///     .default_headers(headers);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg_attr(tarpaulin, coverage(off))]
+    pub fn default_headers(mut self, headers: HeaderMap) -> BriteVerifyClientBuilder {
+        self.builder = self.builder.default_headers(headers);
+        self
+    }
+
+    /// Enable auto gzip decompression by checking the `Content-Encoding` response header.
+    ///
+    /// If auto gzip decompression is turned on:
+    ///
+    /// - When sending a request and if the request's headers do not already contain
+    ///   an `Accept-Encoding` **and** `Range` values, the `Accept-Encoding` header is set to `gzip`.
+    ///   The request body is **not** automatically compressed.
+    /// - When receiving a response, if its headers contain a `Content-Encoding` value of
+    ///   `gzip`, both `Content-Encoding` and `Content-Length` are removed from the
+    ///   headers' set. The response body is automatically decompressed.
+    ///
+    /// Because `briteverify-rs` explicitly enables `reqwest`'s *gzip* feature, this option is
+    /// enabled by default.
+    ///
+    /// #### Example
+    /// ```no_run
+    /// # use briteverify_rs::BriteVerifyClientBuilder;
+    /// #
+    /// # fn doc() -> anyhow::Result<()> {
+    /// let builder: BriteVerifyClientBuilder = BriteVerifyClientBuilder::new()
+    ///     .gzip(true);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg_attr(tarpaulin, coverage(off))]
+    pub fn gzip(mut self, enable: bool) -> BriteVerifyClientBuilder {
+        self.builder = self.builder.gzip(enable);
+        self
+    }
+
+    /// Enable auto brotli decompression by checking the `Content-Encoding` response header.
+    ///
+    /// If auto brotli decompression is turned on:
+    ///
+    /// - When sending a request and if the request's headers do not already contain
+    ///   an `Accept-Encoding` **and** `Range` values, the `Accept-Encoding` header is set to `br`.
+    ///   The request body is **not** automatically compressed.
+    /// - When receiving a response, if its headers contain a `Content-Encoding` value of
+    ///   `br`, both `Content-Encoding` and `Content-Length` are removed from the
+    ///   headers' set. The response body is automatically decompressed.
+    ///
+    /// Because `briteverify-rs` explicitly enables `reqwest`'s *brotli* feature, this option is
+    /// enabled by default.
+    ///
+    /// #### Example
+    /// ```no_run
+    /// # use briteverify_rs::BriteVerifyClientBuilder;
+    /// #
+    /// # fn doc() -> anyhow::Result<()> {
+    /// let builder: BriteVerifyClientBuilder = BriteVerifyClientBuilder::new()
+    ///     .brotli(true);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg_attr(tarpaulin, coverage(off))]
+    pub fn brotli(mut self, enable: bool) -> BriteVerifyClientBuilder {
+        self.builder = self.builder.brotli(enable);
+        self
+    }
+
+    /// Disable auto response body gzip decompression.
+    ///
+    /// This method exists even if the optional `gzip` feature is not enabled.
+    /// This can be used to ensure a `Client` doesn't use gzip decompression
+    /// even if another dependency were to enable the optional `gzip` feature.
+    ///
+    /// #### Example
+    /// ```no_run
+    /// # use briteverify_rs::BriteVerifyClientBuilder;
+    /// #
+    /// # fn doc() -> anyhow::Result<()> {
+    /// let builder: BriteVerifyClientBuilder = BriteVerifyClientBuilder::new()
+    ///     .no_gzip();
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg_attr(tarpaulin, coverage(off))]
+    pub fn no_gzip(mut self) -> BriteVerifyClientBuilder {
         self.builder = self.builder.no_gzip();
         self
     }
@@ -1414,13 +2161,29 @@ impl BriteVerifyClientBuilder {
 /// # Ok(())
 /// # }
 /// ```
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 #[cfg_attr(test, visible::StructFields(pub))]
 pub struct BriteVerifyClient {
     client: reqwest::Client,
-    v1_base_url: url::Url,
-    v3_base_url: url::Url,
+    core: sansio::BriteVerifyCore,
     retry_enabled: bool,
+    validate_email_syntax: bool,
+    normalize_phone_numbers: bool,
+    phone_region: Option<String>,
+    normalize_addresses: bool,
+    dedupe: Option<Arc<Deduplicator>>,
+    budget: Option<Arc<BudgetGuard>>,
+    ledger: Option<Arc<CreditLedger>>,
+    cache: Option<Arc<VerificationCache>>,
+    #[cfg(feature = "sqlite")]
+    persistent: Option<Arc<PersistentCache>>,
+    idempotency: Arc<IdempotentLists>,
+    inflight: Option<Arc<InFlightVerifications>>,
+    options: RequestOptions,
+    sandbox: Option<BriteVerifySimulator>,
+    dry_run: Option<Arc<DryRun>>,
+    cassette: Option<Arc<Cassette>>,
+    timekeeper: Timekeeper,
 }
 
 impl Deref for BriteVerifyClient {
@@ -1439,8 +2202,24 @@ impl TryFrom<reqwest::Client> for BriteVerifyClient {
             Ok(Self {
                 client,
                 retry_enabled: true,
-                v1_base_url: V1_API_BASE_URL.parse::<url::Url>().unwrap(),
-                v3_base_url: V3_API_BASE_URL.parse::<url::Url>().unwrap(),
+                validate_email_syntax: false,
+                normalize_phone_numbers: false,
+                phone_region: None,
+                normalize_addresses: false,
+                dedupe: None,
+                budget: None,
+                ledger: None,
+                cache: None,
+                #[cfg(feature = "sqlite")]
+                persistent: None,
+                idempotency: Arc::default(),
+                inflight: None,
+                core: sansio::BriteVerifyCore::default(),
+                options: RequestOptions::default(),
+                sandbox: None,
+                dry_run: None,
+                cassette: None,
+                timekeeper: Timekeeper::default(),
             })
         } else {
             Err(errors::BriteVerifyClientError::MissingApiKey)
@@ -1451,7 +2230,277 @@ impl TryFrom<reqwest::Client> for BriteVerifyClient {
 impl BriteVerifyClient {
     // <editor-fold desc="// Constructors ... ">
 
-    /// Create a new [`BriteVerifyClient`][BriteVerifyClient] instance
+    /// Create a new [`BriteVerifyClient`][BriteVerifyClient] instance
+    ///
+    /// #### Example
+    /// ```no_run
+    /// # use briteverify_rs::BriteVerifyClient;
+    /// #
+    /// # fn doc() -> anyhow::Result<()> {
+    /// let client: BriteVerifyClient = BriteVerifyClient::new("YOUR API KEY")?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn new<ApiKey: ToString>(api_key: ApiKey) -> Result<Self, errors::BriteVerifyClientError> {
+        Self::builder().api_key(api_key).build()
+    }
+
+    /// Create a new [builder][BriteVerifyClientBuilder] to incrementally
+    /// build a [`BriteVerifyClient`][BriteVerifyClient] with a customised
+    /// configuration
+    ///
+    /// #### Example
+    /// ```no_run
+    /// # use briteverify_rs::{BriteVerifyClient, BriteVerifyClientBuilder};
+    /// #
+    /// # fn doc() -> anyhow::Result<()> {
+    /// let builder: BriteVerifyClientBuilder = BriteVerifyClient::builder();
+    ///
+    /// // ... call various builder methods
+    ///
+    /// let client: BriteVerifyClient = builder.build()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn builder() -> BriteVerifyClientBuilder {
+        BriteVerifyClientBuilder::new()
+    }
+
+    /// Create a copy of the client that applies the supplied
+    /// [`RequestOptions`](RequestOptions) to every request it sends.
+    ///
+    /// The returned client shares its underlying connection pool with
+    /// the original, so creating one per call is cheap. Options are not
+    /// cumulative; any options already attached to `self` are replaced.
+    ///
+    /// #### Example
+    /// ```no_run
+    /// # use std::time::Duration;
+    /// # use briteverify_rs::{BriteVerifyClient, RequestOptions, types::AccountCreditBalance};
+    /// #
+    /// # async fn doc() -> anyhow::Result<()> {
+    /// # let client: BriteVerifyClient = BriteVerifyClient::new("YOUR API KEY")?;
+    /// let balance: AccountCreditBalance = client
+    ///     .with_options(RequestOptions::new().timeout(Duration::from_secs(300)))
+    ///     .get_account_balance()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_options(&self, options: RequestOptions) -> Self {
+        Self {
+            options,
+            ..self.clone()
+        }
+    }
+
+    /// Get the transport-independent [`BriteVerifyCore`](sansio::BriteVerifyCore)
+    /// the client uses to build requests and interpret responses
+    ///
+    /// ___
+    /// **NOTE:** The returned core does *not* carry the client's
+    /// API key, which is attached by the underlying `reqwest`
+    /// client instead.
+    /// ___
+    ///
+    /// #### Example
+    /// ```no_run
+    /// # use briteverify_rs::{BriteVerifyClient, sansio::HttpRequest};
+    /// #
+    /// # fn doc() -> anyhow::Result<()> {
+    /// # let client: BriteVerifyClient = BriteVerifyClient::new("YOUR API KEY")?;
+    /// let request: HttpRequest = client.core().account_balance_request()?;
+    ///
+    /// println!("Balance requests are sent to: {}", request.uri());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn core(&self) -> &sansio::BriteVerifyCore {
+        &self.core
+    }
+
+    /// Get the [`Cassette`](Cassette) the client is
+    /// recording to or replaying from, if any
+    ///
+    /// #### Example
+    /// ```no_run
+    /// # use briteverify_rs::BriteVerifyClient;
+    /// #
+    /// # async fn doc() -> anyhow::Result<()> {
+    /// let client: BriteVerifyClient = BriteVerifyClient::builder()
+    ///     .api_key("YOUR API KEY")
+    ///     .replay_cassette("tests/cassettes/balance.jsonl")
+    ///     .build()?;
+    ///
+    /// client.get_account_balance().await?;
+    ///
+    /// assert_eq!(client.cassette().map(|cassette| cassette.remaining()), Some(0));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn cassette(&self) -> Option<&Cassette> {
+        self.cassette.as_deref()
+    }
+
+    /// Check whether the client is running in sandbox mode
+    /// (see [`sandbox`](crate::sandbox) for details)
+    ///
+    /// #### Example
+    /// ```no_run
+    /// # use briteverify_rs::BriteVerifyClient;
+    /// #
+    /// # fn doc() -> anyhow::Result<()> {
+    /// let client: BriteVerifyClient = BriteVerifyClient::builder()
+    ///     .api_key("YOUR API KEY")
+    ///     .sandbox(true)
+    ///     .build()?;
+    ///
+    /// assert!(client.is_sandbox());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn is_sandbox(&self) -> bool {
+        self.sandbox.is_some()
+    }
+
+    /// Get the [`DryRun`](DryRun) log of every request the
+    /// client has validated and recorded (instead of sending),
+    /// if the client is running in dry-run mode
+    ///
+    /// #### Example
+    /// ```no_run
+    /// # use briteverify_rs::BriteVerifyClient;
+    /// #
+    /// # async fn doc() -> anyhow::Result<()> {
+    /// let client: BriteVerifyClient = BriteVerifyClient::builder()
+    ///     .api_key("YOUR API KEY")
+    ///     .dry_run(true)
+    ///     .build()?;
+    ///
+    /// client.verify_email("test@example.com").await?;
+    ///
+    /// assert_eq!(client.dry_run().map(|log| log.estimated_credits()), Some(1));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn dry_run(&self) -> Option<&DryRun> {
+        self.dry_run.as_deref()
+    }
+
+    /// Get the client's [`Deduplicator`](Deduplicator), if it
+    /// was built with contact deduplication enabled
+    ///
+    /// #### Example
+    /// ```no_run
+    /// # use briteverify_rs::BriteVerifyClient;
+    /// #
+    /// # fn doc() -> anyhow::Result<()> {
+    /// let client: BriteVerifyClient = BriteVerifyClient::builder()
+    ///     .api_key("YOUR API KEY")
+    ///     .deduplicate_contacts(true)
+    ///     .build()?;
+    ///
+    /// assert!(client.deduplicator().is_some());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn deduplicator(&self) -> Option<&Deduplicator> {
+        self.dedupe.as_deref()
+    }
+
+    /// Get the client's [`BudgetGuard`](BudgetGuard), if
+    /// it was built with a credit budget
+    ///
+    /// #### Example
+    /// ```no_run
+    /// # use briteverify_rs::{BriteVerifyClient, budget::CreditBudget};
+    /// #
+    /// # fn doc() -> anyhow::Result<()> {
+    /// let client: BriteVerifyClient = BriteVerifyClient::builder()
+    ///     .api_key("YOUR API KEY")
+    ///     .credit_budget(CreditBudget::new().spend_cap(10_000))
+    ///     .build()?;
+    ///
+    /// assert_eq!(client.budget_guard().and_then(|guard| guard.remaining()), Some(10_000));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn budget_guard(&self) -> Option<&BudgetGuard> {
+        self.budget.as_deref()
+    }
+
+    /// Get the client's [`CreditLedger`](CreditLedger),
+    /// if it was built with one
+    ///
+    /// #### Example
+    /// ```no_run
+    /// # use briteverify_rs::{BriteVerifyClient, ledger::{CreditLedger, InMemoryLedger}};
+    /// #
+    /// # fn doc() -> anyhow::Result<()> {
+    /// let client: BriteVerifyClient = BriteVerifyClient::builder()
+    ///     .api_key("YOUR API KEY")
+    ///     .credit_ledger(CreditLedger::new(InMemoryLedger::new()))
+    ///     .build()?;
+    ///
+    /// assert!(client.credit_ledger().is_some_and(|ledger| !ledger.snapshots_balances()));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn credit_ledger(&self) -> Option<&CreditLedger> {
+        self.ledger.as_deref()
+    }
+
+    /// Get the client's [`VerificationCache`](VerificationCache),
+    /// if it was built with one
+    ///
+    /// #### Example
+    /// ```no_run
+    /// # use briteverify_rs::{BriteVerifyClient, cache::CachePolicy};
+    /// #
+    /// # fn doc() -> anyhow::Result<()> {
+    /// let client: BriteVerifyClient = BriteVerifyClient::builder()
+    ///     .api_key("YOUR API KEY")
+    ///     .verification_cache(CachePolicy::new())
+    ///     .build()?;
+    ///
+    /// assert!(client.verification_cache().is_some_and(|cache| cache.is_empty()));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn verification_cache(&self) -> Option<&VerificationCache> {
+        self.cache.as_deref()
+    }
+
+    /// Get the client's [`PersistentCache`](PersistentCache),
+    /// if it was built with one
+    ///
+    /// #### Example
+    /// ```no_run
+    /// # use briteverify_rs::{
+    /// #     BriteVerifyClient,
+    /// #     store::{PersistentCache, SqliteResultStore},
+    /// # };
+    /// #
+    /// # fn doc() -> anyhow::Result<()> {
+    /// let client: BriteVerifyClient = BriteVerifyClient::builder()
+    ///     .api_key("YOUR API KEY")
+    ///     .persistent_cache(PersistentCache::new(SqliteResultStore::open("results.sqlite3")?))
+    ///     .build()?;
+    ///
+    /// let known = client
+    ///     .persistent_cache()
+    ///     .unwrap()
+    ///     .last_known_email("test@example.com")?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "sqlite")]
+    pub fn persistent_cache(&self) -> Option<&PersistentCache> {
+        self.persistent.as_deref()
+    }
+
+    /// Get the client's [`IdempotentLists`](IdempotentLists), which
+    /// record its [idempotent](crate::idempotency) list creations
     ///
     /// #### Example
     /// ```no_run
@@ -1459,32 +2508,34 @@ impl BriteVerifyClient {
     /// #
     /// # fn doc() -> anyhow::Result<()> {
     /// let client: BriteVerifyClient = BriteVerifyClient::new("YOUR API KEY")?;
+    ///
+    /// let record = client.idempotent_lists().store().get("newsletter-2024-06-01")?;
     /// # Ok(())
     /// # }
     /// ```
-    pub fn new<ApiKey: ToString>(api_key: ApiKey) -> Result<Self, errors::BriteVerifyClientError> {
-        Self::builder().api_key(api_key).build()
+    pub fn idempotent_lists(&self) -> &IdempotentLists {
+        &self.idempotency
     }
 
-    /// Create a new [builder][BriteVerifyClientBuilder] to incrementally
-    /// build a [`BriteVerifyClient`][BriteVerifyClient] with a customised
-    /// configuration
+    /// Get the client's [`InFlightVerifications`](InFlightVerifications),
+    /// if it was built with request coalescing enabled
     ///
     /// #### Example
     /// ```no_run
-    /// # use briteverify_rs::{BriteVerifyClient, BriteVerifyClientBuilder};
+    /// # use briteverify_rs::BriteVerifyClient;
     /// #
     /// # fn doc() -> anyhow::Result<()> {
-    /// let builder: BriteVerifyClientBuilder = BriteVerifyClient::builder();
-    ///
-    /// // ... call various builder methods
+    /// let client: BriteVerifyClient = BriteVerifyClient::builder()
+    ///     .api_key("YOUR API KEY")
+    ///     .coalesce_requests(true)
+    ///     .build()?;
     ///
-    /// let client: BriteVerifyClient = builder.build()?;
+    /// assert!(client.inflight_verifications().is_some_and(|inflight| inflight.is_empty()));
     /// # Ok(())
     /// # }
     /// ```
-    pub fn builder() -> BriteVerifyClientBuilder {
-        BriteVerifyClientBuilder::new()
+    pub fn inflight_verifications(&self) -> Option<&InFlightVerifications> {
+        self.inflight.as_deref()
     }
 
     // </editor-fold desc="// Constructors ... ">
@@ -1501,7 +2552,31 @@ impl BriteVerifyClient {
         &self,
         builder: reqwest::RequestBuilder,
     ) -> Result<reqwest::Response, errors::BriteVerifyClientError> {
+        Ok(self._dispatch(builder).await?.0)
+    }
+
+    /// [internal-implementation]
+    /// Build and send the supplied request, capturing the
+    /// [`ResponseMetadata`](types::ResponseMetadata) of the
+    /// final exchange along the way
+    ///
+    /// If `retry_enabled` is true, rate limit error responses
+    /// will be automatically handled by sleeping until the rate
+    /// limit expires and re-sending the request
+    async fn _dispatch(
+        &self,
+        builder: reqwest::RequestBuilder,
+    ) -> Result<(reqwest::Response, types::ResponseMetadata), errors::BriteVerifyClientError> {
+        let builder = self.options.apply(builder);
+        let retry_enabled = self.options.retry_enabled.unwrap_or(self.retry_enabled);
+
+        let clock = &self.timekeeper.clock;
+        let started = clock.now();
+        let mut retries: u32 = 0;
+
         loop {
+            let attempt = clock.now();
+
             let response = (match builder.try_clone() {
                 Some(instance) => instance,
                 None => break Err(errors::BriteVerifyClientError::UnclonableRequest),
@@ -1509,33 +2584,97 @@ impl BriteVerifyClient {
             .send()
             .await?;
 
-            match (&self.retry_enabled, response.status()) {
+            let latency = clock.now().saturating_duration_since(attempt);
+
+            let retry_after = match retry_enabled {
+                true => {
+                    sansio::BriteVerifyCore::_retry_delay(response.status(), response.headers())
+                }
+                false => None,
+            };
+
+            match (retry_after, response.status()) {
                 (_, StatusCode::UNAUTHORIZED) => {
                     break Err(errors::BriteVerifyClientError::InvalidApiKey);
                 }
-                (&true, StatusCode::TOO_MANY_REQUESTS) => {
-                    let retry_after = 1 + response
-                        .headers()
-                        .get("retry-after")
-                        .and_then(|value| value.to_str().ok())
-                        .and_then(|value| value.parse::<u64>().ok())
-                        .unwrap_or(60);
-
+                (Some(retry_after), _) => {
                     log::warn!(
                         "Request to '{}' responded 429, waiting {} seconds before retry...",
                         response.url(),
-                        &retry_after
+                        retry_after.as_secs()
                     );
 
-                    Delay::new(Duration::from_secs(retry_after)).await;
+                    self.timekeeper.sleeper.sleep(retry_after).await;
+
+                    retries += 1;
                 }
                 _ => {
-                    break Ok(response);
+                    let meta = types::ResponseMetadata::from_response(
+                        &response,
+                        retries,
+                        latency,
+                        clock.now().saturating_duration_since(started),
+                    );
+
+                    break Ok((response, meta));
                 }
             }
         }
     }
 
+    /// [internal-implementation]
+    /// Send the supplied transport-independent request via the
+    /// underlying `reqwest` client and collect the response into
+    /// its transport-independent equivalent
+    async fn _exchange(
+        &self,
+        request: sansio::HttpRequest,
+    ) -> Result<(sansio::HttpResponse, types::ResponseMetadata), errors::BriteVerifyClientError>
+    {
+        if let Some(dry_run) = self.dry_run.as_deref() {
+            return dry_run._handle(request);
+        }
+
+        if let Some(sandbox) = self.sandbox.as_ref() {
+            let uri = request.uri().clone();
+            let reply = sandbox.handle(request);
+            let meta = types::ResponseMetadata::from_local(&uri, &reply)?;
+
+            return Ok((reply, meta));
+        }
+
+        let captured = match self.cassette.as_deref() {
+            None => None,
+            Some(cassette) if cassette.mode() == CassetteMode::Replay => {
+                return cassette._play(&request);
+            }
+            Some(cassette) => Some(cassette._capture(&request)),
+        };
+
+        let request = reqwest::Request::try_from(request)?;
+        let builder = reqwest::RequestBuilder::from_parts(self.client.clone(), request);
+
+        let (response, meta) = self._dispatch(builder).await?;
+
+        let mut reply = http::Response::builder()
+            .status(response.status())
+            .version(response.version());
+
+        if let Some(headers) = reply.headers_mut() {
+            headers.extend(response.headers().clone());
+        }
+
+        let reply = reply
+            .body(response.bytes().await?.to_vec())
+            .context("Could not collect response")?;
+
+        if let (Some(cassette), Some(captured)) = (self.cassette.as_deref(), captured) {
+            cassette._save(captured, &reply)?;
+        }
+
+        Ok((reply, meta))
+    }
+
     /// [internal-implementation]
     /// Actually perform a single-transaction verification
     #[allow(clippy::too_many_arguments)]
@@ -1556,21 +2695,276 @@ impl BriteVerifyClient {
         city: Option<CityName>,
         state: Option<StateNameOrAbbr>,
         zip: Option<ZipCode>,
-    ) -> Result<types::VerificationResponse, errors::BriteVerifyClientError> {
+    ) -> Result<types::Response<types::VerificationResponse>, errors::BriteVerifyClientError> {
         let request = types::VerificationRequest::from_values(
             email, phone, address1, address2, city, state, zip,
         )?;
 
-        let url = self.v1_base_url.append_path("fullverify");
+        self._verify(&request).await
+    }
+
+    /// [internal-implementation]
+    /// Actually perform a single-transaction verification
+    /// of an already-built request
+    pub(crate) async fn _verify(
+        &self,
+        request: &types::VerificationRequest,
+    ) -> Result<types::Response<types::VerificationResponse>, errors::BriteVerifyClientError> {
+        let mut remainder = request.clone();
+        let mut email_report = None;
+        let mut phone_report = None;
+        let mut address_report = None;
+
+        if let Some(email) = remainder.email.take() {
+            match self.validate_email_syntax && !types::EmailAddress::is_valid(&email) {
+                true => email_report = Some(types::EmailAddress::_invalid_format(&email)),
+                false => remainder.email = Some(email),
+            }
+        }
+
+        if let Some(phone) = remainder.phone.take() {
+            match self._normalize_phone(&phone) {
+                Ok(normalized) => remainder.phone = Some(normalized),
+                Err(error) => phone_report = Some(types::PhoneNumber::_invalid(&phone, error)),
+            }
+        }
+
+        if let Some(address) = remainder.address.take() {
+            match self._normalize_address(&address) {
+                Ok(normalized) => remainder.address = Some(normalized),
+                Err(field) => address_report = Some(address._invalid(field)),
+            }
+        }
+
+        if email_report.is_none() && phone_report.is_none() && address_report.is_none() {
+            return self._send_verification(&remainder).await;
+        }
+
+        let mut response = match (&remainder.email, &remainder.phone, &remainder.address) {
+            (None, None, None) => {
+                let uri = self.core.verification_request(request)?.uri().clone();
+                let reply = http::Response::builder()
+                    .status(StatusCode::OK)
+                    .body(Vec::new())
+                    .context("Could not build local response")?;
+
+                types::Response::new(
+                    types::VerificationResponse {
+                        email: None,
+                        phone: None,
+                        address: None,
+                        duration: Duration::ZERO,
+                    },
+                    types::ResponseMetadata::from_local(&uri, &reply)?,
+                )
+            }
+            _ => self._send_verification(&remainder).await?,
+        };
+
+        if email_report.is_some() {
+            response.data.email = email_report;
+        }
+
+        if phone_report.is_some() {
+            response.data.phone = phone_report;
+        }
+
+        if address_report.is_some() {
+            response.data.address = address_report;
+        }
+
+        Ok(response)
+    }
+
+    /// [internal-implementation]
+    /// Normalize the supplied phone number (if phone
+    /// number normalization is enabled), returning the
+    /// error the BriteVerify API would report for it if
+    /// it can't be normalized
+    fn _normalize_phone(&self, phone: &str) -> Result<String, types::VerificationError> {
+        match self.normalize_phone_numbers {
+            false => Ok(phone.to_string()),
+            true => types::PhoneNumber::_parse(phone, self.phone_region.as_deref())
+                .map(|number| number.e164())
+                .map_err(|(error, _)| error),
+        }
+    }
+
+    /// [internal-implementation]
+    /// Normalize the supplied street address (if address
+    /// normalization is enabled), returning the name of the
+    /// offending field if it can't be normalized
+    fn _normalize_address(
+        &self,
+        address: &types::StreetAddressArray,
+    ) -> Result<types::StreetAddressArray, &'static str> {
+        match self.normalize_addresses {
+            false => Ok(address.clone()),
+            true => address.normalized().map_err(|error| match error {
+                errors::BriteVerifyTypeError::InvalidAddressField(field, _) => field,
+                _ => "address1",
+            }),
+        }
+    }
+
+    /// [internal-implementation]
+    /// Send a single-transaction verification request to the
+    /// BriteVerify API, or join an identical in-flight request
+    /// if request coalescing is enabled (and no per-call
+    /// [`RequestOptions`](RequestOptions) are in effect)
+    async fn _send_verification(
+        &self,
+        request: &types::VerificationRequest,
+    ) -> Result<types::Response<types::VerificationResponse>, errors::BriteVerifyClientError> {
+        let inflight = self
+            .inflight
+            .as_ref()
+            .filter(|_| self.options._is_default());
+
+        let Some(inflight) = inflight else {
+            return self._send_uncoalesced(request).await;
+        };
+
+        let client = self.clone();
+        let owned = request.clone();
+
+        inflight
+            ._coalesce(request, move || {
+                futures::FutureExt::boxed(async move { client._send_uncoalesced(&owned).await })
+            })
+            .await
+    }
+
+    /// [internal-implementation]
+    /// Actually send a single-transaction verification
+    /// request to the BriteVerify API
+    async fn _send_uncoalesced(
+        &self,
+        request: &types::VerificationRequest,
+    ) -> Result<types::Response<types::VerificationResponse>, errors::BriteVerifyClientError> {
+        let clock = &self.timekeeper.clock;
+
+        if let Some(cached) = self
+            .cache
+            .as_deref()
+            .and_then(|cache| cache._get(request, clock.now()))
+        {
+            return Ok(cached);
+        }
+
+        #[cfg(feature = "sqlite")]
+        if let Some(stored) = self._stored_verification(request).await? {
+            if let Some(cache) = self.cache.as_deref() {
+                cache._insert(request, &stored, clock.now());
+            }
+
+            return Ok(stored);
+        }
+
+        let response = self
+            ._within_budget(crate::budget::estimate_credits(request), async {
+                let before = self._ledger_balance().await;
+                let (response, meta) = self
+                    ._exchange(self.core.verification_request(request)?)
+                    .await?;
+                let response = self.core.parse_verification(response)?;
+
+                if let Some(ledger) = self.ledger.as_deref() {
+                    let after = self._ledger_balance().await;
+
+                    ledger._record_single(request, &self.options.tags, before, after);
+                }
+
+                Ok(types::Response::new(response, meta))
+            })
+            .await?;
+
+        if let Some(cache) = self.cache.as_deref() {
+            cache._insert(request, &response, clock.now());
+        }
+
+        #[cfg(feature = "sqlite")]
+        if let Some(persistent) = self.persistent.as_deref() {
+            persistent
+                ._record_single(request, &response)
+                .await
+                .unwrap_or_else(|error| log::warn!("Could not store verification: {error:#}"));
+        }
+
+        Ok(response)
+    }
+
+    /// [internal-implementation]
+    /// Rebuild the response to the supplied request from
+    /// the client's persistent cache (if any), if it holds
+    /// fresh results for each of the request's values
+    #[cfg(feature = "sqlite")]
+    async fn _stored_verification(
+        &self,
+        request: &types::VerificationRequest,
+    ) -> Result<Option<types::Response<types::VerificationResponse>>, errors::BriteVerifyClientError>
+    {
+        let Some(persistent) = self.persistent.as_deref() else {
+            return Ok(None);
+        };
+
+        let stored = match persistent._lookup_single(request).await {
+            Ok(Some(stored)) => stored,
+            Ok(None) => return Ok(None),
+            Err(error) => {
+                log::warn!("Could not read stored verifications: {error:#}");
+                return Ok(None);
+            }
+        };
+
+        let uri = self.core.verification_request(request)?.uri().clone();
+        let meta =
+            types::ResponseMetadata::from_local(&uri, &sansio::HttpResponse::new(Vec::new()))?;
+
+        Ok(Some(types::Response::new(stored, meta)))
+    }
+
+    /// [internal-implementation]
+    /// Retrieve the account's available credit balance,
+    /// if the client's credit ledger (if any) records it
+    async fn _ledger_balance(&self) -> Option<u32> {
+        match self.ledger.as_deref() {
+            Some(ledger) if ledger.snapshots_balances() => self
+                .get_account_balance()
+                .await
+                .ok()
+                .map(|balance| balance.credits),
+            _ => None,
+        }
+    }
+
+    /// [internal-implementation]
+    /// Send a request costing (an estimated) number of credits,
+    /// if it fits within the client's credit budget (if any)
+    async fn _within_budget<Output>(
+        &self,
+        required: u64,
+        send: impl std::future::Future<Output = Result<Output, errors::BriteVerifyClientError>>,
+    ) -> Result<Output, errors::BriteVerifyClientError> {
+        let Some(guard) = self.budget.as_ref().filter(|_| required > 0) else {
+            return send.await;
+        };
+
+        let now = self.timekeeper.clock.now();
+        let balance = match guard._needs_balance(now) {
+            true => Some(self.get_account_balance().await?.credits),
+            false => None,
+        };
+
+        let reservation = guard._reserve(required, now, balance)?;
 
-        let response = self._build_and_send(self.post(url).json(&request)).await?;
+        let result = send.await;
 
-        match response.status() {
-            StatusCode::OK => Ok(response.json::<types::VerificationResponse>().await?),
-            _ => Err(errors::BriteVerifyClientError::UnusableResponse(Box::new(
-                response,
-            ))),
+        if result.is_err() {
+            guard._refund(reservation);
         }
+
+        result
     }
 
     /// [internal-implementation]
@@ -1580,31 +2974,27 @@ impl BriteVerifyClient {
         &self,
         list_id: ListId,
         external_id: Option<ExternalId>,
-    ) -> Result<types::VerificationListState, errors::BriteVerifyClientError> {
+    ) -> Result<types::Response<types::VerificationListState>, errors::BriteVerifyClientError> {
         let list_id = list_id.to_string();
-        let url = external_id
-            .map(|ext_id| {
-                self.v3_base_url
-                    .extend_path(["accounts".to_string(), ext_id.to_string()])
-            })
-            .as_ref()
-            .unwrap_or(&self.v3_base_url)
-            .extend_path(["lists", &list_id]);
-
-        let response = self._build_and_send(self.get(url)).await?;
-
-        match response.status() {
-            StatusCode::OK => Ok(response.json::<types::VerificationListState>().await?),
-            StatusCode::NOT_FOUND => Err(errors::BriteVerifyClientError::BulkListNotFound(
-                Box::new(types::BulkListCRUDError {
-                    list_id: Some(list_id),
-                    ..response.json::<types::BulkListCRUDError>().await?
-                }),
-            )),
-            _ => Err(errors::BriteVerifyClientError::UnusableResponse(Box::new(
-                response,
-            ))),
+
+        let (response, meta) = self
+            ._exchange(self.core.get_list_request(&list_id, external_id)?)
+            .await?;
+        let list = self.core.parse_get_list(response, list_id)?;
+
+        if let Some(ledger) = self.ledger.as_deref() {
+            if list.state == types::BatchState::Complete && !ledger._is_completed(&list.id) {
+                let after = self._ledger_balance().await;
+
+                ledger._record_completion(&list, &self.options.tags, after);
+            }
+        }
+
+        if let Some(dedupe) = self.dedupe.as_deref() {
+            dedupe._prune([&list]);
         }
+
+        Ok(types::Response::new(list, meta))
     }
 
     /// [internal-implementation]
@@ -1616,21 +3006,11 @@ impl BriteVerifyClient {
         list_id: String,
         page_number: u64,
     ) -> Result<types::BulkVerificationResponse, errors::BriteVerifyClientError> {
-        let page_url = self.v3_base_url.extend_path([
-            "lists",
-            &list_id,
-            "export",
-            page_number.to_string().as_str(),
-        ]);
-
-        let response = self._build_and_send(self.get(page_url)).await?;
-
-        match response.status() {
-            StatusCode::OK => Ok(response.json::<types::BulkVerificationResponse>().await?),
-            _ => Err(errors::BriteVerifyClientError::UnusableResponse(Box::new(
-                response,
-            ))),
-        }
+        let (response, _) = self
+            ._exchange(self.core.result_page_request(list_id, page_number)?)
+            .await?;
+
+        self.core.parse_result_page(response)
     }
 
     /// [internal-implementation]
@@ -1646,38 +3026,163 @@ impl BriteVerifyClient {
         list_id: Option<ListId>,
         contacts: ContactCollection,
         directive: Directive,
-    ) -> Result<types::CreateListResponse, errors::BriteVerifyClientError> {
+    ) -> Result<types::Response<types::CreateListResponse>, errors::BriteVerifyClientError> {
         // TODO(the-wondersmith): Apply bulk "rate" limit to supplied contacts
         //                        Bulk rate limits are:
         //                          - 100k Emails per page
         //                          - 1M Email addresses per job (or 20 pages of 50k)
 
         let directive = directive.into();
-        let request = types::BulkVerificationRequest::new(contacts, directive);
+        let mut request = types::BulkVerificationRequest::new(contacts, directive);
+
+        if self.normalize_phone_numbers {
+            let mut malformed: Vec<String> = Vec::new();
+
+            for phone in request
+                .contacts
+                .iter_mut()
+                .filter_map(|contact| contact.phone.as_mut())
+            {
+                match self._normalize_phone(phone) {
+                    Ok(normalized) => *phone = normalized,
+                    Err(_) => malformed.push(phone.clone()),
+                }
+            }
+
+            if !malformed.is_empty() {
+                return Err(errors::BriteVerifyClientError::MalformedPhoneNumbers(
+                    malformed,
+                ));
+            }
+        }
 
-        let mut url = self.v3_base_url.append_path("lists");
+        if self.normalize_addresses {
+            let mut malformed: Vec<String> = Vec::new();
+
+            for address in request
+                .contacts
+                .iter_mut()
+                .filter_map(|contact| contact.address.as_mut())
+            {
+                match self._normalize_address(address) {
+                    Ok(normalized) => *address = normalized,
+                    Err(field) => malformed.push(format!(
+                        "{}, {}, {} {} (invalid {field})",
+                        address.address1, address.city, address.state, address.zip
+                    )),
+                }
+            }
 
-        if let Some(id) = list_id.as_ref() {
-            url = url.append_path(id.to_string());
+            if !malformed.is_empty() {
+                return Err(errors::BriteVerifyClientError::MalformedAddresses(
+                    malformed,
+                ));
+            }
         }
 
-        let response = self._build_and_send(self.post(url).json(&request)).await?;
+        if self.validate_email_syntax {
+            let malformed: Vec<String> = request
+                .contacts
+                .iter()
+                .filter_map(|contact| contact.email.as_deref())
+                .filter(|email| !types::EmailAddress::is_valid(email))
+                .map(str::to_string)
+                .collect();
+
+            if !malformed.is_empty() {
+                return Err(errors::BriteVerifyClientError::MalformedEmailAddresses(
+                    malformed,
+                ));
+            }
+        }
 
-        match response.status() {
-            StatusCode::OK | StatusCode::CREATED => {
-                Ok(response.json::<types::CreateListResponse>().await?)
+        let list_id = list_id.as_ref().map(ToString::to_string);
+        let deduped = self.dedupe.as_ref().map(|dedupe| {
+            let deduped = dedupe.dedupe(list_id.as_deref(), std::mem::take(&mut request.contacts));
+
+            request.contacts = deduped.contacts.clone();
+            deduped
+        });
+
+        let credits = crate::budget::estimate_list_credits(&request.contacts);
+        let before = match list_id.as_deref() {
+            Some(id)
+                if self
+                    .ledger
+                    .as_deref()
+                    .is_some_and(|ledger| ledger._is_pending(id)) =>
+            {
+                None
             }
-            StatusCode::NOT_FOUND | StatusCode::BAD_REQUEST => {
-                Err(errors::BriteVerifyClientError::BulkListNotFound(Box::new(
-                    types::BulkListCRUDError {
-                        list_id: list_id.as_ref().map(|id| id.to_string()),
-                        ..response.json::<types::BulkListCRUDError>().await?
+            _ => self._ledger_balance().await,
+        };
+
+        let (response, meta) = self
+            ._within_budget(credits, async {
+                self._exchange(
+                    self.core
+                        .create_or_update_list_request(list_id.clone(), &request)?,
+                )
+                .await
+            })
+            .await?;
+
+        let response = self.core.parse_create_or_update_list(response, list_id)?;
+
+        if let (Some(dedupe), Some(deduped)) = (self.dedupe.as_ref(), deduped) {
+            dedupe._record(&response.list.id, deduped);
+        }
+
+        if let Some(ledger) = self.ledger.as_deref() {
+            ledger._record_upload(&response.list.id, credits, &self.options.tags, before);
+        }
+
+        Ok(types::Response::new(response, meta))
+    }
+
+    /// [internal-implementation]
+    /// Look up the extant list the supplied idempotency
+    /// record refers to, if it can (still) be reused
+    async fn _reuse_list(
+        &self,
+        record: &idempotency::IdempotencyRecord,
+    ) -> Result<Option<types::Response<types::CreateListResponse>>, errors::BriteVerifyClientError>
+    {
+        let mut page = 1u32;
+
+        loop {
+            let response = self
+                .get_filtered_lists_with_metadata(
+                    Some(page),
+                    <Option<chrono::NaiveDate>>::None,
+                    <Option<types::BatchState>>::None,
+                    Nullable::None,
+                )
+                .await?;
+
+            if let Some(list) = self.idempotency._resolve(record, &response.lists).await? {
+                let list = list.clone();
+
+                self.idempotency._confirm(record.clone(), &list.id).await?;
+
+                return Ok(Some(types::Response::new(
+                    types::CreateListResponse {
+                        status: list.state,
+                        message: format!(
+                            "Reusing list created for idempotency token {:?}",
+                            record.token
+                        ),
+                        list,
                     },
-                )))
+                    response.meta,
+                )));
+            }
+
+            if u64::from(page) >= response.total_pages() {
+                return Ok(None);
             }
-            _ => Err(errors::BriteVerifyClientError::UnusableResponse(Box::new(
-                response,
-            ))),
+
+            page += 1;
         }
     }
 
@@ -1688,6 +3193,13 @@ impl BriteVerifyClient {
     /// Get your current account credit balance
     /// [[ref](https://docs.briteverify.com/#07beceb3-2961-4d5b-93a4-9cfeb30f42fa)]
     ///
+    /// ___
+    /// **NOTE:** There is no `_with_metadata` variant of this
+    /// shorthand. Use
+    /// [`get_account_balance_with_metadata`](BriteVerifyClient::get_account_balance_with_metadata)
+    /// to get the [`ResponseMetadata`](types::ResponseMetadata) of the request as well.
+    /// ___
+    ///
     /// #### Example
     /// ```no_run
     /// # use briteverify_rs::BriteVerifyClient;
@@ -1708,6 +3220,11 @@ impl BriteVerifyClient {
     /// Get the total number of credits your account currently has in reserve
     /// [[ref](https://docs.briteverify.com/#07beceb3-2961-4d5b-93a4-9cfeb30f42fa)]
     ///
+    /// ___
+    /// **NOTE:** There is no `_with_metadata` variant of this
+    /// shorthand either (see [`current_credits`](BriteVerifyClient::current_credits)).
+    /// ___
+    ///
     /// #### Example
     /// ```no_run
     /// # use briteverify_rs::BriteVerifyClient;
@@ -1746,15 +3263,43 @@ impl BriteVerifyClient {
     pub async fn get_account_balance(
         &self,
     ) -> Result<types::AccountCreditBalance, errors::BriteVerifyClientError> {
-        let url = format!("{}/accounts/credits", &self.v3_base_url);
-        let response = self._build_and_send(self.get(url)).await?;
-
-        match response.status() {
-            StatusCode::OK => Ok(response.json::<types::AccountCreditBalance>().await?),
-            _ => Err(errors::BriteVerifyClientError::UnusableResponse(Box::new(
-                response,
-            ))),
-        }
+        Ok(self.get_account_balance_with_metadata().await?.into_inner())
+    }
+
+    /// Get your account credit balance along with the
+    /// [`ResponseMetadata`](types::ResponseMetadata) of
+    /// the request that retrieved it
+    /// [[ref](https://docs.briteverify.com/#07beceb3-2961-4d5b-93a4-9cfeb30f42fa)]
+    ///
+    /// #### Example
+    /// ```no_run
+    /// # use briteverify_rs::{BriteVerifyClient, types::{AccountCreditBalance, Response}};
+    /// #
+    /// # async fn doc() -> anyhow::Result<()> {
+    /// # let client: BriteVerifyClient = BriteVerifyClient::new("YOUR API KEY")?;
+    /// let response: Response<AccountCreditBalance> = client
+    ///     .get_account_balance_with_metadata()
+    ///     .await?;
+    ///
+    /// println!(
+    ///     "Balance: {} (request id: {:?}, latency: {:?})",
+    ///     response.data,
+    ///     response.meta.request_id(),
+    ///     response.meta.latency,
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub async fn get_account_balance_with_metadata(
+        &self,
+    ) -> Result<types::Response<types::AccountCreditBalance>, errors::BriteVerifyClientError> {
+        let (response, meta) = self._exchange(self.core.account_balance_request()?).await?;
+
+        Ok(types::Response::new(
+            self.core.parse_account_balance(response)?,
+            meta,
+        ))
     }
 
     /// Verify a "complete" contact record
@@ -1800,22 +3345,68 @@ impl BriteVerifyClient {
         state: StateNameOrAbbr,
         zip: ZipCode,
     ) -> Result<types::VerificationResponse, errors::BriteVerifyClientError> {
-        let response = self
-            ._full_verify(
-                Some(email),
-                Some(phone),
-                Some(address1),
-                address2,
-                Some(city),
-                Some(state),
-                Some(zip),
-            )
-            .await;
+        Ok(self
+            .verify_contact_with_metadata(email, phone, address1, address2, city, state, zip)
+            .await?
+            .into_inner())
+    }
 
-        match response {
-            Ok(data) => Ok(data),
-            Err(error) => Err(error),
-        }
+    /// Verify a "complete" contact record, returning the verification
+    /// results along with the [`ResponseMetadata`](types::ResponseMetadata)
+    /// of the request that produced them
+    /// [[ref](https://docs.briteverify.com/#a7246384-e91e-48a9-8aed-7b71cb74dd42)]
+    ///
+    /// #### Example
+    /// ```no_run
+    /// # use briteverify_rs::{BriteVerifyClient, types::{Response, VerificationResponse}};
+    /// #
+    /// # async fn doc() -> anyhow::Result<()> {
+    /// # let client: BriteVerifyClient = BriteVerifyClient::new("YOUR API KEY")?;
+    /// let verified: Response<VerificationResponse> = client.verify_contact_with_metadata(
+    ///     "test@example.com",
+    ///     "+15555555555",
+    ///     "123 Main St",
+    ///     Some("P.O. Box 456"),
+    ///     "Any Town",
+    ///     "CA",
+    ///     "90210",
+    /// ).await?;
+    ///
+    /// println!("Verified contact data: {:#?}", verified.data);
+    /// println!("Client-observed latency: {:?}", verified.meta.latency);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[allow(clippy::too_many_arguments)]
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub async fn verify_contact_with_metadata<
+        EmailAddress: ToString + Debug,
+        PhoneNumber: ToString + Debug,
+        AddressLine1: ToString + Debug,
+        AddressLine2: ToString + Debug,
+        CityName: ToString + Debug,
+        StateNameOrAbbr: ToString + Debug,
+        ZipCode: ToString + Debug,
+    >(
+        &self,
+        email: EmailAddress,
+        phone: PhoneNumber,
+        address1: AddressLine1,
+        address2: Option<AddressLine2>,
+        city: CityName,
+        state: StateNameOrAbbr,
+        zip: ZipCode,
+    ) -> Result<types::Response<types::VerificationResponse>, errors::BriteVerifyClientError> {
+        self._full_verify(
+            Some(email),
+            Some(phone),
+            Some(address1),
+            address2,
+            Some(city),
+            Some(state),
+            Some(zip),
+        )
+        .await
     }
 
     /// Verify a single email address
@@ -1838,7 +3429,36 @@ impl BriteVerifyClient {
         &self,
         email: EmailAddress,
     ) -> Result<types::EmailVerificationArray, errors::BriteVerifyClientError> {
-        let response = self
+        Ok(self.verify_email_with_metadata(email).await?.into_inner())
+    }
+
+    /// Verify a single email address, returning the verification
+    /// results along with the [`ResponseMetadata`](types::ResponseMetadata)
+    /// of the request that produced them
+    /// [[ref](https://docs.briteverify.com/#e5dd413c-6411-4078-8b4c-0e787f6a9325)]
+    ///
+    /// #### Example
+    /// ```no_run
+    /// # use briteverify_rs::{BriteVerifyClient, types::{EmailVerificationArray, Response}};
+    /// #
+    /// # async fn doc() -> anyhow::Result<()> {
+    /// # let client: BriteVerifyClient = BriteVerifyClient::new("YOUR API KEY")?;
+    /// let response: Response<EmailVerificationArray> = client
+    ///     .verify_email_with_metadata("test@example.com")
+    ///     .await?;
+    ///
+    /// println!("Verified email: {:#?}", response.data);
+    /// println!("Rate limit remaining: {:?}", response.meta.rate_limit_remaining());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub async fn verify_email_with_metadata<EmailAddress: ToString + Debug>(
+        &self,
+        email: EmailAddress,
+    ) -> Result<types::Response<types::EmailVerificationArray>, errors::BriteVerifyClientError>
+    {
+        let (response, meta) = self
             ._full_verify(
                 Some(email),
                 Nullable::None,
@@ -1848,10 +3468,11 @@ impl BriteVerifyClient {
                 Nullable::None,
                 Nullable::None,
             )
-            .await?;
+            .await?
+            .into_parts();
 
         match response.email {
-            Some(data) => Ok(data),
+            Some(data) => Ok(types::Response::new(data, meta)),
             None => Err(
                 errors::BriteVerifyClientError::MismatchedVerificationResponse(Box::new(response)),
             ),
@@ -1878,7 +3499,39 @@ impl BriteVerifyClient {
         &self,
         phone: PhoneNumber,
     ) -> Result<types::PhoneNumberVerificationArray, errors::BriteVerifyClientError> {
-        let response = self
+        Ok(self
+            .verify_phone_number_with_metadata(phone)
+            .await?
+            .into_inner())
+    }
+
+    /// Verify a single phone number, returning the verification
+    /// results along with the [`ResponseMetadata`](types::ResponseMetadata)
+    /// of the request that produced them
+    /// [[ref](https://docs.briteverify.com/#86e335f4-d1b2-4902-9051-4506a48a6b94)]
+    ///
+    /// #### Example
+    /// ```no_run
+    /// # use briteverify_rs::{BriteVerifyClient, types::{PhoneNumberVerificationArray, Response}};
+    /// #
+    /// # async fn doc() -> anyhow::Result<()> {
+    /// # let client: BriteVerifyClient = BriteVerifyClient::new("YOUR API KEY")?;
+    /// let response: Response<PhoneNumberVerificationArray> = client
+    ///     .verify_phone_number_with_metadata("+15555555555")
+    ///     .await?;
+    ///
+    /// println!("Verified phone number: {:#?}", response.data);
+    /// println!("Request id: {:?}", response.meta.request_id());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub async fn verify_phone_number_with_metadata<PhoneNumber: ToString + Debug>(
+        &self,
+        phone: PhoneNumber,
+    ) -> Result<types::Response<types::PhoneNumberVerificationArray>, errors::BriteVerifyClientError>
+    {
+        let (response, meta) = self
             ._full_verify(
                 Nullable::None,
                 Some(phone),
@@ -1888,10 +3541,11 @@ impl BriteVerifyClient {
                 Nullable::None,
                 Nullable::None,
             )
-            .await?;
+            .await?
+            .into_parts();
 
         match response.phone {
-            Some(data) => Ok(data),
+            Some(data) => Ok(types::Response::new(data, meta)),
             None => Err(
                 errors::BriteVerifyClientError::MismatchedVerificationResponse(Box::new(response)),
             ),
@@ -1934,7 +3588,53 @@ impl BriteVerifyClient {
         state: StateNameOrAbbr,
         zip: ZipCode,
     ) -> Result<types::AddressVerificationArray, errors::BriteVerifyClientError> {
-        let response = self
+        Ok(self
+            .verify_street_address_with_metadata(address1, address2, city, state, zip)
+            .await?
+            .into_inner())
+    }
+
+    /// Verify a single street address, returning the verification
+    /// results along with the [`ResponseMetadata`](types::ResponseMetadata)
+    /// of the request that produced them
+    /// [[ref](https://docs.briteverify.com/#f588d8d3-8250-4a8a-9e58-f89c81af6bed)]
+    ///
+    /// #### Example
+    /// ```no_run
+    /// # use briteverify_rs::{BriteVerifyClient, types::{AddressVerificationArray, Response}};
+    /// #
+    /// # async fn doc() -> anyhow::Result<()> {
+    /// # let client: BriteVerifyClient = BriteVerifyClient::new("YOUR API KEY")?;
+    /// let verified: Response<AddressVerificationArray> = client.verify_street_address_with_metadata(
+    ///     "123 Main St",
+    ///     Some("P.O. Box 456"),
+    ///     "Any Town",
+    ///     "CA",
+    ///     "90210",
+    /// ).await?;
+    ///
+    /// println!("Verified address: {:#?}", verified.data);
+    /// println!("Retries required: {}", verified.meta.retries);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub async fn verify_street_address_with_metadata<
+        AddressLine1: ToString + Debug,
+        AddressLine2: ToString + Debug,
+        CityName: ToString + Debug,
+        StateNameOrAbbr: ToString + Debug,
+        ZipCode: ToString + Debug,
+    >(
+        &self,
+        address1: AddressLine1,
+        address2: Option<AddressLine2>,
+        city: CityName,
+        state: StateNameOrAbbr,
+        zip: ZipCode,
+    ) -> Result<types::Response<types::AddressVerificationArray>, errors::BriteVerifyClientError>
+    {
+        let (response, meta) = self
             ._full_verify(
                 Nullable::None,
                 Nullable::None,
@@ -1944,10 +3644,11 @@ impl BriteVerifyClient {
                 Some(state),
                 Some(zip),
             )
-            .await?;
+            .await?
+            .into_parts();
 
         match response.address {
-            Some(data) => Ok(data),
+            Some(data) => Ok(types::Response::new(data, meta)),
             None => Err(
                 errors::BriteVerifyClientError::MismatchedVerificationResponse(Box::new(response)),
             ),
@@ -1962,6 +3663,14 @@ impl BriteVerifyClient {
     /// lists created within the last 7 calendar days
     /// [[ref](https://docs.briteverify.com/#0b5a2a7a-4062-4327-ab0a-4675592e3cd6)]
     ///
+    /// ___
+    /// **NOTE:** There is no `_with_metadata` variant of this
+    /// shorthand. Use
+    /// [`get_filtered_lists_with_metadata`](BriteVerifyClient::get_filtered_lists_with_metadata)
+    /// (without any filters) to get the [`ResponseMetadata`](types::ResponseMetadata)
+    /// of the request as well.
+    /// ___
+    ///
     /// #### Example
     /// ```no_run
     /// # use briteverify_rs::{BriteVerifyClient, types::GetListStatesResponse};
@@ -2015,7 +3724,6 @@ impl BriteVerifyClient {
     /// ```
     #[cfg_attr(feature = "tracing", tracing::instrument)]
     pub async fn get_filtered_lists<
-        'header,
         Date: chrono::Datelike + Debug,
         Page: Into<u32> + Debug,
         State: Clone + Debug + Into<types::BatchState>,
@@ -2027,52 +3735,63 @@ impl BriteVerifyClient {
         state: Option<State>,
         ext_id: Option<ExternalId>,
     ) -> Result<types::GetListStatesResponse, errors::BriteVerifyClientError> {
-        let mut params: Vec<(&'header str, String)> = Vec::new();
-
-        if let Some(page) = page {
-            params.push(("page", page.into().to_string()));
-        }
-
-        if let Some(date) = date {
-            params.push((
-                "date",
-                format!("{}-{:0>2}-{:0>2}", date.year(), date.month(), date.day()),
-            ));
-        }
-
-        if let Some(state) = state {
-            let filter = state.clone().into();
-
-            if matches!(filter, types::BatchState::Unknown) {
-                log::warn!("Declining to include unknown list state as request filter: {state:#?}");
-            } else {
-                params.push(("state", filter.to_string()));
-            }
-        }
-
-        let url = ext_id
-            .map(|id| {
-                self.v3_base_url
-                    .extend_path(["accounts".to_string(), id.to_string()])
-            })
-            .as_ref()
-            .unwrap_or(&self.v3_base_url)
-            .append_path("lists");
+        Ok(self
+            .get_filtered_lists_with_metadata(page, date, state, ext_id)
+            .await?
+            .into_inner())
+    }
 
-        let mut request = self.get(url);
+    /// Retrieve the complete list of all bulk verification lists created
+    /// within the last 7 calendar days filtered by the specified criteria,
+    /// along with the [`ResponseMetadata`](types::ResponseMetadata) of the
+    /// request that retrieved them
+    /// [[ref](https://docs.briteverify.com/#0b5a2a7a-4062-4327-ab0a-4675592e3cd6)]
+    ///
+    /// #### Example
+    /// ```no_run
+    /// use chrono::NaiveDate;
+    /// use briteverify_rs::{BriteVerifyClient, types::{GetListStatesResponse, Response}};
+    /// #
+    /// # async fn doc() -> anyhow::Result<()> {
+    /// # let client: BriteVerifyClient = BriteVerifyClient::new("YOUR API KEY")?;
+    ///
+    /// let lists: Response<GetListStatesResponse> = client
+    ///     .get_filtered_lists_with_metadata(
+    ///         <Option<u32>>::None,
+    ///         <Option<NaiveDate>>::None,
+    ///         Some("open"),
+    ///         <Option<&str>>::None,
+    ///     )
+    ///     .await?;
+    ///
+    /// println!("Open bulk verification lists: {:#?}", lists.data);
+    /// println!("HTTP status: {}", lists.meta.status);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub async fn get_filtered_lists_with_metadata<
+        Date: chrono::Datelike + Debug,
+        Page: Into<u32> + Debug,
+        State: Clone + Debug + Into<types::BatchState>,
+        ExternalId: std::fmt::Display + Debug,
+    >(
+        &self,
+        page: Option<Page>,
+        date: Option<Date>,
+        state: Option<State>,
+        ext_id: Option<ExternalId>,
+    ) -> Result<types::Response<types::GetListStatesResponse>, errors::BriteVerifyClientError> {
+        let (response, meta) = self
+            ._exchange(self.core.get_lists_request(page, date, state, ext_id)?)
+            .await?;
+        let lists = self.core.parse_get_lists(response)?;
 
-        if !params.is_empty() {
-            request = request.query(&params);
+        if let Some(dedupe) = self.dedupe.as_deref() {
+            dedupe._prune(&lists.lists);
         }
 
-        let response = self._build_and_send(request).await?;
-
-        match response.status() {
-            StatusCode::OK => Ok(response.json::<types::GetListStatesResponse>().await?),
-            _ => Err(errors::BriteVerifyClientError::UnusableResponse(Box::new(
-                response,
-            ))),
-        }
+        Ok(types::Response::new(lists, meta))
     }
 
     /// Retrieve the complete list of all bulk verification lists filtered
@@ -2082,6 +3801,9 @@ impl BriteVerifyClient {
     /// **NOTE:** Regardless of specified date, the BriteVerify API
     /// does not appear to persist bulk verification lists older than
     /// 7 calendar days
+    ///
+    /// Like [`get_lists`](BriteVerifyClient::get_lists), this shorthand
+    /// has no `_with_metadata` variant.
     /// ___
     ///
     /// #### Example
@@ -2119,6 +3841,11 @@ impl BriteVerifyClient {
     /// Retrieve the specified "page" of bulk verification lists
     /// [[ref](https://docs.briteverify.com/#0b5a2a7a-4062-4327-ab0a-4675592e3cd6)]
     ///
+    /// ___
+    /// **NOTE:** Like [`get_lists`](BriteVerifyClient::get_lists),
+    /// this shorthand has no `_with_metadata` variant.
+    /// ___
+    ///
     /// #### Example
     /// ```no_run
     /// # use chrono::Datelike;
@@ -2153,6 +3880,11 @@ impl BriteVerifyClient {
     /// value
     /// [[ref](https://docs.briteverify.com/#0b5a2a7a-4062-4327-ab0a-4675592e3cd6)]
     ///
+    /// ___
+    /// **NOTE:** Like [`get_lists`](BriteVerifyClient::get_lists),
+    /// this shorthand has no `_with_metadata` variant.
+    /// ___
+    ///
     /// #### Example
     /// ```no_run
     /// # use chrono::Datelike;
@@ -2247,6 +3979,46 @@ impl BriteVerifyClient {
         contacts: Option<ContactCollection>,
         auto_start: bool,
     ) -> Result<types::CreateListResponse, errors::BriteVerifyClientError> {
+        Ok(self
+            .create_list_with_metadata(contacts, auto_start)
+            .await?
+            .into_inner())
+    }
+
+    /// Create a new bulk verification list with the supplied records,
+    /// (optionally) queue it for immediate processing, and return the
+    /// new list along with the [`ResponseMetadata`](types::ResponseMetadata)
+    /// of the request that created it
+    /// [[ref](https://docs.briteverify.com/#38b4c9eb-31b1-4b8e-9295-a783d8043bc1)]
+    ///
+    /// #### Example
+    /// ```no_run
+    /// # use briteverify_rs::BriteVerifyClient;
+    /// use briteverify_rs::types::{CreateListResponse, Response, VerificationRequest};
+    /// #
+    /// # async fn doc() -> anyhow::Result<()> {
+    /// # let client: BriteVerifyClient = BriteVerifyClient::new("YOUR API KEY")?;
+    ///
+    /// let contacts: [VerificationRequest; 1] = [VerificationRequest::try_from("test@example.com")?];
+    ///
+    /// let list: Response<CreateListResponse> = client
+    ///     .create_list_with_metadata(Some(contacts), true)
+    ///     .await?;
+    ///
+    /// println!("New bulk verification list: {:#?}", list.data);
+    /// println!("Request id: {:?}", list.meta.request_id());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub async fn create_list_with_metadata<
+        Contact: Into<types::VerificationRequest> + Debug,
+        ContactCollection: IntoIterator<Item = Contact> + Debug,
+    >(
+        &self,
+        contacts: Option<ContactCollection>,
+        auto_start: bool,
+    ) -> Result<types::Response<types::CreateListResponse>, errors::BriteVerifyClientError> {
         // TODO(the-wondersmith): Apply bulk "rate" limit to supplied contacts
         //                        Bulk rate limits are:
         //                          - 100k Emails per page
@@ -2269,35 +4041,214 @@ impl BriteVerifyClient {
         }
     }
 
+    /// Create a new bulk verification list with the supplied records and
+    /// (optionally) queue it for immediate processing, unless a list was
+    /// already created for the supplied idempotency token (in which case
+    /// that list is returned instead of creating another)
+    /// [[ref](https://docs.briteverify.com/#38b4c9eb-31b1-4b8e-9295-a783d8043bc1)]
+    ///
+    /// See [`idempotency`](crate::idempotency) for details.
+    ///
+    /// ___
+    /// **NOTE:** Reusing a token for different contacts fails with
+    /// [`ReusedIdempotencyToken`](errors::BriteVerifyClientError::ReusedIdempotencyToken),
+    /// and calling this while a list is already being created for the
+    /// same token fails with
+    /// [`IdempotentRequestInProgress`](errors::BriteVerifyClientError::IdempotentRequestInProgress).
+    /// ___
+    ///
+    /// #### Example
+    /// ```no_run
+    /// # use briteverify_rs::BriteVerifyClient;
+    /// use briteverify_rs::types::{CreateListResponse, VerificationRequest};
+    /// #
+    /// # async fn doc() -> anyhow::Result<()> {
+    /// # let client: BriteVerifyClient = BriteVerifyClient::new("YOUR API KEY")?;
+    ///
+    /// let contacts: [VerificationRequest; 1] = [VerificationRequest::try_from("test@example.com")?];
+    ///
+    /// let list: CreateListResponse = client
+    ///     .create_list_idempotent("newsletter-2024-06-01", contacts, true)
+    ///     .await?;
+    ///
+    /// println!("Bulk verification list: {list:#?}");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub async fn create_list_idempotent<
+        Token: ToString + Debug,
+        Contact: Into<types::VerificationRequest> + Debug,
+        ContactCollection: IntoIterator<Item = Contact> + Debug,
+    >(
+        &self,
+        token: Token,
+        contacts: ContactCollection,
+        auto_start: bool,
+    ) -> Result<types::CreateListResponse, errors::BriteVerifyClientError> {
+        Ok(self
+            .create_list_idempotent_with_metadata(token, contacts, auto_start)
+            .await?
+            .into_inner())
+    }
+
+    /// Create a new bulk verification list with the supplied records (or
+    /// reuse the list already created for the supplied idempotency token),
+    /// and return it along with the [`ResponseMetadata`](types::ResponseMetadata)
+    /// of the request that created (or retrieved) it
+    /// [[ref](https://docs.briteverify.com/#38b4c9eb-31b1-4b8e-9295-a783d8043bc1)]
+    ///
+    /// #### Example
+    /// ```no_run
+    /// # use briteverify_rs::BriteVerifyClient;
+    /// use briteverify_rs::types::{CreateListResponse, Response, VerificationRequest};
+    /// #
+    /// # async fn doc() -> anyhow::Result<()> {
+    /// # let client: BriteVerifyClient = BriteVerifyClient::new("YOUR API KEY")?;
+    ///
+    /// let contacts: [VerificationRequest; 1] = [VerificationRequest::try_from("test@example.com")?];
+    ///
+    /// let list: Response<CreateListResponse> = client
+    ///     .create_list_idempotent_with_metadata("newsletter-2024-06-01", contacts, true)
+    ///     .await?;
+    ///
+    /// println!("Bulk verification list: {:#?}", list.data);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub async fn create_list_idempotent_with_metadata<
+        Token: ToString + Debug,
+        Contact: Into<types::VerificationRequest> + Debug,
+        ContactCollection: IntoIterator<Item = Contact> + Debug,
+    >(
+        &self,
+        token: Token,
+        contacts: ContactCollection,
+        auto_start: bool,
+    ) -> Result<types::Response<types::CreateListResponse>, errors::BriteVerifyClientError> {
+        let token = token.to_string();
+        let contacts: Vec<types::VerificationRequest> =
+            contacts.into_iter().map(Into::into).collect();
+        let fingerprint = idempotency::fingerprint(&contacts, self.phone_region.as_deref());
+        let _in_flight = self.idempotency._begin(&token)?;
+        let existing = self.idempotency._existing(&token, &fingerprint).await?;
+
+        if let Some(record) = existing.as_ref() {
+            if let Some(reused) = self._reuse_list(record).await? {
+                return Ok(reused);
+            }
+        }
+
+        // the number of contacts that'll actually be uploaded
+        let uploaded = match self.dedupe.as_deref() {
+            Some(dedupe) => dedupe.dedupe(None, contacts.clone()).contacts.len(),
+            None => contacts.len(),
+        };
+
+        let record = self
+            .idempotency
+            ._attempt(
+                &token,
+                &fingerprint,
+                uploaded,
+                chrono::Utc::now(),
+                existing.as_ref(),
+            )
+            .await?;
+        let response = match self
+            .create_list_with_metadata(Some(contacts), auto_start)
+            .await
+        {
+            Ok(response) => response,
+            Err(error) => {
+                self.idempotency
+                    ._fail(record, chrono::Utc::now())
+                    .await
+                    .unwrap_or_else(|error| {
+                        log::warn!("Could not record failed list creation: {error:#}")
+                    });
+
+                return Err(error);
+            }
+        };
+
+        self.idempotency._confirm(record, &response.list.id).await?;
+
+        Ok(response)
+    }
+
     /// Append records to the specified bulk verification list and (optionally)
     /// queue it for immediate processing
     /// [[ref](https://docs.briteverify.com/#38b4c9eb-31b1-4b8e-9295-a783d8043bc1:~:text=customer%2DID/lists-,list_id,-(optional))]
     ///
     /// #### Example
     /// ```no_run
-    /// use briteverify_rs::{
-    /// #    BriteVerifyClient,
-    ///     types::{
-    ///       UpdateListResponse,
-    ///       VerificationRequest,
-    ///     },
-    /// };
+    /// use briteverify_rs::{
+    /// #    BriteVerifyClient,
+    ///     types::{
+    ///       UpdateListResponse,
+    ///       VerificationRequest,
+    ///     },
+    /// };
+    /// # async fn doc() -> anyhow::Result<()> {
+    /// # let client: BriteVerifyClient = BriteVerifyClient::new("YOUR API KEY")?;
+    ///
+    /// let contacts: [VerificationRequest; 2] = [
+    ///     VerificationRequest::try_from("some-email@bounce-me.net")?,
+    ///     VerificationRequest::try_from("another-email@a-real-domain.org")?,
+    /// ];
+    ///
+    /// let list: UpdateListResponse = client.update_list("some-list-id", contacts, false).await?;
+    ///
+    /// println!("Updated bulk verification list: {list:#?}");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub async fn update_list<
+        ListId: ToString + Debug,
+        Contact: Into<types::VerificationRequest> + Debug,
+        ContactCollection: IntoIterator<Item = Contact> + Debug,
+    >(
+        &self,
+        list_id: ListId,
+        contacts: ContactCollection,
+        auto_start: bool,
+    ) -> Result<types::UpdateListResponse, errors::BriteVerifyClientError> {
+        Ok(self
+            .update_list_with_metadata(list_id, contacts, auto_start)
+            .await?
+            .into_inner())
+    }
+
+    /// Append records to the specified bulk verification list, (optionally)
+    /// queue it for immediate processing, and return the updated list along
+    /// with the [`ResponseMetadata`](types::ResponseMetadata) of the request
+    /// that updated it
+    /// [[ref](https://docs.briteverify.com/#38b4c9eb-31b1-4b8e-9295-a783d8043bc1:~:text=customer%2DID/lists-,list_id,-(optional))]
+    ///
+    /// #### Example
+    /// ```no_run
+    /// # use briteverify_rs::BriteVerifyClient;
+    /// use briteverify_rs::types::{Response, UpdateListResponse, VerificationRequest};
+    /// #
     /// # async fn doc() -> anyhow::Result<()> {
     /// # let client: BriteVerifyClient = BriteVerifyClient::new("YOUR API KEY")?;
     ///
-    /// let contacts: [VerificationRequest; 2] = [
-    ///     VerificationRequest::try_from("some-email@bounce-me.net")?,
-    ///     VerificationRequest::try_from("another-email@a-real-domain.org")?,
-    /// ];
+    /// let contacts: [VerificationRequest; 1] = [VerificationRequest::try_from("test@example.com")?];
     ///
-    /// let list: UpdateListResponse = client.update_list("some-list-id", contacts, false).await?;
+    /// let list: Response<UpdateListResponse> = client
+    ///     .update_list_with_metadata("some-list-id", contacts, false)
+    ///     .await?;
     ///
-    /// println!("Updated bulk verification list: {list:#?}");
+    /// println!("Updated bulk verification list: {:#?}", list.data);
+    /// println!("Client-observed latency: {:?}", list.meta.latency);
     /// # Ok(())
     /// # }
     /// ```
     #[cfg_attr(feature = "tracing", tracing::instrument)]
-    pub async fn update_list<
+    pub async fn update_list_with_metadata<
         ListId: ToString + Debug,
         Contact: Into<types::VerificationRequest> + Debug,
         ContactCollection: IntoIterator<Item = Contact> + Debug,
@@ -2306,7 +4257,7 @@ impl BriteVerifyClient {
         list_id: ListId,
         contacts: ContactCollection,
         auto_start: bool,
-    ) -> Result<types::UpdateListResponse, errors::BriteVerifyClientError> {
+    ) -> Result<types::Response<types::UpdateListResponse>, errors::BriteVerifyClientError> {
         // TODO(the-wondersmith): Apply bulk "rate" limit to supplied contacts
         //                        Bulk rate limits are:
         //                          - 100k Emails per page
@@ -2338,6 +4289,39 @@ impl BriteVerifyClient {
         &self,
         list_id: ListId,
     ) -> Result<types::VerificationListState, errors::BriteVerifyClientError> {
+        Ok(self
+            .get_list_by_id_with_metadata(list_id)
+            .await?
+            .into_inner())
+    }
+
+    /// Retrieve current "state" of the specified bulk verification list
+    /// along with the [`ResponseMetadata`](types::ResponseMetadata) of
+    /// the request that retrieved it
+    /// [[ref](https://docs.briteverify.com/#b09c09dc-e11e-44a8-b53d-9f1fd9c6792d)]
+    ///
+    /// #### Example
+    /// ```no_run
+    /// # use briteverify_rs::BriteVerifyClient;
+    /// use briteverify_rs::types::{Response, VerificationListState};
+    /// #
+    /// # async fn doc() -> anyhow::Result<()> {
+    /// # let client: BriteVerifyClient = BriteVerifyClient::new("YOUR API KEY")?;
+    ///
+    /// let list: Response<VerificationListState> = client
+    ///     .get_list_by_id_with_metadata("some-list-id")
+    ///     .await?;
+    ///
+    /// println!("Bulk verification list: {:#?}", list.data);
+    /// println!("Rate limit resets at: {:?}", list.meta.rate_limit_reset());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub async fn get_list_by_id_with_metadata<ListId: ToString + Debug>(
+        &self,
+        list_id: ListId,
+    ) -> Result<types::Response<types::VerificationListState>, errors::BriteVerifyClientError> {
         self._get_list_state(list_id, Nullable::None).await
     }
 
@@ -2370,6 +4354,44 @@ impl BriteVerifyClient {
         list_id: ListId,
         external_id: ExternalId,
     ) -> Result<types::VerificationListState, errors::BriteVerifyClientError> {
+        Ok(self
+            .get_list_by_external_id_with_metadata(list_id, external_id)
+            .await?
+            .into_inner())
+    }
+
+    /// Retrieve current "state" of a bulk verification list tied to an
+    /// externally supplied / customer-specific identifier along with the
+    /// [`ResponseMetadata`](types::ResponseMetadata) of the request that
+    /// retrieved it
+    /// [[ref](https://docs.briteverify.com/#b09c09dc-e11e-44a8-b53d-9f1fd9c6792d)]
+    ///
+    /// #### Example
+    /// ```no_run
+    /// # use briteverify_rs::BriteVerifyClient;
+    /// use briteverify_rs::types::{Response, VerificationListState};
+    /// #
+    /// # async fn doc() -> anyhow::Result<()> {
+    /// # let client: BriteVerifyClient = BriteVerifyClient::new("YOUR API KEY")?;
+    ///
+    /// let list: Response<VerificationListState> = client
+    ///     .get_list_by_external_id_with_metadata("some-list-id", "some-customer-id")
+    ///     .await?;
+    ///
+    /// println!("Bulk verification list: {:#?}", list.data);
+    /// println!("Request id: {:?}", list.meta.request_id());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub async fn get_list_by_external_id_with_metadata<
+        ListId: ToString + Debug,
+        ExternalId: std::fmt::Display + Debug,
+    >(
+        &self,
+        list_id: ListId,
+        external_id: ExternalId,
+    ) -> Result<types::Response<types::VerificationListState>, errors::BriteVerifyClientError> {
         self._get_list_state(list_id, Some(external_id)).await
     }
 
@@ -2407,25 +4429,59 @@ impl BriteVerifyClient {
         &self,
         list_id: ListId,
     ) -> Result<types::DeleteListResponse, errors::BriteVerifyClientError> {
+        Ok(self
+            .delete_list_by_id_with_metadata(list_id)
+            .await?
+            .into_inner())
+    }
+
+    /// Delete the specified batch verification list, returning its final
+    /// state along with the [`ResponseMetadata`](types::ResponseMetadata)
+    /// of the request that deleted it
+    /// [[ref](https://docs.briteverify.com/#6c9b9c05-a4a0-435e-a064-af7d9476719d)]
+    ///
+    /// ___
+    /// **NOTE:** The same restrictions documented on
+    /// [`delete_list_by_id`](BriteVerifyClient::delete_list_by_id)
+    /// apply here as well.
+    /// ___
+    ///
+    /// #### Example
+    /// ```no_run
+    /// # use briteverify_rs::BriteVerifyClient;
+    /// use briteverify_rs::types::{DeleteListResponse, Response};
+    /// #
+    /// # async fn doc() -> anyhow::Result<()> {
+    /// # let client: BriteVerifyClient = BriteVerifyClient::new("YOUR API KEY")?;
+    ///
+    /// let response: Response<DeleteListResponse> = client
+    ///     .delete_list_by_id_with_metadata("some-list-id")
+    ///     .await?;
+    ///
+    /// println!("Bulk verification list final state: {:#?}", response.data);
+    /// println!("HTTP status: {}", response.meta.status);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub async fn delete_list_by_id_with_metadata<ListId: ToString + Debug>(
+        &self,
+        list_id: ListId,
+    ) -> Result<types::Response<types::DeleteListResponse>, errors::BriteVerifyClientError> {
         let list_id: String = list_id.to_string();
-        let url = self.v3_base_url.extend_path(["lists", &list_id]);
 
-        let response = self.delete(url).send().await?;
+        let (response, meta) = self
+            ._exchange(self.core.delete_list_request(&list_id)?)
+            .await?;
 
-        match response.status() {
-            StatusCode::OK | StatusCode::ACCEPTED | StatusCode::NO_CONTENT => {
-                Ok(response.json::<types::DeleteListResponse>().await?)
-            }
-            StatusCode::NOT_FOUND => Err(errors::BriteVerifyClientError::BulkListNotFound(
-                Box::new(types::BulkListCRUDError {
-                    list_id: Some(list_id),
-                    ..response.json::<types::BulkListCRUDError>().await?
-                }),
-            )),
-            _ => Err(errors::BriteVerifyClientError::UnusableResponse(Box::new(
-                response,
-            ))),
+        if let Some(dedupe) = self.dedupe.as_ref() {
+            dedupe.forget(&list_id);
         }
+
+        Ok(types::Response::new(
+            self.core.parse_delete_list(response, list_id)?,
+            meta,
+        ))
     }
 
     /// Abandon the specified unprocessed bulk verification list
@@ -2460,6 +4516,39 @@ impl BriteVerifyClient {
         &self,
         list_id: ListId,
     ) -> Result<types::UpdateListResponse, errors::BriteVerifyClientError> {
+        Ok(self
+            .terminate_list_by_id_with_metadata(list_id)
+            .await?
+            .into_inner())
+    }
+
+    /// Abandon the specified unprocessed bulk verification list, returning
+    /// its final state along with the [`ResponseMetadata`](types::ResponseMetadata)
+    /// of the request that abandoned it
+    /// [[ref](https://docs.briteverify.com/#6c9b9c05-a4a0-435e-a064-af7d9476719d:~:text=To-,abandon,-an%20open%20list)]
+    ///
+    /// #### Example
+    /// ```no_run
+    /// # use briteverify_rs::BriteVerifyClient;
+    /// use briteverify_rs::types::{Response, UpdateListResponse};
+    /// #
+    /// # async fn doc() -> anyhow::Result<()> {
+    /// # let client: BriteVerifyClient = BriteVerifyClient::new("YOUR API KEY")?;
+    ///
+    /// let response: Response<UpdateListResponse> = client
+    ///     .terminate_list_by_id_with_metadata("some-list-id")
+    ///     .await?;
+    ///
+    /// println!("Bulk verification list final state: {:#?}", response.data);
+    /// println!("Request id: {:?}", response.meta.request_id());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub async fn terminate_list_by_id_with_metadata<ListId: ToString + Debug>(
+        &self,
+        list_id: ListId,
+    ) -> Result<types::Response<types::UpdateListResponse>, errors::BriteVerifyClientError> {
         self._create_or_update_list(
             Some(list_id),
             <Vec<types::VerificationRequest>>::new(),
@@ -2491,6 +4580,40 @@ impl BriteVerifyClient {
         &self,
         list_id: ListId,
     ) -> Result<types::UpdateListResponse, errors::BriteVerifyClientError> {
+        Ok(self
+            .queue_list_for_processing_with_metadata(list_id)
+            .await?
+            .into_inner())
+    }
+
+    /// Queue the specified (open) bulk verification list for immediate
+    /// processing, returning its updated state along with the
+    /// [`ResponseMetadata`](types::ResponseMetadata) of the request
+    /// that queued it
+    /// [[ref](https://docs.briteverify.com/#0a0cc29d-6d9f-4b0d-9aa5-4166775a8831:~:text=immediately%20start%20a%20list)]
+    ///
+    /// #### Example
+    /// ```no_run
+    /// # use briteverify_rs::BriteVerifyClient;
+    /// use briteverify_rs::types::{Response, UpdateListResponse};
+    /// #
+    /// # async fn doc() -> anyhow::Result<()> {
+    /// # let client: BriteVerifyClient = BriteVerifyClient::new("YOUR API KEY")?;
+    ///
+    /// let response: Response<UpdateListResponse> = client
+    ///     .queue_list_for_processing_with_metadata("some-list-id")
+    ///     .await?;
+    ///
+    /// println!("Bulk verification list state: {:#?}", response.data);
+    /// println!("Client-observed latency: {:?}", response.meta.latency);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub async fn queue_list_for_processing_with_metadata<ListId: ToString + Debug>(
+        &self,
+        list_id: ListId,
+    ) -> Result<types::Response<types::UpdateListResponse>, errors::BriteVerifyClientError> {
         self._create_or_update_list(
             Some(list_id),
             <Vec<types::VerificationRequest>>::new(),
@@ -2506,6 +4629,12 @@ impl BriteVerifyClient {
     /// **NOTE:** Verification results are only available once
     /// a list has finished verifying in its entirety. It is not
     /// possible to retrieve verification results piecemeal.
+    ///
+    /// Because results are retrieved across several requests
+    /// (one per page), this method has no `_with_metadata`
+    /// variant. The [`ResponseMetadata`](types::ResponseMetadata)
+    /// of the list's state lookup is available through
+    /// [`get_list_by_id_with_metadata`](BriteVerifyClient::get_list_by_id_with_metadata).
     /// ___
     ///
     /// #### Example
@@ -2538,6 +4667,7 @@ impl BriteVerifyClient {
         }
 
         let page_count = std::cmp::max(1u64, list_status.page_count.unwrap());
+        let mut exported = true;
 
         let pages: Vec<_> = futures_util::future::join_all(
             (1..=page_count).map(|page_number| self._get_result_page(list_id.clone(), page_number)),
@@ -2547,6 +4677,7 @@ impl BriteVerifyClient {
         .filter(|page| {
             if let Err(error) = page {
                 log::error!("{error:#?}");
+                exported = false;
                 false
             } else {
                 true
@@ -2557,10 +4688,327 @@ impl BriteVerifyClient {
 
         let results: Vec<types::BulkVerificationResult> = itertools::concat(pages);
 
+        let results = match self.dedupe.as_ref() {
+            Some(dedupe) => {
+                let results = dedupe.fan_out(&list_id, results);
+
+                // the duplicates are no longer needed once
+                // every page of results has been exported
+                if exported {
+                    dedupe.forget(&list_id);
+                }
+
+                results
+            }
+            None => results,
+        };
+
+        #[cfg(feature = "sqlite")]
+        if let Some(persistent) = self.persistent.as_deref() {
+            persistent
+                ._record_bulk(&list_id, &results)
+                .await
+                .unwrap_or_else(|error| log::warn!("Could not store list results: {error:#}"));
+        }
+
         Ok(results)
     }
 
     // </editor-fold desc="// Bulk Verification (v3) Endpoints ... ">
+
+    // <editor-fold desc="// Keyed Bulk Verification Jobs ... ">
+
+    /// Create a new bulk verification job from the supplied contacts,
+    /// each paired with a caller-supplied key, and (optionally) queue
+    /// its lists for immediate processing (see [`jobs`](crate::jobs)
+    /// for details)
+    ///
+    /// ___
+    /// **NOTE:** The job is split across as many bulk verification
+    /// lists as necessary, each holding up to one million contacts.
+    /// If uploading any of the job's contacts fails after some of its
+    /// lists were created, the error is returned as an
+    /// [`IncompleteBulkJob`](errors::BriteVerifyClientError::IncompleteBulkJob)
+    /// error listing them, so they can be recovered or cleaned up.
+    /// ___
+    ///
+    /// #### Example
+    /// ```no_run
+    /// # use briteverify_rs::BriteVerifyClient;
+    /// use briteverify_rs::{jobs::BulkJob, types::VerificationRequest};
+    /// #
+    /// # async fn doc() -> anyhow::Result<()> {
+    /// # let client: BriteVerifyClient = BriteVerifyClient::new("YOUR API KEY")?;
+    ///
+    /// let contacts: Vec<(&str, VerificationRequest)> = vec![
+    ///     ("crm-record-1", VerificationRequest::try_from("test@example.com")?),
+    ///     ("crm-record-2", VerificationRequest::try_from("+15555555555")?),
+    /// ];
+    ///
+    /// let job: BulkJob<&str> = client.create_job(contacts, true).await?;
+    ///
+    /// println!("New bulk verification job lists: {:?}", job.list_ids());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(contacts)))]
+    pub async fn create_job<
+        Key,
+        Contact: Into<types::VerificationRequest>,
+        ContactCollection: IntoIterator<Item = (Key, Contact)>,
+    >(
+        &self,
+        contacts: ContactCollection,
+        auto_start: bool,
+    ) -> Result<jobs::BulkJob<Key>, errors::BriteVerifyClientError> {
+        self.create_job_with_list_size(contacts, auto_start, MAX_CONTACTS_PER_LIST)
+            .await
+    }
+
+    /// Create a new bulk verification job from the supplied contacts,
+    /// each paired with a caller-supplied key, split across lists of
+    /// (at most) the supplied size, and (optionally) queue its lists
+    /// for immediate processing
+    ///
+    /// ___
+    /// **NOTE:** The supplied list size is capped at the BriteVerify
+    /// API's limit of one million contacts per list.
+    /// ___
+    ///
+    /// #### Example
+    /// ```no_run
+    /// # use briteverify_rs::BriteVerifyClient;
+    /// use briteverify_rs::{jobs::BulkJob, types::VerificationRequest};
+    /// #
+    /// # async fn doc() -> anyhow::Result<()> {
+    /// # let client: BriteVerifyClient = BriteVerifyClient::new("YOUR API KEY")?;
+    /// # let contacts: Vec<(u64, VerificationRequest)> = Vec::new();
+    ///
+    /// let job: BulkJob<u64> = client
+    ///     .create_job_with_list_size(contacts, true, 250_000)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(contacts)))]
+    pub async fn create_job_with_list_size<
+        Key,
+        Contact: Into<types::VerificationRequest>,
+        ContactCollection: IntoIterator<Item = (Key, Contact)>,
+    >(
+        &self,
+        contacts: ContactCollection,
+        auto_start: bool,
+        list_size: usize,
+    ) -> Result<jobs::BulkJob<Key>, errors::BriteVerifyClientError> {
+        let list_size = list_size.clamp(1, MAX_CONTACTS_PER_LIST);
+        let policy = self
+            .dedupe
+            .as_deref()
+            .map_or_else(DedupePolicy::default, |dedupe| *dedupe.policy());
+
+        let mut job = jobs::BulkJob::_new(policy, self.phone_region.clone());
+        let mut contacts: Vec<(Key, types::VerificationRequest)> = contacts
+            .into_iter()
+            .map(|(key, contact)| (key, contact.into()))
+            .collect();
+
+        while !contacts.is_empty() {
+            let (keys, list): (Vec<Key>, Vec<types::VerificationRequest>) =
+                contacts.drain(..list_size.min(contacts.len())).unzip();
+
+            let page_count = list.len().div_ceil(MAX_CONTACTS_PER_REQUEST);
+            let mut list_id: Option<String> = None;
+
+            for (idx, page) in list.chunks(MAX_CONTACTS_PER_REQUEST).enumerate() {
+                let response = match self
+                    ._create_or_update_list(
+                        list_id.as_ref(),
+                        page.to_vec(),
+                        auto_start && idx + 1 == page_count,
+                    )
+                    .await
+                {
+                    Ok(response) => response,
+                    Err(error) => {
+                        let list_ids: Vec<String> = job
+                            .list_ids()
+                            .into_iter()
+                            .map(str::to_string)
+                            .chain(list_id)
+                            .collect();
+
+                        return Err(match list_ids.is_empty() {
+                            true => error,
+                            false => errors::BriteVerifyClientError::IncompleteBulkJob {
+                                list_ids,
+                                source: Box::new(error),
+                            },
+                        });
+                    }
+                };
+
+                list_id = Some(response.into_inner().list.id);
+            }
+
+            job._record(
+                list_id.unwrap_or_default(),
+                keys.into_iter().zip(list.iter()).collect(),
+            );
+        }
+
+        Ok(job)
+    }
+
+    /// Get the results of every list in the supplied bulk
+    /// verification job, each paired with the key of the
+    /// contact it was produced for
+    ///
+    /// ___
+    /// **NOTE:** The same restrictions documented on
+    /// [`get_results_by_list_id`](BriteVerifyClient::get_results_by_list_id)
+    /// apply to each of the job's lists.
+    /// ___
+    ///
+    /// #### Example
+    /// ```no_run
+    /// # use briteverify_rs::BriteVerifyClient;
+    /// use briteverify_rs::jobs::{BulkJob, KeyedBulkResult};
+    /// #
+    /// # async fn doc() -> anyhow::Result<()> {
+    /// # let client: BriteVerifyClient = BriteVerifyClient::new("YOUR API KEY")?;
+    /// # let job: BulkJob<u64> = client.create_job(Vec::<(u64, briteverify_rs::types::VerificationRequest)>::new(), true).await?;
+    ///
+    /// let results: Vec<KeyedBulkResult<u64>> = client.get_job_results(&job).await?;
+    ///
+    /// for record in results {
+    ///     println!("Record {:?}: {:#?}", record.key, record.result);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(job)))]
+    pub async fn get_job_results<Key: Clone>(
+        &self,
+        job: &jobs::BulkJob<Key>,
+    ) -> Result<Vec<jobs::KeyedBulkResult<Key>>, errors::BriteVerifyClientError> {
+        let mut results = Vec::with_capacity(job.len());
+
+        for list_id in job.list_ids() {
+            let list = self.get_results_by_list_id(list_id).await?;
+
+            results.extend(job.pair(list_id, list));
+        }
+
+        Ok(results)
+    }
+
+    // </editor-fold desc="// Keyed Bulk Verification Jobs ... ">
+
+    // <editor-fold desc="// Credit Balance Monitoring ... ">
+
+    /// Periodically check your account's credit balance, yielding
+    /// an event whenever it meets one of the supplied monitor's
+    /// alert conditions (see [`monitor`](crate::monitor) for details)
+    ///
+    /// ___
+    /// **NOTE:** The returned stream never ends on its own, and
+    /// holds its own clone of the client, so it can be moved into
+    /// a background task. Failed balance checks are yielded as
+    /// errors, after which checking continues as normal.
+    /// ___
+    ///
+    /// #### Example
+    /// ```no_run
+    /// # use std::time::Duration;
+    /// # use futures_util::StreamExt;
+    /// # use briteverify_rs::BriteVerifyClient;
+    /// use briteverify_rs::monitor::BalanceMonitor;
+    /// #
+    /// # async fn doc() -> anyhow::Result<()> {
+    /// # let client: BriteVerifyClient = BriteVerifyClient::new("YOUR API KEY")?;
+    ///
+    /// let mut events = client.monitor_balance(
+    ///     BalanceMonitor::new()
+    ///         .interval(Duration::from_secs(300))
+    ///         .low_balance_threshold(5_000),
+    /// );
+    ///
+    /// tokio::spawn(async move {
+    ///     while let Some(Ok(event)) = events.next().await {
+    ///         println!("Credit balance alert: {event:?}");
+    ///     }
+    /// });
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn monitor_balance(
+        &self,
+        monitor: monitor::BalanceMonitor,
+    ) -> futures::stream::BoxStream<
+        'static,
+        Result<monitor::BalanceEvent, errors::BriteVerifyClientError>,
+    > {
+        monitor._stream(self.clone(), self.timekeeper.clone())
+    }
+
+    /// Periodically check your account's credit balance, handing
+    /// each event yielded by [`monitor_balance`](BriteVerifyClient::monitor_balance)
+    /// to the supplied callback until it returns
+    /// [`ControlFlow::Break`](std::ops::ControlFlow::Break)
+    ///
+    /// ___
+    /// **NOTE:** Balance monitoring is only available on the
+    /// asynchronous client (i.e. `BlockingBriteVerifyClient`
+    /// has no equivalent method)
+    /// ___
+    ///
+    /// #### Example
+    /// ```no_run
+    /// # use std::{ops::ControlFlow, time::Duration};
+    /// # use briteverify_rs::BriteVerifyClient;
+    /// use briteverify_rs::monitor::{BalanceEvent, BalanceMonitor};
+    /// #
+    /// # async fn doc() -> anyhow::Result<()> {
+    /// # let client: BriteVerifyClient = BriteVerifyClient::new("YOUR API KEY")?;
+    ///
+    /// let monitor = BalanceMonitor::new()
+    ///     .low_balance_threshold(5_000)
+    ///     .max_consumption_rate(1_000, Duration::from_secs(3600));
+    ///
+    /// client
+    ///     .watch_balance(monitor, |event| {
+    ///         if let Ok(BalanceEvent::LowBalance { credits, .. }) = event {
+    ///             println!("Only {credits} credits left!");
+    ///         }
+    ///
+    ///         ControlFlow::Continue(())
+    ///     })
+    ///     .await;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn watch_balance<Callback>(
+        &self,
+        monitor: monitor::BalanceMonitor,
+        mut callback: Callback,
+    ) where
+        Callback: FnMut(
+            Result<monitor::BalanceEvent, errors::BriteVerifyClientError>,
+        ) -> std::ops::ControlFlow<()>,
+    {
+        use futures::StreamExt;
+
+        let mut events = self.monitor_balance(monitor);
+
+        while let Some(event) = events.next().await {
+            if callback(event).is_break() {
+                break;
+            }
+        }
+    }
+
+    // </editor-fold desc="// Credit Balance Monitoring ... ">
 }
 
 // </editor-fold desc="// Client ...">
@@ -2943,6 +5391,51 @@ mod tests {
         Ok(assert!(req_builder.build().is_ok()))
     }
 
+    #[rstest::rstest]
+    /// Test that the `BriteVerifyClient`'s `with_options` method
+    /// attaches the supplied options without otherwise altering
+    /// the client's configuration
+    fn test_bv_client_with_options() -> Result<()> {
+        let client = BriteVerifyClient::builder()
+            .api_key(GOOD_KEY)
+            .v1_base_url("https://testing.example.com/api/v1")
+            .build()?;
+
+        assert!(client.options.timeout.is_none());
+        assert!(client.options.retry_enabled.is_none());
+        assert!(client.options.headers.is_empty());
+
+        let options = super::RequestOptions::new()
+            .timeout(std::time::Duration::from_secs(2))
+            .retry_enabled(true)
+            .header(
+                super::HeaderName::from_static("x-request-id"),
+                HeaderValue::from_static("fortune favors the bold"),
+            );
+
+        let scoped = client.with_options(options);
+
+        assert_eq!(&client.core.v1_base_url, &scoped.core.v1_base_url);
+        assert_eq!(&client.core.v3_base_url, &scoped.core.v3_base_url);
+        assert_eq!(client.retry_enabled, scoped.retry_enabled);
+        assert_eq!(
+            Some(std::time::Duration::from_secs(2)),
+            scoped.options.timeout
+        );
+        assert_eq!(Some(true), scoped.options.retry_enabled);
+        assert_str_eq!(
+            "fortune favors the bold",
+            scoped.options.headers["x-request-id"].to_str()?
+        );
+
+        // Options replace, rather than accumulate
+        let rescoped = scoped.with_options(super::RequestOptions::new());
+
+        assert!(rescoped.options.headers.is_empty());
+
+        Ok(())
+    }
+
     // </editor-fold desc="// Tests ...">
 }
 
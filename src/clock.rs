@@ -0,0 +1,256 @@
+//! ## Pluggable Time Sources & Sleepers
+//!
+//! A [`BriteVerifyClient`](crate::BriteVerifyClient) measures time
+//! with a [`Clock`](Clock) and waits out rate limits with a
+//! [`Sleeper`](Sleeper). By default, those are the system's monotonic
+//! clock and [`futures_timer`](futures_timer)'s runtime-agnostic
+//! [`Delay`](futures_timer::Delay), but either can be replaced via
+//! the client's builder, which allows:
+//!
+//! - applications built on a specific async runtime to use that
+//!   runtime's timers (e.g. [`tokio::time::sleep`](https://docs.rs/tokio/latest/tokio/time/fn.sleep.html))
+//! - tests to advance time instantly with a [`VirtualClock`](VirtualClock)
+//!
+//! Any `Fn() -> Instant` can be used as a [`Clock`](Clock), and
+//! any `Fn(Duration) -> impl Future<Output = ()>` can be used
+//! as a [`Sleeper`](Sleeper).
+//!
+//! ## Basic Usage
+//! ```no_run
+//! # use std::time::Duration;
+//! # use briteverify_rs::{BriteVerifyClient, clock::VirtualClock};
+//! #
+//! # async fn doc() -> anyhow::Result<()> {
+//! let clock = VirtualClock::new();
+//!
+//! let client: BriteVerifyClient = BriteVerifyClient::builder()
+//!     .api_key("YOUR API KEY")
+//!     .retry_enabled(true)
+//!     .clock(clock.clone())
+//!     .sleeper(clock.clone())
+//!     .build()?;
+//!
+//! client.get_account_balance().await?;
+//!
+//! // any rate limits encountered were
+//! // "waited out" without actually waiting
+//! println!("Waited (virtually) for: {:?}", clock.elapsed());
+//! # Ok(())
+//! # }
+//! ```
+//
+// Standard Library Imports
+use std::{
+    fmt::Debug,
+    future::Future,
+    sync::{Arc, Mutex, MutexGuard},
+    time::{Duration, Instant},
+};
+
+// Third-Party Imports
+use futures::future::BoxFuture;
+use futures_timer::Delay;
+
+// <editor-fold desc="// Traits ...">
+
+/// A source of the current (monotonic) time
+pub trait Clock: Send + Sync {
+    /// Get the current time
+    fn now(&self) -> Instant;
+}
+
+impl<Func: Fn() -> Instant + Send + Sync> Clock for Func {
+    fn now(&self) -> Instant {
+        self()
+    }
+}
+
+/// A means of waiting for a given amount of time
+pub trait Sleeper: Send + Sync {
+    /// Create a future that resolves once
+    /// the supplied duration has elapsed
+    fn sleep(&self, duration: Duration) -> BoxFuture<'static, ()>;
+}
+
+impl<Func, Fut> Sleeper for Func
+where
+    Func: Fn(Duration) -> Fut + Send + Sync,
+    Fut: Future<Output = ()> + Send + 'static,
+{
+    fn sleep(&self, duration: Duration) -> BoxFuture<'static, ()> {
+        Box::pin(self(duration))
+    }
+}
+
+// </editor-fold desc="// Traits ...">
+
+// <editor-fold desc="// Default Implementations ...">
+
+/// The system's monotonic clock (i.e. [`Instant::now`](Instant::now))
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+}
+
+/// A runtime-agnostic [`Sleeper`](Sleeper) backed
+/// by [`futures_timer`](futures_timer)'s [`Delay`](Delay)
+#[derive(Clone, Copy, Debug, Default)]
+pub struct TimerSleeper;
+
+impl Sleeper for TimerSleeper {
+    fn sleep(&self, duration: Duration) -> BoxFuture<'static, ()> {
+        Box::pin(Delay::new(duration))
+    }
+}
+
+// </editor-fold desc="// Default Implementations ...">
+
+// <editor-fold desc="// VirtualClock ...">
+
+/// [internal-implementation]
+/// The mutable state of a virtual clock
+#[derive(Debug)]
+struct VirtualClockState {
+    origin: Instant,
+    elapsed: Duration,
+    sleeps: Vec<Duration>,
+}
+
+/// A manually-advanced [`Clock`](Clock) that doubles as a
+/// [`Sleeper`](Sleeper) whose sleeps complete immediately
+/// (after advancing the clock by the slept duration)
+///
+/// Clones share the same underlying time, so a single virtual
+/// clock can be handed to a client as both its clock and its
+/// sleeper and then inspected afterward.
+#[derive(Clone, Debug)]
+pub struct VirtualClock {
+    state: Arc<Mutex<VirtualClockState>>,
+}
+
+impl Default for VirtualClock {
+    fn default() -> Self {
+        Self {
+            state: Arc::new(Mutex::new(VirtualClockState {
+                origin: Instant::now(),
+                elapsed: Duration::ZERO,
+                sleeps: Vec::new(),
+            })),
+        }
+    }
+}
+
+impl VirtualClock {
+    /// Create a new virtual clock, starting at the current time
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Advance the clock by the supplied duration
+    pub fn advance(&self, duration: Duration) {
+        self._state().elapsed += duration;
+    }
+
+    /// The total amount of virtual time that
+    /// has passed since the clock was created
+    pub fn elapsed(&self) -> Duration {
+        self._state().elapsed
+    }
+
+    /// Every duration "slept" via the clock, in order
+    pub fn sleeps(&self) -> Vec<Duration> {
+        self._state().sleeps.clone()
+    }
+
+    /// [internal-implementation]
+    /// Lock the clock's mutable state
+    fn _state(&self) -> MutexGuard<'_, VirtualClockState> {
+        self.state
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+impl Clock for VirtualClock {
+    fn now(&self) -> Instant {
+        let state = self._state();
+
+        state.origin + state.elapsed
+    }
+}
+
+impl Sleeper for VirtualClock {
+    fn sleep(&self, duration: Duration) -> BoxFuture<'static, ()> {
+        let mut state = self._state();
+
+        state.elapsed += duration;
+        state.sleeps.push(duration);
+
+        Box::pin(futures::future::ready(()))
+    }
+}
+
+// </editor-fold desc="// VirtualClock ...">
+
+// <editor-fold desc="// Timekeeper ...">
+
+/// The [`Clock`](Clock) and [`Sleeper`](Sleeper)
+/// used by a [`BriteVerifyClient`](crate::BriteVerifyClient)
+#[derive(Clone)]
+pub struct Timekeeper {
+    pub(crate) clock: Arc<dyn Clock>,
+    pub(crate) sleeper: Arc<dyn Sleeper>,
+}
+
+impl Default for Timekeeper {
+    fn default() -> Self {
+        Self {
+            clock: Arc::new(SystemClock),
+            sleeper: Arc::new(TimerSleeper),
+        }
+    }
+}
+
+#[cfg_attr(tarpaulin, coverage(off))]
+impl Debug for Timekeeper {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        formatter.debug_struct("Timekeeper").finish_non_exhaustive()
+    }
+}
+
+// </editor-fold desc="// Timekeeper ...">
+
+// <editor-fold desc="// I/O-Free Tests ...">
+
+#[cfg(test)]
+mod tests {
+    // Standard Library Imports
+    use std::time::Duration;
+
+    // Third-Party Dependencies
+    use pretty_assertions::assert_eq;
+
+    // Crate-Level Imports
+    use super::{Clock, Sleeper, VirtualClock};
+
+    /// Test that virtual clocks advance (and
+    /// record) sleeps without actually waiting
+    #[rstest::rstest]
+    #[test_log::test(tokio::test)]
+    async fn test_virtual_clock() {
+        let clock = VirtualClock::new();
+        let start = clock.now();
+
+        clock.sleep(Duration::from_secs(3600)).await;
+        clock.advance(Duration::from_secs(1));
+
+        assert_eq!(Duration::from_secs(3601), clock.now() - start);
+        assert_eq!(vec![Duration::from_secs(3600)], clock.clone().sleeps());
+    }
+}
+
+// </editor-fold desc="// I/O-Free Tests ...">
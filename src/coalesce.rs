@@ -0,0 +1,281 @@
+//! ## In-Flight Request Coalescing
+//!
+//! A [`BriteVerifyClient`](crate::BriteVerifyClient) built with
+//! [`coalesce_requests`](crate::BriteVerifyClientBuilder::coalesce_requests)
+//! enabled sends at most one single-transaction verification per
+//! distinct request at a time. Calls made while an identical request
+//! is still in flight wait for it to finish instead of sending their
+//! own, and every caller receives the same response (or a copy of the
+//! same error).
+//!
+//! Requests are considered identical if their normalized values (see
+//! [`ContactKey`](crate::dedupe::ContactKey)) are, so concurrent
+//! verifications of `Test@Example.com` and `test@example.com`
+//! share a single HTTP request.
+//!
+//! ___
+//! **NOTE:** Coalescing only applies to requests that are in flight
+//! at the same time. Once a request completes, the next identical
+//! one is sent as usual (unless it can be answered from the client's
+//! [`VerificationCache`](crate::cache::VerificationCache)). Calls that
+//! join an in-flight request are attributed to the caller that sent
+//! it in the client's [`CreditLedger`](crate::ledger::CreditLedger).
+//! Calls made with per-call [`RequestOptions`](crate::RequestOptions)
+//! (see [`with_options`](crate::BriteVerifyClient::with_options)) are
+//! never coalesced, since their overrides and tags would otherwise be
+//! silently ignored.
+//! ___
+//!
+//! ## Basic Usage
+//! ```no_run
+//! # use briteverify_rs::BriteVerifyClient;
+//! #
+//! # async fn doc() -> anyhow::Result<()> {
+//! let client: BriteVerifyClient = BriteVerifyClient::builder()
+//!     .api_key("YOUR API KEY")
+//!     .coalesce_requests(true)
+//!     .build()?;
+//!
+//! // only one request is actually sent
+//! let (first, second) = futures::join!(
+//!     client.verify_email("test@example.com"),
+//!     client.verify_email("TEST@example.com"),
+//! );
+//!
+//! assert_eq!(client.inflight_verifications().unwrap().coalesced(), 1);
+//! # Ok(())
+//! # }
+//! ```
+//
+// Standard Library Imports
+use std::{
+    collections::HashMap,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, Mutex, MutexGuard,
+    },
+};
+
+// Third-Party Imports
+use futures::future::{BoxFuture, FutureExt, Shared};
+
+// Crate-Level Imports
+use crate::{
+    dedupe::{ContactKey, DedupePolicy},
+    errors::BriteVerifyClientError,
+    types::{Response, VerificationRequest, VerificationResponse},
+};
+
+// <editor-fold desc="// Constants ...">
+
+/// The (shareable) outcome of an in-flight verification
+type SharedOutcome = Result<Response<VerificationResponse>, Arc<BriteVerifyClientError>>;
+
+/// An in-flight verification that any number of callers can await
+type InFlight = Shared<BoxFuture<'static, SharedOutcome>>;
+
+// </editor-fold desc="// Constants ...">
+
+// <editor-fold desc="// InFlightVerifications ...">
+
+/// The single-transaction verifications a client
+/// currently has in flight, keyed by their values
+pub struct InFlightVerifications {
+    default_region: Option<String>,
+    coalesced: AtomicU64,
+    pending: Mutex<HashMap<ContactKey, InFlight>>,
+}
+
+#[cfg_attr(tarpaulin, coverage(off))]
+impl std::fmt::Debug for InFlightVerifications {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("InFlightVerifications")
+            .field("default_region", &self.default_region)
+            .field("coalesced", &self.coalesced())
+            .field("len", &self.len())
+            .finish_non_exhaustive()
+    }
+}
+
+impl InFlightVerifications {
+    /// Create a new `InFlightVerifications` instance, interpreting
+    /// phone numbers without a country code relative to the
+    /// supplied default region
+    pub fn new(default_region: Option<String>) -> Self {
+        Self {
+            default_region,
+            coalesced: AtomicU64::new(0),
+            pending: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// The number of distinct verifications currently in flight
+    pub fn len(&self) -> usize {
+        self._pending().len()
+    }
+
+    /// Whether or not any verifications are currently in flight
+    pub fn is_empty(&self) -> bool {
+        self._pending().is_empty()
+    }
+
+    /// The total number of calls that joined an already
+    /// in-flight verification instead of sending their own
+    pub fn coalesced(&self) -> u64 {
+        self.coalesced.load(Ordering::Relaxed)
+    }
+
+    // <editor-fold desc="// Internal Utility Methods ... ">
+
+    /// [internal-implementation]
+    /// Lock the in-flight verifications, recovering
+    /// them if a previous holder panicked
+    fn _pending(&self) -> MutexGuard<'_, HashMap<ContactKey, InFlight>> {
+        self.pending
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    /// [internal-implementation]
+    /// Await the in-flight verification identical to the supplied
+    /// request, or start one with the supplied `send` function if
+    /// there isn't one
+    pub(crate) async fn _coalesce<Start>(
+        self: &Arc<Self>,
+        request: &VerificationRequest,
+        send: Start,
+    ) -> Result<Response<VerificationResponse>, BriteVerifyClientError>
+    where
+        Start: FnOnce() -> BoxFuture<
+            'static,
+            Result<Response<VerificationResponse>, BriteVerifyClientError>,
+        >,
+    {
+        let key = DedupePolicy::new().contact_key(request, self.default_region.as_deref());
+
+        let inflight = {
+            let mut pending = self._pending();
+
+            match pending.get(&key) {
+                Some(inflight) => {
+                    self.coalesced.fetch_add(1, Ordering::Relaxed);
+                    inflight.clone()
+                }
+                None => {
+                    let registry = Arc::clone(self);
+                    let sent = send();
+                    let owned_key = key.clone();
+
+                    let inflight = async move {
+                        let outcome = sent.await.map_err(Arc::new);
+
+                        registry._pending().remove(&owned_key);
+
+                        outcome
+                    }
+                    .boxed()
+                    .shared();
+
+                    pending.insert(key, inflight.clone());
+
+                    inflight
+                }
+            }
+        };
+
+        inflight
+            .await
+            .map_err(|error| Arc::try_unwrap(error).unwrap_or_else(|error| error._duplicate()))
+    }
+
+    // </editor-fold desc="// Internal Utility Methods ... ">
+}
+
+// </editor-fold desc="// InFlightVerifications ...">
+
+// <editor-fold desc="// I/O-Free Tests ...">
+
+#[cfg(test)]
+mod tests {
+    // Standard Library Imports
+    use std::sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    };
+
+    // Third-Party Dependencies
+    use futures::{channel::oneshot, future::FutureExt};
+    use pretty_assertions::assert_eq;
+
+    // Crate-Level Imports
+    use super::InFlightVerifications;
+    use crate::{
+        errors::BriteVerifyClientError,
+        types::{Response, VerificationRequest, VerificationResponse},
+    };
+
+    /// Test that concurrent identical requests share a
+    /// single outcome, and that errors are copied to
+    /// every caller
+    #[rstest::rstest]
+    fn test_coalesce_identical_requests() {
+        let inflight = Arc::new(InFlightVerifications::new(None));
+        let sent = Arc::new(AtomicUsize::new(0));
+        let (release, released) = oneshot::channel::<()>();
+        let released = released.shared();
+
+        let request = |email: &str| VerificationRequest {
+            email: Some(email.to_string()),
+            phone: None,
+            address: None,
+        };
+
+        let calls = ["Test@Example.com", "test@example.com", "TEST@EXAMPLE.COM"].map(|email| {
+            let (inflight, sent, released) = (inflight.clone(), sent.clone(), released.clone());
+
+            async move {
+                inflight
+                    ._coalesce(&request(email), move || {
+                        sent.fetch_add(1, Ordering::SeqCst);
+
+                        async move {
+                            let _ = released.await;
+                            Err::<Response<VerificationResponse>, _>(
+                                BriteVerifyClientError::InsufficientCredits {
+                                    required: 1,
+                                    available: 0,
+                                },
+                            )
+                        }
+                        .boxed()
+                    })
+                    .await
+            }
+        });
+
+        let outcomes = futures::executor::block_on(async {
+            let joined = futures::future::join_all(calls);
+            futures::pin_mut!(joined);
+
+            assert!((&mut joined).now_or_never().is_none());
+            assert_eq!(1, inflight.len());
+
+            release.send(()).unwrap();
+
+            joined.await
+        });
+
+        assert_eq!(1, sent.load(Ordering::SeqCst));
+        assert_eq!(2, inflight.coalesced());
+        assert!(inflight.is_empty());
+        assert!(outcomes.iter().all(|outcome| matches!(
+            outcome,
+            Err(BriteVerifyClientError::InsufficientCredits {
+                required: 1,
+                available: 0
+            })
+        )));
+    }
+}
+
+// </editor-fold desc="// I/O-Free Tests ...">
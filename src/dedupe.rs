@@ -0,0 +1,729 @@
+//! ## Bulk Contact Deduplication
+//!
+//! A [`BriteVerifyClient`](crate::BriteVerifyClient) built with
+//! [`deduplicate_contacts(true)`](crate::BriteVerifyClientBuilder::deduplicate_contacts)
+//! (or with an explicit [`dedupe_policy`](crate::BriteVerifyClientBuilder::dedupe_policy))
+//! only uploads the first of any duplicate contacts in a bulk verification
+//! list, so each distinct contact only costs credits once. Contacts are
+//! considered duplicates when their:
+//!
+//! - email addresses are the same, ignoring the case of the domain and
+//!   (per the client's [`DedupePolicy`](DedupePolicy)) the case of the
+//!   account, dots in Gmail accounts, and plus-tags
+//! - phone numbers are the same, once normalized to E.164 format
+//! - street addresses are the same, once
+//!   [normalized](crate::types::StreetAddressArray::normalized)
+//!
+//! The client remembers the duplicates of every list it creates (or
+//! appends to), and contacts appended to a list that duplicate ones
+//! already in it aren't uploaded again. When the list's results are
+//! exported via [`get_results_by_list_id`](crate::BriteVerifyClient::get_results_by_list_id),
+//! each result is "fanned out" to every original duplicate, with the
+//! email address and phone number of each copy set to the values that
+//! were originally supplied. Results that can't be matched to the
+//! contacts they were produced for are returned unchanged.
+//!
+//! ___
+//! **NOTE:** A list's duplicates are forgotten once its results have
+//! been exported in full (i.e. without any page failing to download),
+//! or once the client sees that the list has expired or been deleted,
+//! so exporting the same list's results again returns them without
+//! fanning them out.
+//! ___
+//!
+//! ## Basic Usage
+//! ```no_run
+//! # use briteverify_rs::{BriteVerifyClient, dedupe::DedupePolicy, types::VerificationRequest};
+//! #
+//! # async fn doc() -> anyhow::Result<()> {
+//! let client: BriteVerifyClient = BriteVerifyClient::builder()
+//!     .api_key("YOUR API KEY")
+//!     .dedupe_policy(DedupePolicy::new().ignore_gmail_dots(true))
+//!     .build()?;
+//!
+//! let contacts: [VerificationRequest; 2] = [
+//!     VerificationRequest::try_from("john.doe@gmail.com")?,
+//!     VerificationRequest::try_from("JohnDoe@Gmail.com")?,
+//! ];
+//!
+//! // only "john.doe@gmail.com" is uploaded
+//! let list = client.create_list(Some(contacts), true).await?;
+//!
+//! // ... once the list has been processed
+//! let results = client.get_results_by_list_id(&list.list.id).await?;
+//!
+//! assert_eq!(results.len(), 2);
+//! # Ok(())
+//! # }
+//! ```
+//
+// Standard Library Imports
+use std::{
+    collections::{HashMap, HashSet},
+    sync::{Mutex, MutexGuard},
+};
+
+// Crate-Level Imports
+use crate::types::{
+    self, address, BatchState, BulkVerificationResult, PhoneNumber, VerificationListState,
+    VerificationRequest,
+};
+
+// <editor-fold desc="// Constants ...">
+
+/// Domains whose accounts ignore any dots in the account name
+const GMAIL_DOMAINS: [&str; 2] = ["gmail.com", "googlemail.com"];
+
+/// List states whose lists' results can
+/// no longer be exported
+const GONE_STATES: [BatchState; 4] = [
+    BatchState::Expired,
+    BatchState::Deleted,
+    BatchState::NotFound,
+    BatchState::Terminated,
+];
+
+// </editor-fold desc="// Constants ...">
+
+// <editor-fold desc="// DedupePolicy ...">
+
+/// How email addresses are compared when deduplicating contacts
+/// (phone numbers and street addresses are always compared
+/// in their normalized forms)
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DedupePolicy {
+    fold_case: bool,
+    ignore_gmail_dots: bool,
+    ignore_plus_tags: bool,
+}
+
+impl Default for DedupePolicy {
+    fn default() -> Self {
+        Self {
+            fold_case: true,
+            ignore_gmail_dots: false,
+            ignore_plus_tags: false,
+        }
+    }
+}
+
+impl DedupePolicy {
+    /// Create a new `DedupePolicy` that ignores the case
+    /// of email addresses, but not Gmail dots or plus-tags
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Ignore (or don't ignore) the case of the account
+    /// part of email addresses (the case of the domain
+    /// is always ignored)
+    pub fn fold_case(mut self, value: bool) -> Self {
+        self.fold_case = value;
+        self
+    }
+
+    /// Ignore (or don't ignore) any dots in the account
+    /// part of Gmail addresses (e.g. `"john.doe@gmail.com"`
+    /// is a duplicate of `"johndoe@gmail.com"`)
+    pub fn ignore_gmail_dots(mut self, value: bool) -> Self {
+        self.ignore_gmail_dots = value;
+        self
+    }
+
+    /// Ignore (or don't ignore) any plus-tags in the account part
+    /// of email addresses (e.g. `"john+news@example.com"` is a
+    /// duplicate of `"john@example.com"`)
+    pub fn ignore_plus_tags(mut self, value: bool) -> Self {
+        self.ignore_plus_tags = value;
+        self
+    }
+
+    /// Get the form of the supplied email address
+    /// used to compare it with others
+    pub fn email_key(&self, email: &str) -> String {
+        let email = email.trim();
+
+        let Some((account, domain)) = email.rsplit_once('@') else {
+            return email.to_string();
+        };
+
+        let mut domain = domain.to_lowercase();
+        let mut account = match self.fold_case {
+            true => account.to_lowercase(),
+            false => account.to_string(),
+        };
+
+        if self.ignore_plus_tags {
+            if let Some((untagged, _)) = account.split_once('+') {
+                account = untagged.to_string();
+            }
+        }
+
+        if self.ignore_gmail_dots && GMAIL_DOMAINS.contains(&domain.as_str()) {
+            account.retain(|char| char != '.');
+            domain = GMAIL_DOMAINS[0].to_string();
+        }
+
+        format!("{account}@{domain}")
+    }
+
+    /// Get the key used to compare the supplied contact with
+    /// others, interpreting phone numbers without a country code
+    /// relative to the supplied default region
+    pub fn contact_key(
+        &self,
+        contact: &VerificationRequest,
+        default_region: Option<&str>,
+    ) -> ContactKey {
+        ContactKey {
+            email: contact.email.as_deref().map(|email| self.email_key(email)),
+            phone: contact
+                .phone
+                .as_deref()
+                .map(|phone| _phone_key(phone, default_region)),
+            address: contact.address.as_ref().map(_address_key),
+        }
+    }
+
+    /// Get the key of the contact the supplied bulk verification
+    /// result was produced for, interpreting phone numbers without
+    /// a country code relative to the supplied default region
+    pub fn result_key(
+        &self,
+        result: &BulkVerificationResult,
+        default_region: Option<&str>,
+    ) -> ContactKey {
+        match result {
+            BulkVerificationResult::Email(email) => ContactKey {
+                email: Some(self.email_key(&email.email)),
+                ..ContactKey::default()
+            },
+            BulkVerificationResult::Contact(contact) => ContactKey {
+                email: contact
+                    .email
+                    .as_ref()
+                    .map(|email| self.email_key(&email.email)),
+                phone: contact
+                    .phone
+                    .as_ref()
+                    .map(|phone| _phone_key(&phone.phone, default_region)),
+                address: contact.address.as_ref().map(|address| {
+                    _address_key(&types::StreetAddressArray::from_values(
+                        address.address1.clone(),
+                        address.address2.clone(),
+                        address.city.clone(),
+                        address.state.clone(),
+                        address.zip.clone(),
+                    ))
+                }),
+            },
+        }
+    }
+}
+
+// </editor-fold desc="// DedupePolicy ...">
+
+// <editor-fold desc="// ContactKey ...">
+
+/// The normalized values two contacts are compared by
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct ContactKey {
+    /// The contact's email address, per the [`DedupePolicy`](DedupePolicy)
+    pub email: Option<String>,
+    /// The contact's phone number, in E.164 format (or its
+    /// digits, if it can't be normalized)
+    pub phone: Option<String>,
+    /// The contact's street address, normalized (or folded,
+    /// if it can't be normalized) and joined with `"|"`
+    pub address: Option<String>,
+}
+
+/// [internal-implementation]
+/// An index of the keys of the contacts uploaded to a list,
+/// used to find the contacts their results were produced for
+pub(crate) struct KeyIndex<'keys> {
+    exact: HashSet<&'keys ContactKey>,
+    partial: HashMap<(Option<&'keys str>, Option<&'keys str>), Option<&'keys ContactKey>>,
+}
+
+impl<'keys> KeyIndex<'keys> {
+    /// [internal-implementation]
+    /// Index the supplied keys
+    pub(crate) fn _new(keys: impl IntoIterator<Item = &'keys ContactKey>) -> Self {
+        let mut index = Self {
+            exact: HashSet::new(),
+            partial: HashMap::new(),
+        };
+
+        for key in keys {
+            index.exact.insert(key);
+            index
+                .partial
+                .entry((key.email.as_deref(), key.phone.as_deref()))
+                .and_modify(|entry| *entry = None)
+                .or_insert(Some(key));
+        }
+
+        index
+    }
+
+    /// [internal-implementation]
+    /// Find the indexed key matching the supplied (result) key
+    ///
+    /// Street addresses may have been corrected by the API,
+    /// so keys are matched on their email address and phone
+    /// number alone if they don't match exactly, as long as
+    /// that's unambiguous
+    pub(crate) fn _resolve(&self, key: &ContactKey) -> Option<&'keys ContactKey> {
+        self.exact.get(key).copied().or_else(|| {
+            self.partial
+                .get(&(key.email.as_deref(), key.phone.as_deref()))
+                .copied()
+                .flatten()
+        })
+    }
+}
+
+// </editor-fold desc="// ContactKey ...">
+
+// <editor-fold desc="// DedupedContacts ...">
+
+/// A collection of contacts with any duplicates removed
+#[derive(Clone, Debug, Default)]
+pub struct DedupedContacts {
+    /// The distinct contacts, in the order they first appeared
+    pub contacts: Vec<VerificationRequest>,
+    groups: Vec<(ContactKey, Vec<VerificationRequest>)>,
+}
+
+impl DedupedContacts {
+    /// The number of contacts that were removed
+    /// as duplicates of other contacts
+    pub fn removed(&self) -> usize {
+        self.groups
+            .iter()
+            .map(|(_, originals)| originals.len())
+            .sum::<usize>()
+            - self.contacts.len()
+    }
+}
+
+// </editor-fold desc="// DedupedContacts ...">
+
+// <editor-fold desc="// Deduplicator ...">
+
+/// Every contact supplied for a single list, grouped by key
+type ListGroups = HashMap<ContactKey, Vec<VerificationRequest>>;
+
+/// Removes duplicate contacts from bulk verification lists,
+/// and fans their results back out to every duplicate
+#[derive(Debug)]
+pub struct Deduplicator {
+    policy: DedupePolicy,
+    default_region: Option<String>,
+    lists: Mutex<HashMap<String, ListGroups>>,
+}
+
+impl Deduplicator {
+    /// Create a new `Deduplicator` using the supplied policy,
+    /// interpreting phone numbers without a country code relative
+    /// to the supplied default region
+    pub fn new(policy: DedupePolicy, default_region: Option<String>) -> Self {
+        Self {
+            policy,
+            default_region,
+            lists: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// The policy used to compare email addresses
+    pub fn policy(&self) -> &DedupePolicy {
+        &self.policy
+    }
+
+    /// Remove any duplicates from the supplied contacts, as
+    /// well as any contacts that duplicate ones already in
+    /// the specified list (if any)
+    pub fn dedupe<Contacts: IntoIterator<Item = VerificationRequest>>(
+        &self,
+        list_id: Option<&str>,
+        contacts: Contacts,
+    ) -> DedupedContacts {
+        let known: HashSet<ContactKey> = list_id
+            .and_then(|id| {
+                self._lists()
+                    .get(id)
+                    .map(|groups| groups.keys().cloned().collect())
+            })
+            .unwrap_or_default();
+
+        let mut deduped = DedupedContacts::default();
+        let mut positions: HashMap<ContactKey, usize> = HashMap::new();
+
+        for contact in contacts {
+            let key = self
+                .policy
+                .contact_key(&contact, self.default_region.as_deref());
+
+            match positions.get(&key) {
+                Some(idx) => deduped.groups[*idx].1.push(contact),
+                None => {
+                    if !known.contains(&key) {
+                        deduped.contacts.push(contact.clone());
+                    }
+
+                    positions.insert(key.clone(), deduped.groups.len());
+                    deduped.groups.push((key, vec![contact]));
+                }
+            }
+        }
+
+        deduped
+    }
+
+    /// Fan the supplied results of the specified list back out to
+    /// every original duplicate of the contacts they were produced for
+    pub fn fan_out(
+        &self,
+        list_id: &str,
+        results: Vec<BulkVerificationResult>,
+    ) -> Vec<BulkVerificationResult> {
+        let lists = self._lists();
+
+        let Some(groups) = lists.get(list_id) else {
+            return results;
+        };
+
+        let index = KeyIndex::_new(groups.keys());
+
+        results
+            .into_iter()
+            .flat_map(|result| {
+                let key = self
+                    .policy
+                    .result_key(&result, self.default_region.as_deref());
+                let originals = index._resolve(&key).and_then(|key| groups.get(key));
+
+                match originals {
+                    None => vec![result],
+                    Some(originals) => originals
+                        .iter()
+                        .map(|original| _with_original_values(&result, original))
+                        .collect(),
+                }
+            })
+            .collect()
+    }
+
+    /// Forget the duplicates recorded for the specified list
+    pub fn forget(&self, list_id: &str) {
+        self._lists().remove(list_id);
+    }
+
+    /// The number of lists whose duplicates are
+    /// currently being remembered
+    pub fn len(&self) -> usize {
+        self._lists().len()
+    }
+
+    /// Whether or not the duplicates of any
+    /// lists are currently being remembered
+    pub fn is_empty(&self) -> bool {
+        self._lists().is_empty()
+    }
+
+    // <editor-fold desc="// Internal Utility Methods ... ">
+
+    /// [internal-implementation]
+    /// Lock the deduplicator's per-list records
+    fn _lists(&self) -> MutexGuard<'_, HashMap<String, ListGroups>> {
+        self.lists
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    /// [internal-implementation]
+    /// Record the duplicates of the contacts
+    /// uploaded to the specified list
+    pub(crate) fn _record(&self, list_id: &str, deduped: DedupedContacts) {
+        let mut lists = self._lists();
+        let groups = lists.entry(list_id.to_string()).or_default();
+
+        for (key, originals) in deduped.groups {
+            groups.entry(key).or_default().extend(originals);
+        }
+    }
+
+    /// [internal-implementation]
+    /// Forget the duplicates recorded for the supplied
+    /// lists if their results can no longer be exported
+    pub(crate) fn _prune<'list>(
+        &self,
+        lists: impl IntoIterator<Item = &'list VerificationListState>,
+    ) {
+        let mut recorded = self._lists();
+
+        lists
+            .into_iter()
+            .filter(|list| GONE_STATES.contains(&list.state))
+            .for_each(|list| {
+                recorded.remove(&list.id);
+            });
+    }
+
+    // </editor-fold desc="// Internal Utility Methods ... ">
+}
+
+// </editor-fold desc="// Deduplicator ...">
+
+// <editor-fold desc="// Internal Functions ...">
+
+/// [internal-implementation]
+/// Get the form of the supplied phone
+/// number used to compare it with others
+fn _phone_key(phone: &str, default_region: Option<&str>) -> String {
+    match PhoneNumber::parse(phone, default_region) {
+        Ok(number) => number.e164(),
+        Err(_) => phone.chars().filter(char::is_ascii_digit).collect(),
+    }
+}
+
+/// [internal-implementation]
+/// Get the form of the supplied street
+/// address used to compare it with others
+fn _address_key(value: &types::StreetAddressArray) -> String {
+    let normalized = value.normalized().unwrap_or_else(|_| {
+        types::StreetAddressArray::from_values(
+            address::fold(&value.address1),
+            value.address2.as_deref().map(address::fold),
+            address::fold(&value.city),
+            address::fold(&value.state),
+            address::fold(&value.zip),
+        )
+    });
+
+    [
+        normalized.address1.as_str(),
+        normalized.address2.as_deref().unwrap_or_default(),
+        normalized.city.as_str(),
+        normalized.state.as_str(),
+        normalized.zip.as_str(),
+    ]
+    .join("|")
+}
+
+/// [internal-implementation]
+/// Copy the supplied result, replacing the email address and
+/// phone number it reports with those of the supplied contact
+fn _with_original_values(
+    result: &BulkVerificationResult,
+    original: &VerificationRequest,
+) -> BulkVerificationResult {
+    let mut result = result.clone();
+
+    match &mut result {
+        BulkVerificationResult::Email(email) => {
+            if let Some(value) = original.email.as_ref() {
+                email.email = value.clone();
+            }
+        }
+        BulkVerificationResult::Contact(contact) => {
+            if let (Some(email), Some(value)) = (contact.email.as_mut(), original.email.as_ref()) {
+                email.email = value.clone();
+            }
+
+            if let (Some(phone), Some(value)) = (contact.phone.as_mut(), original.phone.as_ref()) {
+                phone.phone = value.clone();
+            }
+        }
+    }
+
+    result
+}
+
+// </editor-fold desc="// Internal Functions ...">
+
+// <editor-fold desc="// I/O-Free Tests ...">
+
+#[cfg(test)]
+mod tests {
+    // Third-Party Dependencies
+    use pretty_assertions::assert_eq;
+
+    // Crate-Level Imports
+    use super::{DedupePolicy, Deduplicator};
+    use crate::types::{
+        bulk::BulkEmailVerificationArray, BatchState, BulkContactVerificationResult,
+        BulkVerificationResult, VerificationListState, VerificationRequest, VerificationStatus,
+    };
+
+    /// Test that email addresses are compared
+    /// according to the configured policy
+    #[rstest::rstest]
+    #[case(
+        DedupePolicy::new(),
+        "John.Doe+News@GMAIL.com",
+        "john.doe+news@gmail.com"
+    )]
+    #[case(
+        DedupePolicy::new().fold_case(false),
+        "John.Doe+News@GMAIL.com",
+        "John.Doe+News@gmail.com"
+    )]
+    #[case(
+        DedupePolicy::new().ignore_plus_tags(true),
+        "John.Doe+News@GMAIL.com",
+        "john.doe@gmail.com"
+    )]
+    #[case(
+        DedupePolicy::new().ignore_gmail_dots(true).ignore_plus_tags(true),
+        "John.Doe+News@googlemail.com",
+        "johndoe@gmail.com"
+    )]
+    #[case(
+        DedupePolicy::new().ignore_gmail_dots(true),
+        "john.doe@example.com",
+        "john.doe@example.com"
+    )]
+    fn test_email_key(#[case] policy: DedupePolicy, #[case] email: &str, #[case] expected: &str) {
+        assert_eq!(expected, policy.email_key(email));
+    }
+
+    /// Test that duplicate contacts are removed, including
+    /// those that duplicate contacts already in a list
+    #[rstest::rstest]
+    fn test_dedupe() {
+        let dedupe = Deduplicator::new(DedupePolicy::new(), Some("US".to_string()));
+        let contact = |email: &str, phone: &str| VerificationRequest {
+            email: Some(email.to_string()),
+            phone: Some(phone.to_string()),
+            ..VerificationRequest::default()
+        };
+
+        let deduped = dedupe.dedupe(
+            None,
+            [
+                contact("test@example.com", "(954) 555-1234"),
+                contact("TEST@example.com", "+1 954-555-1234 ext. 12"),
+                contact("test@example.com", "954-555-9999"),
+            ],
+        );
+
+        assert_eq!(2, deduped.contacts.len());
+        assert_eq!(1, deduped.removed());
+
+        dedupe._record("some-list-id", deduped);
+
+        let appended = dedupe.dedupe(
+            Some("some-list-id"),
+            [
+                contact("Test@Example.com", "9545551234"),
+                contact("other@example.com", "9545551234"),
+            ],
+        );
+
+        assert_eq!(
+            vec![Some("other@example.com")],
+            appended
+                .contacts
+                .iter()
+                .map(|contact| contact.email.as_deref())
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(1, appended.removed());
+    }
+
+    /// Test that results are fanned back out
+    /// to every original duplicate
+    #[rstest::rstest]
+    fn test_fan_out() {
+        let dedupe = Deduplicator::new(
+            DedupePolicy::new().ignore_gmail_dots(true),
+            Option::<String>::None,
+        );
+        let emails = [
+            "john.doe@gmail.com",
+            "JohnDoe@Gmail.com",
+            "jane@example.com",
+        ];
+
+        let deduped = dedupe.dedupe(
+            None,
+            emails.map(|email| VerificationRequest::try_from(email).unwrap()),
+        );
+        dedupe._record("some-list-id", deduped);
+
+        let result = |email: &str| {
+            BulkVerificationResult::Contact(BulkContactVerificationResult {
+                email: Some(BulkEmailVerificationArray {
+                    email: email.to_string(),
+                    status: VerificationStatus::Valid,
+                    secondary_status: None,
+                }),
+                phone: None,
+                address: None,
+            })
+        };
+
+        let fanned = dedupe.fan_out(
+            "some-list-id",
+            vec![
+                result("john.doe@gmail.com"),
+                result("jane@example.com"),
+                result("unknown@example.com"),
+            ],
+        );
+
+        assert_eq!(
+            vec![
+                result(emails[0]),
+                result(emails[1]),
+                result(emails[2]),
+                result("unknown@example.com"),
+            ],
+            fanned
+        );
+
+        dedupe.forget("some-list-id");
+
+        assert_eq!(
+            1,
+            dedupe
+                .fan_out("some-list-id", vec![result("john.doe@gmail.com")])
+                .len()
+        );
+    }
+
+    /// Test that the duplicates of lists whose
+    /// results can no longer be exported are forgotten
+    #[rstest::rstest]
+    fn test_prune() {
+        let dedupe = Deduplicator::new(DedupePolicy::new(), Option::<String>::None);
+        let list = |id: &str, state: BatchState| VerificationListState {
+            id: id.to_string(),
+            state,
+            ..VerificationListState::default()
+        };
+
+        for id in ["open", "complete", "expired", "deleted"] {
+            dedupe._record(
+                id,
+                dedupe.dedupe(
+                    None,
+                    [VerificationRequest::try_from("test@example.com").unwrap()],
+                ),
+            );
+        }
+
+        dedupe._prune(&[
+            list("open", BatchState::Open),
+            list("complete", BatchState::Complete),
+            list("expired", BatchState::Expired),
+            list("deleted", BatchState::Deleted),
+        ]);
+
+        assert_eq!(2, dedupe.len());
+    }
+}
+
+// </editor-fold desc="// I/O-Free Tests ...">
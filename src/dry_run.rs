@@ -0,0 +1,535 @@
+//! ## Dry-Run Request Validation & Logging
+//!
+//! A [`BriteVerifyClient`](crate::BriteVerifyClient) built with
+//! [`dry_run(true)`](crate::BriteVerifyClientBuilder::dry_run)
+//! builds every request exactly as it otherwise would, but never
+//! sends any of them. Instead, each request is:
+//!
+//! - validated (every contact must have at least one complete value
+//!   to verify, bulk requests must respect the BriteVerify API's
+//!   per-request and per-list size limits, and list ids must be
+//!   well-formed), with invalid requests rejected via
+//!   [`InvalidDryRunRequest`](crate::errors::BriteVerifyClientError::InvalidDryRunRequest)
+//! - recorded, along with an estimate of the number of credits it
+//!   would have cost, to the client's [`DryRun`](DryRun) log
+//! - answered with a synthetic response in which every verified
+//!   value's status is `unknown`, and whose
+//!   [metadata](crate::types::ResponseMetadata::is_dry_run) marks
+//!   it as a dry-run response
+//!
+//! Bulk verification lists created during a dry run behave the
+//! way real lists do (they can be polled, will "complete", and
+//! will have results), but lists that weren't created during
+//! the dry run are reported as not found.
+//!
+//! ## Basic Usage
+//! ```no_run
+//! # use briteverify_rs::{BriteVerifyClient, types::VerificationStatus};
+//! #
+//! # async fn doc() -> anyhow::Result<()> {
+//! let client: BriteVerifyClient = BriteVerifyClient::builder()
+//!     .api_key("YOUR API KEY")
+//!     .dry_run(true)
+//!     .build()?;
+//!
+//! let response = client.verify_email("test@example.com").await?;
+//!
+//! assert_eq!(response.status, VerificationStatus::Unknown);
+//!
+//! let log = client.dry_run().unwrap();
+//!
+//! assert_eq!(log.len(), 1);
+//! assert_eq!(log.estimated_credits(), 1);
+//! # Ok(())
+//! # }
+//! ```
+//
+// Standard Library Imports
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex, MutexGuard},
+};
+
+// Third-Party Imports
+use http::{HeaderValue, Method};
+use serde_json::Value;
+
+// Crate-Level Imports
+use crate::{
+    errors::BriteVerifyClientError,
+    sansio::{HttpRequest, HttpResponse},
+    simulator::BriteVerifySimulator,
+    types,
+};
+
+// <editor-fold desc="// Constants ...">
+
+/// The name of the header included in
+/// every synthetic dry-run response
+pub(crate) const DRY_RUN_HEADER: &str = "x-briteverify-dry-run";
+
+/// The maximum number of contacts the BriteVerify
+/// API accepts in a single bulk list request
+pub const MAX_CONTACTS_PER_REQUEST: usize = 100_000;
+
+/// The maximum number of contacts the BriteVerify
+/// API accepts in a single bulk verification list
+pub const MAX_CONTACTS_PER_LIST: usize = 1_000_000;
+
+// </editor-fold desc="// Constants ...">
+
+// <editor-fold desc="// DryRunRequest ...">
+
+/// A request recorded (instead of sent) by a dry-run client
+#[derive(Clone, Debug)]
+pub struct DryRunRequest {
+    /// The request's HTTP method
+    pub method: Method,
+    /// The URL the request would have been sent to
+    pub url: url::Url,
+    /// The request's (JSON) body, if it had one
+    pub body: Option<Value>,
+    /// The number of credits the request would
+    /// (at most) have cost if it had been sent
+    pub estimated_credits: u64,
+    /// Why the request was rejected, if it
+    /// failed validation
+    pub rejection: Option<String>,
+}
+
+impl DryRunRequest {
+    /// Check whether the request passed validation
+    pub fn is_valid(&self) -> bool {
+        self.rejection.is_none()
+    }
+}
+
+// </editor-fold desc="// DryRunRequest ...">
+
+// <editor-fold desc="// DryRun ...">
+
+/// [internal-implementation]
+/// The mutable state of a dry run
+#[derive(Debug, Default)]
+struct DryRunState {
+    requests: Vec<DryRunRequest>,
+    list_sizes: HashMap<String, usize>,
+}
+
+/// The inspectable log of every request
+/// made by a dry-run client
+#[derive(Debug)]
+pub struct DryRun {
+    state: Mutex<DryRunState>,
+    simulator: BriteVerifySimulator,
+}
+
+impl DryRun {
+    /// [internal-implementation]
+    /// Create a new, empty dry-run log
+    pub(crate) fn _new() -> Self {
+        Self {
+            state: Mutex::new(DryRunState::default()),
+            simulator: BriteVerifySimulator::_unauthenticated(Arc::new(Self::_unknown_response)),
+        }
+    }
+
+    /// Every request recorded so far (including
+    /// those that failed validation), in order
+    pub fn requests(&self) -> Vec<DryRunRequest> {
+        self._state().requests.clone()
+    }
+
+    /// The number of requests recorded so far
+    pub fn len(&self) -> usize {
+        self._state().requests.len()
+    }
+
+    /// Check whether any requests have been recorded
+    pub fn is_empty(&self) -> bool {
+        self._state().requests.is_empty()
+    }
+
+    /// The total number of credits the valid requests
+    /// recorded so far would (at most) have cost
+    pub fn estimated_credits(&self) -> u64 {
+        self._state()
+            .requests
+            .iter()
+            .filter(|request| request.is_valid())
+            .map(|request| request.estimated_credits)
+            .sum()
+    }
+
+    /// Discard every request recorded so far
+    pub fn clear(&self) {
+        self._state().requests.clear();
+    }
+
+    // <editor-fold desc="// Internal Utility Methods ... ">
+
+    /// [internal-implementation]
+    /// Lock the dry run's mutable state
+    fn _state(&self) -> MutexGuard<'_, DryRunState> {
+        self.state
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    /// [internal-implementation]
+    /// Build a response in which every verified
+    /// value's status is `unknown`
+    fn _unknown_response(request: &types::VerificationRequest) -> types::VerificationResponse {
+        let mut response = crate::InMemoryBriteVerifyApi::valid_response(request);
+
+        if let Some(email) = response.email.as_mut() {
+            email.status = types::VerificationStatus::Unknown;
+        }
+
+        if let Some(phone) = response.phone.as_mut() {
+            phone.status = types::VerificationStatus::Unknown;
+        }
+
+        if let Some(address) = response.address.as_mut() {
+            address.status = types::VerificationStatus::Unknown;
+        }
+
+        response
+    }
+
+    /// [internal-implementation]
+    /// Validate, record, and answer the supplied request
+    pub(crate) fn _handle(
+        &self,
+        request: HttpRequest,
+    ) -> Result<(HttpResponse, types::ResponseMetadata), BriteVerifyClientError> {
+        let uri = request.uri().clone();
+        let method = request.method().clone();
+        let body = serde_json::from_slice::<Value>(request.body()).ok();
+
+        let segments: Vec<String> = uri
+            .path()
+            .split('/')
+            .filter(|segment| !segment.is_empty())
+            .map(str::to_string)
+            .collect();
+
+        let verdict = self._validate(&method, &segments, request.body());
+        let (estimated_credits, rejection) = match &verdict {
+            Ok(cost) => (*cost, None),
+            Err(reason) => (0, Some(reason.clone())),
+        };
+
+        self._state().requests.push(DryRunRequest {
+            method: method.clone(),
+            url: url::Url::parse(&uri.to_string())?,
+            body,
+            estimated_credits,
+            rejection,
+        });
+
+        if let Err(reason) = verdict {
+            return Err(BriteVerifyClientError::InvalidDryRunRequest(reason));
+        }
+
+        let mut response = self.simulator.handle(request);
+
+        response
+            .headers_mut()
+            .insert(DRY_RUN_HEADER, HeaderValue::from_static("true"));
+
+        self._track(&method, &segments, &response);
+
+        let meta = types::ResponseMetadata::from_local(&uri, &response)?;
+
+        Ok((response, meta))
+    }
+
+    /// [internal-implementation]
+    /// Validate the supplied request, returning the number
+    /// of credits it would (at most) cost if it's valid, or
+    /// the reason it's invalid otherwise
+    fn _validate(&self, method: &Method, segments: &[String], body: &[u8]) -> Result<u64, String> {
+        let segments: Vec<&str> = segments.iter().map(String::as_str).collect();
+
+        let Some(start) = segments
+            .windows(2)
+            .position(|pair| pair[0] == "api" && matches!(pair[1], "v1" | "v3"))
+        else {
+            return Err("Unknown endpoint".to_string());
+        };
+
+        match (method, &segments[start + 2..]) {
+            (&Method::POST, ["fullverify"]) => {
+                let request = serde_json::from_slice::<types::VerificationRequest>(body)
+                    .map_err(|error| format!("Unparseable verification request: {error}"))?;
+
+                Self::_validate_contact(&request)
+            }
+            (&Method::POST, ["lists"] | ["accounts", _, "lists"]) => {
+                self._validate_list_request(None, body)
+            }
+            (&Method::POST, ["lists", list_id]) => {
+                Self::_validate_list_id(list_id)?;
+                self._validate_list_request(Some(list_id), body)
+            }
+            (_, ["lists", list_id, ..] | ["accounts", _, "lists", list_id, ..]) => {
+                Self::_validate_list_id(list_id).map(|_| 0)
+            }
+            _ => Ok(0),
+        }
+    }
+
+    /// [internal-implementation]
+    /// Validate a bulk list creation / update request
+    fn _validate_list_request(&self, list_id: Option<&str>, body: &[u8]) -> Result<u64, String> {
+        if body.is_empty() {
+            return Ok(0);
+        }
+
+        let request = serde_json::from_slice::<types::BulkVerificationRequest>(body)
+            .map_err(|error| format!("Unparseable bulk verification request: {error}"))?;
+
+        if request.contacts.len() > MAX_CONTACTS_PER_REQUEST {
+            return Err(format!(
+                "Request exceeds the limit of {MAX_CONTACTS_PER_REQUEST} entries in a single request ({} supplied)",
+                request.contacts.len()
+            ));
+        }
+
+        let existing = list_id
+            .and_then(|id| self._state().list_sizes.get(id).copied())
+            .unwrap_or_default();
+
+        if existing + request.contacts.len() > MAX_CONTACTS_PER_LIST {
+            return Err(format!(
+                "List would exceed the limit of {MAX_CONTACTS_PER_LIST} entries in a single list ({} total)",
+                existing + request.contacts.len()
+            ));
+        }
+
+        request
+            .contacts
+            .iter()
+            .enumerate()
+            .map(|(idx, contact)| {
+                Self::_validate_contact(contact)
+                    .map_err(|reason| format!("Contact #{idx}: {reason}"))
+            })
+            .sum()
+    }
+
+    /// [internal-implementation]
+    /// Validate a single contact, returning the number
+    /// of credits its verification would cost
+    fn _validate_contact(contact: &types::VerificationRequest) -> Result<u64, String> {
+        let blank = |value: &str| value.trim().is_empty();
+        let mut cost = 0u64;
+
+        if let Some(email) = contact.email.as_deref() {
+            if blank(email) {
+                return Err("Email address is blank".to_string());
+            }
+
+            cost += 1;
+        }
+
+        if let Some(phone) = contact.phone.as_deref() {
+            if blank(phone) {
+                return Err("Phone number is blank".to_string());
+            }
+
+            cost += 1;
+        }
+
+        if let Some(address) = contact.address.as_ref() {
+            let missing: Vec<&str> = [
+                ("address1", &address.address1),
+                ("city", &address.city),
+                ("state", &address.state),
+                ("zip", &address.zip),
+            ]
+            .into_iter()
+            .filter(|(_, value)| blank(value))
+            .map(|(field, _)| field)
+            .collect();
+
+            if !missing.is_empty() {
+                return Err(format!(
+                    "Street address is missing required fields: {}",
+                    missing.join(", ")
+                ));
+            }
+
+            cost += 1;
+        }
+
+        match cost {
+            0 => Err("No email address, phone number, or street address to verify".to_string()),
+            _ => Ok(cost),
+        }
+    }
+
+    /// [internal-implementation]
+    /// Validate the format of a bulk list id
+    /// (i.e. a hyphenated UUID)
+    fn _validate_list_id(list_id: &str) -> Result<(), String> {
+        let groups: Vec<&str> = list_id.split('-').collect();
+        let well_formed = groups.len() == 5
+            && groups.iter().zip([8, 4, 4, 4, 12]).all(|(group, len)| {
+                group.len() == len && group.chars().all(|char| char.is_ascii_hexdigit())
+            });
+
+        match well_formed {
+            true => Ok(()),
+            false => Err(format!("Malformed list id: {list_id:?}")),
+        }
+    }
+
+    /// [internal-implementation]
+    /// Keep track of the number of contacts
+    /// in lists created or updated successfully
+    fn _track(&self, method: &Method, segments: &[String], response: &HttpResponse) {
+        let is_list_upload = method == Method::POST
+            && segments
+                .iter()
+                .rev()
+                .take(2)
+                .any(|segment| segment == "lists");
+
+        if !is_list_upload || !response.status().is_success() {
+            return;
+        }
+
+        let Some(list) = serde_json::from_slice::<Value>(response.body())
+            .ok()
+            .and_then(|body| body.get("list").cloned())
+        else {
+            return;
+        };
+
+        let Some(id) = list.get("id").and_then(Value::as_str) else {
+            return;
+        };
+
+        let contacts = self
+            ._state()
+            .requests
+            .last()
+            .and_then(|request| request.body.as_ref())
+            .and_then(|body| body.get("contacts"))
+            .and_then(Value::as_array)
+            .map(Vec::len)
+            .unwrap_or_default();
+
+        *self._state().list_sizes.entry(id.to_string()).or_default() += contacts;
+    }
+
+    // </editor-fold desc="// Internal Utility Methods ... ">
+}
+
+// </editor-fold desc="// DryRun ...">
+
+// <editor-fold desc="// I/O-Free Tests ...">
+
+#[cfg(test)]
+mod tests {
+    // Third-Party Dependencies
+    use pretty_assertions::assert_eq;
+
+    // Crate-Level Imports
+    use super::DryRun;
+    use crate::{sansio::BriteVerifyCore, types};
+
+    /// Test that dry runs estimate the cost of, and
+    /// answer, valid single-transaction requests
+    #[rstest::rstest]
+    fn test_dry_run_single_verification() {
+        let dry_run = DryRun::_new();
+        let core = BriteVerifyCore::new("dry run").unwrap();
+        let contact = types::VerificationRequest::builder()
+            .email("test@example.com")
+            .phone("+1 (954) 555-1234")
+            .build()
+            .unwrap();
+
+        let (response, meta) = dry_run
+            ._handle(core.verification_request(&contact).unwrap())
+            .unwrap();
+
+        assert!(meta.is_dry_run());
+        assert_eq!(
+            types::VerificationStatus::Unknown,
+            core.parse_verification(response)
+                .unwrap()
+                .email
+                .unwrap()
+                .status
+        );
+        assert_eq!(2, dry_run.estimated_credits());
+
+        let blank = types::VerificationRequest {
+            email: Some(" ".to_string()),
+            ..types::VerificationRequest::default()
+        };
+
+        assert!(dry_run
+            ._handle(core.verification_request(&blank).unwrap())
+            .is_err());
+        assert_eq!(2, dry_run.len());
+        assert_eq!(2, dry_run.estimated_credits());
+        assert!(!dry_run.requests()[1].is_valid());
+    }
+
+    /// Test that dry runs enforce bulk
+    /// size limits and list id formats
+    #[rstest::rstest]
+    fn test_dry_run_bulk_validation() {
+        let dry_run = DryRun::_new();
+        let core = BriteVerifyCore::new("dry run").unwrap();
+        let contacts = |count: usize| {
+            types::BulkVerificationRequest::new(
+                vec![types::VerificationRequest::try_from("test@example.com").unwrap(); count],
+                Option::<&str>::None,
+            )
+        };
+
+        assert!(dry_run
+            ._handle(
+                core.create_or_update_list_request(
+                    Option::<&str>::None,
+                    &contacts(super::MAX_CONTACTS_PER_REQUEST + 1)
+                )
+                .unwrap()
+            )
+            .is_err());
+
+        let (response, _) = dry_run
+            ._handle(
+                core.create_or_update_list_request(Option::<&str>::None, &contacts(3))
+                    .unwrap(),
+            )
+            .unwrap();
+        let list = core
+            .parse_create_or_update_list(response, Option::<&str>::None)
+            .unwrap()
+            .list;
+
+        assert_eq!(3, dry_run.estimated_credits());
+        assert_eq!(Some(&3), dry_run._state().list_sizes.get(&list.id));
+        assert!(dry_run
+            ._handle(
+                core.get_list_request("not-a-list-id", Option::<&str>::None)
+                    .unwrap()
+            )
+            .is_err());
+        assert!(dry_run
+            ._handle(
+                core.get_list_request(&list.id, Option::<&str>::None)
+                    .unwrap()
+            )
+            .is_ok());
+    }
+}
+
+// </editor-fold desc="// I/O-Free Tests ...">
@@ -54,11 +54,149 @@ pub enum BriteVerifyClientError {
     /// (based on HTTP status code)
     #[error("Unusable (non-2xx) response")]
     UnusableResponse(Box<reqwest::Response>),
+    /// The BriteVerify API returned a response whose
+    /// body could not be deserialized as expected
+    #[error("Response body cannot be parsed")]
+    UnparseableResponse(#[from] serde_json::Error),
+    /// A client replaying a [`Cassette`][crate::cassette::Cassette]
+    /// was asked to send a request that has no matching
+    /// recorded response
+    #[error("No recorded response matches request: {0}")]
+    UnmatchedCassetteRequest(String),
+    /// A client running in [dry-run](crate::dry_run) mode
+    /// was asked to send a request that failed validation
+    #[error("Dry-run request failed validation: {0}")]
+    InvalidDryRunRequest(String),
+    /// A bulk verification list includes email addresses
+    /// that failed local syntax validation (and would be
+    /// reported by the BriteVerify API as `invalid_format`)
+    #[error("Contacts include {} malformed email address(es): {}", .0.len(), _preview(.0))]
+    MalformedEmailAddresses(Vec<String>),
+    /// A bulk verification list includes phone numbers
+    /// that could not be normalized to E.164 form
+    #[error("Contacts include {} malformed phone number(s): {}", .0.len(), _preview(.0))]
+    MalformedPhoneNumbers(Vec<String>),
+    /// A bulk verification list includes street
+    /// addresses that could not be normalized
+    #[error("Contacts include {} malformed street address(es): {}", .0.len(), _preview(.0))]
+    MalformedAddresses(Vec<String>),
+    /// The (estimated) cost of a request exceeds the account's
+    /// live credit balance (less the [budget](crate::budget)'s
+    /// minimum balance)
+    #[error("Request would cost {required} credit(s), but only {available} are available")]
+    InsufficientCredits {
+        /// The (estimated) cost of the request
+        required: u64,
+        /// The number of credits available to spend
+        available: u64,
+    },
+    /// The (estimated) cost of a request exceeds what remains
+    /// of the [budget](crate::budget)'s spend cap
+    #[error(
+        "Request would cost {required} credit(s), but only {remaining} remain within the spend cap"
+    )]
+    SpendCapExceeded {
+        /// The (estimated) cost of the request
+        required: u64,
+        /// The number of credits remaining within the spend cap
+        remaining: u64,
+    },
+    /// An [idempotency](crate::idempotency) token was
+    /// reused to create a list with different contents
+    #[error("Idempotency token {0:?} was already used for a list with different contents")]
+    ReusedIdempotencyToken(String),
+    /// A list is already being created for an
+    /// [idempotency](crate::idempotency) token
+    #[error("A list is already being created for idempotency token {0:?}")]
+    IdempotentRequestInProgress(String),
+    /// Creating a keyed bulk verification [job](crate::jobs)
+    /// failed after some of its lists were already created
+    #[error("Bulk verification job failed after creating {} list(s): {source}", .list_ids.len())]
+    IncompleteBulkJob {
+        /// The ids of the lists that were created (including
+        /// any that were only partially uploaded), in order
+        list_ids: Vec<String>,
+        /// The error that interrupted the job's creation
+        #[source]
+        source: Box<BriteVerifyClientError>,
+    },
     /// A catch-all error for any other errors encountered
     #[error(transparent)]
     Other(#[from] anyhow::Error),
 }
 
+impl BriteVerifyClientError {
+    /// [internal-implementation]
+    /// Create an equivalent copy of the error, reproducing errors
+    /// that can't be cloned (e.g. transport or parsing errors) as
+    /// [`Other`](BriteVerifyClientError::Other) errors with the
+    /// same message
+    pub(crate) fn _duplicate(&self) -> Self {
+        match self {
+            Self::MissingApiKey => Self::MissingApiKey,
+            Self::InvalidApiKey => Self::InvalidApiKey,
+            Self::UnclonableRequest => Self::UnclonableRequest,
+            Self::MismatchedVerificationResponse(response) => {
+                Self::MismatchedVerificationResponse(response.clone())
+            }
+            Self::BulkListNotFound(error) => Self::BulkListNotFound(error.clone()),
+            Self::InvalidBaseUrl(error) => Self::InvalidBaseUrl(*error),
+            Self::UnmatchedCassetteRequest(request) => {
+                Self::UnmatchedCassetteRequest(request.clone())
+            }
+            Self::InvalidDryRunRequest(reason) => Self::InvalidDryRunRequest(reason.clone()),
+            Self::MalformedEmailAddresses(values) => Self::MalformedEmailAddresses(values.clone()),
+            Self::MalformedPhoneNumbers(values) => Self::MalformedPhoneNumbers(values.clone()),
+            Self::MalformedAddresses(values) => Self::MalformedAddresses(values.clone()),
+            Self::InsufficientCredits {
+                required,
+                available,
+            } => Self::InsufficientCredits {
+                required: *required,
+                available: *available,
+            },
+            Self::SpendCapExceeded {
+                required,
+                remaining,
+            } => Self::SpendCapExceeded {
+                required: *required,
+                remaining: *remaining,
+            },
+            Self::ReusedIdempotencyToken(token) => Self::ReusedIdempotencyToken(token.clone()),
+            Self::IdempotentRequestInProgress(token) => {
+                Self::IdempotentRequestInProgress(token.clone())
+            }
+            Self::IncompleteBulkJob { list_ids, source } => Self::IncompleteBulkJob {
+                list_ids: list_ids.clone(),
+                source: Box::new(source._duplicate()),
+            },
+            other => Self::Other(anyhow::anyhow!("{other:#}")),
+        }
+    }
+}
+
+/// [internal-implementation]
+/// The number of malformed values included
+/// in the messages of errors that list them
+const PREVIEW_LEN: usize = 3;
+
+/// [internal-implementation]
+/// Render the first few of the supplied values (and
+/// how many were left out) for use in an error message
+fn _preview(values: &[String]) -> String {
+    let shown = values
+        .iter()
+        .take(PREVIEW_LEN)
+        .map(|value| format!("{value:?}"))
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    match values.len().saturating_sub(PREVIEW_LEN) {
+        0 => shown,
+        omitted => format!("{shown} (and {omitted} more)"),
+    }
+}
+
 /// Errors encountered when building a
 /// `BriteVerifyClient`-recognized request
 #[derive(Debug, Error)]
@@ -67,7 +205,11 @@ pub enum BriteVerifyTypeError {
     #[error("Current builder state cannot be used to construct a valid `VerificationRequest`")]
     UnbuildableRequest(Box<VerificationRequestBuilder>),
     /// The builder state is incomplete
-    #[error("Current builder state cannot be used to construct a valid `StreetAddressArray`")]
+    #[error(
+        "Current builder state cannot be used to construct a valid \
+        `StreetAddressArray` (missing: {})",
+        .0.missing_fields().join(", "),
+    )]
     UnbuildableAddressArray(Box<AddressArrayBuilder>),
     /// The value cannot be unambiguously
     /// resolved to a known request type
@@ -77,6 +219,26 @@ pub enum BriteVerifyTypeError {
         .0,
     )]
     AmbiguousTryFromValue(String),
+    /// The value is not a syntactically
+    /// valid email address
+    #[error("Malformed email address {0:?}: {1}")]
+    MalformedEmailAddress(String, &'static str),
+    /// The value cannot be normalized to
+    /// a plausible E.164 phone number
+    #[error("Malformed phone number {0:?}: {1}")]
+    MalformedPhoneNumber(String, &'static str),
+    /// The region is not a supported default
+    /// region for phone number normalization
+    #[error("Unsupported default phone number region: {0:?}")]
+    UnknownPhoneRegion(String),
+    /// The value of the named street address
+    /// field cannot be normalized
+    #[error("Invalid street address {0}: {1:?}")]
+    InvalidAddressField(&'static str, String),
+    /// The value cannot be parsed as
+    /// a free-form US street address
+    #[error("Unparseable street address {0:?}: {1}")]
+    UnparseableAddress(String, &'static str),
     /// A catch-all error for any other errors encountered
     #[error(transparent)]
     Other(#[from] anyhow::Error),
@@ -0,0 +1,481 @@
+//! ## Seedable Fake Data Factories
+//!
+//! Every public request and response type implements
+//! [`Fake`](Fake), which generates realistic (and internally
+//! consistent) instances of it from a [`Faker`](Faker). For
+//! example, a faked [`EmailVerificationArray`](crate::types::EmailVerificationArray)
+//! with an `invalid` status will always carry a matching email-related
+//! error code, and a faked [`VerificationListState`](crate::types::VerificationListState)
+//! in the `complete` state will always have results to export.
+//!
+//! Fakers created via [`Faker::seeded`](Faker::seeded) always
+//! produce the same sequence of values for the same seed, which
+//! makes them suitable for snapshot-style tests.
+//!
+//! ## Basic Usage
+//! ```no_run
+//! # use briteverify_rs::{fake::{Fake, Faker}, types};
+//! #
+//! # fn doc() -> anyhow::Result<()> {
+//! let mut faker = Faker::seeded(42);
+//!
+//! let list: types::VerificationListState = faker.fake();
+//! let results: Vec<types::BulkVerificationResult> = faker.fake_many(10);
+//!
+//! let email = types::EmailVerificationArray::fake_with_status(
+//!     &mut faker,
+//!     types::VerificationStatus::Invalid,
+//! );
+//!
+//! assert!(email.error_code.is_some());
+//! assert_eq!(
+//!     types::AccountCreditBalance::fake_seeded(7).credits,
+//!     types::AccountCreditBalance::fake_seeded(7).credits,
+//! );
+//! # Ok(())
+//! # }
+//! ```
+//
+// Third-Party Imports
+use chrono::{DateTime, TimeZone, Utc};
+use rand::{rngs::StdRng, seq::SliceRandom, Rng, SeedableRng};
+
+// Crate-Level Imports
+use crate::types::VerificationError;
+
+// Re-Exports
+pub use crate::utils::test_utils::{
+    datetime_range, random_datetime_between, within_the_last_few_hours, within_the_last_week,
+};
+
+// <editor-fold desc="// Constants ...">
+
+/// The error codes the BriteVerify API
+/// reports for invalid email addresses
+pub const EMAIL_ERRORS: [VerificationError; 7] = [
+    VerificationError::Disposable,
+    VerificationError::RoleAddress,
+    VerificationError::InvalidFormat,
+    VerificationError::MailboxFullInvalid,
+    VerificationError::EmailDomainInvalid,
+    VerificationError::EmailAccountInvalid,
+    VerificationError::EmailAddressInvalid,
+];
+
+/// The error codes the BriteVerify API
+/// reports for invalid phone numbers
+pub const PHONE_ERRORS: [VerificationError; 4] = [
+    VerificationError::InvalidFormat,
+    VerificationError::InvalidPrefix,
+    VerificationError::BlankPhoneNumber,
+    VerificationError::InvalidPhoneNumber,
+];
+
+/// The error codes the BriteVerify API
+/// reports for invalid street addresses
+pub const ADDRESS_ERRORS: [VerificationError; 14] = [
+    VerificationError::PMBRequired,
+    VerificationError::SuiteInvalid,
+    VerificationError::SuiteMissing,
+    VerificationError::MultipleMatch,
+    VerificationError::UnknownStreet,
+    VerificationError::ZipCodeInvalid,
+    VerificationError::BoxNumberInvalid,
+    VerificationError::BoxNumberMissing,
+    VerificationError::DirectionalsInvalid,
+    VerificationError::StreetNumberInvalid,
+    VerificationError::StreetNumberMissing,
+    VerificationError::SuiteInvalidMissing,
+    VerificationError::MissingMinimumInputs,
+    VerificationError::NonDeliverableAddress,
+];
+
+/// [internal-implementation]
+/// The instant seeded fakers treat as "now"
+/// (2023-06-01T12:00:00Z), so that the timestamps
+/// they generate are reproducible
+const SEEDED_NOW: i64 = 1_685_620_800;
+
+const FIRST_NAMES: [&str; 16] = [
+    "james",
+    "mary",
+    "robert",
+    "patricia",
+    "john",
+    "jennifer",
+    "michael",
+    "linda",
+    "david",
+    "elizabeth",
+    "william",
+    "barbara",
+    "maria",
+    "susan",
+    "carlos",
+    "jessica",
+];
+
+const LAST_NAMES: [&str; 16] = [
+    "smith",
+    "johnson",
+    "williams",
+    "brown",
+    "jones",
+    "garcia",
+    "miller",
+    "davis",
+    "rodriguez",
+    "martinez",
+    "hernandez",
+    "lopez",
+    "wilson",
+    "anderson",
+    "thomas",
+    "taylor",
+];
+
+const DOMAINS: [&str; 8] = [
+    "example.com",
+    "example.net",
+    "example.org",
+    "gmail.com",
+    "yahoo.com",
+    "outlook.com",
+    "icloud.com",
+    "validity.com",
+];
+
+const DISPOSABLE_DOMAINS: [&str; 4] = [
+    "mailinator.com",
+    "guerrillamail.com",
+    "10minutemail.com",
+    "trashmail.com",
+];
+
+const ROLE_ACCOUNTS: [&str; 6] = ["sales", "support", "info", "admin", "billing", "contact"];
+
+const STREET_NAMES: [&str; 12] = [
+    "Main",
+    "Oak",
+    "Pine",
+    "Maple",
+    "Cedar",
+    "Elm",
+    "Washington",
+    "Lake",
+    "Hill",
+    "Park",
+    "Boy Scout",
+    "Sunset",
+];
+
+const STREET_SUFFIXES: [&str; 6] = ["St", "Ave", "Blvd", "Rd", "Dr", "Ln"];
+
+const SECONDARY_UNITS: [&str; 3] = ["Apt", "Ste", "Unit"];
+
+/// (city, state, ZIP code) triples that
+/// actually belong together
+const LOCALITIES: [(&str, &str, &str); 10] = [
+    ("Tampa", "FL", "33607"),
+    ("Miami", "FL", "33101"),
+    ("Austin", "TX", "78701"),
+    ("Denver", "CO", "80202"),
+    ("Boston", "MA", "02108"),
+    ("Seattle", "WA", "98101"),
+    ("Chicago", "IL", "60601"),
+    ("Atlanta", "GA", "30303"),
+    ("Portland", "OR", "97204"),
+    ("Beverly Hills", "CA", "90210"),
+];
+
+const AREA_CODES: [&str; 10] = [
+    "212", "305", "312", "404", "512", "617", "720", "813", "954", "206",
+];
+
+// </editor-fold desc="// Constants ...">
+
+// <editor-fold desc="// Fake ...">
+
+/// A type that can generate realistic fake instances of itself
+pub trait Fake: Sized {
+    /// Generate a fake instance using the supplied [`Faker`](Faker)
+    fn fake_with(faker: &mut Faker) -> Self;
+
+    /// Generate a fake instance using a randomly seeded [`Faker`](Faker)
+    fn fake() -> Self {
+        Self::fake_with(&mut Faker::new())
+    }
+
+    /// Generate a fake instance using a [`Faker`](Faker)
+    /// seeded with the supplied value
+    fn fake_seeded(seed: u64) -> Self {
+        Self::fake_with(&mut Faker::seeded(seed))
+    }
+}
+
+impl<T: Fake> Fake for Option<T> {
+    fn fake_with(faker: &mut Faker) -> Self {
+        faker.chance(0.5).then(|| faker.fake())
+    }
+}
+
+// </editor-fold desc="// Fake ...">
+
+// <editor-fold desc="// Faker ...">
+
+/// A (seedable) source of fake values
+#[derive(Clone, Debug)]
+pub struct Faker {
+    rng: StdRng,
+    now: DateTime<Utc>,
+}
+
+impl Default for Faker {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Faker {
+    // <editor-fold desc="// Constructors ... ">
+
+    /// Create a new randomly seeded faker whose
+    /// timestamps are generated relative to the
+    /// current date and time
+    pub fn new() -> Self {
+        Self {
+            rng: StdRng::from_entropy(),
+            now: Utc::now(),
+        }
+    }
+
+    /// Create a new faker seeded with the supplied value,
+    /// whose timestamps are generated relative to a fixed
+    /// instant (so that *every* value it generates is
+    /// reproducible)
+    pub fn seeded(seed: u64) -> Self {
+        Self {
+            rng: StdRng::seed_from_u64(seed),
+            now: Utc
+                .timestamp_opt(SEEDED_NOW, 0)
+                .single()
+                .unwrap_or_else(Utc::now),
+        }
+    }
+
+    /// Generate timestamps relative to the
+    /// supplied date and time instead
+    pub fn anchored_at(mut self, now: DateTime<Utc>) -> Self {
+        self.now = now;
+        self
+    }
+
+    // </editor-fold desc="// Constructors ... ">
+
+    // <editor-fold desc="// Generators ... ">
+
+    /// The date and time the faker treats as "now"
+    pub fn now(&self) -> DateTime<Utc> {
+        self.now
+    }
+
+    /// The faker's underlying random number generator
+    pub fn rng(&mut self) -> &mut StdRng {
+        &mut self.rng
+    }
+
+    /// Generate a fake instance of any [`Fake`](Fake) type
+    pub fn fake<T: Fake>(&mut self) -> T {
+        T::fake_with(self)
+    }
+
+    /// Generate the specified number of fake
+    /// instances of any [`Fake`](Fake) type
+    pub fn fake_many<T: Fake>(&mut self, count: usize) -> Vec<T> {
+        (0..count).map(|_| T::fake_with(self)).collect()
+    }
+
+    /// Return `true` with the supplied probability
+    pub fn chance(&mut self, probability: f64) -> bool {
+        self.rng.gen_bool(probability.clamp(0.0, 1.0))
+    }
+
+    /// Choose one of the supplied values at random
+    ///
+    /// **NOTE:** panics if `values` is empty
+    pub fn pick<T: Copy>(&mut self, values: &[T]) -> T {
+        *values
+            .choose(&mut self.rng)
+            .expect("Cannot pick from an empty slice")
+    }
+
+    /// Generate a realistic email address
+    pub fn email(&mut self) -> String {
+        let first = self.pick(&FIRST_NAMES);
+        let last = self.pick(&LAST_NAMES);
+        let domain = self.pick(&DOMAINS);
+
+        match self.rng.gen_range(0..3) {
+            0 => format!("{first}.{last}@{domain}"),
+            1 => format!("{}{last}@{domain}", &first[..1]),
+            _ => format!("{first}{}@{domain}", self.rng.gen_range(1..100)),
+        }
+    }
+
+    /// Generate a realistic email address at a
+    /// well-known disposable email domain
+    pub fn disposable_email(&mut self) -> String {
+        let account = self.pick(&LAST_NAMES);
+        let domain = self.pick(&DISPOSABLE_DOMAINS);
+
+        format!("{account}{}@{domain}", self.rng.gen_range(100..1000))
+    }
+
+    /// Generate a realistic "role" email address
+    /// (e.g. `sales@example.com`)
+    pub fn role_email(&mut self) -> String {
+        format!("{}@{}", self.pick(&ROLE_ACCOUNTS), self.pick(&DOMAINS))
+    }
+
+    /// Generate a realistic (North American) phone number
+    pub fn phone_number(&mut self) -> String {
+        let area = self.pick(&AREA_CODES);
+        let exchange = self.rng.gen_range(200..1000);
+        let line = self.rng.gen_range(0..10_000);
+
+        format!("1{area}{exchange}{line:04}")
+    }
+
+    /// Generate a realistic street number and name
+    /// (e.g. `4010 Boy Scout Blvd`)
+    pub fn street(&mut self) -> String {
+        format!(
+            "{} {} {}",
+            self.rng.gen_range(1..10_000),
+            self.pick(&STREET_NAMES),
+            self.pick(&STREET_SUFFIXES)
+        )
+    }
+
+    /// Generate a realistic secondary address
+    /// unit (e.g. `Ste 1100`)
+    pub fn secondary_unit(&mut self) -> String {
+        format!(
+            "{} {}",
+            self.pick(&SECONDARY_UNITS),
+            self.rng.gen_range(1..2000)
+        )
+    }
+
+    /// Generate a matching city, state, and ZIP code
+    pub fn locality(&mut self) -> (&'static str, &'static str, &'static str) {
+        self.pick(&LOCALITIES)
+    }
+
+    /// Generate a BriteVerify-style (UUID v4-formatted) list identifier
+    pub fn list_id(&mut self) -> String {
+        let bytes: [u8; 16] = self.rng.gen();
+        let hex: String = bytes.iter().map(|byte| format!("{byte:02x}")).collect();
+
+        format!(
+            "{}-{}-4{}-{:x}{}-{}",
+            &hex[..8],
+            &hex[8..12],
+            &hex[13..16],
+            8 + (bytes[8] & 0x3),
+            &hex[17..20],
+            &hex[20..32]
+        )
+    }
+
+    /// Generate a timestamp from within the past week
+    pub fn timestamp(&mut self) -> DateTime<Utc> {
+        let now = self.now;
+
+        within_the_last_week(&mut self.rng, &now)
+    }
+
+    // </editor-fold desc="// Generators ... ">
+}
+
+// </editor-fold desc="// Faker ...">
+
+// <editor-fold desc="// I/O-Free Tests ...">
+
+#[cfg(test)]
+mod tests {
+    // Third-Party Dependencies
+    use pretty_assertions::assert_eq;
+
+    // Crate-Level Imports
+    use super::{Fake, Faker, ADDRESS_ERRORS, EMAIL_ERRORS, PHONE_ERRORS};
+    use crate::types;
+
+    /// Test that seeded fakers generate
+    /// identical values for identical seeds
+    #[rstest::rstest]
+    fn test_seeded_fakers_are_reproducible() {
+        let left: Vec<types::VerificationListState> = Faker::seeded(1234).fake_many(10);
+        let right: Vec<types::VerificationListState> = Faker::seeded(1234).fake_many(10);
+
+        assert_eq!(left, right);
+        assert_eq!(
+            types::BulkVerificationResponse::fake_seeded(99),
+            types::BulkVerificationResponse::fake_seeded(99)
+        );
+    }
+
+    /// Test that fake verification results pair
+    /// their statuses with appropriate error codes
+    #[rstest::rstest]
+    fn test_fake_results_are_consistent() {
+        let mut faker = Faker::seeded(5678);
+
+        for response in faker.fake_many::<types::VerificationResponse>(200) {
+            if let Some(email) = response.email {
+                let invalid = email.status == types::VerificationStatus::Invalid;
+
+                assert_eq!(invalid, email.error_code.is_some());
+                assert!(email
+                    .error_code
+                    .is_none_or(|code| EMAIL_ERRORS.contains(&code)));
+                assert_eq!(
+                    email.disposable,
+                    email.error_code == Some(types::VerificationError::Disposable)
+                );
+            }
+
+            if let Some(phone) = response.phone {
+                let invalid = phone.status == types::VerificationStatus::Invalid;
+
+                assert_eq!(invalid, !phone.errors.is_empty());
+                assert!(phone.errors.iter().all(|error| PHONE_ERRORS
+                    .iter()
+                    .any(|code| error.as_str() == Some(code.to_string().as_str()))));
+            }
+
+            if let Some(address) = response.address {
+                let invalid = address.status == types::VerificationStatus::Invalid;
+
+                assert_eq!(invalid, !address.errors.is_empty());
+                assert!(address.errors.iter().all(|error| ADDRESS_ERRORS
+                    .iter()
+                    .any(|code| error.as_str() == Some(code.to_string().as_str()))));
+            }
+        }
+
+        for list in faker.fake_many::<types::VerificationListState>(200) {
+            let finished = matches!(
+                list.state,
+                types::BatchState::Complete | types::BatchState::Delivered
+            );
+
+            assert_eq!(finished, list.page_count.is_some());
+            assert_eq!(finished, list.results_path.is_some());
+            assert!(list.progress <= 100);
+            assert!(list.created_at <= faker.now());
+        }
+    }
+}
+
+// </editor-fold desc="// I/O-Free Tests ...">
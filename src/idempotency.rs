@@ -0,0 +1,817 @@
+//! ## Idempotent List Creation
+//!
+//! `create_list` is not idempotent: if the response to a request
+//! that created a list is lost (e.g. to a timeout), retrying it creates
+//! a second list, which reserves its own credits once it's started.
+//!
+//! [`create_list_idempotent`](crate::BriteVerifyClient::create_list_idempotent)
+//! takes a caller-generated idempotency token identifying a single logical
+//! job, and records each attempt to create its list in an
+//! [`IdempotencyStore`](IdempotencyStore) before sending it. When it's
+//! called again with the same token, it:
+//!
+//! - checks that the supplied contacts match the recorded
+//!   [`fingerprint`](fingerprint), refusing to reuse the token
+//!   for a different job
+//! - reuses the list recorded for the token, if it still exists
+//!   (and hasn't expired, been deleted, etc)
+//! - otherwise, if the previous attempt's response was lost, looks
+//!   up (via [`get_filtered_lists`](crate::BriteVerifyClient::get_filtered_lists))
+//!   the earliest list created while that attempt was in flight that
+//!   isn't recorded for any other token and whose verified record
+//!   count is consistent with the supplied contacts, and reuses it
+//! - creates a new list only if none of the above applies
+//!
+//! Only one list creation can be in flight for a token at a time:
+//! concurrent calls for the same token on the same client fail with
+//! [`IdempotentRequestInProgress`](crate::errors::BriteVerifyClientError::IdempotentRequestInProgress)
+//! instead of creating a second list, as does every call but one when
+//! clients (or processes) sharing a store race to record an attempt
+//! for the same token.
+//!
+//! Contacts are fingerprinted by their normalized values (see
+//! [`ContactKey`](crate::dedupe::ContactKey)), irrespective of order.
+//!
+//! ___
+//! **NOTE:** The BriteVerify API doesn't expose a list's contents, so
+//! a list whose creation response was lost can only be recognized by
+//! when it was created and how many records it has verified (which is
+//! only conclusive once it's complete). Lists created by other means
+//! (e.g. other clients or `create_list`) while the attempt was in flight
+//! may be mistaken for it. Use a
+//! [`FileIdempotencyStore`](FileIdempotencyStore) shared by every process
+//! creating lists for the same account to keep such mix-ups to a minimum.
+//! ___
+//!
+//! ## Basic Usage
+//! ```no_run
+//! # use briteverify_rs::{
+//! #     BriteVerifyClient,
+//! #     idempotency::{FileIdempotencyStore, IdempotentLists},
+//! #     types::VerificationRequest,
+//! # };
+//! #
+//! # async fn doc() -> anyhow::Result<()> {
+//! let client: BriteVerifyClient = BriteVerifyClient::builder()
+//!     .api_key("YOUR API KEY")
+//!     .idempotent_lists(IdempotentLists::new(FileIdempotencyStore::open("lists.json")?))
+//!     .build()?;
+//!
+//! let contacts = [VerificationRequest::try_from("test@example.com")?];
+//!
+//! // safe to retry: every attempt resolves to the same list
+//! let list = client
+//!     .create_list_idempotent("newsletter-2024-06-01", contacts, true)
+//!     .await?;
+//! # Ok(())
+//! # }
+//! ```
+//
+// Standard Library Imports
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Debug,
+    fs::OpenOptions,
+    io::{Read, Seek, Write},
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex, MutexGuard},
+    time::Duration,
+};
+
+// Third Party Imports
+use anyhow::Context;
+use chrono::{DateTime, Utc};
+use fs4::fs_std::FileExt;
+use serde::{Deserialize, Serialize};
+
+// Crate-Level Imports
+use crate::{
+    dedupe::DedupePolicy,
+    errors::BriteVerifyClientError,
+    types::{BatchState, VerificationListState, VerificationRequest},
+    utils::{fnv1a, BlockingWorker},
+};
+
+// <editor-fold desc="// Constants ...">
+
+/// The default amount of clock skew tolerated between the local
+/// system and the BriteVerify API when looking up lists whose
+/// creation response was lost
+pub const DEFAULT_CLOCK_SKEW: Duration = Duration::from_secs(300);
+
+/// The default amount of time a [`FileIdempotencyStore`](FileIdempotencyStore)
+/// keeps records after their (most recent) attempt
+pub const DEFAULT_RETENTION: Duration = Duration::from_secs(30 * 24 * 3600);
+
+// </editor-fold desc="// Constants ...">
+
+// <editor-fold desc="// Fingerprinting ...">
+
+/// Compute a stable fingerprint of the supplied contacts' normalized
+/// values (irrespective of their order), interpreting phone numbers
+/// without a country code relative to the supplied default region
+pub fn fingerprint<'contact, Contacts: IntoIterator<Item = &'contact VerificationRequest>>(
+    contacts: Contacts,
+    default_region: Option<&str>,
+) -> String {
+    let policy = DedupePolicy::new();
+    let mut keys: Vec<String> = contacts
+        .into_iter()
+        .map(|contact| {
+            let key = policy.contact_key(contact, default_region);
+
+            [key.email, key.phone, key.address]
+                .map(Option::unwrap_or_default)
+                .join("\u{1f}")
+        })
+        .collect();
+
+    keys.sort_unstable();
+
+    let hash = fnv1a(keys.iter().flat_map(|key| key.bytes().chain([b'\n'])));
+
+    format!("{:016x}:{}", hash, keys.len())
+}
+
+// </editor-fold desc="// Fingerprinting ...">
+
+// <editor-fold desc="// IdempotencyRecord ...">
+
+/// The recorded attempt(s) to create the list for a single idempotency token
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IdempotencyRecord {
+    /// The caller-generated idempotency token
+    pub token: String,
+    /// The [`fingerprint`](fingerprint) of the list's contacts
+    pub fingerprint: String,
+    /// When the list was (most recently) attempted to be created
+    pub attempted_on: DateTime<Utc>,
+    /// The number of contacts uploaded to the list (i.e.
+    /// after any deduplication), if it's known
+    #[serde(default)]
+    pub contacts: Option<usize>,
+    /// The id of the created list, if its creation was confirmed
+    #[serde(default)]
+    pub list_id: Option<String>,
+    /// When the (most recent) attempt's request failed, if it did
+    /// (after which the attempt can't have created a list)
+    #[serde(default)]
+    pub failed_on: Option<DateTime<Utc>>,
+}
+
+// </editor-fold desc="// IdempotencyRecord ...">
+
+// <editor-fold desc="// IdempotencyStore ...">
+
+/// A local home for [`IdempotencyRecord`](IdempotencyRecord)s
+pub trait IdempotencyStore: Send + Sync {
+    /// Get the record for the supplied token (if any)
+    fn get(&self, token: &str) -> Result<Option<IdempotencyRecord>, BriteVerifyClientError>;
+
+    /// Store the supplied record, replacing any
+    /// existing record for the same token
+    fn put(&self, record: IdempotencyRecord) -> Result<(), BriteVerifyClientError>;
+
+    /// Store the supplied record if the existing record for the same
+    /// token (if any) is still the expected one, returning whether it
+    /// was stored (i.e. `false` if another attempt replaced it first)
+    ///
+    /// ___
+    /// **NOTE:** The default implementation isn't atomic, so stores
+    /// shared by multiple clients or processes should override it.
+    /// ___
+    fn replace(
+        &self,
+        expected: Option<&IdempotencyRecord>,
+        record: IdempotencyRecord,
+    ) -> Result<bool, BriteVerifyClientError> {
+        if self.get(&record.token)?.as_ref() != expected {
+            return Ok(false);
+        }
+
+        self.put(record)?;
+
+        Ok(true)
+    }
+
+    /// Get the ids of every list recorded for any token
+    fn claimed(&self) -> Result<HashSet<String>, BriteVerifyClientError>;
+}
+
+/// An [`IdempotencyStore`](IdempotencyStore) that keeps its records in memory
+///
+/// ___
+/// **NOTE:** Clones share the same records.
+/// ___
+#[derive(Clone, Debug, Default)]
+pub struct InMemoryIdempotencyStore {
+    records: Arc<Mutex<HashMap<String, IdempotencyRecord>>>,
+}
+
+impl InMemoryIdempotencyStore {
+    /// Create a new, empty `InMemoryIdempotencyStore`
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// [internal-implementation]
+    /// Lock the store's records, recovering
+    /// them if a previous holder panicked
+    fn _records(&self) -> MutexGuard<'_, HashMap<String, IdempotencyRecord>> {
+        self.records
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+impl IdempotencyStore for InMemoryIdempotencyStore {
+    fn get(&self, token: &str) -> Result<Option<IdempotencyRecord>, BriteVerifyClientError> {
+        Ok(self._records().get(token).cloned())
+    }
+
+    fn put(&self, record: IdempotencyRecord) -> Result<(), BriteVerifyClientError> {
+        self._records().insert(record.token.clone(), record);
+        Ok(())
+    }
+
+    fn replace(
+        &self,
+        expected: Option<&IdempotencyRecord>,
+        record: IdempotencyRecord,
+    ) -> Result<bool, BriteVerifyClientError> {
+        let mut records = self._records();
+
+        if records.get(&record.token) != expected {
+            return Ok(false);
+        }
+
+        records.insert(record.token.clone(), record);
+
+        Ok(true)
+    }
+
+    fn claimed(&self) -> Result<HashSet<String>, BriteVerifyClientError> {
+        Ok(self
+            ._records()
+            .values()
+            .filter_map(|record| record.list_id.clone())
+            .collect())
+    }
+}
+
+/// An [`IdempotencyStore`](IdempotencyStore) backed by a single JSON
+/// file, which is locked while it's being read or written (so it can
+/// be shared by multiple processes)
+///
+/// Records are discarded once they've been kept for longer than the
+/// store's retention period (30 days by default) since their (most
+/// recent) attempt, after which their tokens can be reused.
+#[derive(Debug)]
+pub struct FileIdempotencyStore {
+    path: PathBuf,
+    guard: Mutex<()>,
+    retention: Duration,
+}
+
+impl FileIdempotencyStore {
+    /// Open (or create) the idempotency store at the supplied path
+    pub fn open<StorePath: AsRef<Path>>(path: StorePath) -> Result<Self, BriteVerifyClientError> {
+        let store = Self {
+            path: path.as_ref().to_path_buf(),
+            guard: Mutex::new(()),
+            retention: DEFAULT_RETENTION,
+        };
+
+        store._transact(|_| ((), false))?;
+
+        Ok(store)
+    }
+
+    /// Set how long records are kept after their (most recent) attempt
+    pub fn retention(mut self, value: Duration) -> Self {
+        self.retention = value;
+        self
+    }
+
+    /// The path of the store's file
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    // <editor-fold desc="// Internal Utility Methods ... ">
+
+    /// [internal-implementation]
+    /// Read, (optionally) modify, and rewrite the store's file
+    /// while holding an exclusive lock on it, discarding any
+    /// records older than the store's retention period
+    fn _transact<Output>(
+        &self,
+        operation: impl FnOnce(&mut HashMap<String, IdempotencyRecord>) -> (Output, bool),
+    ) -> Result<Output, BriteVerifyClientError> {
+        let _guard = self
+            .guard
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+
+        if let Some(parent) = self.path.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+            std::fs::create_dir_all(parent)
+                .with_context(|| format!("Could not create directory: {}", parent.display()))?;
+        }
+
+        let mut file = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(false)
+            .open(&self.path)
+            .with_context(|| {
+                format!("Could not open idempotency store: {}", self.path.display())
+            })?;
+
+        file.lock_exclusive().with_context(|| {
+            format!("Could not lock idempotency store: {}", self.path.display())
+        })?;
+
+        let mut contents = String::new();
+
+        file.read_to_string(&mut contents).with_context(|| {
+            format!("Could not read idempotency store: {}", self.path.display())
+        })?;
+
+        let mut records: HashMap<String, IdempotencyRecord> = match contents.trim().is_empty() {
+            true => HashMap::new(),
+            false => serde_json::from_str(&contents).with_context(|| {
+                format!("Could not parse idempotency store: {}", self.path.display())
+            })?,
+        };
+
+        let cutoff = chrono::Duration::from_std(self.retention)
+            .ok()
+            .and_then(|retention| Utc::now().checked_sub_signed(retention))
+            .unwrap_or(DateTime::<Utc>::MIN_UTC);
+        let count = records.len();
+
+        records.retain(|_, record| record.attempted_on >= cutoff);
+
+        let pruned = records.len() != count;
+        let (output, modified) = operation(&mut records);
+
+        if modified || pruned {
+            let contents = serde_json::to_vec(&records)?;
+
+            file.set_len(0)
+                .and_then(|_| file.rewind())
+                .and_then(|_| file.write_all(&contents))
+                .and_then(|_| file.sync_data())
+                .with_context(|| {
+                    format!("Could not write idempotency store: {}", self.path.display())
+                })?;
+        }
+
+        Ok(output)
+    }
+
+    // </editor-fold desc="// Internal Utility Methods ... ">
+}
+
+impl IdempotencyStore for FileIdempotencyStore {
+    fn get(&self, token: &str) -> Result<Option<IdempotencyRecord>, BriteVerifyClientError> {
+        self._transact(|records| (records.get(token).cloned(), false))
+    }
+
+    fn put(&self, record: IdempotencyRecord) -> Result<(), BriteVerifyClientError> {
+        self._transact(|records| {
+            records.insert(record.token.clone(), record);
+            ((), true)
+        })
+    }
+
+    fn replace(
+        &self,
+        expected: Option<&IdempotencyRecord>,
+        record: IdempotencyRecord,
+    ) -> Result<bool, BriteVerifyClientError> {
+        self._transact(|records| {
+            if records.get(&record.token) != expected {
+                return (false, false);
+            }
+
+            records.insert(record.token.clone(), record);
+            (true, true)
+        })
+    }
+
+    fn claimed(&self) -> Result<HashSet<String>, BriteVerifyClientError> {
+        self._transact(|records| {
+            let claimed = records
+                .values()
+                .filter_map(|record| record.list_id.clone())
+                .collect();
+
+            (claimed, false)
+        })
+    }
+}
+
+// </editor-fold desc="// IdempotencyStore ...">
+
+// <editor-fold desc="// IdempotentLists ...">
+
+/// Resolves retried list creations to the lists
+/// recorded in an [`IdempotencyStore`](IdempotencyStore)
+pub struct IdempotentLists {
+    worker: BlockingWorker<dyn IdempotencyStore>,
+    clock_skew: Duration,
+    in_flight: Arc<Mutex<HashSet<String>>>,
+}
+
+#[cfg_attr(tarpaulin, coverage(off))]
+impl Debug for IdempotentLists {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        formatter
+            .debug_struct("IdempotentLists")
+            .field("clock_skew", &self.clock_skew)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Default for IdempotentLists {
+    fn default() -> Self {
+        Self::new(InMemoryIdempotencyStore::new())
+    }
+}
+
+impl IdempotentLists {
+    /// Create a new `IdempotentLists` instance backed by the supplied store
+    pub fn new<Store: IdempotencyStore + 'static>(store: Store) -> Self {
+        Self {
+            worker: BlockingWorker::new("briteverify-idempotency", Arc::new(store)),
+            clock_skew: DEFAULT_CLOCK_SKEW,
+            in_flight: Arc::default(),
+        }
+    }
+
+    /// Set the amount of clock skew tolerated between the local
+    /// system and the BriteVerify API (including the time it
+    /// takes the API to create a list) when looking up lists
+    /// whose creation response was lost
+    pub fn clock_skew(mut self, value: Duration) -> Self {
+        self.clock_skew = value;
+        self
+    }
+
+    /// The underlying store
+    pub fn store(&self) -> &dyn IdempotencyStore {
+        self.worker.resource()
+    }
+
+    // <editor-fold desc="// Internal Utility Methods ... ">
+
+    /// [internal-implementation]
+    /// Mark the supplied token as having a list creation in flight
+    /// (until the returned guard is dropped), refusing to if it
+    /// already has one
+    pub(crate) fn _begin(&self, token: &str) -> Result<InFlightToken, BriteVerifyClientError> {
+        let mut in_flight = self
+            .in_flight
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+
+        if !in_flight.insert(token.to_string()) {
+            return Err(BriteVerifyClientError::IdempotentRequestInProgress(
+                token.to_string(),
+            ));
+        }
+
+        Ok(InFlightToken {
+            token: token.to_string(),
+            in_flight: Arc::clone(&self.in_flight),
+        })
+    }
+
+    /// [internal-implementation]
+    /// Get the existing record for the supplied token (if any),
+    /// refusing to reuse it for contacts with a different fingerprint
+    pub(crate) async fn _existing(
+        &self,
+        token: &str,
+        fingerprint: &str,
+    ) -> Result<Option<IdempotencyRecord>, BriteVerifyClientError> {
+        let key = token.to_string();
+
+        match self.worker.run(move |store| store.get(&key)).await? {
+            Some(record) if record.fingerprint != fingerprint => Err(
+                BriteVerifyClientError::ReusedIdempotencyToken(token.to_string()),
+            ),
+            record => Ok(record),
+        }
+    }
+
+    /// [internal-implementation]
+    /// Find the list the supplied record refers to among the
+    /// supplied lists, if it can (still) be reused
+    pub(crate) async fn _resolve<'list>(
+        &self,
+        record: &IdempotencyRecord,
+        lists: &'list [VerificationListState],
+    ) -> Result<Option<&'list VerificationListState>, BriteVerifyClientError> {
+        let reusable = lists.iter().filter(|list| _is_reusable(list.state));
+
+        if let Some(list_id) = record.list_id.as_deref() {
+            return Ok(reusable.into_iter().find(|list| list.id == list_id));
+        }
+
+        let claimed = self.worker.run(|store| store.claimed()).await?;
+        let skew = chrono::Duration::from_std(self.clock_skew).ok();
+        let earliest = skew
+            .and_then(|skew| record.attempted_on.checked_sub_signed(skew))
+            .unwrap_or(DateTime::<Utc>::MIN_UTC);
+        let latest = match record.failed_on {
+            None => DateTime::<Utc>::MAX_UTC,
+            Some(failed_on) => skew
+                .and_then(|skew| failed_on.checked_add_signed(skew))
+                .unwrap_or(DateTime::<Utc>::MAX_UTC),
+        };
+
+        Ok(reusable
+            .filter(|list| (earliest..=latest).contains(&list.created_at))
+            .filter(|list| _could_hold(list, record.contacts) && !claimed.contains(&list.id))
+            .min_by_key(|list| list.created_at))
+    }
+
+    /// [internal-implementation]
+    /// Record an attempt to create the list for the supplied
+    /// token, uploading the supplied number of contacts, unless
+    /// another attempt replaced the supplied existing record
+    pub(crate) async fn _attempt(
+        &self,
+        token: &str,
+        fingerprint: &str,
+        contacts: usize,
+        now: DateTime<Utc>,
+        existing: Option<&IdempotencyRecord>,
+    ) -> Result<IdempotencyRecord, BriteVerifyClientError> {
+        let record = IdempotencyRecord {
+            token: token.to_string(),
+            fingerprint: fingerprint.to_string(),
+            attempted_on: now,
+            contacts: Some(contacts),
+            list_id: None,
+            failed_on: None,
+        };
+
+        let (expected, replacement) = (existing.cloned(), record.clone());
+        let replaced = self
+            .worker
+            .run(move |store| store.replace(expected.as_ref(), replacement))
+            .await?;
+
+        if !replaced {
+            return Err(BriteVerifyClientError::IdempotentRequestInProgress(
+                token.to_string(),
+            ));
+        }
+
+        Ok(record)
+    }
+
+    /// [internal-implementation]
+    /// Record that the supplied record's attempt failed
+    pub(crate) async fn _fail(
+        &self,
+        mut record: IdempotencyRecord,
+        now: DateTime<Utc>,
+    ) -> Result<(), BriteVerifyClientError> {
+        record.failed_on = Some(now);
+        self.worker.run(move |store| store.put(record)).await
+    }
+
+    /// [internal-implementation]
+    /// Record the list created for the supplied record's token
+    pub(crate) async fn _confirm(
+        &self,
+        mut record: IdempotencyRecord,
+        list_id: &str,
+    ) -> Result<(), BriteVerifyClientError> {
+        if record.list_id.as_deref() == Some(list_id) {
+            return Ok(());
+        }
+
+        record.list_id = Some(list_id.to_string());
+        self.worker.run(move |store| store.put(record)).await
+    }
+
+    // </editor-fold desc="// Internal Utility Methods ... ">
+}
+
+/// [internal-implementation]
+/// Marks a token as having a list creation
+/// in flight for as long as it's alive
+pub(crate) struct InFlightToken {
+    token: String,
+    in_flight: Arc<Mutex<HashSet<String>>>,
+}
+
+impl Drop for InFlightToken {
+    fn drop(&mut self) {
+        self.in_flight
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .remove(&self.token);
+    }
+}
+
+/// [internal-implementation]
+/// Whether or not a list in the supplied state can stand
+/// in for one that was (attempted to be) created
+fn _is_reusable(state: BatchState) -> bool {
+    !matches!(
+        state,
+        BatchState::Deleted
+            | BatchState::Expired
+            | BatchState::NotFound
+            | BatchState::Terminated
+            | BatchState::ImportError
+            | BatchState::MissingData
+            | BatchState::ExceedsLimit
+            | BatchState::InvalidState
+            | BatchState::DuplicateData
+            | BatchState::Unknown
+    )
+}
+
+/// [internal-implementation]
+/// Whether or not the supplied list could have been created
+/// with the supplied number of contacts (if known), judging
+/// by how many of its records it has verified
+fn _could_hold(list: &VerificationListState, contacts: Option<usize>) -> bool {
+    let Some(contacts) = contacts.map(|contacts| contacts as u64) else {
+        return true;
+    };
+
+    match list.state {
+        BatchState::Complete | BatchState::Delivered => list.total_verified == contacts,
+        _ => list.total_verified <= contacts,
+    }
+}
+
+// </editor-fold desc="// IdempotentLists ...">
+
+// <editor-fold desc="// I/O-Free Tests ...">
+
+#[cfg(test)]
+mod tests {
+    // Standard Library Imports
+    use std::time::Duration;
+
+    // Third-Party Dependencies
+    use chrono::{TimeZone, Utc};
+    use pretty_assertions::assert_eq;
+
+    // Crate-Level Imports
+    use super::{
+        fingerprint, IdempotencyRecord, IdempotencyStore, IdempotentLists, InMemoryIdempotencyStore,
+    };
+    use crate::{
+        errors::BriteVerifyClientError,
+        types::{BatchState, VerificationListState, VerificationRequest},
+    };
+
+    fn contact(email: &str) -> VerificationRequest {
+        VerificationRequest {
+            email: Some(email.to_string()),
+            phone: None,
+            address: None,
+        }
+    }
+
+    /// Test that fingerprints ignore the order and
+    /// formatting of contacts, but not their values
+    #[rstest::rstest]
+    fn test_fingerprint() {
+        let first = [contact("a@example.com"), contact("b@example.com")];
+        let reordered = [contact(" B@Example.com"), contact("a@example.com")];
+        let different = [contact("a@example.com"), contact("c@example.com")];
+
+        assert_eq!(fingerprint(&first, None), fingerprint(&reordered, None));
+        assert_ne!(fingerprint(&first, None), fingerprint(&different, None));
+        assert_ne!(fingerprint(&first, None), fingerprint(&first[..1], None));
+    }
+
+    /// Test that records resolve to reusable, unclaimed lists created
+    /// while their attempt was in flight (and consistent with their
+    /// number of contacts), and that tokens can't be reused for
+    /// different contents
+    #[rstest::rstest]
+    fn test_resolve_lists() {
+        futures::executor::block_on(async {
+            let store = InMemoryIdempotencyStore::new();
+            let lists = IdempotentLists::new(store.clone()).clock_skew(Duration::from_secs(60));
+            let at = |minute: u32| Utc.with_ymd_and_hms(2024, 6, 1, 12, minute, 0).unwrap();
+            let list =
+                |id: &str, minute: u32, state: BatchState, verified: u64| VerificationListState {
+                    id: id.to_string(),
+                    state,
+                    total_verified: verified,
+                    created_at: at(minute),
+                    ..VerificationListState::default()
+                };
+
+            let known = [
+                list("stale", 0, BatchState::Open, 0),
+                list("deleted", 10, BatchState::Deleted, 0),
+                list("claimed", 10, BatchState::Open, 0),
+                list("smaller", 10, BatchState::Complete, 2),
+                list("larger", 10, BatchState::Verifying, 4),
+                list("lost", 11, BatchState::Verifying, 1),
+                list("later", 12, BatchState::Open, 0),
+            ];
+
+            let other = lists
+                ._attempt("other", "fp-0", 1, at(9), None)
+                .await
+                .unwrap();
+            lists._confirm(other, "claimed").await.unwrap();
+
+            let record = lists
+                ._attempt("token", "fp-1", 3, at(10), None)
+                .await
+                .unwrap();
+
+            assert!(matches!(
+                lists._existing("token", "fp-2").await,
+                Err(BriteVerifyClientError::ReusedIdempotencyToken(token)) if token == "token"
+            ));
+            assert_eq!(
+                Some(record.clone()),
+                lists._existing("token", "fp-1").await.unwrap()
+            );
+            assert_eq!(
+                Some("lost"),
+                lists
+                    ._resolve(&record, &known)
+                    .await
+                    .unwrap()
+                    .map(|list| list.id.as_str())
+            );
+
+            // lists created after the attempt failed aren't considered
+            let failed = IdempotencyRecord {
+                failed_on: Some(at(9)),
+                ..record.clone()
+            };
+
+            assert_eq!(None, lists._resolve(&failed, &known).await.unwrap());
+
+            lists._confirm(record, "later").await.unwrap();
+
+            let record = store.get("token").unwrap().unwrap();
+
+            assert_eq!(
+                Some("later"),
+                lists
+                    ._resolve(&record, &known)
+                    .await
+                    .unwrap()
+                    .map(|list| list.id.as_str())
+            );
+            assert_eq!(None, lists._resolve(&record, &known[..6]).await.unwrap());
+        });
+    }
+
+    /// Test that only one attempt per token can be in flight,
+    /// and that attempts can't replace ones recorded after
+    /// the existing record was read
+    #[rstest::rstest]
+    fn test_in_flight_attempts() {
+        futures::executor::block_on(async {
+            let lists = IdempotentLists::new(InMemoryIdempotencyStore::new());
+            let now = Utc.with_ymd_and_hms(2024, 6, 1, 12, 0, 0).unwrap();
+
+            let guard = lists._begin("token").unwrap();
+
+            assert!(matches!(
+                lists._begin("token"),
+                Err(BriteVerifyClientError::IdempotentRequestInProgress(token)) if token == "token"
+            ));
+            assert!(lists._begin("other").is_ok());
+
+            drop(guard);
+
+            assert!(lists._begin("token").is_ok());
+
+            let first = lists._attempt("token", "fp", 1, now, None).await.unwrap();
+
+            assert!(matches!(
+                lists._attempt("token", "fp", 1, now, None).await,
+                Err(BriteVerifyClientError::IdempotentRequestInProgress(token)) if token == "token"
+            ));
+            assert!(lists
+                ._attempt("token", "fp", 1, now, Some(&first))
+                .await
+                .is_ok());
+        });
+    }
+}
+
+// </editor-fold desc="// I/O-Free Tests ...">
@@ -0,0 +1,251 @@
+//! ## Keyed Bulk Verification Jobs
+//!
+//! Bulk verification results don't include any reference to the
+//! records they were produced for. A [`BulkJob`](BulkJob) pairs each
+//! contact submitted via [`create_job`](crate::BriteVerifyClient::create_job)
+//! with a caller-supplied key (e.g. a CRM record id, or any other
+//! payload), which is kept client-side. The job's results, exported via
+//! [`get_job_results`](crate::BriteVerifyClient::get_job_results), are
+//! each paired with the key of the contact they were produced for.
+//!
+//! Jobs with more contacts than a single bulk verification list can
+//! hold are split across as many lists as necessary, and the contacts
+//! of each list are uploaded in as many requests as necessary.
+//!
+//! Results are matched to contacts by their normalized values (see
+//! [`ContactKey`](crate::dedupe::ContactKey)), so duplicate contacts
+//! each get their own key, and results for corrected street addresses
+//! are matched by their email address and phone number, as long as
+//! that's unambiguous. Results that can't be matched to a contact
+//! are returned without a key.
+//!
+//! ## Basic Usage
+//! ```no_run
+//! # use briteverify_rs::{BriteVerifyClient, jobs::BulkJob, types::VerificationRequest};
+//! #
+//! # async fn doc() -> anyhow::Result<()> {
+//! # let client: BriteVerifyClient = BriteVerifyClient::new("YOUR API KEY")?;
+//! let contacts: Vec<(u64, VerificationRequest)> = vec![
+//!     (1001, VerificationRequest::try_from("test@example.com")?),
+//!     (1002, VerificationRequest::try_from("+15555555555")?),
+//! ];
+//!
+//! let job: BulkJob<u64> = client.create_job(contacts, true).await?;
+//!
+//! // ... once the job's lists have been processed
+//! for record in client.get_job_results(&job).await? {
+//!     println!("CRM record {:?}: {:#?}", record.key, record.result);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+//
+// Standard Library Imports
+use std::collections::{HashMap, VecDeque};
+
+// Crate-Level Imports
+use crate::{
+    dedupe::{ContactKey, DedupePolicy, KeyIndex},
+    types::{BulkVerificationResult, VerificationRequest},
+};
+
+// <editor-fold desc="// KeyedBulkResult ...">
+
+/// A bulk verification result, paired with the
+/// key of the contact it was produced for
+#[derive(Clone, Debug)]
+pub struct KeyedBulkResult<Key> {
+    /// The key supplied with the contact the result was
+    /// produced for, if it could be matched to one
+    pub key: Option<Key>,
+    /// The id of the bulk verification
+    /// list that produced the result
+    pub list_id: String,
+    /// The bulk verification result
+    pub result: BulkVerificationResult,
+}
+
+// </editor-fold desc="// KeyedBulkResult ...">
+
+// <editor-fold desc="// BulkJob ...">
+
+/// [internal-implementation]
+/// The keys of the contacts in a single
+/// bulk verification list, grouped by contact
+#[derive(Clone, Debug)]
+struct JobList<Key> {
+    id: String,
+    len: usize,
+    keys: HashMap<ContactKey, VecDeque<Key>>,
+}
+
+/// A bulk verification job, made up of one or more
+/// bulk verification lists whose contacts each carry
+/// a caller-supplied key
+#[derive(Clone, Debug)]
+pub struct BulkJob<Key> {
+    policy: DedupePolicy,
+    default_region: Option<String>,
+    lists: Vec<JobList<Key>>,
+}
+
+impl<Key> BulkJob<Key> {
+    /// The ids of the job's bulk verification lists
+    pub fn list_ids(&self) -> Vec<&str> {
+        self.lists.iter().map(|list| list.id.as_str()).collect()
+    }
+
+    /// The total number of contacts in the job
+    pub fn len(&self) -> usize {
+        self.lists.iter().map(|list| list.len).sum()
+    }
+
+    /// Check whether the job has any contacts
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Pair the supplied results of the specified list with
+    /// the keys of the contacts they were produced for
+    pub fn pair(
+        &self,
+        list_id: &str,
+        results: Vec<BulkVerificationResult>,
+    ) -> Vec<KeyedBulkResult<Key>>
+    where
+        Key: Clone,
+    {
+        let mut keys = self
+            .lists
+            .iter()
+            .find(|list| list.id == list_id)
+            .map(|list| list.keys.clone())
+            .unwrap_or_default();
+        let contacts: Vec<ContactKey> = keys.keys().cloned().collect();
+        let index = KeyIndex::_new(&contacts);
+
+        results
+            .into_iter()
+            .map(|result| {
+                let contact = self
+                    .policy
+                    .result_key(&result, self.default_region.as_deref());
+
+                KeyedBulkResult {
+                    key: index
+                        ._resolve(&contact)
+                        .and_then(|contact| keys.get_mut(contact))
+                        .and_then(VecDeque::pop_front),
+                    list_id: list_id.to_string(),
+                    result,
+                }
+            })
+            .collect()
+    }
+
+    // <editor-fold desc="// Internal Utility Methods ... ">
+
+    /// [internal-implementation]
+    /// Create a new job without any lists
+    pub(crate) fn _new(policy: DedupePolicy, default_region: Option<String>) -> Self {
+        Self {
+            policy,
+            default_region,
+            lists: Vec::new(),
+        }
+    }
+
+    /// [internal-implementation]
+    /// Record the keys of the contacts uploaded to the specified list
+    pub(crate) fn _record(&mut self, list_id: String, contacts: Vec<(Key, &VerificationRequest)>) {
+        let mut list = JobList {
+            id: list_id,
+            len: contacts.len(),
+            keys: HashMap::new(),
+        };
+
+        for (key, contact) in contacts {
+            list.keys
+                .entry(
+                    self.policy
+                        .contact_key(contact, self.default_region.as_deref()),
+                )
+                .or_default()
+                .push_back(key);
+        }
+
+        self.lists.push(list);
+    }
+
+    // </editor-fold desc="// Internal Utility Methods ... ">
+}
+
+// </editor-fold desc="// BulkJob ...">
+
+// <editor-fold desc="// I/O-Free Tests ...">
+
+#[cfg(test)]
+mod tests {
+    // Third-Party Dependencies
+    use pretty_assertions::assert_eq;
+
+    // Crate-Level Imports
+    use super::BulkJob;
+    use crate::{
+        dedupe::DedupePolicy,
+        types::{
+            bulk::BulkEmailVerificationArray, BulkVerificationResult, VerificationRequest,
+            VerificationStatus,
+        },
+    };
+
+    /// Test that results are paired with the keys of the
+    /// contacts they were produced for, including duplicates
+    #[rstest::rstest]
+    fn test_pair_results() {
+        let contacts = [
+            ("a", "test@example.com"),
+            ("b", "TEST@example.com"),
+            ("c", "other@example.com"),
+        ]
+        .map(|(key, email)| (key, VerificationRequest::try_from(email).unwrap()));
+
+        let mut job = BulkJob::_new(DedupePolicy::new(), None);
+        job._record(
+            "some-list-id".to_string(),
+            contacts
+                .iter()
+                .map(|(key, contact)| (*key, contact))
+                .collect(),
+        );
+
+        assert_eq!(3, job.len());
+        assert_eq!(vec!["some-list-id"], job.list_ids());
+
+        let result = |email: &str| {
+            BulkVerificationResult::Email(BulkEmailVerificationArray {
+                email: email.to_string(),
+                status: VerificationStatus::Valid,
+                secondary_status: None,
+            })
+        };
+
+        let keys: Vec<Option<&str>> = job
+            .pair(
+                "some-list-id",
+                vec![
+                    result("other@example.com"),
+                    result("test@example.com"),
+                    result("test@example.com"),
+                    result("test@example.com"),
+                ],
+            )
+            .into_iter()
+            .map(|record| record.key)
+            .collect();
+
+        assert_eq!(vec![Some("c"), Some("a"), Some("b"), None], keys);
+    }
+}
+
+// </editor-fold desc="// I/O-Free Tests ...">
@@ -0,0 +1,559 @@
+//! ## Credit Consumption Ledger
+//!
+//! A [`BriteVerifyClient`](crate::BriteVerifyClient) built with a
+//! [`credit_ledger`](crate::BriteVerifyClientBuilder::credit_ledger)
+//! writes a [`LedgerEntry`](LedgerEntry) to the ledger's
+//! [`LedgerSink`](LedgerSink) for:
+//!
+//! - every successful single-transaction verification, costing one
+//!   credit per email address, phone number, and street address verified
+//! - every bulk verification list, once the client first observes it as
+//!   complete (e.g. via [`get_list_by_id`](crate::BriteVerifyClient::get_list_by_id)),
+//!   costing the credits of every contact uploaded to it (or, for lists
+//!   the client didn't upload, its `total_verified_emails` plus its
+//!   `total_verified_phones`)
+//!
+//! If [enabled](CreditLedger::snapshot_balances), the account's credit
+//! balance is retrieved before and after each verification (or, for
+//! lists, before the list's first upload and after its completion) and
+//! included in the entry, so that estimated costs can be reconciled
+//! against actual consumption.
+//!
+//! ___
+//! **NOTE:** Balance snapshots cost two extra requests per verification,
+//! and the balance is account-wide, so an entry's `balance_before` and
+//! `balance_after` only reflect that entry's request when nothing else
+//! (including other requests sent concurrently by the same client) is
+//! consuming the account's credits at the same time.
+//! ___
+//!
+//! Entries are tagged with the tenant and labels of the
+//! [`RequestOptions`](crate::RequestOptions) of the client that sent
+//! them (or, for lists, that created them), allowing consumption to
+//! be [summarized](summarize) per tenant, per list, or per label.
+//!
+//! Any `Fn(&LedgerEntry)` can be used as a [`LedgerSink`](LedgerSink),
+//! and an [`InMemoryLedger`](InMemoryLedger) is provided for simple
+//! in-process reporting.
+//!
+//! ## Basic Usage
+//! ```no_run
+//! # use briteverify_rs::{
+//! #     BriteVerifyClient, RequestOptions,
+//! #     ledger::{CreditLedger, InMemoryLedger},
+//! # };
+//! #
+//! # async fn doc() -> anyhow::Result<()> {
+//! let ledger = InMemoryLedger::new();
+//!
+//! let client: BriteVerifyClient = BriteVerifyClient::builder()
+//!     .api_key("YOUR API KEY")
+//!     .credit_ledger(CreditLedger::new(ledger.clone()))
+//!     .build()?;
+//!
+//! client
+//!     .with_options(RequestOptions::new().tenant("acme").label("pipeline", "signup"))
+//!     .verify_email("test@example.com")
+//!     .await?;
+//!
+//! for (tenant, summary) in ledger.by_tenant() {
+//!     println!("{tenant:?} consumed {} credits", summary.credits);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+//
+// Standard Library Imports
+use std::{
+    collections::{BTreeMap, HashMap, HashSet},
+    fmt::Debug,
+    sync::{Arc, Mutex, MutexGuard},
+};
+
+// Third Party Imports
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+// Crate-Level Imports
+use crate::types::{VerificationListState, VerificationRequest};
+
+// <editor-fold desc="// LedgerTags ...">
+
+/// The tenant and caller-supplied labels
+/// a ledger entry is attributed to
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct LedgerTags {
+    /// The tenant the entry is attributed to
+    pub tenant: Option<String>,
+    /// Arbitrary caller-supplied labels
+    /// (e.g. a pipeline or job name)
+    pub labels: BTreeMap<String, String>,
+}
+
+// </editor-fold desc="// LedgerTags ...">
+
+// <editor-fold desc="// LedgerEntry ...">
+
+/// The kind of request a ledger entry was recorded for
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LedgerEntryKind {
+    /// A single-transaction verification
+    Single,
+    /// A bulk verification list
+    Bulk,
+}
+
+/// The credits consumed by a single
+/// verification or bulk verification list
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LedgerEntry {
+    /// When the entry was recorded
+    pub recorded_on: DateTime<Utc>,
+    /// The kind of request the entry was recorded for
+    pub kind: LedgerEntryKind,
+    /// The tenant the entry is attributed to
+    pub tenant: Option<String>,
+    /// The id of the bulk verification list
+    /// the entry was recorded for (if any)
+    pub list_id: Option<String>,
+    /// The caller-supplied labels the entry is attributed to
+    #[serde(default)]
+    pub labels: BTreeMap<String, String>,
+    /// The number of credits consumed
+    pub credits: u64,
+    /// The account's available credit balance
+    /// before the request (if retrieved)
+    pub balance_before: Option<u32>,
+    /// The account's available credit balance
+    /// after the request (if retrieved)
+    pub balance_after: Option<u32>,
+    /// The total number of contacts verified
+    pub total_verified: u64,
+    /// The total number of email addresses verified
+    pub total_verified_emails: u64,
+    /// The total number of phone numbers verified
+    pub total_verified_phones: u64,
+}
+
+impl LedgerEntry {
+    /// The value of the entry's label with the supplied name (if any)
+    pub fn label(&self, name: &str) -> Option<&str> {
+        self.labels.get(name).map(String::as_str)
+    }
+}
+
+// </editor-fold desc="// LedgerEntry ...">
+
+// <editor-fold desc="// Sinks ...">
+
+/// A destination for ledger entries
+pub trait LedgerSink: Send + Sync {
+    /// Record the supplied entry
+    fn record(&self, entry: &LedgerEntry);
+}
+
+impl<Func: Fn(&LedgerEntry) + Send + Sync> LedgerSink for Func {
+    fn record(&self, entry: &LedgerEntry) {
+        self(entry)
+    }
+}
+
+/// A [`LedgerSink`](LedgerSink) that keeps every entry in memory
+///
+/// Clones share the same underlying entries, so a ledger
+/// can be handed to a client and then queried afterward.
+#[derive(Clone, Debug, Default)]
+pub struct InMemoryLedger {
+    entries: Arc<Mutex<Vec<LedgerEntry>>>,
+}
+
+impl InMemoryLedger {
+    /// Create a new, empty `InMemoryLedger`
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Every entry recorded so far, in order
+    pub fn entries(&self) -> Vec<LedgerEntry> {
+        self._entries().clone()
+    }
+
+    /// A summary of every entry recorded so far
+    pub fn total(&self) -> LedgerSummary {
+        self._entries()
+            .iter()
+            .fold(LedgerSummary::default(), |mut summary, entry| {
+                summary._add(entry);
+                summary
+            })
+    }
+
+    /// Summaries of the entries recorded so far, per tenant
+    pub fn by_tenant(&self) -> BTreeMap<Option<String>, LedgerSummary> {
+        summarize(self._entries().iter(), |entry| entry.tenant.clone())
+    }
+
+    /// Summaries of the entries recorded so far, per list
+    /// (with single-transaction verifications under `None`)
+    pub fn by_list(&self) -> BTreeMap<Option<String>, LedgerSummary> {
+        summarize(self._entries().iter(), |entry| entry.list_id.clone())
+    }
+
+    /// Summaries of the entries recorded so far, per
+    /// value of the label with the supplied name
+    pub fn by_label(&self, name: &str) -> BTreeMap<Option<String>, LedgerSummary> {
+        summarize(self._entries().iter(), |entry| {
+            entry.label(name).map(str::to_string)
+        })
+    }
+
+    /// [internal-implementation]
+    /// Lock the ledger's entries
+    fn _entries(&self) -> MutexGuard<'_, Vec<LedgerEntry>> {
+        self.entries
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+impl LedgerSink for InMemoryLedger {
+    fn record(&self, entry: &LedgerEntry) {
+        self._entries().push(entry.clone());
+    }
+}
+
+// </editor-fold desc="// Sinks ...">
+
+// <editor-fold desc="// Summaries ...">
+
+/// The combined credit consumption of several ledger entries
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct LedgerSummary {
+    /// The number of entries summarized
+    pub entries: usize,
+    /// The total number of credits consumed
+    pub credits: u64,
+    /// The total number of contacts verified
+    pub total_verified: u64,
+    /// The total number of email addresses verified
+    pub total_verified_emails: u64,
+    /// The total number of phone numbers verified
+    pub total_verified_phones: u64,
+}
+
+impl LedgerSummary {
+    /// [internal-implementation]
+    /// Add the supplied entry to the summary
+    fn _add(&mut self, entry: &LedgerEntry) {
+        self.entries += 1;
+        self.credits += entry.credits;
+        self.total_verified += entry.total_verified;
+        self.total_verified_emails += entry.total_verified_emails;
+        self.total_verified_phones += entry.total_verified_phones;
+    }
+}
+
+/// Summarize the supplied entries, grouped by
+/// the key the supplied function derives from each
+///
+/// #### Example
+/// ```no_run
+/// # use std::collections::BTreeMap;
+/// # use briteverify_rs::ledger::{summarize, LedgerEntry, LedgerEntryKind, LedgerSummary};
+/// #
+/// # fn doc(entries: Vec<LedgerEntry>) {
+/// let per_kind: BTreeMap<LedgerEntryKind, LedgerSummary> =
+///     summarize(&entries, |entry| entry.kind);
+/// # }
+/// ```
+pub fn summarize<'entries, Key: Ord>(
+    entries: impl IntoIterator<Item = &'entries LedgerEntry>,
+    key: impl Fn(&LedgerEntry) -> Key,
+) -> BTreeMap<Key, LedgerSummary> {
+    let mut summaries: BTreeMap<Key, LedgerSummary> = BTreeMap::new();
+
+    for entry in entries {
+        summaries.entry(key(entry)).or_default()._add(entry);
+    }
+
+    summaries
+}
+
+// </editor-fold desc="// Summaries ...">
+
+// <editor-fold desc="// CreditLedger ...">
+
+/// [internal-implementation]
+/// A bulk verification list whose contacts have
+/// been uploaded, but which hasn't completed yet
+#[derive(Debug, Default)]
+struct PendingList {
+    credits: u64,
+    tags: LedgerTags,
+    balance_before: Option<u32>,
+}
+
+/// [internal-implementation]
+/// The mutable state of a credit ledger
+#[derive(Debug, Default)]
+struct LedgerState {
+    pending: HashMap<String, PendingList>,
+    completed: HashSet<String>,
+}
+
+/// Records the credits a client consumes to a [`LedgerSink`](LedgerSink)
+pub struct CreditLedger {
+    sink: Box<dyn LedgerSink>,
+    snapshot_balances: bool,
+    state: Mutex<LedgerState>,
+}
+
+#[cfg_attr(tarpaulin, coverage(off))]
+impl Debug for CreditLedger {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        formatter
+            .debug_struct("CreditLedger")
+            .field("snapshot_balances", &self.snapshot_balances)
+            .finish_non_exhaustive()
+    }
+}
+
+impl CreditLedger {
+    /// Create a new `CreditLedger` that writes its entries to the supplied sink
+    pub fn new<Sink: LedgerSink + 'static>(sink: Sink) -> Self {
+        Self {
+            sink: Box::new(sink),
+            snapshot_balances: false,
+            state: Mutex::new(LedgerState::default()),
+        }
+    }
+
+    /// Enable or disable retrieving the account's credit balance
+    /// before and after every request (which costs two extra
+    /// requests, but no credits)
+    ///
+    /// ___
+    /// **NOTE:** Balance snapshots are `disabled` by default, and are
+    /// only meaningful for requests that aren't sent concurrently with
+    /// any others (see [`ledger`](crate::ledger) for details).
+    /// ___
+    pub fn snapshot_balances(mut self, value: bool) -> Self {
+        self.snapshot_balances = value;
+        self
+    }
+
+    /// Check whether the account's credit balance is
+    /// retrieved before and after every request
+    pub fn snapshots_balances(&self) -> bool {
+        self.snapshot_balances
+    }
+
+    // <editor-fold desc="// Internal Utility Methods ... ">
+
+    /// [internal-implementation]
+    /// Lock the ledger's mutable state
+    fn _state(&self) -> MutexGuard<'_, LedgerState> {
+        self.state
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    /// [internal-implementation]
+    /// Record a completed single-transaction verification
+    pub(crate) fn _record_single(
+        &self,
+        request: &VerificationRequest,
+        tags: &LedgerTags,
+        balance_before: Option<u32>,
+        balance_after: Option<u32>,
+    ) {
+        self.sink.record(&LedgerEntry {
+            recorded_on: Utc::now(),
+            kind: LedgerEntryKind::Single,
+            tenant: tags.tenant.clone(),
+            list_id: None,
+            labels: tags.labels.clone(),
+            credits: crate::budget::estimate_credits(request),
+            balance_before,
+            balance_after,
+            total_verified: 1,
+            total_verified_emails: u64::from(request.email.is_some()),
+            total_verified_phones: u64::from(request.phone.is_some()),
+        });
+    }
+
+    /// [internal-implementation]
+    /// Check whether the specified list has (or
+    /// will have) its starting balance recorded
+    pub(crate) fn _is_pending(&self, list_id: &str) -> bool {
+        self._state().pending.contains_key(list_id)
+    }
+
+    /// [internal-implementation]
+    /// Record the upload of contacts costing the
+    /// supplied number of credits to the specified list
+    pub(crate) fn _record_upload(
+        &self,
+        list_id: &str,
+        credits: u64,
+        tags: &LedgerTags,
+        balance_before: Option<u32>,
+    ) {
+        let mut state = self._state();
+
+        if state.completed.contains(list_id) {
+            return;
+        }
+
+        let pending = state
+            .pending
+            .entry(list_id.to_string())
+            .or_insert_with(|| PendingList {
+                credits: 0,
+                tags: tags.clone(),
+                balance_before,
+            });
+
+        pending.credits += credits;
+    }
+
+    /// [internal-implementation]
+    /// Check whether the specified list's
+    /// completion has already been recorded
+    pub(crate) fn _is_completed(&self, list_id: &str) -> bool {
+        self._state().completed.contains(list_id)
+    }
+
+    /// [internal-implementation]
+    /// Record the completion of the supplied list (once)
+    pub(crate) fn _record_completion(
+        &self,
+        list: &VerificationListState,
+        tags: &LedgerTags,
+        balance_after: Option<u32>,
+    ) {
+        let pending = {
+            let mut state = self._state();
+
+            if !state.completed.insert(list.id.clone()) {
+                return;
+            }
+
+            state.pending.remove(&list.id)
+        };
+
+        let PendingList {
+            credits,
+            tags,
+            balance_before,
+        } = pending.unwrap_or_else(|| PendingList {
+            credits: list.total_verified_emails + list.total_verified_phones,
+            tags: tags.clone(),
+            balance_before: None,
+        });
+
+        self.sink.record(&LedgerEntry {
+            recorded_on: Utc::now(),
+            kind: LedgerEntryKind::Bulk,
+            tenant: tags.tenant,
+            list_id: Some(list.id.clone()),
+            labels: tags.labels,
+            credits,
+            balance_before,
+            balance_after,
+            total_verified: list.total_verified,
+            total_verified_emails: list.total_verified_emails,
+            total_verified_phones: list.total_verified_phones,
+        });
+    }
+
+    // </editor-fold desc="// Internal Utility Methods ... ">
+}
+
+// </editor-fold desc="// CreditLedger ...">
+
+// <editor-fold desc="// I/O-Free Tests ...">
+
+#[cfg(test)]
+mod tests {
+    // Standard Library Imports
+    use std::collections::BTreeMap;
+
+    // Third-Party Dependencies
+    use pretty_assertions::assert_eq;
+
+    // Crate-Level Imports
+    use super::{CreditLedger, InMemoryLedger, LedgerEntryKind, LedgerSummary, LedgerTags};
+    use crate::types::{VerificationListState, VerificationRequest};
+
+    fn tags(tenant: &str, pipeline: &str) -> LedgerTags {
+        LedgerTags {
+            tenant: Some(tenant.to_string()),
+            labels: BTreeMap::from([("pipeline".to_string(), pipeline.to_string())]),
+        }
+    }
+
+    /// Test that recorded entries are
+    /// attributed and summarized correctly
+    #[rstest::rstest]
+    fn test_ledger_summaries() {
+        let sink = InMemoryLedger::new();
+        let ledger = CreditLedger::new(sink.clone());
+
+        let contact = VerificationRequest {
+            email: Some("test@example.com".to_string()),
+            phone: Some("+15555555555".to_string()),
+            address: None,
+        };
+
+        ledger._record_single(&contact, &tags("acme", "signup"), Some(100), Some(98));
+        ledger._record_upload("list-1", 5, &tags("acme", "nightly"), Some(98));
+        ledger._record_upload("list-1", 3, &tags("other", "other"), None);
+
+        let list = VerificationListState {
+            id: "list-1".to_string(),
+            total_verified: 6,
+            total_verified_emails: 6,
+            total_verified_phones: 2,
+            ..VerificationListState::default()
+        };
+
+        assert!(ledger._is_pending("list-1"));
+
+        ledger._record_completion(&list, &LedgerTags::default(), Some(90));
+        ledger._record_completion(&list, &LedgerTags::default(), Some(90));
+
+        assert!(ledger._is_completed("list-1"));
+        assert!(!ledger._is_pending("list-1"));
+
+        let entries = sink.entries();
+
+        assert_eq!(2, entries.len());
+        assert_eq!(LedgerEntryKind::Bulk, entries[1].kind);
+        assert_eq!(8, entries[1].credits);
+        assert_eq!(
+            (Some(98), Some(90)),
+            (entries[1].balance_before, entries[1].balance_after)
+        );
+        assert_eq!(Some("nightly"), entries[1].label("pipeline"));
+
+        assert_eq!(
+            LedgerSummary {
+                entries: 2,
+                credits: 10,
+                total_verified: 7,
+                total_verified_emails: 7,
+                total_verified_phones: 3,
+            },
+            sink.by_tenant()[&Some("acme".to_string())]
+        );
+        assert_eq!(
+            vec![Some("nightly".to_string()), Some("signup".to_string())],
+            sink.by_label("pipeline").into_keys().collect::<Vec<_>>()
+        );
+        assert_eq!(10, sink.total().credits);
+    }
+}
+
+// </editor-fold desc="// I/O-Free Tests ...">
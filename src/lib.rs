@@ -3,6 +3,7 @@
 #![cfg_attr(tarpaulin, register_tool(tarpaulin))]
 #![cfg_attr(tarpaulin, feature(coverage_attribute))]
 #![deny(missing_docs, missing_debug_implementations)]
+#![cfg_attr(test, allow(missing_docs, clippy::unit_arg))]
 
 //! # briteverify-rs
 //!
@@ -15,8 +16,9 @@
 //! the BriteVerify API from Rust. It tries to be easy to use, with
 //! a focus on allowing devs to quickly get up and running with the API.
 //!
-//! The [`BriteVerifyClient`][BriteVerifyClient] is asynchronous. It
-//! does not currently support synchronous usage at all.
+//! The [`BriteVerifyClient`][BriteVerifyClient] is asynchronous. A
+//! synchronous `BlockingBriteVerifyClient` with the same methods is
+//! available with the `blocking` feature enabled.
 //!
 //! ## Features
 //! `briteverify-rs` provides:
@@ -26,15 +28,33 @@
 //! - Support for all[¹](#first-note) [single-transaction](https://docs.briteverify.com/#79e00732-b734-4308-ac7f-820d62dde01f)
 //!   and [bulk](https://docs.briteverify.com/#382f454d-dad2-49c3-b320-c7d117fcc20a)[²](#second-note) BriteVerify API endpoints
 //! - Easy-to-use API that follows Rust conventions
+//! - A transport-independent ["sans-I/O"](sansio) core for building requests
+//!   and parsing responses with any HTTP client
+//! - A [`BriteVerifyApi`](api::BriteVerifyApi) trait with an in-memory
+//!   implementation for testing code that depends on the API
+//! - A stateful local [simulator](https://docs.rs/briteverify-rs/latest/briteverify_rs/simulator/)
+//!   of the BriteVerify API (with the `simulator` feature enabled)
+//! - Seedable [fake data](https://docs.rs/briteverify-rs/latest/briteverify_rs/fake/)
+//!   factories for every request and response type (with the `fake`
+//!   feature enabled)
+//! - A credit-free [sandbox](sandbox) mode that answers requests
+//!   locally based on "magic" input values
+//! - A [dry-run](dry_run) mode that validates and records requests
+//!   (and estimates their credit cost) instead of sending them
+//! - A pluggable [clock](clock) and sleeper for deterministic
+//!   retry handling and runtime-specific timers
+//! - A persistent, SQLite-backed [store](https://docs.rs/briteverify-rs/latest/briteverify_rs/store/)
+//!   of verification results shared between processes (with the
+//!   `sqlite` feature enabled)
 //!
 //! ---
 //! - <span id="first-note" style="font-weight: bold">1:</span> `briteverify-rs` makes a best-effort attempt to stay current with
-//!    the BriteVerify API, but is ultimately maintained independently.
-//!    Best-effort means that no guarantees are made, but PRs are always
-//!    accepted.
+//!   the BriteVerify API, but is ultimately maintained independently.
+//!   Best-effort means that no guarantees are made, but PRs are always
+//!   accepted.
 //! - <span id="second-note" style="font-weight: bold">2:</span> `briteverify-rs` currently implements support for the `v3` bulk
-//!    endpoints, with no plans to add support for legacy or deprecated
-//!    endpoints (namely the `v2` endpoints in this case).
+//!   endpoints, with no plans to add support for legacy or deprecated
+//!   endpoints (namely the `v2` endpoints in this case).
 //!---
 //!
 //! ## Basic Usage
@@ -54,12 +74,39 @@
 //! # }
 //! ```
 //!
+pub mod api;
+#[cfg(feature = "blocking")]
+pub mod blocking;
+pub mod budget;
+pub mod cache;
+pub mod cassette;
 pub mod client;
+pub mod clock;
+pub mod coalesce;
+pub mod dedupe;
+pub mod dry_run;
 pub mod errors;
+#[cfg(feature = "fake")]
+pub mod fake;
+pub mod idempotency;
+pub mod jobs;
+pub mod ledger;
+pub mod monitor;
+pub mod sandbox;
+pub mod sansio;
+#[cfg(feature = "simulator")]
+pub mod simulator;
+#[cfg(not(feature = "simulator"))]
+pub(crate) mod simulator;
+#[cfg(feature = "sqlite")]
+pub mod store;
 pub mod types;
 #[cfg(any(test, tarpaulin))]
 pub mod utils;
 #[cfg(not(any(test, tarpaulin)))]
 pub(crate) mod utils;
 
-pub use client::{BriteVerifyClient, BriteVerifyClientBuilder};
+pub use api::{BriteVerifyApi, InMemoryBriteVerifyApi};
+#[cfg(feature = "blocking")]
+pub use blocking::BlockingBriteVerifyClient;
+pub use client::{BriteVerifyClient, BriteVerifyClientBuilder, RequestOptions};
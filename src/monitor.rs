@@ -0,0 +1,368 @@
+//! ## Low-Balance Monitoring
+//!
+//! A [`BalanceMonitor`](BalanceMonitor) describes when an account's
+//! credit balance warrants an alert. Handing one to a client's
+//! [`monitor_balance`](crate::BriteVerifyClient::monitor_balance)
+//! produces a [`Stream`](futures::Stream) that periodically retrieves
+//! the account's balance and yields a [`BalanceEvent`](BalanceEvent)
+//! whenever:
+//!
+//! - the available (non-reserve) credit balance drops below the
+//!   configured threshold (once per drop, i.e. the alert is re-armed
+//!   once the balance recovers)
+//! - the number of credits in reserve grows by more than the
+//!   configured amount between two consecutive checks
+//! - credits are consumed faster than the configured rate
+//!   between two consecutive checks
+//!
+//! Failed balance checks are yielded as errors without ending
+//! the stream. Alternatively, [`watch_balance`](crate::BriteVerifyClient::watch_balance)
+//! drives the same checks in the background of a single future,
+//! handing each event to a callback.
+//!
+//! Checks are spaced out with the client's [`Sleeper`](crate::clock::Sleeper),
+//! and elapsed time is measured with its [`Clock`](crate::clock::Clock).
+//!
+//! ## Basic Usage
+//! ```no_run
+//! # use std::time::Duration;
+//! # use futures_util::StreamExt;
+//! # use briteverify_rs::{BriteVerifyClient, monitor::{BalanceEvent, BalanceMonitor}};
+//! #
+//! # async fn doc() -> anyhow::Result<()> {
+//! # let client: BriteVerifyClient = BriteVerifyClient::new("YOUR API KEY")?;
+//! let monitor = BalanceMonitor::new()
+//!     .interval(Duration::from_secs(300))
+//!     .low_balance_threshold(5_000)
+//!     .reserve_growth_threshold(50_000)
+//!     .max_consumption_rate(1_000, Duration::from_secs(3600));
+//!
+//! let mut events = client.monitor_balance(monitor);
+//!
+//! while let Some(event) = events.next().await {
+//!     match event {
+//!         Ok(BalanceEvent::LowBalance { credits, .. }) => {
+//!             println!("Only {credits} credits left!");
+//!         }
+//!         Ok(other) => println!("Credit balance alert: {other:?}"),
+//!         Err(error) => println!("Couldn't check credit balance: {error}"),
+//!     }
+//! }
+//! # Ok(())
+//! # }
+//! ```
+//
+// Standard Library Imports
+use std::{
+    collections::VecDeque,
+    time::{Duration, Instant},
+};
+
+// Third-Party Imports
+use futures::stream::{self, BoxStream, StreamExt};
+
+// Crate-Level Imports
+use crate::{
+    clock::Timekeeper, errors::BriteVerifyClientError, types::AccountCreditBalance,
+    BriteVerifyClient,
+};
+
+// <editor-fold desc="// Constants ...">
+
+/// The default amount of time between balance checks
+pub const DEFAULT_CHECK_INTERVAL: Duration = Duration::from_secs(60);
+
+// </editor-fold desc="// Constants ...">
+
+// <editor-fold desc="// BalanceEvent ...">
+
+/// A noteworthy change in an account's credit balance
+#[derive(Clone, Debug)]
+pub enum BalanceEvent {
+    /// The available (non-reserve) credit balance
+    /// dropped below the configured threshold
+    LowBalance {
+        /// The available credit balance
+        credits: u32,
+        /// The configured threshold
+        threshold: u32,
+        /// The balance that triggered the event
+        balance: AccountCreditBalance,
+    },
+    /// The number of credits in reserve grew by more than
+    /// the configured amount since the previous check
+    ReserveGrowth {
+        /// The number of credits in reserve as of the previous check
+        previous: u32,
+        /// The number of credits in reserve as of the current check
+        current: u32,
+        /// The balance that triggered the event
+        balance: AccountCreditBalance,
+    },
+    /// Credits were consumed faster than the
+    /// configured rate since the previous check
+    RapidConsumption {
+        /// The number of credits consumed since the previous check
+        consumed: u32,
+        /// The amount of time that passed since the previous check
+        elapsed: Duration,
+        /// The balance that triggered the event
+        balance: AccountCreditBalance,
+    },
+}
+
+impl BalanceEvent {
+    /// The balance that triggered the event
+    pub fn balance(&self) -> &AccountCreditBalance {
+        match self {
+            Self::LowBalance { balance, .. }
+            | Self::ReserveGrowth { balance, .. }
+            | Self::RapidConsumption { balance, .. } => balance,
+        }
+    }
+}
+
+// </editor-fold desc="// BalanceEvent ...">
+
+// <editor-fold desc="// BalanceMonitor ...">
+
+/// The conditions under which an account's
+/// credit balance warrants an alert
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BalanceMonitor {
+    interval: Duration,
+    low_balance: Option<u32>,
+    reserve_growth: Option<u32>,
+    consumption_rate: Option<(u32, Duration)>,
+}
+
+impl Default for BalanceMonitor {
+    fn default() -> Self {
+        Self {
+            interval: DEFAULT_CHECK_INTERVAL,
+            low_balance: None,
+            reserve_growth: None,
+            consumption_rate: None,
+        }
+    }
+}
+
+impl BalanceMonitor {
+    /// Create a new `BalanceMonitor` that checks the
+    /// account's balance every minute, but doesn't
+    /// alert on anything until configured to
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the amount of time between balance checks
+    pub fn interval(mut self, value: Duration) -> Self {
+        self.interval = value;
+        self
+    }
+
+    /// Alert when the available (non-reserve) credit
+    /// balance drops below the supplied threshold
+    pub fn low_balance_threshold(mut self, credits: u32) -> Self {
+        self.low_balance = Some(credits);
+        self
+    }
+
+    /// Alert when the number of credits in reserve grows by
+    /// more than the supplied amount between two checks
+    pub fn reserve_growth_threshold(mut self, credits: u32) -> Self {
+        self.reserve_growth = Some(credits);
+        self
+    }
+
+    /// Alert when more than the supplied number of credits
+    /// are consumed per the supplied period of time (as
+    /// measured between two checks)
+    ///
+    /// ___
+    /// **NOTE:** Credits moved into reserve count as consumed,
+    /// while credits added to the balance are ignored.
+    /// ___
+    pub fn max_consumption_rate(mut self, credits: u32, per: Duration) -> Self {
+        self.consumption_rate = Some((credits, per));
+        self
+    }
+
+    // <editor-fold desc="// Internal Utility Methods ... ">
+
+    /// [internal-implementation]
+    /// Determine which events (if any) the supplied
+    /// balance triggers, given the monitor's state
+    fn _evaluate(
+        &self,
+        state: &mut MonitorState,
+        balance: AccountCreditBalance,
+        now: Instant,
+    ) -> Vec<BalanceEvent> {
+        let mut events = Vec::new();
+
+        if let Some(threshold) = self.low_balance {
+            let low = balance.credits < threshold;
+
+            if low && !state.low {
+                events.push(BalanceEvent::LowBalance {
+                    credits: balance.credits,
+                    threshold,
+                    balance: balance.clone(),
+                });
+            }
+
+            state.low = low;
+        }
+
+        if let Some((previous, checked)) = state.previous.take() {
+            let growth = balance
+                .credits_in_reserve
+                .saturating_sub(previous.credits_in_reserve);
+
+            if self.reserve_growth.is_some_and(|limit| growth > limit) {
+                events.push(BalanceEvent::ReserveGrowth {
+                    previous: previous.credits_in_reserve,
+                    current: balance.credits_in_reserve,
+                    balance: balance.clone(),
+                });
+            }
+
+            let consumed = previous.credits.saturating_sub(balance.credits);
+            let elapsed = now.saturating_duration_since(checked);
+
+            if let Some((credits, per)) = self.consumption_rate {
+                // consumed / elapsed > credits / per, without the division
+                if u128::from(consumed) * per.as_millis()
+                    > u128::from(credits) * elapsed.as_millis()
+                {
+                    events.push(BalanceEvent::RapidConsumption {
+                        consumed,
+                        elapsed,
+                        balance: balance.clone(),
+                    });
+                }
+            }
+        }
+
+        state.previous = Some((balance, now));
+
+        events
+    }
+
+    /// [internal-implementation]
+    /// Periodically check the supplied client's balance,
+    /// yielding any events (or errors) encountered
+    pub(crate) fn _stream(
+        self,
+        client: BriteVerifyClient,
+        timekeeper: Timekeeper,
+    ) -> BoxStream<'static, Result<BalanceEvent, BriteVerifyClientError>> {
+        let state = MonitorState::default();
+
+        stream::unfold(
+            (self, client, timekeeper, state),
+            |(monitor, client, timekeeper, mut state)| async move {
+                loop {
+                    if let Some(item) = state.pending.pop_front() {
+                        return Some((item, (monitor, client, timekeeper, state)));
+                    }
+
+                    if state.started {
+                        timekeeper.sleeper.sleep(monitor.interval).await;
+                    }
+
+                    state.started = true;
+
+                    match client.get_account_balance().await {
+                        Ok(balance) => {
+                            let now = timekeeper.clock.now();
+                            let events = monitor._evaluate(&mut state, balance, now);
+
+                            state.pending.extend(events.into_iter().map(Ok));
+                        }
+                        Err(error) => state.pending.push_back(Err(error)),
+                    }
+                }
+            },
+        )
+        .boxed()
+    }
+
+    // </editor-fold desc="// Internal Utility Methods ... ">
+}
+
+/// [internal-implementation]
+/// The mutable state of a running balance monitor
+#[derive(Debug, Default)]
+struct MonitorState {
+    started: bool,
+    low: bool,
+    previous: Option<(AccountCreditBalance, Instant)>,
+    pending: VecDeque<Result<BalanceEvent, BriteVerifyClientError>>,
+}
+
+// </editor-fold desc="// BalanceMonitor ...">
+
+// <editor-fold desc="// I/O-Free Tests ...">
+
+#[cfg(test)]
+mod tests {
+    // Standard Library Imports
+    use std::time::{Duration, Instant};
+
+    // Third-Party Dependencies
+    use chrono::Utc;
+    use pretty_assertions::assert_eq;
+
+    // Crate-Level Imports
+    use super::{BalanceEvent, BalanceMonitor, MonitorState};
+    use crate::types::AccountCreditBalance;
+
+    fn balance(credits: u32, credits_in_reserve: u32) -> AccountCreditBalance {
+        AccountCreditBalance {
+            credits,
+            credits_in_reserve,
+            recorded_on: Utc::now(),
+        }
+    }
+
+    /// Test that balance changes trigger
+    /// the expected (kinds of) events
+    #[rstest::rstest]
+    fn test_evaluate_balance() {
+        let monitor = BalanceMonitor::new()
+            .low_balance_threshold(100)
+            .reserve_growth_threshold(50)
+            .max_consumption_rate(10, Duration::from_secs(60));
+
+        let mut state = MonitorState::default();
+        let start = Instant::now();
+
+        let mut check = |credits: u32, reserve: u32, minutes: u64| -> Vec<&'static str> {
+            monitor
+                ._evaluate(
+                    &mut state,
+                    balance(credits, reserve),
+                    start + Duration::from_secs(minutes * 60),
+                )
+                .into_iter()
+                .map(|event| match event {
+                    BalanceEvent::LowBalance { .. } => "low",
+                    BalanceEvent::ReserveGrowth { .. } => "reserve",
+                    BalanceEvent::RapidConsumption { .. } => "rate",
+                })
+                .collect()
+        };
+
+        assert_eq!(Vec::<&str>::new(), check(500, 0, 0));
+        assert_eq!(Vec::<&str>::new(), check(490, 50, 1));
+        assert_eq!(vec!["reserve", "rate"], check(400, 101, 2));
+        assert_eq!(vec!["low", "rate"], check(90, 101, 3));
+        assert_eq!(Vec::<&str>::new(), check(85, 101, 4));
+        assert_eq!(Vec::<&str>::new(), check(1_000, 0, 5));
+        assert_eq!(vec!["low"], check(99, 0, 600));
+    }
+}
+
+// </editor-fold desc="// I/O-Free Tests ...">
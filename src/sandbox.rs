@@ -0,0 +1,489 @@
+//! ## Credit-Free Sandbox Mode
+//!
+//! A [`BriteVerifyClient`](crate::BriteVerifyClient) built with
+//! [`sandbox(true)`](crate::BriteVerifyClientBuilder::sandbox)
+//! never contacts the BriteVerify API. Instead, every request is
+//! answered locally (by an in-process instance of the
+//! `simulator` module's `BriteVerifySimulator`) without spending
+//! any credits, which makes it possible to exercise every path
+//! through code that consumes the API's responses (e.g. in a
+//! staging environment).
+//!
+//! Single-transaction verifications *and* bulk verification lists
+//! are supported, with each verified value's outcome determined by
+//! the "magic" values below. Values that don't match any of them
+//! are reported as valid.
+//!
+//! #### Email Addresses
+//! Matched against the address's account (the part before the `@`),
+//! ignoring case and any `+tag`:
+//!
+//! | Account       | Status        | Error Code              | Notes                 |
+//! |---------------|---------------|-------------------------|-----------------------|
+//! | `valid`       | `valid`       |                         |                       |
+//! | `role`        | `valid`       |                         | `role_address: true`  |
+//! | `acceptall`   | `accept_all`  |                         |                       |
+//! | `unknown`     | `unknown`     |                         |                       |
+//! | `invalid`     | `invalid`     | `email_address_invalid` |                       |
+//! | `disposable`  | `invalid`     | `disposable`            | `disposable: true`    |
+//! | `roleaddress` | `invalid`     | `role_address`          | `role_address: true`  |
+//! | `mailboxfull` | `invalid`     | `mailbox_full_invalid`  |                       |
+//! | `baddomain`   | `invalid`     | `email_domain_invalid`  |                       |
+//! | `badaccount`  | `invalid`     | `email_account_invalid` |                       |
+//!
+//! Addresses without exactly one `@` (or with an empty account
+//! or domain) are reported as `invalid` with an `invalid_format`
+//! error code.
+//!
+//! #### Phone Numbers
+//! Matched against the number's last four digits:
+//!
+//! | Last Digits    | Status    | Errors                 | Service Type |
+//! |----------------|-----------|------------------------|--------------|
+//! | `0001`         | `invalid` | `invalid_phone_number` |              |
+//! | `0002`         | `invalid` | `invalid_prefix`       |              |
+//! | `0003`         | `invalid` | `invalid_format`       |              |
+//! | `0004`         | `unknown` |                        |              |
+//! | `0005`         | `valid`   |                        | `land`       |
+//! | *(no digits)*  | `invalid` | `blank_phone_number`   |              |
+//! | *(any other)*  | `valid`   |                        | `mobile`     |
+//!
+//! #### Street Addresses
+//! Matched against the address's 5-digit ZIP code:
+//!
+//! | ZIP Code | Status    | Errors                    |
+//! |----------|-----------|---------------------------|
+//! | `00001`  | `invalid` | `zip_code_invalid`        |
+//! | `00002`  | `invalid` | `unknown_street`          |
+//! | `00003`  | `invalid` | `street_number_invalid`   |
+//! | `00004`  | `invalid` | `street_number_missing`   |
+//! | `00005`  | `invalid` | `suite_missing`           |
+//! | `00006`  | `invalid` | `suite_invalid`           |
+//! | `00007`  | `invalid` | `suite_invalid_missing`   |
+//! | `00008`  | `invalid` | `box_number_missing`      |
+//! | `00009`  | `invalid` | `box_number_invalid`      |
+//! | `00010`  | `invalid` | `pmb_required`            |
+//! | `00011`  | `invalid` | `multiple_match`          |
+//! | `00012`  | `invalid` | `directionals_invalid`    |
+//! | `00013`  | `invalid` | `non_deliverable_address` |
+//! | `00014`  | `invalid` | `missing_minimum_inputs`  |
+//! | `00015`  | `unknown` |                           |
+//! | `00016`  | `valid`   | *(none, but `corrected`)* |
+//!
+//! ## Basic Usage
+//! ```no_run
+//! # use briteverify_rs::{BriteVerifyClient, types::{VerificationError, VerificationStatus}};
+//! #
+//! # async fn doc() -> anyhow::Result<()> {
+//! let client: BriteVerifyClient = BriteVerifyClient::builder()
+//!     .api_key("YOUR API KEY")
+//!     .sandbox(true)
+//!     .build()?;
+//!
+//! let response = client.verify_email("disposable@example.com").await?;
+//!
+//! assert_eq!(response.status, VerificationStatus::Invalid);
+//! assert_eq!(response.error_code, Some(VerificationError::Disposable));
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! The magic-value mapping is also available on its own (e.g. as
+//! the verifier of an [`InMemoryBriteVerifyApi`](crate::InMemoryBriteVerifyApi))
+//! via [`magic_response`](magic_response).
+//
+// Standard Library Imports
+use std::time::Duration;
+
+// Third-Party Imports
+use serde_json::Value;
+
+// Crate-Level Imports
+use crate::types::{
+    AddressVerificationArray, EmailVerificationArray, PhoneNumberVerificationArray,
+    StreetAddressArray, VerificationError, VerificationRequest, VerificationResponse,
+    VerificationStatus,
+};
+
+// <editor-fold desc="// Magic Values ...">
+
+/// Build the response the sandbox gives for the supplied request
+/// based on the "magic" values it contains (see the
+/// [module-level documentation](self) for the complete mapping)
+///
+/// #### Example
+/// ```no_run
+/// # use briteverify_rs::{sandbox, types::{VerificationRequest, VerificationStatus}};
+/// #
+/// # fn doc() -> anyhow::Result<()> {
+/// let request = VerificationRequest::try_from("acceptall@example.com")?;
+/// let response = sandbox::magic_response(&request);
+///
+/// assert_eq!(response.email.unwrap().status, VerificationStatus::AcceptAll);
+/// # Ok(())
+/// # }
+/// ```
+pub fn magic_response(request: &VerificationRequest) -> VerificationResponse {
+    VerificationResponse {
+        email: request.email.as_deref().map(_email),
+        phone: request.phone.as_deref().map(_phone),
+        address: request.address.as_ref().map(_address),
+        duration: Duration::ZERO,
+    }
+}
+
+/// [internal-implementation]
+/// Build the sandbox's verification of an email address
+fn _email(address: &str) -> EmailVerificationArray {
+    let (account, domain) = address.rsplit_once('@').unwrap_or((address, ""));
+    let magic = account
+        .split('+')
+        .next()
+        .unwrap_or_default()
+        .to_ascii_lowercase();
+
+    let mut response = EmailVerificationArray {
+        address: address.to_string(),
+        account: account.to_string(),
+        domain: domain.to_string(),
+        status: VerificationStatus::Valid,
+        connected: None,
+        disposable: false,
+        role_address: false,
+        error_code: None,
+        error: None,
+    };
+
+    let well_formed = !account.is_empty() && !domain.is_empty() && !account.contains('@');
+
+    let (status, error) = match magic.as_str() {
+        _ if !well_formed => (
+            VerificationStatus::Invalid,
+            Some(VerificationError::InvalidFormat),
+        ),
+        "role" => {
+            response.role_address = true;
+            (VerificationStatus::Valid, None)
+        }
+        "acceptall" => (VerificationStatus::AcceptAll, None),
+        "unknown" => (VerificationStatus::Unknown, None),
+        "invalid" => (
+            VerificationStatus::Invalid,
+            Some(VerificationError::EmailAddressInvalid),
+        ),
+        "disposable" => {
+            response.disposable = true;
+            (
+                VerificationStatus::Invalid,
+                Some(VerificationError::Disposable),
+            )
+        }
+        "roleaddress" => {
+            response.role_address = true;
+            (
+                VerificationStatus::Invalid,
+                Some(VerificationError::RoleAddress),
+            )
+        }
+        "mailboxfull" => (
+            VerificationStatus::Invalid,
+            Some(VerificationError::MailboxFullInvalid),
+        ),
+        "baddomain" => (
+            VerificationStatus::Invalid,
+            Some(VerificationError::EmailDomainInvalid),
+        ),
+        "badaccount" => (
+            VerificationStatus::Invalid,
+            Some(VerificationError::EmailAccountInvalid),
+        ),
+        _ => (VerificationStatus::Valid, None),
+    };
+
+    response.status = status;
+    response.error = error.map(VerificationError::_message);
+    response.error_code = error;
+
+    response
+}
+
+/// [internal-implementation]
+/// Build the sandbox's verification of a phone number
+fn _phone(number: &str) -> PhoneNumberVerificationArray {
+    let digits: String = number.chars().filter(char::is_ascii_digit).collect();
+    let magic = &digits[digits.len().saturating_sub(4)..];
+
+    let (status, error, service_type) = match magic {
+        "" => (
+            VerificationStatus::Invalid,
+            Some(VerificationError::BlankPhoneNumber),
+            None,
+        ),
+        "0001" => (
+            VerificationStatus::Invalid,
+            Some(VerificationError::InvalidPhoneNumber),
+            None,
+        ),
+        "0002" => (
+            VerificationStatus::Invalid,
+            Some(VerificationError::InvalidPrefix),
+            None,
+        ),
+        "0003" => (
+            VerificationStatus::Invalid,
+            Some(VerificationError::InvalidFormat),
+            None,
+        ),
+        "0004" => (VerificationStatus::Unknown, None, None),
+        "0005" => (VerificationStatus::Valid, None, Some("land")),
+        _ => (VerificationStatus::Valid, None, Some("mobile")),
+    };
+
+    PhoneNumberVerificationArray {
+        number: digits,
+        status,
+        service_type: service_type.map(str::to_string),
+        phone_location: None,
+        errors: error.into_iter().map(_error_value).collect(),
+    }
+}
+
+/// [internal-implementation]
+/// Build the sandbox's verification of a street address
+fn _address(address: &StreetAddressArray) -> AddressVerificationArray {
+    let zip: String = address.zip.trim().chars().take(5).collect();
+
+    let (status, error, corrected) = match zip.as_str() {
+        "00001" => (
+            VerificationStatus::Invalid,
+            Some(VerificationError::ZipCodeInvalid),
+            false,
+        ),
+        "00002" => (
+            VerificationStatus::Invalid,
+            Some(VerificationError::UnknownStreet),
+            false,
+        ),
+        "00003" => (
+            VerificationStatus::Invalid,
+            Some(VerificationError::StreetNumberInvalid),
+            false,
+        ),
+        "00004" => (
+            VerificationStatus::Invalid,
+            Some(VerificationError::StreetNumberMissing),
+            false,
+        ),
+        "00005" => (
+            VerificationStatus::Invalid,
+            Some(VerificationError::SuiteMissing),
+            false,
+        ),
+        "00006" => (
+            VerificationStatus::Invalid,
+            Some(VerificationError::SuiteInvalid),
+            false,
+        ),
+        "00007" => (
+            VerificationStatus::Invalid,
+            Some(VerificationError::SuiteInvalidMissing),
+            false,
+        ),
+        "00008" => (
+            VerificationStatus::Invalid,
+            Some(VerificationError::BoxNumberMissing),
+            false,
+        ),
+        "00009" => (
+            VerificationStatus::Invalid,
+            Some(VerificationError::BoxNumberInvalid),
+            false,
+        ),
+        "00010" => (
+            VerificationStatus::Invalid,
+            Some(VerificationError::PMBRequired),
+            false,
+        ),
+        "00011" => (
+            VerificationStatus::Invalid,
+            Some(VerificationError::MultipleMatch),
+            false,
+        ),
+        "00012" => (
+            VerificationStatus::Invalid,
+            Some(VerificationError::DirectionalsInvalid),
+            false,
+        ),
+        "00013" => (
+            VerificationStatus::Invalid,
+            Some(VerificationError::NonDeliverableAddress),
+            false,
+        ),
+        "00014" => (
+            VerificationStatus::Invalid,
+            Some(VerificationError::MissingMinimumInputs),
+            false,
+        ),
+        "00015" => (VerificationStatus::Unknown, None, false),
+        "00016" => (VerificationStatus::Valid, None, true),
+        _ => (VerificationStatus::Valid, None, false),
+    };
+
+    AddressVerificationArray {
+        address1: address.address1.clone(),
+        address2: address.address2.clone(),
+        city: address.city.clone(),
+        state: address.state.clone(),
+        zip: address.zip.clone(),
+        status,
+        corrected,
+        errors: error.into_iter().map(_error_value).collect(),
+        secondary_status: None,
+    }
+}
+
+/// [internal-implementation]
+/// Render a verification error the way the BriteVerify
+/// API does in the `errors` array of phone number and
+/// street address verifications
+fn _error_value(error: VerificationError) -> Value {
+    Value::String(error.to_string())
+}
+
+// </editor-fold desc="// Magic Values ...">
+
+// <editor-fold desc="// I/O-Free Tests ...">
+
+#[cfg(test)]
+mod tests {
+    // Third-Party Dependencies
+    use pretty_assertions::assert_eq;
+
+    // Crate-Level Imports
+    use super::{_address, _email, _phone};
+    use crate::types::{StreetAddressArray, VerificationError, VerificationStatus};
+
+    /// Test that the sandbox maps magic email
+    /// addresses to the expected outcomes
+    #[rstest::rstest]
+    #[case("valid@example.com", VerificationStatus::Valid, None)]
+    #[case("someone@example.com", VerificationStatus::Valid, None)]
+    #[case("AcceptAll+tag@example.com", VerificationStatus::AcceptAll, None)]
+    #[case("unknown@example.com", VerificationStatus::Unknown, None)]
+    #[case(
+        "invalid@example.com",
+        VerificationStatus::Invalid,
+        Some(VerificationError::EmailAddressInvalid)
+    )]
+    #[case(
+        "disposable@example.com",
+        VerificationStatus::Invalid,
+        Some(VerificationError::Disposable)
+    )]
+    #[case(
+        "baddomain@example.com",
+        VerificationStatus::Invalid,
+        Some(VerificationError::EmailDomainInvalid)
+    )]
+    #[case(
+        "valid@",
+        VerificationStatus::Invalid,
+        Some(VerificationError::InvalidFormat)
+    )]
+    fn test_magic_emails(
+        #[case] address: &str,
+        #[case] status: VerificationStatus,
+        #[case] error: Option<VerificationError>,
+    ) {
+        let response = _email(address);
+
+        assert_eq!(status, response.status);
+        assert_eq!(error, response.error_code);
+        assert_eq!(
+            address.starts_with("disposable"),
+            response.disposable,
+            "Expected only the disposable address to be disposable"
+        );
+    }
+
+    /// Test that the sandbox maps magic phone
+    /// numbers to the expected outcomes
+    #[rstest::rstest]
+    #[case("+1 (555) 555-1234", VerificationStatus::Valid, None)]
+    #[case(
+        "555-555-0001",
+        VerificationStatus::Invalid,
+        Some(VerificationError::InvalidPhoneNumber)
+    )]
+    #[case(
+        "555-555-0002",
+        VerificationStatus::Invalid,
+        Some(VerificationError::InvalidPrefix)
+    )]
+    #[case("555-555-0004", VerificationStatus::Unknown, None)]
+    #[case(
+        "not a number",
+        VerificationStatus::Invalid,
+        Some(VerificationError::BlankPhoneNumber)
+    )]
+    fn test_magic_phone_numbers(
+        #[case] number: &str,
+        #[case] status: VerificationStatus,
+        #[case] error: Option<VerificationError>,
+    ) {
+        let response = _phone(number);
+        let errors: Vec<serde_json::Value> = error
+            .into_iter()
+            .map(|error| serde_json::Value::String(error.to_string()))
+            .collect();
+
+        assert_eq!(status, response.status);
+        assert_eq!(errors, response.errors);
+    }
+
+    /// Test that the sandbox maps magic ZIP
+    /// codes to the expected outcomes
+    #[rstest::rstest]
+    #[case("90210", VerificationStatus::Valid, None, false)]
+    #[case("00016-1234", VerificationStatus::Valid, None, true)]
+    #[case("00015", VerificationStatus::Unknown, None, false)]
+    #[case("00002", VerificationStatus::Invalid, Some("unknown_street"), false)]
+    #[case("00010", VerificationStatus::Invalid, Some("pmb_required"), false)]
+    fn test_magic_addresses(
+        #[case] zip: &str,
+        #[case] status: VerificationStatus,
+        #[case] error: Option<&str>,
+        #[case] corrected: bool,
+    ) {
+        let address = StreetAddressArray::builder()
+            .address1("123 Main St")
+            .city("Any Town")
+            .state("CA")
+            .zip(zip)
+            .build()
+            .unwrap();
+        let response = _address(&address);
+
+        assert_eq!(status, response.status);
+        assert_eq!(corrected, response.corrected);
+        assert_eq!(
+            error
+                .map(serde_json::Value::from)
+                .into_iter()
+                .collect::<Vec<_>>(),
+            response.errors
+        );
+    }
+
+    /// Test that email error messages are
+    /// rendered the way the BriteVerify API does
+    #[rstest::rstest]
+    fn test_error_message() {
+        assert_eq!(
+            "Email account invalid",
+            VerificationError::EmailAccountInvalid._message()
+        );
+    }
+}
+
+// </editor-fold desc="// I/O-Free Tests ...">
@@ -0,0 +1,674 @@
+//! ## Transport-Independent ("Sans-I/O") Request Building & Response Parsing
+//!
+//! [`BriteVerifyCore`](BriteVerifyCore) turns each BriteVerify API
+//! operation into a plain [`http::Request`](http::Request) and
+//! interprets the corresponding [`http::Response`](http::Response)
+//! back into the crate's typed results and errors. It performs no
+//! I/O of its own, so any HTTP client (or none at all) can be used
+//! to actually exchange the requests and responses it deals in.
+//!
+//! [`BriteVerifyClient`](crate::BriteVerifyClient) is a `reqwest`-based
+//! adapter built on top of it.
+//!
+//! ## Basic Usage
+//! ```no_run
+//! # use briteverify_rs::{sansio::{BriteVerifyCore, HttpRequest, HttpResponse}, types::AccountCreditBalance};
+//! #
+//! # fn send(request: HttpRequest) -> HttpResponse { unimplemented!() }
+//! # fn doc() -> anyhow::Result<()> {
+//! let core: BriteVerifyCore = BriteVerifyCore::new("YOUR API KEY")?;
+//!
+//! let request: HttpRequest = core.account_balance_request()?;
+//! let response: HttpResponse = send(request); // <- your transport of choice
+//!
+//! let balance: AccountCreditBalance = core.parse_account_balance(response)?;
+//! # Ok(())
+//! # }
+//! ```
+//
+// Standard Library Imports
+use std::{fmt::Debug, time::Duration};
+
+// Third-Party Imports
+use anyhow::Context;
+use http::{
+    header::{HeaderValue, AUTHORIZATION, CONTENT_TYPE, RETRY_AFTER},
+    Method, StatusCode,
+};
+use serde::de::DeserializeOwned;
+
+// Crate-Level Imports
+use crate::{errors::BriteVerifyClientError, types, utils::ExtensibleUrl};
+
+// <editor-fold desc="// Constants ...">
+
+/// An HTTP request ready to be sent to the BriteVerify API
+pub type HttpRequest = http::Request<Vec<u8>>;
+
+/// An HTTP response received from the BriteVerify API
+pub type HttpResponse = http::Response<Vec<u8>>;
+
+pub(crate) static V1_API_BASE_URL: &str = "https://bpi.briteverify.com/api/v1";
+pub(crate) static V3_API_BASE_URL: &str = "https://bulk-api.briteverify.com/api/v3";
+
+/// The number of seconds to wait before retrying
+/// a rate-limited request if the BriteVerify API
+/// doesn't say how long to wait
+const DEFAULT_RETRY_AFTER: u64 = 60;
+
+// </editor-fold desc="// Constants ...">
+
+// <editor-fold desc="// BriteVerifyCore ...">
+
+/// Transport-independent builder of BriteVerify API requests
+/// and interpreter of BriteVerify API responses
+///
+/// ## Basic Usage
+/// ```no_run
+/// # use briteverify_rs::sansio::{BriteVerifyCore, HttpRequest};
+/// #
+/// # fn doc() -> anyhow::Result<()> {
+/// let core: BriteVerifyCore = BriteVerifyCore::new("YOUR API KEY")?;
+///
+/// let request: HttpRequest = core.get_list_request("some-list-id", <Option<&str>>::None)?;
+///
+/// assert_eq!(request.uri().path(), "/api/v3/lists/some-list-id");
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+#[cfg_attr(test, visible::StructFields(pub))]
+pub struct BriteVerifyCore {
+    api_key: Option<HeaderValue>,
+    v1_base_url: url::Url,
+    v3_base_url: url::Url,
+}
+
+impl Default for BriteVerifyCore {
+    fn default() -> Self {
+        Self {
+            api_key: None,
+            v1_base_url: url::Url::parse(V1_API_BASE_URL)
+                .expect("Couldn't parse default v1 base url"),
+            v3_base_url: url::Url::parse(V3_API_BASE_URL)
+                .expect("Couldn't parse default v3 base url"),
+        }
+    }
+}
+
+impl BriteVerifyCore {
+    // <editor-fold desc="// Constructors ... ">
+
+    /// Create a new [`BriteVerifyCore`](BriteVerifyCore) instance
+    /// that authorizes every request it builds with the supplied
+    /// API key and targets the official BriteVerify API endpoints
+    ///
+    /// #### Example
+    /// ```no_run
+    /// # use briteverify_rs::sansio::BriteVerifyCore;
+    /// #
+    /// # fn doc() -> anyhow::Result<()> {
+    /// let core: BriteVerifyCore = BriteVerifyCore::new("YOUR API KEY")?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn new<ApiKey: ToString>(api_key: ApiKey) -> Result<Self, BriteVerifyClientError> {
+        let api_key: String = format!(
+            "ApiKey: {}",
+            api_key.to_string().replace("ApiKey: ", "").trim()
+        );
+
+        let mut header = HeaderValue::from_str(&api_key)?;
+        header.set_sensitive(true);
+
+        Ok(Self {
+            api_key: Some(header),
+            ..Self::default()
+        })
+    }
+
+    /// [internal-implementation]
+    /// Create a new instance from already-validated components
+    pub(crate) fn from_parts(
+        api_key: Option<HeaderValue>,
+        v1_base_url: url::Url,
+        v3_base_url: url::Url,
+    ) -> Self {
+        Self {
+            api_key,
+            v1_base_url,
+            v3_base_url,
+        }
+    }
+
+    /// Override the base URL used for single-transaction (v1) requests
+    ///
+    /// #### Example
+    /// ```no_run
+    /// # use briteverify_rs::sansio::BriteVerifyCore;
+    /// #
+    /// # fn doc() -> anyhow::Result<()> {
+    /// let core: BriteVerifyCore = BriteVerifyCore::new("YOUR API KEY")?
+    ///     .v1_base_url("http://localhost:8080/api/v1".parse()?);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn v1_base_url(mut self, url: url::Url) -> Self {
+        self.v1_base_url = url;
+        self
+    }
+
+    /// Override the base URL used for bulk (v3) requests
+    ///
+    /// #### Example
+    /// ```no_run
+    /// # use briteverify_rs::sansio::BriteVerifyCore;
+    /// #
+    /// # fn doc() -> anyhow::Result<()> {
+    /// let core: BriteVerifyCore = BriteVerifyCore::new("YOUR API KEY")?
+    ///     .v3_base_url("http://localhost:8080/api/v3".parse()?);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn v3_base_url(mut self, url: url::Url) -> Self {
+        self.v3_base_url = url;
+        self
+    }
+
+    // </editor-fold desc="// Constructors ... ">
+
+    // <editor-fold desc="// Internal Utility Methods ... ">
+
+    /// [internal-implementation]
+    /// Build a request with the supplied method, url, and (optional) JSON body
+    fn _request<Body: serde::Serialize>(
+        &self,
+        method: Method,
+        url: url::Url,
+        body: Option<&Body>,
+    ) -> Result<HttpRequest, BriteVerifyClientError> {
+        let mut builder = http::Request::builder().method(method).uri(url.as_str());
+
+        if let Some(key) = self.api_key.as_ref() {
+            builder = builder.header(AUTHORIZATION, key.clone());
+        }
+
+        let body = match body {
+            None => Vec::new(),
+            Some(data) => {
+                builder = builder.header(CONTENT_TYPE, "application/json");
+                serde_json::to_vec(data).context("Could not serialize request body")?
+            }
+        };
+
+        Ok(builder
+            .body(body)
+            .context("Could not build a usable request")?)
+    }
+
+    /// [internal-implementation]
+    /// Build a body-less `GET` request for the supplied url
+    fn _get(&self, url: url::Url) -> Result<HttpRequest, BriteVerifyClientError> {
+        self._request(Method::GET, url, Option::<&()>::None)
+    }
+
+    /// [internal-implementation]
+    /// Get the v3 base url, scoped to the supplied
+    /// customer-specific identifier (if any)
+    fn _v3_url_for<ExternalId: std::fmt::Display>(
+        &self,
+        external_id: Option<ExternalId>,
+    ) -> url::Url {
+        external_id
+            .map(|ext_id| {
+                self.v3_base_url
+                    .extend_path(["accounts".to_string(), ext_id.to_string()])
+            })
+            .unwrap_or_else(|| self.v3_base_url.clone())
+    }
+
+    /// [internal-implementation]
+    /// Interpret the supplied response, deserializing its body
+    /// if its status is one of the `expected` status codes and
+    /// translating it into the appropriate error otherwise
+    fn _interpret<T: DeserializeOwned>(
+        response: HttpResponse,
+        expected: &[StatusCode],
+        not_found: Option<(&[StatusCode], Option<String>)>,
+    ) -> Result<T, BriteVerifyClientError> {
+        let status = response.status();
+
+        if status == StatusCode::UNAUTHORIZED {
+            return Err(BriteVerifyClientError::InvalidApiKey);
+        }
+
+        if expected.contains(&status) {
+            return Ok(serde_json::from_slice::<T>(response.body())?);
+        }
+
+        match not_found {
+            Some((codes, list_id)) if codes.contains(&status) => Err(
+                BriteVerifyClientError::BulkListNotFound(Box::new(types::BulkListCRUDError {
+                    list_id,
+                    ..serde_json::from_slice::<types::BulkListCRUDError>(response.body())?
+                })),
+            ),
+            _ => Err(BriteVerifyClientError::UnusableResponse(Box::new(
+                reqwest::Response::from(response),
+            ))),
+        }
+    }
+
+    // </editor-fold desc="// Internal Utility Methods ... ">
+
+    // <editor-fold desc="// Rate Limit Handling ... ">
+
+    /// Determine how long to wait before re-sending a request
+    /// that produced the supplied response, if the response
+    /// indicates that the request was rate limited
+    ///
+    /// #### Example
+    /// ```no_run
+    /// # use std::time::Duration;
+    /// # use briteverify_rs::sansio::{BriteVerifyCore, HttpResponse};
+    /// #
+    /// # fn doc(response: HttpResponse) {
+    /// if let Some(delay) = BriteVerifyCore::retry_delay(&response) {
+    ///     std::thread::sleep(delay);
+    ///     // ... re-send the request
+    /// }
+    /// # }
+    /// ```
+    pub fn retry_delay<Body>(response: &http::Response<Body>) -> Option<Duration> {
+        Self::_retry_delay(response.status(), response.headers())
+    }
+
+    /// [internal-implementation]
+    /// Determine how long to wait before re-sending a request
+    /// given the status and headers of its response
+    pub(crate) fn _retry_delay(status: StatusCode, headers: &http::HeaderMap) -> Option<Duration> {
+        if status != StatusCode::TOO_MANY_REQUESTS {
+            return None;
+        }
+
+        let retry_after = 1 + headers
+            .get(RETRY_AFTER)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.parse::<u64>().ok())
+            .unwrap_or(DEFAULT_RETRY_AFTER);
+
+        Some(Duration::from_secs(retry_after))
+    }
+
+    // </editor-fold desc="// Rate Limit Handling ... ">
+
+    // <editor-fold desc="// Request Builders ... ">
+
+    /// Build a request for your current account credit balance
+    /// [[ref](https://docs.briteverify.com/#07beceb3-2961-4d5b-93a4-9cfeb30f42fa)]
+    pub fn account_balance_request(&self) -> Result<HttpRequest, BriteVerifyClientError> {
+        self._get(self.v3_base_url.extend_path(["accounts", "credits"]))
+    }
+
+    /// Build a single-transaction verification request
+    /// [[ref](https://docs.briteverify.com/#a7246384-e91e-48a9-8aed-7b71cb74dd42)]
+    pub fn verification_request(
+        &self,
+        request: &types::VerificationRequest,
+    ) -> Result<HttpRequest, BriteVerifyClientError> {
+        self._request(
+            Method::POST,
+            self.v1_base_url.append_path("fullverify"),
+            Some(request),
+        )
+    }
+
+    /// Build a request for the bulk verification lists
+    /// matching the supplied filter criteria
+    /// [[ref](https://docs.briteverify.com/#0b5a2a7a-4062-4327-ab0a-4675592e3cd6)]
+    pub fn get_lists_request<
+        Date: chrono::Datelike + Debug,
+        Page: Into<u32> + Debug,
+        State: Clone + Debug + Into<types::BatchState>,
+        ExternalId: std::fmt::Display + Debug,
+    >(
+        &self,
+        page: Option<Page>,
+        date: Option<Date>,
+        state: Option<State>,
+        ext_id: Option<ExternalId>,
+    ) -> Result<HttpRequest, BriteVerifyClientError> {
+        let mut params: Vec<(&str, String)> = Vec::new();
+
+        if let Some(page) = page {
+            params.push(("page", page.into().to_string()));
+        }
+
+        if let Some(date) = date {
+            params.push((
+                "date",
+                format!("{}-{:0>2}-{:0>2}", date.year(), date.month(), date.day()),
+            ));
+        }
+
+        if let Some(state) = state {
+            let filter = state.clone().into();
+
+            if matches!(filter, types::BatchState::Unknown) {
+                log::warn!("Declining to include unknown list state as request filter: {state:#?}");
+            } else {
+                params.push(("state", filter.to_string()));
+            }
+        }
+
+        let mut url = self._v3_url_for(ext_id).append_path("lists");
+
+        if !params.is_empty() {
+            url.query_pairs_mut().extend_pairs(params);
+        }
+
+        self._get(url)
+    }
+
+    /// Build a request to create a new or mutate an
+    /// extant bulk verification list
+    /// [[ref](https://docs.briteverify.com/#38b4c9eb-31b1-4b8e-9295-a783d8043bc1)]
+    pub fn create_or_update_list_request<ListId: ToString>(
+        &self,
+        list_id: Option<ListId>,
+        request: &types::BulkVerificationRequest,
+    ) -> Result<HttpRequest, BriteVerifyClientError> {
+        let mut url = self.v3_base_url.append_path("lists");
+
+        if let Some(id) = list_id {
+            url = url.append_path(id.to_string());
+        }
+
+        self._request(Method::POST, url, Some(request))
+    }
+
+    /// Build a request for the current "state" of
+    /// the specified bulk verification list
+    /// [[ref](https://docs.briteverify.com/#b09c09dc-e11e-44a8-b53d-9f1fd9c6792d)]
+    pub fn get_list_request<ListId: ToString, ExternalId: std::fmt::Display>(
+        &self,
+        list_id: ListId,
+        external_id: Option<ExternalId>,
+    ) -> Result<HttpRequest, BriteVerifyClientError> {
+        self._get(
+            self._v3_url_for(external_id)
+                .extend_path(["lists".to_string(), list_id.to_string()]),
+        )
+    }
+
+    /// Build a request to delete the specified bulk verification list
+    /// [[ref](https://docs.briteverify.com/#6c9b9c05-a4a0-435e-a064-af7d9476719d)]
+    pub fn delete_list_request<ListId: ToString>(
+        &self,
+        list_id: ListId,
+    ) -> Result<HttpRequest, BriteVerifyClientError> {
+        self._request(
+            Method::DELETE,
+            self.v3_base_url
+                .extend_path(["lists".to_string(), list_id.to_string()]),
+            Option::<&()>::None,
+        )
+    }
+
+    /// Build a request for the specified page of results
+    /// from the specified bulk verification list
+    /// [[ref](https://docs.briteverify.com/#0a0cc29d-6d9f-4b0d-9aa5-4166775a8831)]
+    pub fn result_page_request<ListId: ToString>(
+        &self,
+        list_id: ListId,
+        page_number: u64,
+    ) -> Result<HttpRequest, BriteVerifyClientError> {
+        self._get(self.v3_base_url.extend_path([
+            "lists".to_string(),
+            list_id.to_string(),
+            "export".to_string(),
+            page_number.to_string(),
+        ]))
+    }
+
+    // </editor-fold desc="// Request Builders ... ">
+
+    // <editor-fold desc="// Response Parsers ... ">
+
+    /// Interpret the response to an
+    /// [`account_balance_request`](BriteVerifyCore::account_balance_request)
+    pub fn parse_account_balance(
+        &self,
+        response: HttpResponse,
+    ) -> Result<types::AccountCreditBalance, BriteVerifyClientError> {
+        Self::_interpret(response, &[StatusCode::OK], None)
+    }
+
+    /// Interpret the response to a
+    /// [`verification_request`](BriteVerifyCore::verification_request)
+    pub fn parse_verification(
+        &self,
+        response: HttpResponse,
+    ) -> Result<types::VerificationResponse, BriteVerifyClientError> {
+        Self::_interpret(response, &[StatusCode::OK], None)
+    }
+
+    /// Interpret the response to a
+    /// [`get_lists_request`](BriteVerifyCore::get_lists_request)
+    pub fn parse_get_lists(
+        &self,
+        response: HttpResponse,
+    ) -> Result<types::GetListStatesResponse, BriteVerifyClientError> {
+        Self::_interpret(response, &[StatusCode::OK], None)
+    }
+
+    /// Interpret the response to a
+    /// [`create_or_update_list_request`](BriteVerifyCore::create_or_update_list_request)
+    pub fn parse_create_or_update_list<ListId: ToString>(
+        &self,
+        response: HttpResponse,
+        list_id: Option<ListId>,
+    ) -> Result<types::CreateListResponse, BriteVerifyClientError> {
+        Self::_interpret(
+            response,
+            &[StatusCode::OK, StatusCode::CREATED],
+            Some((
+                &[StatusCode::NOT_FOUND, StatusCode::BAD_REQUEST],
+                list_id.map(|id| id.to_string()),
+            )),
+        )
+    }
+
+    /// Interpret the response to a
+    /// [`get_list_request`](BriteVerifyCore::get_list_request)
+    pub fn parse_get_list<ListId: ToString>(
+        &self,
+        response: HttpResponse,
+        list_id: ListId,
+    ) -> Result<types::VerificationListState, BriteVerifyClientError> {
+        Self::_interpret(
+            response,
+            &[StatusCode::OK],
+            Some((&[StatusCode::NOT_FOUND], Some(list_id.to_string()))),
+        )
+    }
+
+    /// Interpret the response to a
+    /// [`delete_list_request`](BriteVerifyCore::delete_list_request)
+    pub fn parse_delete_list<ListId: ToString>(
+        &self,
+        response: HttpResponse,
+        list_id: ListId,
+    ) -> Result<types::DeleteListResponse, BriteVerifyClientError> {
+        Self::_interpret(
+            response,
+            &[StatusCode::OK, StatusCode::ACCEPTED, StatusCode::NO_CONTENT],
+            Some((&[StatusCode::NOT_FOUND], Some(list_id.to_string()))),
+        )
+    }
+
+    /// Interpret the response to a
+    /// [`result_page_request`](BriteVerifyCore::result_page_request)
+    pub fn parse_result_page(
+        &self,
+        response: HttpResponse,
+    ) -> Result<types::BulkVerificationResponse, BriteVerifyClientError> {
+        Self::_interpret(response, &[StatusCode::OK], None)
+    }
+
+    // </editor-fold desc="// Response Parsers ... ">
+}
+
+// </editor-fold desc="// BriteVerifyCore ...">
+
+// <editor-fold desc="// I/O-Free Tests ...">
+
+#[cfg(test)]
+mod tests {
+    // Standard Library Imports
+    use std::time::Duration;
+
+    // Third-Party Dependencies
+    use http::{Method, StatusCode};
+    use pretty_assertions::{assert_eq, assert_str_eq};
+
+    // Crate-Level Imports
+    use super::{BriteVerifyCore, HttpResponse};
+    use crate::{errors::BriteVerifyClientError, types};
+
+    fn core() -> BriteVerifyCore {
+        BriteVerifyCore::new("i am the very model of a modern major general").unwrap()
+    }
+
+    fn response(status: u16, body: &str) -> HttpResponse {
+        http::Response::builder()
+            .status(status)
+            .body(body.as_bytes().to_vec())
+            .unwrap()
+    }
+
+    /// Test that requests built by `BriteVerifyCore`
+    /// carry the expected method, url, and headers
+    #[rstest::rstest]
+    fn test_core_builds_requests() {
+        let core = core();
+
+        let request = core.account_balance_request().unwrap();
+
+        assert_eq!(request.method(), Method::GET);
+        assert_str_eq!(
+            request.uri().to_string(),
+            "https://bulk-api.briteverify.com/api/v3/accounts/credits"
+        );
+        assert!(request
+            .headers()
+            .get(http::header::AUTHORIZATION)
+            .is_some_and(|value| value.is_sensitive()));
+
+        let contact = types::VerificationRequest::try_from("test@example.com").unwrap();
+        let request = core.verification_request(&contact).unwrap();
+
+        assert_eq!(request.method(), Method::POST);
+        assert_str_eq!(request.uri().path(), "/api/v1/fullverify");
+        assert_eq!(
+            serde_json::from_slice::<serde_json::Value>(request.body()).unwrap(),
+            serde_json::json!({"email": "test@example.com"}),
+        );
+
+        let request = core
+            .get_lists_request(
+                Some(2u32),
+                Option::<chrono::NaiveDate>::None,
+                Some("open"),
+                Some("a-customer"),
+            )
+            .unwrap();
+
+        assert_str_eq!(request.uri().path(), "/api/v3/accounts/a-customer/lists");
+        assert_str_eq!(request.uri().query().unwrap(), "page=2&state=open");
+
+        let request = core.delete_list_request("a-list").unwrap();
+
+        assert_eq!(request.method(), Method::DELETE);
+        assert_str_eq!(request.uri().path(), "/api/v3/lists/a-list");
+        assert!(request.body().is_empty());
+
+        let request = core.result_page_request("a-list", 3).unwrap();
+
+        assert_str_eq!(request.uri().path(), "/api/v3/lists/a-list/export/3");
+    }
+
+    /// Test that `BriteVerifyCore` translates responses
+    /// into the expected typed results and errors
+    #[rstest::rstest]
+    fn test_core_parses_responses() {
+        let core = core();
+
+        let balance = core
+            .parse_account_balance(response(
+                200,
+                r#"{"credits": 10, "credits_in_reserve": 5, "recorded_on": "2021-07-27T21:10:10.000+0000"}"#,
+            ))
+            .unwrap();
+
+        assert_eq!(balance.credits, 10u32);
+
+        assert!(matches!(
+            core.parse_account_balance(response(401, "")),
+            Err(BriteVerifyClientError::InvalidApiKey)
+        ));
+
+        assert!(core
+            .parse_get_lists(response(422, ""))
+            .is_err_and(|error| match error {
+                BriteVerifyClientError::UnusableResponse(inner) => {
+                    inner.status() == StatusCode::UNPROCESSABLE_ENTITY
+                }
+                _ => false,
+            }));
+
+        assert!(core
+            .parse_get_list(
+                response(404, r#"{"code": "not_found", "message": "nope"}"#),
+                "a-list"
+            )
+            .is_err_and(|error| match error {
+                BriteVerifyClientError::BulkListNotFound(inner) => {
+                    inner.list_id.as_deref() == Some("a-list")
+                }
+                _ => false,
+            }));
+
+        assert!(matches!(
+            core.parse_verification(response(200, "not json")),
+            Err(BriteVerifyClientError::UnparseableResponse(_))
+        ));
+    }
+
+    /// Test that `BriteVerifyCore` reports the expected
+    /// delay for rate-limited responses (and only those)
+    #[rstest::rstest]
+    fn test_core_retry_delay() {
+        let limited = http::Response::builder()
+            .status(429)
+            .header("retry-after", "4")
+            .body(())
+            .unwrap();
+
+        assert_eq!(
+            BriteVerifyCore::retry_delay(&limited),
+            Some(Duration::from_secs(5))
+        );
+
+        let limited = http::Response::builder().status(429).body(()).unwrap();
+
+        assert_eq!(
+            BriteVerifyCore::retry_delay(&limited),
+            Some(Duration::from_secs(61))
+        );
+
+        assert_eq!(BriteVerifyCore::retry_delay(&response(200, "")), None);
+    }
+}
+
+// </editor-fold desc="// I/O-Free Tests ...">
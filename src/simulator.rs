@@ -0,0 +1,1118 @@
+//! ## Stateful Local BriteVerify API Simulator
+//!
+//! [`BriteVerifySimulator`](BriteVerifySimulator) is a stand-in for
+//! the BriteVerify API that keeps its own account and list state.
+//! It implements the `v1` `fullverify` endpoint, the `v3` account
+//! credit balance endpoint, and the complete `v3` bulk list lifecycle:
+//!
+//! - every verified value costs one credit, with bulk lists reserving
+//!   their credits when queued and spending them once completed
+//! - queued lists move from `pending` to `verifying` to `complete`
+//!   one step each time their state is polled
+//! - completed lists' results are exported in pages of a configurable
+//!   size, and the list becomes `delivered` once its last page is read
+//! - completed lists expire (and their results become unavailable)
+//!   after a configurable amount of time
+//!
+//! The simulator can be used entirely in-process via
+//! [`handle`](BriteVerifySimulator::handle), served over HTTP
+//! via [`start`](BriteVerifySimulator::start), or run as a
+//! standalone server via the `briteverify-simulator` binary.
+//!
+//! ## Basic Usage
+//! ```no_run
+//! # use briteverify_rs::simulator::BriteVerifySimulator;
+//! #
+//! # async fn doc() -> anyhow::Result<()> {
+//! let simulator = BriteVerifySimulator::new("YOUR API KEY").credits(100);
+//! let server = simulator.start().await?;
+//!
+//! let balance = server.client()?.get_account_balance().await?;
+//!
+//! assert_eq!(balance.credits, 100);
+//! # Ok(())
+//! # }
+//! ```
+#![cfg_attr(not(feature = "simulator"), allow(dead_code))]
+//
+// Standard Library Imports
+use std::{
+    collections::{BTreeMap, HashMap},
+    fmt,
+    sync::{Arc, Mutex, MutexGuard},
+    time::Duration,
+};
+
+// Third-Party Imports
+use chrono::{DateTime, NaiveDate, Utc};
+use http::{header::CONTENT_TYPE, Method, StatusCode};
+use serde_json::{json, Value};
+
+// Crate-Level Imports
+use crate::{
+    api::{InMemoryBriteVerifyApi, Verifier},
+    sansio::{HttpRequest, HttpResponse},
+    types,
+};
+
+// Conditional Imports
+#[cfg(feature = "simulator")]
+use crate::{errors::BriteVerifyClientError, BriteVerifyClient};
+#[cfg(feature = "simulator")]
+use std::{convert::Infallible, net::SocketAddr};
+
+// <editor-fold desc="// Constants ...">
+
+/// The number of list states included in
+/// each page of a list-of-lists response
+const LISTS_PER_PAGE: usize = 50;
+
+/// The default number of result records
+/// included in each page of a list export
+const DEFAULT_PAGE_SIZE: usize = 50;
+
+/// The default amount of time a completed list's
+/// results remain available (48 hours)
+const DEFAULT_LIST_TTL: Duration = Duration::from_secs(48 * 60 * 60);
+
+/// The format of the timestamps included
+/// in the BriteVerify API's list states
+const TIMESTAMP_FORMAT: &str = "%m-%d-%Y %I:%M %P";
+
+// </editor-fold desc="// Constants ...">
+
+// <editor-fold desc="// Simulator State ...">
+
+/// [internal-implementation]
+/// A bulk verification list held by the simulator
+#[derive(Clone, Debug)]
+struct SimulatedList {
+    state: types::VerificationListState,
+    contacts: Vec<types::VerificationRequest>,
+    results: Vec<types::BulkVerificationResult>,
+    reserved: u32,
+}
+
+/// [internal-implementation]
+/// The mutable state of a simulator
+#[derive(Debug, Default)]
+struct SimulatorState {
+    balance: types::AccountCreditBalance,
+    lists: BTreeMap<String, SimulatedList>,
+    next_id: u64,
+}
+
+// </editor-fold desc="// Simulator State ...">
+
+// <editor-fold desc="// BriteVerifySimulator ...">
+
+/// A stateful, local stand-in for the BriteVerify API
+///
+/// Clones share the same underlying state.
+#[derive(Clone)]
+pub struct BriteVerifySimulator {
+    api_key: Option<String>,
+    page_size: usize,
+    list_ttl: Duration,
+    verifier: Arc<Verifier>,
+    state: Arc<Mutex<SimulatorState>>,
+}
+
+impl fmt::Debug for BriteVerifySimulator {
+    #[cfg_attr(tarpaulin, coverage(off))]
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter
+            .debug_struct("BriteVerifySimulator")
+            .field("page_size", &self.page_size)
+            .field("list_ttl", &self.list_ttl)
+            .field("state", &self.state)
+            .finish_non_exhaustive()
+    }
+}
+
+impl BriteVerifySimulator {
+    // <editor-fold desc="// Constructors ... ">
+
+    /// Create a new simulator that only accepts requests
+    /// authorized with the supplied API key, with no credits
+    /// and no lists, and which reports every verified value
+    /// as valid
+    pub fn new<ApiKey: ToString>(api_key: ApiKey) -> Self {
+        Self {
+            api_key: Some(
+                api_key
+                    .to_string()
+                    .replace("ApiKey: ", "")
+                    .trim()
+                    .to_string(),
+            ),
+            page_size: DEFAULT_PAGE_SIZE,
+            list_ttl: DEFAULT_LIST_TTL,
+            verifier: Arc::new(InMemoryBriteVerifyApi::valid_response),
+            state: Arc::new(Mutex::new(SimulatorState::default())),
+        }
+    }
+
+    /// [internal-implementation]
+    /// Create a new simulator that accepts every request
+    /// (regardless of authorization), with effectively
+    /// unlimited credits, and which verifies values
+    /// using the supplied function
+    pub(crate) fn _unauthenticated(verifier: Arc<Verifier>) -> Self {
+        Self {
+            api_key: None,
+            verifier,
+            ..Self::new("")
+        }
+        .credits(u32::MAX)
+    }
+
+    /// Set the account's number of available credits
+    pub fn credits(self, value: u32) -> Self {
+        self.set_credits(value);
+        self
+    }
+
+    /// Set the number of result records included
+    /// in each page of a list's exported results
+    pub fn page_size(mut self, value: usize) -> Self {
+        self.page_size = value.max(1);
+        self
+    }
+
+    /// Set how long a completed list's results
+    /// remain available before the list expires
+    pub fn list_ttl(mut self, value: Duration) -> Self {
+        self.list_ttl = value;
+        self
+    }
+
+    /// Set the function used to verify the values
+    /// of single-transaction requests and bulk lists
+    pub fn verifier<Func>(mut self, func: Func) -> Self
+    where
+        Func:
+            Fn(&types::VerificationRequest) -> types::VerificationResponse + Send + Sync + 'static,
+    {
+        self.verifier = Arc::new(func);
+        self
+    }
+
+    // </editor-fold desc="// Constructors ... ">
+
+    // <editor-fold desc="// State Accessors ... ">
+
+    /// The account's current credit balance
+    pub fn balance(&self) -> types::AccountCreditBalance {
+        types::AccountCreditBalance {
+            recorded_on: Utc::now(),
+            ..self._state().balance.clone()
+        }
+    }
+
+    /// Change the account's number of available credits
+    pub fn set_credits(&self, value: u32) {
+        self._state().balance.credits = value;
+    }
+
+    /// The current state of every list held
+    /// by the simulator (without advancing them)
+    pub fn lists(&self) -> Vec<types::VerificationListState> {
+        self._state()
+            .lists
+            .values()
+            .map(|list| list.state.clone())
+            .collect()
+    }
+
+    // </editor-fold desc="// State Accessors ... ">
+
+    // <editor-fold desc="// Transports ... ">
+
+    /// Answer a single BriteVerify API request
+    ///
+    /// #### Example
+    /// ```no_run
+    /// # use briteverify_rs::{sansio::BriteVerifyCore, simulator::BriteVerifySimulator};
+    /// #
+    /// # fn doc() -> anyhow::Result<()> {
+    /// let simulator = BriteVerifySimulator::new("YOUR API KEY").credits(10);
+    /// let core = BriteVerifyCore::new("YOUR API KEY")?;
+    ///
+    /// let response = simulator.handle(core.account_balance_request()?);
+    ///
+    /// assert_eq!(core.parse_account_balance(response)?.credits, 10);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn handle(&self, request: HttpRequest) -> HttpResponse {
+        let authorized = self.api_key.as_ref().is_none_or(|key| {
+            request
+                .headers()
+                .get(http::header::AUTHORIZATION)
+                .and_then(|value| value.to_str().ok())
+                .is_some_and(|value| value == format!("ApiKey: {key}"))
+        });
+
+        if !authorized {
+            return Self::_error(StatusCode::UNAUTHORIZED, "unauthorized", "Unauthorized");
+        }
+
+        let segments: Vec<&str> = request
+            .uri()
+            .path()
+            .split('/')
+            .filter(|segment| !segment.is_empty())
+            .collect();
+
+        let Some(start) = segments
+            .windows(2)
+            .position(|pair| pair[0] == "api" && matches!(pair[1], "v1" | "v3"))
+        else {
+            return Self::_not_found("Unknown endpoint");
+        };
+
+        let query: HashMap<String, String> = request
+            .uri()
+            .query()
+            .map(|query| {
+                url::form_urlencoded::parse(query.as_bytes())
+                    .into_owned()
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        let body = request.body();
+        let now = Utc::now();
+
+        match (
+            request.method(),
+            segments[start + 1],
+            &segments[start + 2..],
+        ) {
+            (&Method::POST, "v1", ["fullverify"]) => self._full_verify(body),
+            (&Method::GET, "v3", ["accounts", "credits"]) => {
+                Self::_json(StatusCode::OK, &self.balance())
+            }
+            (&Method::GET, "v3", ["lists"]) => self._get_lists(None, &query, now),
+            (&Method::GET, "v3", ["accounts", ext_id, "lists"]) => {
+                self._get_lists(Some(ext_id), &query, now)
+            }
+            (&Method::POST, "v3", ["lists"]) => self._create_list(None, body, now),
+            (&Method::POST, "v3", ["accounts", ext_id, "lists"]) => {
+                self._create_list(Some(ext_id), body, now)
+            }
+            (&Method::GET, "v3", ["lists", list_id]) => self._get_list(list_id, None, now),
+            (&Method::GET, "v3", ["accounts", ext_id, "lists", list_id]) => {
+                self._get_list(list_id, Some(ext_id), now)
+            }
+            (&Method::POST, "v3", ["lists", list_id]) => self._update_list(list_id, body, now),
+            (&Method::DELETE, "v3", ["lists", list_id]) => self._delete_list(list_id, now),
+            (&Method::GET, "v3", ["lists", list_id, "export", page]) => {
+                self._export_list(list_id, page, now)
+            }
+            _ => Self::_not_found("Unknown endpoint"),
+        }
+    }
+
+    /// Serve the simulator over HTTP on an
+    /// available port on the loopback interface
+    #[cfg(feature = "simulator")]
+    pub async fn start(&self) -> std::io::Result<RunningSimulator> {
+        self.start_on(SocketAddr::from(([127, 0, 0, 1], 0))).await
+    }
+
+    /// Serve the simulator over HTTP on the supplied address
+    #[cfg(feature = "simulator")]
+    pub async fn start_on(&self, address: SocketAddr) -> std::io::Result<RunningSimulator> {
+        let listener = std::net::TcpListener::bind(address)?;
+        listener.set_nonblocking(true)?;
+
+        let simulator = self.clone();
+        let server = hyper::Server::from_tcp(listener)
+            .map_err(std::io::Error::other)?
+            .serve(hyper::service::make_service_fn(move |_| {
+                let simulator = simulator.clone();
+
+                async move {
+                    Ok::<_, Infallible>(hyper::service::service_fn(move |request| {
+                        let simulator = simulator.clone();
+
+                        async move { Ok::<_, Infallible>(simulator._serve(request).await) }
+                    }))
+                }
+            }));
+
+        let address = server.local_addr();
+        let (shutdown, signal) = tokio::sync::oneshot::channel::<()>();
+
+        tokio::spawn(server.with_graceful_shutdown(async {
+            signal.await.ok();
+        }));
+
+        Ok(RunningSimulator {
+            address,
+            api_key: self.api_key.clone().unwrap_or_default(),
+            shutdown: Some(shutdown),
+        })
+    }
+
+    // </editor-fold desc="// Transports ... ">
+
+    // <editor-fold desc="// Internal Utility Methods ... ">
+
+    /// [internal-implementation]
+    /// Lock the simulator's mutable state
+    fn _state(&self) -> MutexGuard<'_, SimulatorState> {
+        self.state
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    /// [internal-implementation]
+    /// Answer a request received over HTTP
+    #[cfg(feature = "simulator")]
+    async fn _serve(&self, request: hyper::Request<hyper::Body>) -> hyper::Response<hyper::Body> {
+        let (parts, body) = request.into_parts();
+
+        let response = match hyper::body::to_bytes(body).await {
+            Ok(bytes) => self.handle(HttpRequest::from_parts(parts, bytes.to_vec())),
+            Err(error) => Self::_error(StatusCode::BAD_REQUEST, "bad_request", &error.to_string()),
+        };
+
+        response.map(hyper::Body::from)
+    }
+
+    /// [internal-implementation]
+    /// Build a response with the supplied
+    /// status and JSON-serialized body
+    fn _json<Body: serde::Serialize>(status: StatusCode, body: &Body) -> HttpResponse {
+        let mut response = http::Response::new(serde_json::to_vec(body).unwrap_or_default());
+
+        *response.status_mut() = status;
+        response.headers_mut().insert(
+            CONTENT_TYPE,
+            http::HeaderValue::from_static("application/json"),
+        );
+
+        response
+    }
+
+    /// [internal-implementation]
+    /// Build an error response in the
+    /// format used by the BriteVerify API
+    fn _error(status: StatusCode, code: &str, message: &str) -> HttpResponse {
+        Self::_json(status, &json!({"code": code, "message": message}))
+    }
+
+    /// [internal-implementation]
+    /// Build a "not found" error response
+    fn _not_found(message: &str) -> HttpResponse {
+        Self::_error(StatusCode::NOT_FOUND, "not_found", message)
+    }
+
+    /// [internal-implementation]
+    /// The number of credits needed to verify the supplied request
+    fn _cost(request: &types::VerificationRequest) -> u32 {
+        [
+            request.email.is_some(),
+            request.phone.is_some(),
+            request.address.is_some(),
+        ]
+        .into_iter()
+        .filter(|present| *present)
+        .count() as u32
+    }
+
+    /// [internal-implementation]
+    /// Serialize a list state the way the BriteVerify API does
+    fn _list_json(list: &types::VerificationListState) -> Value {
+        let format = |timestamp: &DateTime<Utc>| timestamp.format(TIMESTAMP_FORMAT).to_string();
+        let mut value = serde_json::to_value(list).unwrap_or_default();
+
+        value["created_at"] = json!(format(&list.created_at));
+        value["expiration_date"] = json!(list.expiration_date.as_ref().map(format));
+
+        value
+    }
+
+    /// [internal-implementation]
+    /// Build a successful list CRUD response
+    fn _crud(
+        status: StatusCode,
+        message: &str,
+        list: &types::VerificationListState,
+    ) -> HttpResponse {
+        Self::_json(
+            status,
+            &json!({
+                "status": types::BatchState::Success,
+                "message": message,
+                "list": Self::_list_json(list),
+            }),
+        )
+    }
+
+    /// [internal-implementation]
+    /// Expire the supplied list if its results are past their
+    /// expiration date, then advance it one step further
+    /// through processing if it's been queued
+    fn _tick(
+        &self,
+        balance: &mut types::AccountCreditBalance,
+        list: &mut SimulatedList,
+        now: DateTime<Utc>,
+    ) {
+        Self::_expire(list, now);
+
+        match list.state.state {
+            types::BatchState::Pending => {
+                list.state.state = types::BatchState::Verifying;
+                list.state.progress = 50;
+                list.state.total_verified = list.contacts.len() as u64 / 2;
+            }
+            types::BatchState::Verifying => self._complete(balance, list, now),
+            _ => {}
+        }
+    }
+
+    /// [internal-implementation]
+    /// Expire the supplied list if its
+    /// results are past their expiration date
+    fn _expire(list: &mut SimulatedList, now: DateTime<Utc>) {
+        let completed = matches!(
+            list.state.state,
+            types::BatchState::Complete | types::BatchState::Delivered
+        );
+
+        if completed
+            && list
+                .state
+                .expiration_date
+                .is_some_and(|expiry| now >= expiry)
+        {
+            list.state.state = types::BatchState::Expired;
+            list.state.page_count = None;
+            list.state.results_path = None;
+            list.results.clear();
+        }
+    }
+
+    /// [internal-implementation]
+    /// Verify the supplied list's records and spend its reserved credits
+    fn _complete(
+        &self,
+        balance: &mut types::AccountCreditBalance,
+        list: &mut SimulatedList,
+        now: DateTime<Utc>,
+    ) {
+        list.results = list
+            .contacts
+            .iter()
+            .map(|contact| InMemoryBriteVerifyApi::_bulk_result((self.verifier)(contact)))
+            .collect();
+
+        let count = |field: fn(&types::VerificationRequest) -> bool| {
+            list.contacts
+                .iter()
+                .filter(|contact| field(contact))
+                .count() as u64
+        };
+
+        list.state.state = types::BatchState::Complete;
+        list.state.progress = 100;
+        list.state.total_verified = list.contacts.len() as u64;
+        list.state.total_verified_emails = count(|contact| contact.email.is_some());
+        list.state.total_verified_phones = count(|contact| contact.phone.is_some());
+        list.state.page_count = Some(list.results.len().max(1).div_ceil(self.page_size) as u64);
+        list.state.results_path = format!("/api/v3/lists/{}/export/1", list.state.id)
+            .parse()
+            .ok();
+        list.state.expiration_date = chrono::Duration::from_std(self.list_ttl)
+            .ok()
+            .and_then(|ttl| now.checked_add_signed(ttl));
+
+        balance.credits_in_reserve = balance.credits_in_reserve.saturating_sub(list.reserved);
+        list.reserved = 0;
+    }
+
+    /// [internal-implementation]
+    /// Queue the supplied list for processing,
+    /// reserving the credits needed to verify it
+    fn _start(
+        balance: &mut types::AccountCreditBalance,
+        list: &mut SimulatedList,
+    ) -> Result<(), (&'static str, &'static str)> {
+        if list.state.state != types::BatchState::Open {
+            return Err(("invalid_state", "List is not open"));
+        }
+
+        if list.contacts.is_empty() {
+            return Err(("missing_data", "List has no records to verify"));
+        }
+
+        let cost: u32 = list.contacts.iter().map(Self::_cost).sum();
+
+        if balance.credits < cost {
+            return Err(("exceeds_limit", "Insufficient credits to verify list"));
+        }
+
+        balance.credits -= cost;
+        balance.credits_in_reserve += cost;
+        list.reserved = cost;
+        list.state.state = types::BatchState::Pending;
+
+        Ok(())
+    }
+
+    // </editor-fold desc="// Internal Utility Methods ... ">
+
+    // <editor-fold desc="// Endpoint Implementations ... ">
+
+    /// [internal-implementation]
+    /// Perform a single-transaction verification
+    fn _full_verify(&self, body: &[u8]) -> HttpResponse {
+        let Ok(request) = serde_json::from_slice::<types::VerificationRequest>(body) else {
+            return Self::_error(
+                StatusCode::BAD_REQUEST,
+                "bad_request",
+                "Unparseable request",
+            );
+        };
+
+        let cost = Self::_cost(&request);
+
+        if cost == 0 {
+            return Self::_error(
+                StatusCode::BAD_REQUEST,
+                "missing_data",
+                "No values to verify",
+            );
+        }
+
+        {
+            let mut state = self._state();
+
+            if state.balance.credits < cost {
+                return Self::_error(
+                    StatusCode::PAYMENT_REQUIRED,
+                    "insufficient_credits",
+                    "Insufficient credits",
+                );
+            }
+
+            state.balance.credits -= cost;
+        }
+
+        Self::_json(StatusCode::OK, &(self.verifier)(&request))
+    }
+
+    /// [internal-implementation]
+    /// Get the (filtered) states of all lists
+    fn _get_lists(
+        &self,
+        ext_id: Option<&str>,
+        query: &HashMap<String, String>,
+        now: DateTime<Utc>,
+    ) -> HttpResponse {
+        let date = query
+            .get("date")
+            .and_then(|date| NaiveDate::parse_from_str(date, "%Y-%m-%d").ok());
+        let filter = query
+            .get("state")
+            .map(|state| types::BatchState::from(state.as_str()));
+        let page = query
+            .get("page")
+            .and_then(|page| page.parse::<usize>().ok())
+            .unwrap_or(1)
+            .max(1);
+
+        let mut state = self._state();
+        let SimulatorState { balance, lists, .. } = &mut *state;
+
+        let matching: Vec<Value> = lists
+            .values_mut()
+            .filter(|list| list.state.external_id.as_deref() == ext_id)
+            .map(|list| {
+                self._tick(balance, list, now);
+                &list.state
+            })
+            .filter(|list| date.is_none_or(|date| list.created_at.date_naive() == date))
+            .filter(|list| filter.is_none_or(|filter| list.state == filter))
+            .map(Self::_list_json)
+            .collect();
+
+        let pages = matching.len().div_ceil(LISTS_PER_PAGE).max(1);
+        let lists: Vec<Value> = matching
+            .into_iter()
+            .skip((page - 1) * LISTS_PER_PAGE)
+            .take(LISTS_PER_PAGE)
+            .collect();
+
+        Self::_json(
+            StatusCode::OK,
+            &json!({"message": format!("Page {page} of {pages}"), "lists": lists}),
+        )
+    }
+
+    /// [internal-implementation]
+    /// Get the state of a single list
+    fn _get_list(&self, list_id: &str, ext_id: Option<&str>, now: DateTime<Utc>) -> HttpResponse {
+        let mut state = self._state();
+        let SimulatorState { balance, lists, .. } = &mut *state;
+
+        match lists
+            .get_mut(list_id)
+            .filter(|list| list.state.external_id.as_deref() == ext_id)
+        {
+            None => Self::_not_found("List not found"),
+            Some(list) => {
+                self._tick(balance, list, now);
+                Self::_json(StatusCode::OK, &Self::_list_json(&list.state))
+            }
+        }
+    }
+
+    /// [internal-implementation]
+    /// Create a new list
+    fn _create_list(&self, ext_id: Option<&str>, body: &[u8], now: DateTime<Utc>) -> HttpResponse {
+        let request = match body.is_empty() {
+            true => types::BulkVerificationRequest::default(),
+            false => match serde_json::from_slice::<types::BulkVerificationRequest>(body) {
+                Ok(request) => request,
+                Err(_) => {
+                    return Self::_error(
+                        StatusCode::BAD_REQUEST,
+                        "bad_request",
+                        "Unparseable request",
+                    )
+                }
+            },
+        };
+
+        let mut state = self._state();
+        let SimulatorState {
+            balance,
+            lists,
+            next_id,
+        } = &mut *state;
+
+        *next_id += 1;
+
+        let mut list = SimulatedList {
+            state: types::VerificationListState {
+                id: format!("00000000-0000-4000-8000-{:012x}", next_id),
+                external_id: ext_id.map(str::to_string),
+                state: types::BatchState::Open,
+                created_at: now,
+                ..types::VerificationListState::default()
+            },
+            contacts: request.contacts,
+            results: Vec::new(),
+            reserved: 0,
+        };
+
+        if request.directive == types::BulkListDirective::Start {
+            if let Err((code, message)) = Self::_start(balance, &mut list) {
+                return Self::_error(StatusCode::BAD_REQUEST, code, message);
+            }
+        }
+
+        let response = Self::_crud(StatusCode::CREATED, "New list created", &list.state);
+
+        lists.insert(list.state.id.clone(), list);
+
+        response
+    }
+
+    /// [internal-implementation]
+    /// Append records to, start, or terminate a list
+    fn _update_list(&self, list_id: &str, body: &[u8], now: DateTime<Utc>) -> HttpResponse {
+        let Ok(request) = serde_json::from_slice::<types::BulkVerificationRequest>(body) else {
+            return Self::_error(
+                StatusCode::BAD_REQUEST,
+                "bad_request",
+                "Unparseable request",
+            );
+        };
+
+        let mut state = self._state();
+        let SimulatorState { balance, lists, .. } = &mut *state;
+
+        let Some(list) = lists.get_mut(list_id) else {
+            return Self::_not_found("List not found");
+        };
+
+        Self::_expire(list, now);
+
+        if request.directive == types::BulkListDirective::Terminate {
+            if !matches!(
+                list.state.state,
+                types::BatchState::Open | types::BatchState::Pending | types::BatchState::Verifying
+            ) {
+                return Self::_error(
+                    StatusCode::BAD_REQUEST,
+                    "invalid_state",
+                    "List cannot be terminated",
+                );
+            }
+
+            balance.credits += list.reserved;
+            balance.credits_in_reserve = balance.credits_in_reserve.saturating_sub(list.reserved);
+            list.reserved = 0;
+            list.state.state = types::BatchState::Terminated;
+
+            return Self::_crud(StatusCode::OK, "List terminated", &list.state);
+        }
+
+        if !request.contacts.is_empty() {
+            if list.state.state != types::BatchState::Open {
+                return Self::_error(StatusCode::BAD_REQUEST, "invalid_state", "List is not open");
+            }
+
+            list.contacts.extend(request.contacts);
+        }
+
+        if request.directive == types::BulkListDirective::Start {
+            if let Err((code, message)) = Self::_start(balance, list) {
+                return Self::_error(StatusCode::BAD_REQUEST, code, message);
+            }
+        }
+
+        Self::_crud(StatusCode::OK, "List updated", &list.state)
+    }
+
+    /// [internal-implementation]
+    /// Delete a list
+    fn _delete_list(&self, list_id: &str, now: DateTime<Utc>) -> HttpResponse {
+        let mut state = self._state();
+
+        let Some(list) = state.lists.get_mut(list_id) else {
+            return Self::_not_found("List not found");
+        };
+
+        Self::_expire(list, now);
+
+        if !matches!(
+            list.state.state,
+            types::BatchState::Prepped
+                | types::BatchState::Complete
+                | types::BatchState::Delivered
+                | types::BatchState::ImportError
+                | types::BatchState::Expired
+        ) {
+            return Self::_error(
+                StatusCode::BAD_REQUEST,
+                "invalid_state",
+                "List cannot be deleted in its current state",
+            );
+        }
+
+        let mut list = state
+            .lists
+            .remove(list_id)
+            .map(|list| list.state)
+            .unwrap_or_default();
+
+        list.state = types::BatchState::Deleted;
+
+        Self::_crud(StatusCode::OK, "List deleted", &list)
+    }
+
+    /// [internal-implementation]
+    /// Export a page of a list's results
+    fn _export_list(&self, list_id: &str, page: &str, now: DateTime<Utc>) -> HttpResponse {
+        let mut state = self._state();
+
+        let Some(list) = state.lists.get_mut(list_id) else {
+            return Self::_not_found("List not found");
+        };
+
+        Self::_expire(list, now);
+
+        let Some(page_count) = list.state.page_count.filter(|_| {
+            matches!(
+                list.state.state,
+                types::BatchState::Complete | types::BatchState::Delivered
+            )
+        }) else {
+            return Self::_error(
+                StatusCode::BAD_REQUEST,
+                "invalid_state",
+                "List results are not available",
+            );
+        };
+
+        let page = match page.parse::<u64>() {
+            Ok(page) if (1..=page_count).contains(&page) => page,
+            _ => return Self::_not_found("Page not found"),
+        };
+
+        let results: Vec<types::BulkVerificationResult> = list
+            .results
+            .iter()
+            .skip((page as usize - 1) * self.page_size)
+            .take(self.page_size)
+            .cloned()
+            .collect();
+
+        if page == page_count {
+            list.state.state = types::BatchState::Delivered;
+        }
+
+        Self::_json(
+            StatusCode::OK,
+            &types::BulkVerificationResponse {
+                status: list.state.state,
+                page_count,
+                results,
+            },
+        )
+    }
+
+    // </editor-fold desc="// Endpoint Implementations ... ">
+}
+
+// </editor-fold desc="// BriteVerifySimulator ...">
+
+// <editor-fold desc="// RunningSimulator ...">
+
+/// A [`BriteVerifySimulator`](BriteVerifySimulator) being served
+/// over HTTP, which stops serving when dropped
+#[derive(Debug)]
+#[cfg(feature = "simulator")]
+pub struct RunningSimulator {
+    address: SocketAddr,
+    api_key: String,
+    shutdown: Option<tokio::sync::oneshot::Sender<()>>,
+}
+
+#[cfg(feature = "simulator")]
+impl Drop for RunningSimulator {
+    fn drop(&mut self) {
+        if let Some(shutdown) = self.shutdown.take() {
+            shutdown.send(()).ok();
+        }
+    }
+}
+
+#[cfg(feature = "simulator")]
+impl RunningSimulator {
+    /// The address the simulator is being served on
+    pub fn address(&self) -> SocketAddr {
+        self.address
+    }
+
+    /// The simulator's base url for single-transaction (v1) requests
+    pub fn v1_base_url(&self) -> String {
+        format!("http://{}/api/v1", self.address)
+    }
+
+    /// The simulator's base url for bulk (v3) requests
+    pub fn v3_base_url(&self) -> String {
+        format!("http://{}/api/v3", self.address)
+    }
+
+    /// Build a [`BriteVerifyClient`](BriteVerifyClient)
+    /// that sends all of its requests to the simulator
+    pub fn client(&self) -> Result<BriteVerifyClient, BriteVerifyClientError> {
+        BriteVerifyClient::builder()
+            .api_key(&self.api_key)
+            .v1_base_url(self.v1_base_url().as_str())
+            .v3_base_url(self.v3_base_url().as_str())
+            .build()
+    }
+}
+
+// </editor-fold desc="// RunningSimulator ...">
+
+// <editor-fold desc="// I/O-Free Tests ...">
+
+#[cfg(test)]
+mod tests {
+    // Standard Library Imports
+    use std::time::Duration;
+
+    // Third-Party Dependencies
+    use pretty_assertions::assert_eq;
+
+    // Crate-Level Imports
+    use super::BriteVerifySimulator;
+    use crate::{sansio::BriteVerifyCore, types};
+
+    const API_KEY: &str = "i am the very model of a modern major general";
+
+    fn contacts(count: usize) -> Vec<types::VerificationRequest> {
+        (0..count)
+            .map(|idx| types::VerificationRequest::try_from(format!("test-{idx}@example.com")))
+            .collect::<Result<_, _>>()
+            .unwrap()
+    }
+
+    /// Test that the simulator rejects unauthorized requests
+    /// and debits credits for single-transaction verifications
+    #[rstest::rstest]
+    fn test_simulator_single_verification() {
+        let simulator = BriteVerifySimulator::new(API_KEY).credits(1);
+        let core = BriteVerifyCore::new(API_KEY).unwrap();
+        let contact = types::VerificationRequest::try_from("test@example.com").unwrap();
+
+        let response = simulator.handle(
+            BriteVerifyCore::new("nope")
+                .unwrap()
+                .account_balance_request()
+                .unwrap(),
+        );
+
+        assert_eq!(http::StatusCode::UNAUTHORIZED, response.status());
+
+        let response = core
+            .parse_verification(simulator.handle(core.verification_request(&contact).unwrap()))
+            .unwrap();
+
+        assert_eq!(
+            types::VerificationStatus::Valid,
+            response.email.unwrap().status
+        );
+        assert_eq!(0, simulator.balance().credits);
+
+        let response = simulator.handle(core.verification_request(&contact).unwrap());
+
+        assert_eq!(http::StatusCode::PAYMENT_REQUIRED, response.status());
+    }
+
+    /// Test that the simulator walks bulk verification lists
+    /// through their lifecycle, reserving and spending credits
+    /// and paginating their results
+    #[rstest::rstest]
+    fn test_simulator_list_lifecycle() {
+        let simulator = BriteVerifySimulator::new(API_KEY).credits(10).page_size(2);
+        let core = BriteVerifyCore::new(API_KEY).unwrap();
+
+        let request = types::BulkVerificationRequest::new(contacts(3), true);
+        let created = core
+            .parse_create_or_update_list(
+                simulator.handle(
+                    core.create_or_update_list_request(Option::<&str>::None, &request)
+                        .unwrap(),
+                ),
+                Option::<&str>::None,
+            )
+            .unwrap();
+        let list_id = created.list.id.as_str();
+
+        assert_eq!(types::BatchState::Pending, created.list.state);
+        assert_eq!(7, simulator.balance().credits);
+        assert_eq!(3, simulator.balance().credits_in_reserve);
+
+        let poll = || {
+            core.parse_get_list(
+                simulator.handle(
+                    core.get_list_request(list_id, Option::<&str>::None)
+                        .unwrap(),
+                ),
+                list_id,
+            )
+            .unwrap()
+        };
+
+        assert_eq!(types::BatchState::Verifying, poll().state);
+
+        let list = poll();
+
+        assert_eq!(types::BatchState::Complete, list.state);
+        assert_eq!(Some(2), list.page_count);
+        assert_eq!(0, simulator.balance().credits_in_reserve);
+
+        let page = |number| {
+            core.parse_result_page(
+                simulator.handle(core.result_page_request(list_id, number).unwrap()),
+            )
+            .unwrap()
+        };
+
+        assert_eq!(2, page(1).results.len());
+        assert_eq!(1, page(2).results.len());
+        assert_eq!(types::BatchState::Delivered, poll().state);
+
+        let deleted = core
+            .parse_delete_list(
+                simulator.handle(core.delete_list_request(list_id).unwrap()),
+                list_id,
+            )
+            .unwrap();
+
+        assert_eq!(types::BatchState::Deleted, deleted.list.state);
+        assert!(simulator.lists().is_empty());
+    }
+
+    /// Test that the simulator expires completed lists, refuses
+    /// to start lists it can't afford, and refunds credits
+    /// reserved for terminated lists
+    #[rstest::rstest]
+    fn test_simulator_expiry_and_refunds() {
+        let simulator = BriteVerifySimulator::new(API_KEY)
+            .credits(2)
+            .list_ttl(Duration::ZERO);
+        let core = BriteVerifyCore::new(API_KEY).unwrap();
+        let create = |count: usize| {
+            simulator.handle(
+                core.create_or_update_list_request(
+                    Option::<&str>::None,
+                    &types::BulkVerificationRequest::new(contacts(count), true),
+                )
+                .unwrap(),
+            )
+        };
+
+        assert_eq!(http::StatusCode::BAD_REQUEST, create(3).status());
+
+        let list = core
+            .parse_create_or_update_list(create(1), Option::<&str>::None)
+            .unwrap()
+            .list;
+        let poll = || {
+            core.parse_get_list(
+                simulator.handle(
+                    core.get_list_request(&list.id, Option::<&str>::None)
+                        .unwrap(),
+                ),
+                &list.id,
+            )
+            .unwrap()
+            .state
+        };
+
+        poll();
+
+        assert_eq!(types::BatchState::Complete, poll());
+        assert_eq!(types::BatchState::Expired, poll());
+
+        let list = core
+            .parse_create_or_update_list(create(1), Option::<&str>::None)
+            .unwrap()
+            .list;
+
+        assert_eq!(0, simulator.balance().credits);
+
+        let terminate = types::BulkVerificationRequest::new(
+            Vec::<types::VerificationRequest>::new(),
+            "terminate",
+        );
+        let terminated = core
+            .parse_create_or_update_list(
+                simulator.handle(
+                    core.create_or_update_list_request(Some(&list.id), &terminate)
+                        .unwrap(),
+                ),
+                Some(&list.id),
+            )
+            .unwrap();
+
+        assert_eq!(types::BatchState::Terminated, terminated.list.state);
+        assert_eq!(1, simulator.balance().credits);
+        assert_eq!(0, simulator.balance().credits_in_reserve);
+    }
+}
+
+// </editor-fold desc="// I/O-Free Tests ...">
@@ -0,0 +1,757 @@
+//! ## Persistent Verification Result Store
+//!
+//! Available with the `sqlite` feature enabled.
+//!
+//! A [`PersistentCache`](PersistentCache) keeps the results of
+//! single-transaction verifications and bulk verification list exports
+//! in a [`ResultStore`](ResultStore) that outlives the process, so that
+//! every service (or CLI run) on the same host can reuse prior results
+//! while they're still fresh.
+//!
+//! A [`BriteVerifyClient`](crate::BriteVerifyClient) built with a
+//! [`persistent_cache`](crate::BriteVerifyClientBuilder::persistent_cache):
+//!
+//! - records the result for every value (email address, phone number,
+//!   or street address) it verifies, along with when it was verified
+//! - answers single-transaction verifications without sending them if
+//!   fresh single-transaction results are stored for each of its values
+//! - records every result exported via
+//!   [`get_results_by_list_id`](crate::BriteVerifyClient::get_results_by_list_id)
+//!
+//! Values are stored in normalized form (see [`ContactKey`](crate::dedupe::ContactKey)).
+//! Freshness is measured against the system's wall clock, as stored
+//! results are shared between processes.
+//!
+//! The [`SqliteResultStore`](SqliteResultStore) keeps results in an
+//! SQLite database, holding the latest result for every value from each
+//! source (so the database only grows with the number of distinct values
+//! verified), and migrates it to the current [schema version](SCHEMA_VERSION)
+//! when it's opened. Any other storage engine can be used by implementing
+//! [`ResultStore`](ResultStore) for it.
+//!
+//! ___
+//! **NOTE:** Store I/O is blocking, so clients run it on a background
+//! thread owned by their [`PersistentCache`](PersistentCache) instead of
+//! on the async runtime. The cache's public methods (e.g.
+//! [`last_known_email`](PersistentCache::last_known_email) and
+//! [`prune`](PersistentCache::prune)) block the calling thread.
+//! ___
+//!
+//! ## Basic Usage
+//! ```no_run
+//! # use std::time::Duration;
+//! # use briteverify_rs::{
+//! #     BriteVerifyClient,
+//! #     store::{PersistentCache, SqliteResultStore},
+//! # };
+//! #
+//! # async fn doc() -> anyhow::Result<()> {
+//! let cache = PersistentCache::new(SqliteResultStore::open("/var/cache/briteverify.sqlite3")?)
+//!     .freshness(Duration::from_secs(30 * 24 * 3600));
+//!
+//! let client: BriteVerifyClient = BriteVerifyClient::builder()
+//!     .api_key("YOUR API KEY")
+//!     .persistent_cache(cache)
+//!     .build()?;
+//!
+//! client.verify_email("test@example.com").await?;
+//!
+//! let cache = client.persistent_cache().unwrap();
+//!
+//! if let Some(known) = cache.last_known_email("test@example.com")? {
+//!     println!("Last known status: {} (as of {})", known.status, known.recorded_on);
+//! }
+//!
+//! // discard everything older than the freshness window
+//! cache.prune()?;
+//! # Ok(())
+//! # }
+//! ```
+//
+// Standard Library Imports
+use std::{
+    fmt::Debug,
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex, MutexGuard},
+    time::Duration,
+};
+
+// Third Party Imports
+use anyhow::Context;
+use chrono::{DateTime, SecondsFormat, Utc};
+use rusqlite::{params, Connection, OptionalExtension};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use serde_json::Value;
+
+// Crate-Level Imports
+use crate::{
+    dedupe::{ContactKey, DedupePolicy},
+    errors::BriteVerifyClientError,
+    types::{
+        BulkVerificationResult, StreetAddressArray, VerificationRequest, VerificationResponse,
+        VerificationStatus,
+    },
+    utils::BlockingWorker,
+};
+
+// <editor-fold desc="// Constants ...">
+
+/// [internal-implementation]
+/// Every schema migration, in order (the migration
+/// at index `n` upgrades version `n` to `n + 1`)
+static MIGRATIONS: [&str; 1] = [
+    // version 1: the latest result for each value from each source
+    "CREATE TABLE results (
+        kind TEXT NOT NULL,
+        value TEXT NOT NULL,
+        source TEXT NOT NULL,
+        status TEXT NOT NULL,
+        secondary_status TEXT,
+        list_id TEXT,
+        recorded_on TEXT NOT NULL,
+        data TEXT NOT NULL,
+        PRIMARY KEY (kind, value, source)
+    );
+    CREATE INDEX results_by_value ON results (kind, value, recorded_on);
+    CREATE INDEX results_by_age ON results (recorded_on);",
+];
+
+/// The schema version written by this version of `briteverify-rs`
+pub const SCHEMA_VERSION: u32 = MIGRATIONS.len() as u32;
+
+/// The default amount of time stored results are considered fresh
+pub const DEFAULT_FRESHNESS: Duration = Duration::from_secs(24 * 3600);
+
+/// How long to wait for another connection
+/// to release its lock on a store's database
+const BUSY_TIMEOUT: Duration = Duration::from_secs(5);
+
+// </editor-fold desc="// Constants ...">
+
+// <editor-fold desc="// StoredResult ...">
+
+/// The kind of value a stored result is for
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum StoredValueKind {
+    /// An email address
+    Email,
+    /// A phone number
+    Phone,
+    /// A street address
+    Address,
+}
+
+/// Where a stored result came from
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum StoredSource {
+    /// A single-transaction verification
+    Single,
+    /// A bulk verification list export
+    Bulk,
+}
+
+/// The stored result of verifying a single value
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct StoredResult {
+    /// The kind of value verified
+    pub kind: StoredValueKind,
+    /// The verified value (in normalized form)
+    pub value: String,
+    /// The value's validity "status"
+    pub status: VerificationStatus,
+    /// The value's "secondary" validity status (if any)
+    #[serde(default)]
+    pub secondary_status: Option<String>,
+    /// Where the result came from
+    pub source: StoredSource,
+    /// The id of the bulk verification list
+    /// the result was exported from (if any)
+    #[serde(default)]
+    pub list_id: Option<String>,
+    /// When the result was stored
+    pub recorded_on: DateTime<Utc>,
+    /// The complete verification data returned
+    /// by the BriteVerify API for the value
+    #[serde(default)]
+    pub data: Value,
+}
+
+// </editor-fold desc="// StoredResult ...">
+
+// <editor-fold desc="// ResultStore ...">
+
+/// A persistent home for verification results
+pub trait ResultStore: Send + Sync {
+    /// Store the supplied results
+    fn insert(&self, results: Vec<StoredResult>) -> Result<(), BriteVerifyClientError>;
+
+    /// Get the most recently stored result for the supplied
+    /// (normalized) value, optionally from a specific source
+    fn latest(
+        &self,
+        kind: StoredValueKind,
+        value: &str,
+        source: Option<StoredSource>,
+    ) -> Result<Option<StoredResult>, BriteVerifyClientError>;
+
+    /// Discard every result stored before the supplied
+    /// time, returning the number of results discarded
+    fn prune(&self, before: DateTime<Utc>) -> Result<usize, BriteVerifyClientError>;
+}
+
+/// A [`ResultStore`](ResultStore) backed by an SQLite database
+#[derive(Debug)]
+pub struct SqliteResultStore {
+    path: PathBuf,
+    connection: Mutex<Connection>,
+}
+
+impl SqliteResultStore {
+    /// Open (or create) the result store at the supplied
+    /// path, migrating it to the current schema version
+    ///
+    /// ___
+    /// **NOTE:** Stores written by a newer version of
+    /// `briteverify-rs` (i.e. with a newer schema version)
+    /// can't be opened.
+    /// ___
+    pub fn open<StorePath: AsRef<Path>>(path: StorePath) -> Result<Self, BriteVerifyClientError> {
+        let path = path.as_ref().to_path_buf();
+
+        if let Some(parent) = path.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+            std::fs::create_dir_all(parent)
+                .with_context(|| format!("Could not create directory: {}", parent.display()))?;
+        }
+
+        let connection = Connection::open(&path)
+            .with_context(|| format!("Could not open result store: {}", path.display()))?;
+
+        Self::_init(path, connection)
+    }
+
+    /// Create a new result store that only
+    /// lives as long as the returned instance
+    pub fn in_memory() -> Result<Self, BriteVerifyClientError> {
+        let connection =
+            Connection::open_in_memory().context("Could not create in-memory result store")?;
+
+        Self::_init(PathBuf::from(":memory:"), connection)
+    }
+
+    /// The path of the store's database
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    // <editor-fold desc="// Internal Utility Methods ... ">
+
+    /// [internal-implementation]
+    /// Configure the supplied connection and
+    /// migrate its database if necessary
+    fn _init(path: PathBuf, mut connection: Connection) -> Result<Self, BriteVerifyClientError> {
+        connection
+            .busy_timeout(BUSY_TIMEOUT)
+            .and_then(|_| {
+                connection.pragma_update_and_check(None, "journal_mode", "WAL", |_| Ok(()))
+            })
+            .with_context(|| format!("Could not configure result store: {}", path.display()))?;
+
+        let transaction = connection
+            .transaction_with_behavior(rusqlite::TransactionBehavior::Immediate)
+            .with_context(|| format!("Could not lock result store: {}", path.display()))?;
+
+        let version: u32 = transaction
+            .pragma_query_value(None, "user_version", |row| row.get(0))
+            .with_context(|| format!("Could not read result store: {}", path.display()))?;
+
+        if version > SCHEMA_VERSION {
+            return Err(anyhow::anyhow!(
+                "Result store {} has schema version {version}, \
+                 but only versions up to {SCHEMA_VERSION} are supported",
+                path.display()
+            )
+            .into());
+        }
+
+        for migration in &MIGRATIONS[version as usize..] {
+            transaction
+                .execute_batch(migration)
+                .with_context(|| format!("Could not migrate result store: {}", path.display()))?;
+        }
+
+        transaction
+            .pragma_update(None, "user_version", SCHEMA_VERSION)
+            .and_then(|_| transaction.commit())
+            .with_context(|| format!("Could not migrate result store: {}", path.display()))?;
+
+        Ok(Self {
+            path,
+            connection: Mutex::new(connection),
+        })
+    }
+
+    /// [internal-implementation]
+    /// Lock the store's connection
+    fn _connection(&self) -> MutexGuard<'_, Connection> {
+        self.connection
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    // </editor-fold desc="// Internal Utility Methods ... ">
+}
+
+impl ResultStore for SqliteResultStore {
+    fn insert(&self, results: Vec<StoredResult>) -> Result<(), BriteVerifyClientError> {
+        let mut connection = self._connection();
+        let transaction = connection
+            .transaction()
+            .with_context(|| format!("Could not lock result store: {}", self.path.display()))?;
+
+        {
+            // older results never replace newer ones
+            let mut upsert = transaction
+                .prepare_cached(
+                    "INSERT INTO results (
+                        kind, value, source, status, secondary_status, list_id, recorded_on, data
+                    )
+                    VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)
+                    ON CONFLICT (kind, value, source) DO UPDATE SET
+                        status = excluded.status,
+                        secondary_status = excluded.secondary_status,
+                        list_id = excluded.list_id,
+                        recorded_on = excluded.recorded_on,
+                        data = excluded.data
+                    WHERE excluded.recorded_on >= results.recorded_on",
+                )
+                .context("Could not prepare result store insert")?;
+
+            for result in results {
+                upsert
+                    .execute(params![
+                        _to_text(&result.kind)?,
+                        result.value,
+                        _to_text(&result.source)?,
+                        _to_text(&result.status)?,
+                        result.secondary_status,
+                        result.list_id,
+                        _timestamp(result.recorded_on),
+                        result.data.to_string(),
+                    ])
+                    .with_context(|| {
+                        format!("Could not write result store: {}", self.path.display())
+                    })?;
+            }
+        }
+
+        transaction
+            .commit()
+            .with_context(|| format!("Could not write result store: {}", self.path.display()))?;
+
+        Ok(())
+    }
+
+    fn latest(
+        &self,
+        kind: StoredValueKind,
+        value: &str,
+        source: Option<StoredSource>,
+    ) -> Result<Option<StoredResult>, BriteVerifyClientError> {
+        let source = source.as_ref().map(_to_text).transpose()?;
+
+        let row = self
+            ._connection()
+            .prepare_cached(
+                "SELECT source, status, secondary_status, list_id, recorded_on, data
+                FROM results
+                WHERE kind = ?1 AND value = ?2 AND (?3 IS NULL OR source = ?3)
+                ORDER BY recorded_on DESC
+                LIMIT 1",
+            )
+            .and_then(|mut select| {
+                select
+                    .query_row(params![_to_text(&kind).ok(), value, source], |row| {
+                        Ok((
+                            row.get::<_, String>(0)?,
+                            row.get::<_, String>(1)?,
+                            row.get::<_, Option<String>>(2)?,
+                            row.get::<_, Option<String>>(3)?,
+                            row.get::<_, String>(4)?,
+                            row.get::<_, String>(5)?,
+                        ))
+                    })
+                    .optional()
+            })
+            .with_context(|| format!("Could not read result store: {}", self.path.display()))?;
+
+        let Some((source, status, secondary_status, list_id, recorded_on, data)) = row else {
+            return Ok(None);
+        };
+
+        Ok(Some(StoredResult {
+            kind,
+            value: value.to_string(),
+            status: _from_text(status)?,
+            secondary_status,
+            source: _from_text(source)?,
+            list_id,
+            recorded_on: DateTime::parse_from_rfc3339(&recorded_on)
+                .context("Could not parse stored result timestamp")?
+                .with_timezone(&Utc),
+            data: serde_json::from_str(&data)?,
+        }))
+    }
+
+    fn prune(&self, before: DateTime<Utc>) -> Result<usize, BriteVerifyClientError> {
+        let pruned = self
+            ._connection()
+            .execute(
+                "DELETE FROM results WHERE recorded_on < ?1",
+                params![_timestamp(before)],
+            )
+            .with_context(|| format!("Could not write result store: {}", self.path.display()))?;
+
+        Ok(pruned)
+    }
+}
+
+/// [internal-implementation]
+/// Render the supplied timestamp in a fixed-width
+/// form, so that timestamps sort chronologically
+fn _timestamp(value: DateTime<Utc>) -> String {
+    value.to_rfc3339_opts(SecondsFormat::Micros, true)
+}
+
+/// [internal-implementation]
+/// Render the supplied (string-like) enum value
+/// the way it's serialized
+fn _to_text<Enum: Serialize>(value: &Enum) -> Result<String, BriteVerifyClientError> {
+    match serde_json::to_value(value)? {
+        Value::String(text) => Ok(text),
+        other => Ok(other.to_string()),
+    }
+}
+
+/// [internal-implementation]
+/// Parse the supplied (string-like) enum value
+fn _from_text<Enum: DeserializeOwned>(text: String) -> Result<Enum, BriteVerifyClientError> {
+    Ok(serde_json::from_value(Value::String(text))?)
+}
+
+// </editor-fold desc="// ResultStore ...">
+
+// <editor-fold desc="// PersistentCache ...">
+
+/// Reuses the (fresh) verification
+/// results kept in a [`ResultStore`](ResultStore)
+pub struct PersistentCache {
+    worker: BlockingWorker<dyn ResultStore>,
+    freshness: Duration,
+    default_region: Option<String>,
+}
+
+#[cfg_attr(tarpaulin, coverage(off))]
+impl Debug for PersistentCache {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        formatter
+            .debug_struct("PersistentCache")
+            .field("freshness", &self.freshness)
+            .field("default_region", &self.default_region)
+            .finish_non_exhaustive()
+    }
+}
+
+impl PersistentCache {
+    /// Create a new `PersistentCache` backed by the supplied
+    /// store, whose results are considered fresh for a day
+    pub fn new<Store: ResultStore + 'static>(store: Store) -> Self {
+        Self {
+            worker: BlockingWorker::new("briteverify-store", Arc::new(store)),
+            freshness: DEFAULT_FRESHNESS,
+            default_region: None,
+        }
+    }
+
+    /// Set how long stored results are considered fresh
+    pub fn freshness(mut self, value: Duration) -> Self {
+        self.freshness = value;
+        self
+    }
+
+    /// Treat phone numbers without a country
+    /// code as belonging to the supplied region
+    pub fn default_region<Region: ToString>(mut self, region: Region) -> Self {
+        self.default_region = Some(region.to_string());
+        self
+    }
+
+    /// The cache's underlying store
+    pub fn store(&self) -> &dyn ResultStore {
+        self.worker.resource()
+    }
+
+    /// Get the most recently stored result for the supplied
+    /// email address (regardless of its freshness)
+    pub fn last_known_email(
+        &self,
+        email: &str,
+    ) -> Result<Option<StoredResult>, BriteVerifyClientError> {
+        self.worker.resource().latest(
+            StoredValueKind::Email,
+            &DedupePolicy::new().email_key(email),
+            None,
+        )
+    }
+
+    /// Get the most recently stored result for the supplied
+    /// phone number (regardless of its freshness)
+    pub fn last_known_phone(
+        &self,
+        phone: &str,
+    ) -> Result<Option<StoredResult>, BriteVerifyClientError> {
+        let key = self._key(&VerificationRequest {
+            phone: Some(phone.to_string()),
+            ..VerificationRequest::default()
+        });
+
+        self.worker
+            .resource()
+            .latest(StoredValueKind::Phone, &key.phone.unwrap_or_default(), None)
+    }
+
+    /// Get the most recently stored result for the supplied
+    /// street address (regardless of its freshness)
+    pub fn last_known_address(
+        &self,
+        address: &StreetAddressArray,
+    ) -> Result<Option<StoredResult>, BriteVerifyClientError> {
+        let key = self._key(&VerificationRequest {
+            address: Some(address.clone()),
+            ..VerificationRequest::default()
+        });
+
+        self.worker.resource().latest(
+            StoredValueKind::Address,
+            &key.address.unwrap_or_default(),
+            None,
+        )
+    }
+
+    /// Discard every stored result that's no longer
+    /// fresh, returning the number of results discarded
+    pub fn prune(&self) -> Result<usize, BriteVerifyClientError> {
+        self.worker.resource().prune(self._cutoff())
+    }
+
+    // <editor-fold desc="// Internal Utility Methods ... ">
+
+    /// [internal-implementation]
+    /// The normalized values of the supplied request
+    fn _key(&self, request: &VerificationRequest) -> ContactKey {
+        DedupePolicy::new().contact_key(request, self.default_region.as_deref())
+    }
+
+    /// [internal-implementation]
+    /// The time before which stored results are stale
+    fn _cutoff(&self) -> DateTime<Utc> {
+        chrono::Duration::from_std(self.freshness)
+            .ok()
+            .and_then(|freshness| Utc::now().checked_sub_signed(freshness))
+            .unwrap_or(DateTime::<Utc>::MIN_UTC)
+    }
+
+    /// [internal-implementation]
+    /// Get the fresh, stored single-transaction result for the
+    /// supplied value, if the value is present (or `Some(None)`
+    /// if it isn't, as there's nothing to look up)
+    fn _fresh<Data: DeserializeOwned>(
+        store: &dyn ResultStore,
+        kind: StoredValueKind,
+        value: Option<&String>,
+        cutoff: DateTime<Utc>,
+    ) -> Result<Option<Option<Data>>, BriteVerifyClientError> {
+        let Some(value) = value else {
+            return Ok(Some(None));
+        };
+
+        Ok(store
+            .latest(kind, value, Some(StoredSource::Single))?
+            .filter(|result| result.recorded_on >= cutoff)
+            .and_then(|result| serde_json::from_value(result.data).ok())
+            .map(Some))
+    }
+
+    /// [internal-implementation]
+    /// Rebuild the response to the supplied request from fresh,
+    /// stored single-transaction results for each of its values
+    pub(crate) async fn _lookup_single(
+        &self,
+        request: &VerificationRequest,
+    ) -> Result<Option<VerificationResponse>, BriteVerifyClientError> {
+        let key = self._key(request);
+        let cutoff = self._cutoff();
+
+        if key == ContactKey::default() {
+            return Ok(None);
+        }
+
+        self.worker
+            .run(move |store| {
+                let (Some(email), Some(phone), Some(address)) = (
+                    Self::_fresh(store, StoredValueKind::Email, key.email.as_ref(), cutoff)?,
+                    Self::_fresh(store, StoredValueKind::Phone, key.phone.as_ref(), cutoff)?,
+                    Self::_fresh(
+                        store,
+                        StoredValueKind::Address,
+                        key.address.as_ref(),
+                        cutoff,
+                    )?,
+                ) else {
+                    return Ok(None);
+                };
+
+                Ok(Some(VerificationResponse {
+                    email,
+                    phone,
+                    address,
+                    duration: Duration::ZERO,
+                }))
+            })
+            .await
+    }
+
+    /// [internal-implementation]
+    /// Store the results of a single-transaction verification
+    pub(crate) async fn _record_single(
+        &self,
+        request: &VerificationRequest,
+        response: &VerificationResponse,
+    ) -> Result<(), BriteVerifyClientError> {
+        let key = self._key(request);
+        let recorded_on = Utc::now();
+
+        let result = |kind, value: Option<String>, status, data: Value| {
+            value.map(|value| StoredResult {
+                kind,
+                value,
+                status,
+                secondary_status: None,
+                source: StoredSource::Single,
+                list_id: None,
+                recorded_on,
+                data,
+            })
+        };
+
+        let mut results = Vec::new();
+
+        if let Some(email) = response.email.as_ref() {
+            results.extend(result(
+                StoredValueKind::Email,
+                key.email,
+                email.status,
+                serde_json::to_value(email)?,
+            ));
+        }
+
+        if let Some(phone) = response.phone.as_ref() {
+            results.extend(result(
+                StoredValueKind::Phone,
+                key.phone,
+                phone.status,
+                serde_json::to_value(phone)?,
+            ));
+        }
+
+        if let Some(address) = response.address.as_ref() {
+            results.extend(result(
+                StoredValueKind::Address,
+                key.address,
+                address.status,
+                serde_json::to_value(address)?,
+            ));
+        }
+
+        self.worker.run(move |store| store.insert(results)).await
+    }
+
+    /// [internal-implementation]
+    /// Store the results exported from a bulk verification list
+    pub(crate) async fn _record_bulk(
+        &self,
+        list_id: &str,
+        results: &[BulkVerificationResult],
+    ) -> Result<(), BriteVerifyClientError> {
+        let policy = DedupePolicy::new();
+        let recorded_on = Utc::now();
+        let mut stored = Vec::new();
+
+        let mut push = |kind, value: Option<String>, status, secondary: Option<&String>, data| {
+            if let Some(value) = value {
+                stored.push(StoredResult {
+                    kind,
+                    value,
+                    status,
+                    secondary_status: secondary.cloned(),
+                    source: StoredSource::Bulk,
+                    list_id: Some(list_id.to_string()),
+                    recorded_on,
+                    data,
+                });
+            }
+        };
+
+        for result in results {
+            let key = policy.result_key(result, self.default_region.as_deref());
+
+            match result {
+                BulkVerificationResult::Email(email) => push(
+                    StoredValueKind::Email,
+                    key.email,
+                    email.status,
+                    email.secondary_status.as_ref(),
+                    serde_json::to_value(email)?,
+                ),
+                BulkVerificationResult::Contact(contact) => {
+                    if let Some(email) = contact.email.as_ref() {
+                        push(
+                            StoredValueKind::Email,
+                            key.email,
+                            email.status,
+                            email.secondary_status.as_ref(),
+                            serde_json::to_value(email)?,
+                        );
+                    }
+
+                    if let Some(phone) = contact.phone.as_ref() {
+                        push(
+                            StoredValueKind::Phone,
+                            key.phone,
+                            phone.status,
+                            phone.secondary_status.as_ref(),
+                            serde_json::to_value(phone)?,
+                        );
+                    }
+
+                    if let Some(address) = contact.address.as_ref() {
+                        push(
+                            StoredValueKind::Address,
+                            key.address,
+                            address.status,
+                            address.secondary_status.as_ref(),
+                            serde_json::to_value(address)?,
+                        );
+                    }
+                }
+            }
+        }
+
+        self.worker.run(move |store| store.insert(stored)).await
+    }
+
+    // </editor-fold desc="// Internal Utility Methods ... ">
+}
+
+// </editor-fold desc="// PersistentCache ...">
@@ -6,9 +6,9 @@ use std::fmt;
 use chrono::prelude::{DateTime, Utc};
 
 // Conditional Imports
-#[cfg(test)]
+#[cfg(any(test, feature = "fake"))]
 #[doc(hidden)]
-#[cfg_attr(any(test, tarpaulin), allow(unused_imports))]
+#[allow(unused_imports)]
 pub use self::foundry::*;
 
 // <editor-fold desc="// AccountCreditBalance ...">
@@ -16,7 +16,7 @@ pub use self::foundry::*;
 /// Account credit balance and credits
 /// [in reserve](https://docs.briteverify.com/#07beceb3-2961-4d5b-93a4-9cfeb30f42fa),
 /// current as of the `recorded_on` timestamp.
-#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
 pub struct AccountCreditBalance {
     /// The total number of available
     /// non-reserve verification credits
@@ -53,8 +53,28 @@ impl fmt::Display for AccountCreditBalance {
 
 // <editor-fold desc="// Test Helpers & Factory Implementations ...">
 
-#[cfg(test)]
+#[cfg(any(test, feature = "fake"))]
 #[doc(hidden)]
-mod foundry {}
+mod foundry {
+    // Third-Party Imports
+    #[cfg(feature = "fake")]
+    use rand::Rng;
+
+    // Crate-Level Imports
+    #[cfg(feature = "fake")]
+    use crate::fake::{Fake, Faker};
+
+    #[cfg(feature = "fake")]
+    impl Fake for super::AccountCreditBalance {
+        /// Generate a random account balance, recorded "now"
+        fn fake_with(faker: &mut Faker) -> Self {
+            Self {
+                credits: faker.rng().gen_range(0..100_000),
+                credits_in_reserve: faker.rng().gen_range(0..5_000),
+                recorded_on: faker.now(),
+            }
+        }
+    }
+}
 
 // </editor-fold desc="// Test Helpers & Factory Implementations ...">
@@ -0,0 +1,1043 @@
+//! ## Local Street Address Normalization
+//!
+//! Local, credit-free standardization of US street addresses in the style of
+//! [USPS Publication 28](https://pe.usps.com/text/pub28/welcome.htm):
+//!
+//! - surrounding whitespace is trimmed, internal whitespace is collapsed,
+//!   stray periods and commas are removed, and every value is upper-cased
+//! - state names are replaced with their USPS abbreviations
+//! - ZIP codes are checked and formatted as either `"12345"` or `"12345-6789"`
+//! - street suffixes (e.g. `"Avenue"` -> `"AVE"`), directionals (e.g. `"North"`
+//!   -> `"N"`), and secondary unit designators (e.g. `"Suite"` -> `"STE"`) are
+//!   abbreviated
+//!
+//! Normalizing addresses before verifying them avoids spending credits on
+//! addresses the BriteVerify API would reject outright (e.g. for an invalid
+//! ZIP code or missing inputs), and makes identical addresses compare equal.
+//!
+//! ## Basic Usage
+//! ```no_run
+//! # use briteverify_rs::types::StreetAddressArray;
+//! #
+//! # fn doc() -> anyhow::Result<()> {
+//! let address: StreetAddressArray = StreetAddressArray::builder()
+//!     .address1("123 north main street")
+//!     .address2("suite 100")
+//!     .city("any town")
+//!     .state("California")
+//!     .zip("902101234")
+//!     .normalize(true)
+//!     .build()?;
+//!
+//! assert_eq!(address.address1, "123 N MAIN ST");
+//! assert_eq!(address.address2.as_deref(), Some("STE 100"));
+//! assert_eq!(address.city, "ANY TOWN");
+//! assert_eq!(address.state, "CA");
+//! assert_eq!(address.zip, "90210-1234");
+//! # Ok(())
+//! # }
+//! ```
+//
+// Standard Library Imports
+use std::collections::HashMap;
+
+// Third Party Imports
+use serde_json::{Map as JsonMap, Value};
+
+// Crate-Level Imports
+use super::{
+    enums::{VerificationError, VerificationStatus},
+    single::{AddressArrayBuilder, AddressVerificationArray, StreetAddressArray},
+};
+use crate::errors::BriteVerifyTypeError;
+
+// <editor-fold desc="// Constants ...">
+
+/// US states, districts, territories, and armed forces
+/// "states", as `(name, USPS abbreviation)`
+const STATES: [(&str, &str); 62] = [
+    ("ALABAMA", "AL"),
+    ("ALASKA", "AK"),
+    ("AMERICAN SAMOA", "AS"),
+    ("ARIZONA", "AZ"),
+    ("ARKANSAS", "AR"),
+    ("ARMED FORCES AMERICAS", "AA"),
+    ("ARMED FORCES EUROPE", "AE"),
+    ("ARMED FORCES PACIFIC", "AP"),
+    ("CALIFORNIA", "CA"),
+    ("COLORADO", "CO"),
+    ("CONNECTICUT", "CT"),
+    ("DELAWARE", "DE"),
+    ("DISTRICT OF COLUMBIA", "DC"),
+    ("FEDERATED STATES OF MICRONESIA", "FM"),
+    ("FLORIDA", "FL"),
+    ("GEORGIA", "GA"),
+    ("GUAM", "GU"),
+    ("HAWAII", "HI"),
+    ("IDAHO", "ID"),
+    ("ILLINOIS", "IL"),
+    ("INDIANA", "IN"),
+    ("IOWA", "IA"),
+    ("KANSAS", "KS"),
+    ("KENTUCKY", "KY"),
+    ("LOUISIANA", "LA"),
+    ("MAINE", "ME"),
+    ("MARSHALL ISLANDS", "MH"),
+    ("MARYLAND", "MD"),
+    ("MASSACHUSETTS", "MA"),
+    ("MICHIGAN", "MI"),
+    ("MINNESOTA", "MN"),
+    ("MISSISSIPPI", "MS"),
+    ("MISSOURI", "MO"),
+    ("MONTANA", "MT"),
+    ("NEBRASKA", "NE"),
+    ("NEVADA", "NV"),
+    ("NEW HAMPSHIRE", "NH"),
+    ("NEW JERSEY", "NJ"),
+    ("NEW MEXICO", "NM"),
+    ("NEW YORK", "NY"),
+    ("NORTH CAROLINA", "NC"),
+    ("NORTH DAKOTA", "ND"),
+    ("NORTHERN MARIANA ISLANDS", "MP"),
+    ("OHIO", "OH"),
+    ("OKLAHOMA", "OK"),
+    ("OREGON", "OR"),
+    ("PALAU", "PW"),
+    ("PENNSYLVANIA", "PA"),
+    ("PUERTO RICO", "PR"),
+    ("RHODE ISLAND", "RI"),
+    ("SOUTH CAROLINA", "SC"),
+    ("SOUTH DAKOTA", "SD"),
+    ("TENNESSEE", "TN"),
+    ("TEXAS", "TX"),
+    ("UTAH", "UT"),
+    ("VERMONT", "VT"),
+    ("VIRGIN ISLANDS", "VI"),
+    ("VIRGINIA", "VA"),
+    ("WASHINGTON", "WA"),
+    ("WEST VIRGINIA", "WV"),
+    ("WISCONSIN", "WI"),
+    ("WYOMING", "WY"),
+];
+
+/// Common street suffixes (and their common variants),
+/// as `(suffix, USPS abbreviation)`
+const STREET_SUFFIXES: [(&str, &str); 72] = [
+    ("ALLEY", "ALY"),
+    ("ANNEX", "ANX"),
+    ("ARCADE", "ARC"),
+    ("AVENUE", "AVE"),
+    ("AV", "AVE"),
+    ("AVEN", "AVE"),
+    ("AVENU", "AVE"),
+    ("AVN", "AVE"),
+    ("BAYOU", "BYU"),
+    ("BEACH", "BCH"),
+    ("BEND", "BND"),
+    ("BLUFF", "BLF"),
+    ("BOULEVARD", "BLVD"),
+    ("BOUL", "BLVD"),
+    ("BOULV", "BLVD"),
+    ("BRANCH", "BR"),
+    ("BRIDGE", "BRG"),
+    ("BROOK", "BRK"),
+    ("BYPASS", "BYP"),
+    ("CANYON", "CYN"),
+    ("CAUSEWAY", "CSWY"),
+    ("CENTER", "CTR"),
+    ("CENTRE", "CTR"),
+    ("CIRCLE", "CIR"),
+    ("CIRC", "CIR"),
+    ("COURT", "CT"),
+    ("COVE", "CV"),
+    ("CREEK", "CRK"),
+    ("CRESCENT", "CRES"),
+    ("CROSSING", "XING"),
+    ("DRIVE", "DR"),
+    ("DRIV", "DR"),
+    ("DRV", "DR"),
+    ("EXPRESSWAY", "EXPY"),
+    ("EXTENSION", "EXT"),
+    ("FREEWAY", "FWY"),
+    ("GARDENS", "GDNS"),
+    ("GATEWAY", "GTWY"),
+    ("GROVE", "GRV"),
+    ("HARBOR", "HBR"),
+    ("HEIGHTS", "HTS"),
+    ("HIGHWAY", "HWY"),
+    ("HIGHWY", "HWY"),
+    ("HILL", "HL"),
+    ("HOLLOW", "HOLW"),
+    ("ISLAND", "IS"),
+    ("JUNCTION", "JCT"),
+    ("LAKE", "LK"),
+    ("LANDING", "LNDG"),
+    ("LANE", "LN"),
+    ("MEADOWS", "MDWS"),
+    ("MOUNT", "MT"),
+    ("MOUNTAIN", "MTN"),
+    ("PARKWAY", "PKWY"),
+    ("PKY", "PKWY"),
+    ("PLACE", "PL"),
+    ("PLAZA", "PLZ"),
+    ("POINT", "PT"),
+    ("RIDGE", "RDG"),
+    ("ROAD", "RD"),
+    ("ROUTE", "RTE"),
+    ("SQUARE", "SQ"),
+    ("STREET", "ST"),
+    ("STR", "ST"),
+    ("STRT", "ST"),
+    ("TERRACE", "TER"),
+    ("TRAIL", "TRL"),
+    ("TURNPIKE", "TPKE"),
+    ("VALLEY", "VLY"),
+    ("VIEW", "VW"),
+    ("VILLAGE", "VLG"),
+    ("WAY", "WAY"),
+];
+
+/// Directionals, as `(direction, USPS abbreviation)`
+const DIRECTIONALS: [(&str, &str); 8] = [
+    ("NORTH", "N"),
+    ("SOUTH", "S"),
+    ("EAST", "E"),
+    ("WEST", "W"),
+    ("NORTHEAST", "NE"),
+    ("NORTHWEST", "NW"),
+    ("SOUTHEAST", "SE"),
+    ("SOUTHWEST", "SW"),
+];
+
+/// Secondary unit designators, as
+/// `(designator, USPS abbreviation)`
+const UNIT_DESIGNATORS: [(&str, &str); 12] = [
+    ("APARTMENT", "APT"),
+    ("BUILDING", "BLDG"),
+    ("DEPARTMENT", "DEPT"),
+    ("FLOOR", "FL"),
+    ("LOT", "LOT"),
+    ("OFFICE", "OFC"),
+    ("ROOM", "RM"),
+    ("SPACE", "SPC"),
+    ("SUITE", "STE"),
+    ("TRAILER", "TRLR"),
+    ("UNIT", "UNIT"),
+    ("#", "#"),
+];
+
+/// Country names that may trail a free-form US street address
+const COUNTRY_NAMES: [&str; 6] = [
+    "US",
+    "USA",
+    "U S",
+    "U S A",
+    "UNITED STATES",
+    "UNITED STATES OF AMERICA",
+];
+
+/// Accepted field names (and their common aliases), as `(alias,
+/// field)`, compared after lower-casing and removing any spaces,
+/// underscores, hyphens, and periods
+const FIELD_ALIASES: [(&str, &str); 19] = [
+    ("address1", "address1"),
+    ("address", "address1"),
+    ("street", "address1"),
+    ("streetaddress", "address1"),
+    ("line1", "address1"),
+    ("addressline1", "address1"),
+    ("address2", "address2"),
+    ("street2", "address2"),
+    ("line2", "address2"),
+    ("addressline2", "address2"),
+    ("city", "city"),
+    ("town", "city"),
+    ("state", "state"),
+    ("province", "state"),
+    ("region", "state"),
+    ("zip", "zip"),
+    ("zipcode", "zip"),
+    ("postalcode", "zip"),
+    ("postcode", "zip"),
+];
+
+// </editor-fold desc="// Constants ...">
+
+// <editor-fold desc="// Normalization Functions ...">
+
+/// Get the USPS abbreviation of the supplied
+/// US state name (or abbreviation)
+pub fn state_abbreviation(value: &str) -> Option<&'static str> {
+    let value = fold(value);
+
+    STATES
+        .iter()
+        .find(|(name, abbr)| value == *name || value == *abbr)
+        .map(|(_, abbr)| *abbr)
+}
+
+/// Get the full name of the US state with the
+/// supplied USPS abbreviation (or name)
+pub fn state_name(value: &str) -> Option<&'static str> {
+    let value = fold(value);
+
+    STATES
+        .iter()
+        .find(|(name, abbr)| value == *name || value == *abbr)
+        .map(|(name, _)| *name)
+}
+
+/// Check and format the supplied ZIP or ZIP+4 code as
+/// either `"12345"` or `"12345-6789"`, ignoring any
+/// whitespace and the ZIP+4 separator
+pub fn normalize_zip(value: &str) -> Option<String> {
+    let compact: String = value
+        .chars()
+        .filter(|char| !char.is_whitespace() && *char != '-')
+        .collect();
+
+    if !compact.chars().all(|char| char.is_ascii_digit()) {
+        return None;
+    }
+
+    match compact.len() {
+        5 => Some(compact),
+        9 => Some(format!("{}-{}", &compact[..5], &compact[5..])),
+        _ => None,
+    }
+}
+
+/// Standardize the supplied street address line, abbreviating
+/// its street suffix, directionals, and secondary unit designator
+pub fn normalize_street(value: &str) -> String {
+    let mut tokens: Vec<String> = fold(value)
+        .split(' ')
+        .map(|token| token.trim_end_matches(['.', ',']).to_string())
+        .filter(|token| !token.is_empty())
+        .collect();
+
+    // everything from the first unit designator
+    // onward is a secondary unit
+    let unit = (0..tokens.len())
+        .find(|idx| _unit_designator(&tokens[*idx]).is_some())
+        .unwrap_or(tokens.len());
+
+    if let Some(designator) = tokens
+        .get(unit)
+        .and_then(|token| _lookup(&UNIT_DESIGNATORS, token))
+    {
+        tokens[unit] = designator.to_string();
+    }
+
+    // any directionals immediately preceding the secondary
+    // unit (or the end of the line) are post-directionals
+    let mut suffix = unit;
+
+    while suffix > 1 && _lookup(&DIRECTIONALS, &tokens[suffix - 1]).is_some() {
+        suffix -= 1;
+    }
+
+    for token in &mut tokens[suffix..unit] {
+        *token = _lookup(&DIRECTIONALS, token).unwrap_or(token).to_string();
+    }
+
+    // the street suffix is the last word of the street name
+    // (which must have at least one word besides the number)
+    let number = usize::from(
+        tokens
+            .first()
+            .is_some_and(|token| token.chars().any(|char| char.is_ascii_digit())),
+    );
+
+    let suffix = match suffix.checked_sub(1) {
+        Some(idx) if idx > number => match _lookup(&STREET_SUFFIXES, &tokens[idx]) {
+            Some(abbr) => {
+                tokens[idx] = abbr.to_string();
+                idx
+            }
+            None => suffix,
+        },
+        _ => suffix,
+    };
+
+    // a directional immediately following the house number
+    // is a pre-directional, unless it's the street's name
+    if number + 1 < suffix {
+        if let Some(abbr) = _lookup(&DIRECTIONALS, &tokens[number]) {
+            tokens[number] = abbr.to_string();
+        }
+    }
+
+    tokens.join(" ")
+}
+
+/// Trim, collapse the internal whitespace of,
+/// and upper-case the supplied value
+pub fn fold(value: &str) -> String {
+    value
+        .split_whitespace()
+        .collect::<Vec<&str>>()
+        .join(" ")
+        .to_uppercase()
+}
+
+/// [internal-implementation]
+/// Look up the abbreviation of the supplied token
+fn _lookup(table: &[(&str, &'static str)], token: &str) -> Option<&'static str> {
+    table
+        .iter()
+        .find(|(name, abbr)| token == *name || token == *abbr)
+        .map(|(_, abbr)| *abbr)
+}
+
+/// [internal-implementation]
+/// Look up the abbreviation of the supplied
+/// (potential) secondary unit designator
+fn _unit_designator(token: &str) -> Option<&'static str> {
+    match token.strip_prefix('#') {
+        Some(_) => Some("#"),
+        None => _lookup(&UNIT_DESIGNATORS, token),
+    }
+}
+
+// </editor-fold desc="// Normalization Functions ...">
+
+// <editor-fold desc="// StreetAddressArray Normalization ...">
+
+impl StreetAddressArray {
+    /// Get a normalized copy of the street address (see
+    /// [`types::address`](crate::types::address) for details)
+    pub fn normalized(&self) -> Result<Self, BriteVerifyTypeError> {
+        let invalid = |field: &'static str, value: &str| {
+            BriteVerifyTypeError::InvalidAddressField(field, value.to_string())
+        };
+
+        let address1 = normalize_street(&self.address1);
+        let address2 = self
+            .address2
+            .as_deref()
+            .map(normalize_street)
+            .filter(|value| !value.is_empty());
+        let city = fold(&self.city);
+
+        if address1.is_empty() {
+            return Err(invalid("address1", &self.address1));
+        }
+
+        if city.is_empty() {
+            return Err(invalid("city", &self.city));
+        }
+
+        Ok(Self {
+            address1,
+            address2,
+            city,
+            state: state_abbreviation(&self.state)
+                .ok_or_else(|| invalid("state", &self.state))?
+                .to_string(),
+            zip: normalize_zip(&self.zip).ok_or_else(|| invalid("zip", &self.zip))?,
+        })
+    }
+
+    /// [internal-implementation]
+    /// Build the verification result the BriteVerify API would
+    /// report for the supplied (un-normalizable) street address
+    pub(crate) fn _invalid(&self, field: &str) -> AddressVerificationArray {
+        let error = match field {
+            "zip" => VerificationError::ZipCodeInvalid,
+            _ => VerificationError::MissingMinimumInputs,
+        };
+
+        AddressVerificationArray {
+            address1: self.address1.clone(),
+            address2: self.address2.clone(),
+            city: self.city.clone(),
+            state: self.state.clone(),
+            zip: self.zip.clone(),
+            status: VerificationStatus::Invalid,
+            corrected: false,
+            errors: vec![serde_json::Value::String(error.to_string())],
+            secondary_status: None,
+        }
+    }
+}
+
+// </editor-fold desc="// StreetAddressArray Normalization ...">
+
+// <editor-fold desc="// Free-Form Address Parsing ...">
+
+impl StreetAddressArray {
+    /// Parse a free-form, single- or multi-line US street address
+    /// (e.g. `"123 Main St Ste 100, Any Town, CA 90210"`)
+    ///
+    /// Lines and comma-separated parts are read from the end: the
+    /// ZIP code, the state (name or abbreviation), the city, and then
+    /// the street address lines. A secondary unit on the first street
+    /// line is split out into `address2`. The parsed values are not
+    /// normalized, use [`normalized`](StreetAddressArray::normalized)
+    /// for that.
+    ///
+    /// #### Example
+    /// ```no_run
+    /// # use briteverify_rs::types::StreetAddressArray;
+    /// #
+    /// # fn doc() -> anyhow::Result<()> {
+    /// let address = StreetAddressArray::parse("123 Main St Ste 100\nAny Town, CA 90210")?;
+    ///
+    /// assert_eq!(address.address1, "123 Main St");
+    /// assert_eq!(address.address2.as_deref(), Some("Ste 100"));
+    /// assert_eq!(address.city, "Any Town");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn parse<Value: AsRef<str>>(value: Value) -> Result<Self, BriteVerifyTypeError> {
+        let value = value.as_ref();
+        let unparseable = |reason: &'static str| {
+            BriteVerifyTypeError::UnparseableAddress(value.to_string(), reason)
+        };
+
+        let mut parts: Vec<Vec<&str>> = value
+            .split(['\n', ','])
+            .map(|part| part.split_whitespace().collect::<Vec<&str>>())
+            .filter(|words| !words.is_empty())
+            .collect();
+
+        // a trailing country (as its own part or the last word)
+        if parts.last().is_some_and(|words| _is_country(words)) {
+            parts.pop();
+        } else if let Some(words) = parts.last_mut() {
+            if words.len() > 1 && _is_country(&words[words.len() - 1..]) {
+                words.pop();
+            }
+        }
+
+        let zip = parts
+            .last_mut()
+            .and_then(|words| words.pop())
+            .filter(|word| normalize_zip(word).is_some())
+            .ok_or_else(|| unparseable("missing ZIP code"))?;
+
+        if parts.last().is_some_and(Vec::is_empty) {
+            parts.pop();
+        }
+
+        // state names may be up to four words
+        // long (e.g. "District of Columbia")
+        let state = parts
+            .last_mut()
+            .and_then(|words| {
+                let longest = words.len().min(4);
+
+                (1..=longest).rev().find_map(|count| {
+                    let state = words[words.len() - count..].join(" ");
+
+                    state_abbreviation(&state).map(|_| {
+                        words.truncate(words.len() - count);
+                        state
+                    })
+                })
+            })
+            .ok_or_else(|| unparseable("missing state"))?;
+
+        let city = match parts.pop() {
+            // the city shares a line with the state
+            Some(words) if !words.is_empty() && !parts.is_empty() => words.join(" "),
+            // the city, state, and street address are all on the same line
+            Some(words) if !words.is_empty() => {
+                let split = _street_end(&words).ok_or_else(|| unparseable("missing city"))?;
+
+                parts.push(words[..split].to_vec());
+                words[split..].join(" ")
+            }
+            // the city is on its own line
+            _ => parts
+                .pop()
+                .map(|words| words.join(" "))
+                .ok_or_else(|| unparseable("missing city"))?,
+        };
+
+        let mut lines = parts.into_iter();
+        let mut address1 = lines
+            .next()
+            .ok_or_else(|| unparseable("missing street address"))?;
+        let mut address2: Vec<String> = lines.map(|words| words.join(" ")).collect();
+
+        // a secondary unit on the first street line
+        if address2.is_empty() {
+            if let Some(unit) =
+                (1..address1.len()).find(|idx| _unit_designator(&_token(address1[*idx])).is_some())
+            {
+                address2.push(address1.split_off(unit).join(" "));
+            }
+        }
+
+        Ok(Self {
+            address1: address1.join(" "),
+            address2: Some(address2.join(", ")).filter(|value| !value.is_empty()),
+            city,
+            state,
+            zip: zip.to_string(),
+        })
+    }
+}
+
+impl std::str::FromStr for StreetAddressArray {
+    type Err = BriteVerifyTypeError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::parse(value)
+    }
+}
+
+/// [internal-implementation]
+/// Fold the supplied word of a free-form address for table lookups
+fn _token(word: &str) -> String {
+    fold(word.trim_end_matches(['.', ',']))
+}
+
+/// [internal-implementation]
+/// Check if the supplied words are the name of the United States
+fn _is_country(words: &[&str]) -> bool {
+    let name = fold(&words.join(" ").replace('.', " "));
+
+    COUNTRY_NAMES.contains(&name.as_str())
+}
+
+/// [internal-implementation]
+/// Find the end of the street address in a line that also contains
+/// the city, i.e. the word after the street suffix and any trailing
+/// directional and secondary unit
+fn _street_end(words: &[&str]) -> Option<usize> {
+    let tokens: Vec<String> = words.iter().map(|word| _token(word)).collect();
+    let number = usize::from(
+        tokens
+            .first()
+            .is_some_and(|token| token.chars().any(|char| char.is_ascii_digit())),
+    );
+
+    let mut end = (number + 1..tokens.len())
+        .find(|idx| _lookup(&STREET_SUFFIXES, &tokens[*idx]).is_some())?
+        + 1;
+
+    if tokens
+        .get(end)
+        .is_some_and(|token| _lookup(&DIRECTIONALS, token).is_some())
+    {
+        end += 1;
+    }
+
+    match tokens.get(end) {
+        Some(token) if token.starts_with('#') => end += 1,
+        Some(token) if _unit_designator(token).is_some() => end += 2,
+        _ => {}
+    }
+
+    Some(end).filter(|end| *end < tokens.len())
+}
+
+// </editor-fold desc="// Free-Form Address Parsing ...">
+
+// <editor-fold desc="// Flexible-Input Conversions ...">
+
+impl AddressArrayBuilder {
+    /// Create a new `AddressArrayBuilder` pre-populated from the
+    /// supplied `(field name, value)` pairs, accepting the common
+    /// aliases of each field's name (e.g. `"postal_code"`, `"Zip Code"`,
+    /// or `"zip"`) and ignoring unrecognized names and blank values
+    ///
+    /// #### Example
+    /// ```no_run
+    /// # use briteverify_rs::types::AddressArrayBuilder;
+    /// #
+    /// # fn doc() -> anyhow::Result<()> {
+    /// let address = AddressArrayBuilder::from_fields([
+    ///     ("Street", Some("123 Main St")),
+    ///     ("City", Some("Any Town")),
+    ///     ("Province", Some("CA")),
+    ///     ("Postal Code", Some("90210")),
+    /// ])
+    /// .build()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_fields<Field: AsRef<str>, Displayable: ToString>(
+        fields: impl IntoIterator<Item = (Field, Option<Displayable>)>,
+    ) -> Self {
+        fields
+            .into_iter()
+            .filter_map(|(field, value)| Some((_field_name(field.as_ref())?, value?.to_string())))
+            .filter(|(_, value)| !value.trim().is_empty())
+            .fold(Self::new(), |builder, (field, value)| match field {
+                "address1" => builder.address1(value),
+                "address2" => builder.address2(value),
+                "city" => builder.city(value),
+                "state" => builder.state(value),
+                _ => builder.zip(value),
+            })
+    }
+}
+
+impl StreetAddressArray {
+    /// Create a new `StreetAddressArray` from a CSV row
+    /// and its header row (see [`AddressArrayBuilder::from_fields`]
+    /// for the accepted column names)
+    ///
+    /// #### Example
+    /// ```no_run
+    /// # use briteverify_rs::types::StreetAddressArray;
+    /// #
+    /// # fn doc() -> anyhow::Result<()> {
+    /// let address = StreetAddressArray::from_csv_row(
+    ///     &["email", "line1", "city", "state", "zipcode"],
+    ///     &["test@example.com", "123 Main St", "Any Town", "CA", "90210"],
+    /// )?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_csv_row<Header: AsRef<str>, Field: AsRef<str>>(
+        headers: &[Header],
+        row: &[Field],
+    ) -> Result<Self, BriteVerifyTypeError> {
+        AddressArrayBuilder::from_fields(
+            headers
+                .iter()
+                .zip(row)
+                .map(|(header, field)| (header.as_ref(), Some(field.as_ref()))),
+        )
+        .build()
+    }
+}
+
+impl TryFrom<Value> for StreetAddressArray {
+    type Error = BriteVerifyTypeError;
+
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        Self::try_from(&value)
+    }
+}
+
+impl TryFrom<&Value> for StreetAddressArray {
+    type Error = BriteVerifyTypeError;
+
+    /// Create a new `StreetAddressArray` from a JSON object, or
+    /// from the object under the `"address"` key of one
+    fn try_from(value: &Value) -> Result<Self, Self::Error> {
+        match value.as_object() {
+            Some(data) => Self::try_from(data),
+            None => AddressArrayBuilder::new().build(),
+        }
+    }
+}
+
+impl TryFrom<JsonMap<String, Value>> for StreetAddressArray {
+    type Error = BriteVerifyTypeError;
+
+    fn try_from(data: JsonMap<String, Value>) -> Result<Self, Self::Error> {
+        Self::try_from(&data)
+    }
+}
+
+impl TryFrom<&JsonMap<String, Value>> for StreetAddressArray {
+    type Error = BriteVerifyTypeError;
+
+    fn try_from(data: &JsonMap<String, Value>) -> Result<Self, Self::Error> {
+        if let Some(Value::Object(address)) = data.get("address") {
+            return Self::try_from(address);
+        }
+
+        AddressArrayBuilder::from_fields(data.iter().map(|(field, value)| {
+            let value = match value {
+                Value::String(value) => Some(value.clone()),
+                Value::Number(value) => Some(value.to_string()),
+                _ => None,
+            };
+
+            (field, value)
+        }))
+        .build()
+    }
+}
+
+impl TryFrom<HashMap<String, String>> for StreetAddressArray {
+    type Error = BriteVerifyTypeError;
+
+    fn try_from(data: HashMap<String, String>) -> Result<Self, Self::Error> {
+        Self::try_from(&data)
+    }
+}
+
+impl TryFrom<&HashMap<String, String>> for StreetAddressArray {
+    type Error = BriteVerifyTypeError;
+
+    fn try_from(data: &HashMap<String, String>) -> Result<Self, Self::Error> {
+        AddressArrayBuilder::from_fields(data.iter().map(|(field, value)| (field, Some(value))))
+            .build()
+    }
+}
+
+impl TryFrom<HashMap<String, Option<String>>> for StreetAddressArray {
+    type Error = BriteVerifyTypeError;
+
+    fn try_from(data: HashMap<String, Option<String>>) -> Result<Self, Self::Error> {
+        Self::try_from(&data)
+    }
+}
+
+impl TryFrom<&HashMap<String, Option<String>>> for StreetAddressArray {
+    type Error = BriteVerifyTypeError;
+
+    fn try_from(data: &HashMap<String, Option<String>>) -> Result<Self, Self::Error> {
+        AddressArrayBuilder::from_fields(data.iter().map(|(field, value)| (field, value.as_ref())))
+            .build()
+    }
+}
+
+impl TryFrom<HashMap<&str, &str>> for StreetAddressArray {
+    type Error = BriteVerifyTypeError;
+
+    fn try_from(data: HashMap<&str, &str>) -> Result<Self, Self::Error> {
+        Self::try_from(&data)
+    }
+}
+
+impl TryFrom<&HashMap<&str, &str>> for StreetAddressArray {
+    type Error = BriteVerifyTypeError;
+
+    fn try_from(data: &HashMap<&str, &str>) -> Result<Self, Self::Error> {
+        AddressArrayBuilder::from_fields(data.iter().map(|(field, value)| (field, Some(value))))
+            .build()
+    }
+}
+
+/// [internal-implementation]
+/// Resolve the supplied (potentially aliased) field
+/// name to its `StreetAddressArray` field name
+fn _field_name(alias: &str) -> Option<&'static str> {
+    let alias: String = alias
+        .chars()
+        .filter(|char| !matches!(char, ' ' | '_' | '-' | '.'))
+        .collect::<String>()
+        .to_lowercase();
+
+    FIELD_ALIASES
+        .iter()
+        .find(|(name, _)| alias == *name)
+        .map(|(_, field)| *field)
+}
+
+// </editor-fold desc="// Flexible-Input Conversions ...">
+
+// <editor-fold desc="// I/O-Free Tests ...">
+
+#[cfg(test)]
+mod tests {
+    // Third-Party Dependencies
+    use pretty_assertions::assert_eq;
+
+    // Standard Library Imports
+    use std::collections::HashMap;
+
+    // Crate-Level Imports
+    use crate::{errors::BriteVerifyTypeError, types::StreetAddressArray};
+
+    /// Test that street address lines are standardized
+    #[rstest::rstest]
+    #[case("123 Main Street", "123 MAIN ST")]
+    #[case("  123   main st. ", "123 MAIN ST")]
+    #[case("123 North Main Street", "123 N MAIN ST")]
+    #[case("123 North Street", "123 NORTH ST")]
+    #[case("123 Main Street Northwest", "123 MAIN ST NW")]
+    #[case("123 Main Street Apartment 4", "123 MAIN ST APT 4")]
+    #[case("123 Main St., Suite 100", "123 MAIN ST STE 100")]
+    #[case("123 Main Street #4", "123 MAIN ST #4")]
+    #[case("123 Avenue of the Americas", "123 AVENUE OF THE AMERICAS")]
+    #[case("456 Park Avenue South", "456 PARK AVE S")]
+    #[case("Suite 100", "STE 100")]
+    fn test_normalize_street(#[case] value: &str, #[case] expected: &str) {
+        assert_eq!(expected, super::normalize_street(value));
+    }
+
+    /// Test that states are converted between
+    /// their names and USPS abbreviations
+    #[rstest::rstest]
+    #[case("California", Some("CA"))]
+    #[case(" ca ", Some("CA"))]
+    #[case("district  of columbia", Some("DC"))]
+    #[case("Wyoming", Some("WY"))]
+    #[case("Atlantis", None)]
+    fn test_state_abbreviation(#[case] value: &str, #[case] expected: Option<&str>) {
+        assert_eq!(expected, super::state_abbreviation(value));
+    }
+
+    /// Test that ZIP and ZIP+4 codes are checked and formatted
+    #[rstest::rstest]
+    #[case("90210", Some("90210"))]
+    #[case(" 90210-1234 ", Some("90210-1234"))]
+    #[case("902101234", Some("90210-1234"))]
+    #[case("9021", None)]
+    #[case("90210-12", None)]
+    #[case("9O210", None)]
+    fn test_normalize_zip(#[case] value: &str, #[case] expected: Option<&str>) {
+        assert_eq!(expected.map(str::to_string), super::normalize_zip(value));
+    }
+
+    /// Test that free-form street addresses are parsed
+    #[rstest::rstest]
+    #[case(
+        "123 Main St Ste 100, Any Town, CA 90210",
+        ("123 Main St", Some("Ste 100"), "Any Town", "CA", "90210")
+    )]
+    #[case(
+        "123 Main St.\nApt 4\nAny Town, California 90210-1234\nUSA",
+        ("123 Main St.", Some("Apt 4"), "Any Town", "California", "90210-1234")
+    )]
+    #[case(
+        "123 North Main Street #4 Any Town CA 90210",
+        ("123 North Main Street", Some("#4"), "Any Town", "CA", "90210")
+    )]
+    #[case(
+        "456 Park Avenue South, New York, New York 10001, United States",
+        ("456 Park Avenue South", None, "New York", "New York", "10001")
+    )]
+    #[case(
+        "1600 Pennsylvania Ave NW, Washington District of Columbia 20500",
+        ("1600 Pennsylvania Ave NW", None, "Washington", "District of Columbia", "20500")
+    )]
+    fn test_parse_address(
+        #[case] value: &str,
+        #[case] expected: (&str, Option<&str>, &str, &str, &str),
+    ) {
+        let (address1, address2, city, state, zip) = expected;
+
+        assert_eq!(
+            StreetAddressArray::from_values(address1, address2, city, state, zip),
+            StreetAddressArray::parse(value).unwrap()
+        );
+    }
+
+    /// Test that incomplete or ambiguous free-form
+    /// street addresses are rejected
+    #[rstest::rstest]
+    #[case("123 Main St, Any Town, CA", "missing ZIP code")]
+    #[case("123 Main St, Any Town, 90210", "missing state")]
+    #[case("Any Town, CA 90210", "missing street address")]
+    #[case("123 Main St CA 90210", "missing city")]
+    #[case("not an address", "missing ZIP code")]
+    fn test_parse_invalid_address(#[case] value: &str, #[case] expected: &str) {
+        assert!(matches!(
+            StreetAddressArray::parse(value),
+            Err(crate::errors::BriteVerifyTypeError::UnparseableAddress(_, reason))
+                if reason == expected
+        ));
+    }
+
+    /// Test that street addresses can be created from
+    /// JSON objects, string maps, and CSV rows using
+    /// the common aliases of each field's name
+    #[rstest::rstest]
+    fn test_flexible_conversions() {
+        let expected = StreetAddressArray::from_values(
+            "123 Main St",
+            Some("Ste 100"),
+            "Any Town",
+            "CA",
+            "01234",
+        );
+
+        let json = serde_json::json!({
+            "address": {
+                "street": "123 Main St",
+                "Address Line 2": "Ste 100",
+                "city": "Any Town",
+                "province": "CA",
+                "postal_code": "01234",
+            }
+        });
+
+        assert_eq!(expected, StreetAddressArray::try_from(&json).unwrap());
+
+        let map: HashMap<&str, &str> = [
+            ("line1", "123 Main St"),
+            ("line2", "Ste 100"),
+            ("town", "Any Town"),
+            ("region", "CA"),
+            ("zipcode", "01234"),
+        ]
+        .into_iter()
+        .collect();
+
+        assert_eq!(expected, StreetAddressArray::try_from(map).unwrap());
+
+        let row = StreetAddressArray::from_csv_row(
+            &["Email", "Address1", "Address2", "City", "State", "ZIP Code"],
+            &[
+                "test@example.com",
+                "123 Main St",
+                "Ste 100",
+                "Any Town",
+                "CA",
+                "01234",
+            ],
+        );
+
+        assert_eq!(expected, row.unwrap());
+    }
+
+    /// Test that flexible conversions list the missing
+    /// fields when a street address can't be created
+    #[rstest::rstest]
+    fn test_flexible_conversion_errors() {
+        let data: HashMap<String, Option<String>> = [
+            ("address1".to_string(), Some("123 Main St".to_string())),
+            ("city".to_string(), Some("  ".to_string())),
+            ("state".to_string(), None),
+            ("zip".to_string(), Some("90210".to_string())),
+        ]
+        .into_iter()
+        .collect();
+
+        let error = StreetAddressArray::try_from(data).unwrap_err();
+
+        assert!(error.to_string().ends_with("(missing: city, state)"));
+        assert!(matches!(
+            error,
+            BriteVerifyTypeError::UnbuildableAddressArray(builder)
+                if builder.missing_fields() == ["city", "state"]
+        ));
+
+        assert!(StreetAddressArray::try_from(serde_json::json!("123 Main St")).is_err());
+    }
+
+    /// Test that complete street addresses
+    /// are normalized (or rejected)
+    #[rstest::rstest]
+    fn test_normalized_address() {
+        let address = StreetAddressArray::from_values(
+            "123 main street",
+            Some("  "),
+            " any  town ",
+            "new york",
+            "10001",
+        );
+        let normalized = address.normalized().unwrap();
+
+        assert_eq!("123 MAIN ST", normalized.address1);
+        assert_eq!(None, normalized.address2);
+        assert_eq!("ANY TOWN", normalized.city);
+        assert_eq!("NY", normalized.state);
+        assert_eq!(Some("NEW YORK"), super::state_name(&normalized.state));
+
+        let invalid = StreetAddressArray {
+            zip: "1234".to_string(),
+            ..address
+        };
+
+        assert!(invalid.normalized().is_err());
+        assert_eq!(
+            vec![serde_json::json!("zip_code_invalid")],
+            invalid._invalid("zip").errors
+        );
+    }
+}
+
+// </editor-fold desc="// I/O-Free Tests ...">
@@ -15,8 +15,8 @@ use super::{
 
 // Conditional Imports
 #[doc(hidden)]
-#[cfg(any(test, tarpaulin, feature = "ci"))]
-#[cfg_attr(any(test, tarpaulin, feature = "ci"), allow(unused_imports))]
+#[cfg(any(test, tarpaulin, feature = "ci", feature = "fake"))]
+#[allow(unused_imports)]
 pub use self::foundry::*;
 
 // <editor-fold desc="// Bulk Requests ...">
@@ -25,7 +25,7 @@ pub use self::foundry::*;
 
 /// A request for verification of multiple "contact" records
 #[cfg_attr(any(test, tarpaulin, feature = "ci"), derive(PartialEq))]
-#[derive(Debug, Default, serde::Serialize, serde::Deserialize)]
+#[derive(Clone, Debug, Default, serde::Serialize, serde::Deserialize)]
 pub struct BulkVerificationRequest {
     /// The "contact" records to be verified
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
@@ -75,7 +75,7 @@ impl BulkVerificationRequest {
 
 /// An error message returned by the BriteVerify API
 #[cfg_attr(any(test, tarpaulin, feature = "ci"), derive(PartialEq))]
-#[derive(Debug, Default, serde::Serialize, serde::Deserialize)]
+#[derive(Clone, Debug, Default, serde::Serialize, serde::Deserialize)]
 pub struct BulkListCRUDError {
     /// A list's BriteVerify API-issued identifier
     #[serde(
@@ -109,7 +109,7 @@ pub struct BulkListCRUDError {
 /// Details of the current "state" of a bulk verification
 /// job / request / "list" ([ref](https://docs.briteverify.com/#0b5a2a7a-4062-4327-ab0a-4675592e3cd6))
 #[cfg_attr(any(test, tarpaulin, feature = "ci"), derive(PartialEq))]
-#[derive(Debug, Default, serde::Serialize, serde::Deserialize)]
+#[derive(Clone, Debug, Default, serde::Serialize, serde::Deserialize)]
 pub struct VerificationListState {
     /// The list's unique identifier, issued by
     /// and specific to the BriteVerify API.
@@ -226,7 +226,7 @@ pub struct VerificationListState {
 /// by any user-specified parameters (e.g. `date`,
 /// `page`, or `state`)
 #[cfg_attr(any(test, tarpaulin, feature = "ci"), derive(PartialEq))]
-#[derive(Debug, Default, serde::Serialize, serde::Deserialize)]
+#[derive(Clone, Debug, Default, serde::Serialize, serde::Deserialize)]
 pub struct GetListStatesResponse {
     /// Usually page numbers (if provided)
     #[serde(default)]
@@ -271,11 +271,7 @@ impl GetListStatesResponse {
         match self.message.as_ref() {
             None => (1u64, 1u64),
             Some(message) => {
-                let mut values = message
-                    .split(' ')
-                    .map(|value| value.parse::<u64>())
-                    .filter(Result::is_ok)
-                    .map(Result::unwrap);
+                let mut values = message.split(' ').flat_map(|value| value.parse::<u64>());
 
                 (values.next().unwrap_or(1u64), values.next().unwrap_or(1u64))
             }
@@ -316,7 +312,7 @@ impl GetListStatesResponse {
 /// well-formed request to create, update, or
 /// delete a bulk verification list
 #[cfg_attr(any(test, tarpaulin, feature = "ci"), derive(PartialEq))]
-#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
 pub struct BulkListCRUDResponse {
     /// The current "status" of the
     /// created / updated / deleted list
@@ -351,7 +347,7 @@ pub type DeleteListResponse = BulkListCRUDResponse;
 /// The `email` element of a bulk verification result
 /// record returned by the BriteVerify API
 #[cfg_attr(any(test, tarpaulin, feature = "ci"), derive(PartialEq))]
-#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
 pub struct BulkEmailVerificationArray {
     /// The verified email address
     pub email: String,
@@ -369,7 +365,7 @@ pub struct BulkEmailVerificationArray {
 /// The `phone` element of a bulk verification result
 /// record returned by the BriteVerify API
 #[cfg_attr(any(test, tarpaulin, feature = "ci"), derive(PartialEq))]
-#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
 pub struct BulkPhoneNumberVerificationArray {
     /// The verified phone number
     pub phone: String,
@@ -403,7 +399,7 @@ pub type BulkAddressVerificationArray = AddressVerificationArray;
 /// the BriteVerify bulk verification API
 /// for "contacts"-type requests
 #[cfg_attr(any(test, tarpaulin, feature = "ci"), derive(PartialEq))]
-#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
 pub struct BulkContactVerificationResult {
     /// Verification data for the requested
     /// email address
@@ -422,7 +418,8 @@ pub struct BulkContactVerificationResult {
 /// A single result record returned by
 /// the BriteVerify bulk verification API
 #[cfg_attr(any(test, tarpaulin, feature = "ci"), derive(PartialEq))]
-#[derive(serde::Serialize, serde::Deserialize)]
+#[allow(clippy::large_enum_variant)]
+#[derive(Clone, serde::Serialize, serde::Deserialize)]
 #[serde(untagged)]
 pub enum BulkVerificationResult {
     /// A single result record returned by
@@ -453,7 +450,7 @@ impl fmt::Debug for BulkVerificationResult {
 /// metadata returned by the BriteVerify bulk
 /// verification API
 #[cfg_attr(any(test, tarpaulin, feature = "ci"), derive(PartialEq))]
-#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
 pub struct BulkVerificationResponse {
     /// The current "status" of the bulk
     /// verification list
@@ -475,9 +472,23 @@ pub struct BulkVerificationResponse {
 // <editor-fold desc="// Test Helpers & Factory Implementations ...">
 
 #[doc(hidden)]
-#[cfg(any(test, tarpaulin, feature = "ci"))]
+#[cfg(any(test, tarpaulin, feature = "ci", feature = "fake"))]
 mod foundry {
-
+    // Third Party Imports
+    #[cfg(feature = "fake")]
+    use rand::Rng;
+
+    // Crate-Level Imports
+    #[cfg(feature = "fake")]
+    use crate::{
+        fake::{Fake, Faker},
+        types::{
+            AddressVerificationArray, BatchState, EmailVerificationArray,
+            PhoneNumberVerificationArray, VerificationStatus,
+        },
+    };
+
+    #[cfg(any(test, tarpaulin, feature = "ci"))]
     impl<
             Contact: Into<super::VerificationRequest>,
             ContactCollection: IntoIterator<Item = Contact>,
@@ -502,6 +513,255 @@ mod foundry {
             }
         }
     }
+
+    #[cfg(feature = "fake")]
+    impl Fake for super::BulkVerificationRequest {
+        /// Generate a random request to create or update a list
+        fn fake_with(faker: &mut Faker) -> Self {
+            let count = faker.rng().gen_range(1..=10);
+
+            Self {
+                contacts: faker.fake_many(count),
+                directive: faker.fake(),
+            }
+        }
+    }
+
+    #[cfg(feature = "fake")]
+    impl Fake for super::BulkListCRUDError {
+        /// Generate a random list termination error
+        fn fake_with(faker: &mut Faker) -> Self {
+            let timestamp = faker.timestamp().format("%m-%d-%Y %I:%M %P");
+
+            Self {
+                list_id: None,
+                status: BatchState::ImportError,
+                message: Some(match faker.chance(0.5) {
+                    true => format!("user terminated at {timestamp}"),
+                    false => format!("auto-terminated at {timestamp} due to inactivity"),
+                }),
+            }
+        }
+    }
+
+    #[cfg(feature = "fake")]
+    impl Fake for super::VerificationListState {
+        /// Generate a random list in one of the states
+        /// lists are commonly observed to be in
+        fn fake_with(faker: &mut Faker) -> Self {
+            let state = faker.pick(&[
+                BatchState::Open,
+                BatchState::Pending,
+                BatchState::Prepped,
+                BatchState::Verifying,
+                BatchState::Complete,
+                BatchState::Delivered,
+                BatchState::Terminated,
+                BatchState::Expired,
+                BatchState::ImportError,
+            ]);
+
+            Self::fake_with_state(faker, state)
+        }
+    }
+
+    #[cfg(feature = "fake")]
+    impl super::VerificationListState {
+        /// Generate a random list in the supplied state, with
+        /// progress, page count, results path, expiration date,
+        /// and errors consistent with that state
+        pub fn fake_with_state(faker: &mut Faker, state: BatchState) -> Self {
+            let id = faker.list_id();
+            let contacts: u64 = faker.rng().gen_range(1..=10_000);
+            let finished = matches!(state, BatchState::Complete | BatchState::Delivered);
+
+            let progress: u64 = match state {
+                BatchState::Verifying => faker.rng().gen_range(1..100),
+                BatchState::Terminated | BatchState::ImportError => faker.rng().gen_range(0..100),
+                BatchState::Complete | BatchState::Delivered | BatchState::Expired => 100,
+                _ => 0,
+            };
+            let total_verified = contacts * progress / 100;
+            let total_verified_emails = faker.rng().gen_range(0..=total_verified);
+
+            let created_at = match state {
+                BatchState::Expired => faker.now() - chrono::Duration::days(8),
+                _ => faker.timestamp(),
+            };
+
+            let errors = match state {
+                BatchState::Terminated | BatchState::ImportError => vec![faker.fake()],
+                BatchState::Expired => vec![super::BulkListCRUDError {
+                    list_id: None,
+                    status: BatchState::Expired,
+                    message: Some("Expired. Download Unavailable.".to_string()),
+                }],
+                _ => Vec::new(),
+            };
+
+            Self {
+                external_id: faker
+                    .chance(0.25)
+                    .then(|| faker.rng().gen_range(1_000..10_000).to_string()),
+                state,
+                progress,
+                total_verified,
+                page_count: finished.then(|| total_verified.div_ceil(500).max(1)),
+                total_verified_emails,
+                total_verified_phones: total_verified - total_verified_emails,
+                created_at,
+                results_path: finished
+                    .then(|| {
+                        format!("https://bulk-api.briteverify.com/api/v3/lists/{id}/export/1")
+                            .parse()
+                            .ok()
+                    })
+                    .flatten(),
+                expiration_date: (finished || state == BatchState::Expired)
+                    .then(|| created_at + chrono::Duration::days(7)),
+                errors,
+                id,
+            }
+        }
+    }
+
+    #[cfg(feature = "fake")]
+    impl Fake for super::GetListStatesResponse {
+        /// Generate a random single-page collection of lists
+        fn fake_with(faker: &mut Faker) -> Self {
+            let count = faker.rng().gen_range(1..=5);
+
+            Self {
+                message: Some("Page 1 of 1".to_string()),
+                lists: faker.fake_many(count),
+            }
+        }
+    }
+
+    #[cfg(feature = "fake")]
+    impl Fake for super::BulkListCRUDResponse {
+        /// Generate a random response to a list's
+        /// creation, update, or deletion
+        fn fake_with(faker: &mut Faker) -> Self {
+            let (state, message) = faker.pick(&[
+                (BatchState::Open, "created new list"),
+                (BatchState::Open, "updated existing list"),
+                (BatchState::Pending, "list queued for processing"),
+                (BatchState::Terminated, "list terminated"),
+                (BatchState::Deleted, "list deleted"),
+            ]);
+            let mut list = super::VerificationListState::fake_with_state(faker, state);
+
+            list.errors.clear();
+
+            Self {
+                status: BatchState::Success,
+                message: message.to_string(),
+                list,
+            }
+        }
+    }
+
+    #[cfg(feature = "fake")]
+    impl Fake for super::BulkEmailVerificationArray {
+        /// Generate a random bulk email verification result
+        fn fake_with(faker: &mut Faker) -> Self {
+            let status = faker.fake();
+
+            Self::fake_with_status(faker, status)
+        }
+    }
+
+    #[cfg(feature = "fake")]
+    impl super::BulkEmailVerificationArray {
+        /// Generate a random bulk email verification result with
+        /// the supplied status (and, if the status is `invalid`,
+        /// a matching email-related secondary status)
+        pub fn fake_with_status(faker: &mut Faker, status: VerificationStatus) -> Self {
+            let email = EmailVerificationArray::fake_with_status(faker, status);
+
+            Self {
+                email: email.address,
+                status: email.status,
+                secondary_status: email.error_code.map(|code| code.to_string()),
+            }
+        }
+    }
+
+    #[cfg(feature = "fake")]
+    impl Fake for super::BulkPhoneNumberVerificationArray {
+        /// Generate a random bulk phone number verification result
+        fn fake_with(faker: &mut Faker) -> Self {
+            let status = faker.fake();
+
+            Self::fake_with_status(faker, status)
+        }
+    }
+
+    #[cfg(feature = "fake")]
+    impl super::BulkPhoneNumberVerificationArray {
+        /// Generate a random bulk phone number verification result
+        /// with the supplied status (and, if the status is `invalid`,
+        /// a matching phone-related secondary status)
+        pub fn fake_with_status(faker: &mut Faker, status: VerificationStatus) -> Self {
+            let phone = PhoneNumberVerificationArray::fake_with_status(faker, status);
+
+            Self {
+                phone: phone.number,
+                status: phone.status,
+                phone_location: None,
+                secondary_status: phone
+                    .errors
+                    .first()
+                    .and_then(|error| error.as_str())
+                    .map(str::to_string),
+                service_type: phone.service_type,
+            }
+        }
+    }
+
+    #[cfg(feature = "fake")]
+    impl Fake for super::BulkContactVerificationResult {
+        /// Generate a random bulk verification result for a
+        /// contact with at least one of an email, phone number,
+        /// or address
+        fn fake_with(faker: &mut Faker) -> Self {
+            let fields: u8 = faker.rng().gen_range(1..8);
+
+            Self {
+                email: (fields & 1 != 0).then(|| faker.fake()),
+                phone: (fields & 2 != 0).then(|| faker.fake()),
+                address: (fields & 4 != 0).then(|| faker.fake::<AddressVerificationArray>()),
+            }
+        }
+    }
+
+    #[cfg(feature = "fake")]
+    impl Fake for super::BulkVerificationResult {
+        /// Generate a random "contacts"-type
+        /// or "email"-type bulk verification result
+        fn fake_with(faker: &mut Faker) -> Self {
+            match faker.chance(0.5) {
+                true => Self::Contact(faker.fake()),
+                false => Self::Email(faker.fake()),
+            }
+        }
+    }
+
+    #[cfg(feature = "fake")]
+    impl Fake for super::BulkVerificationResponse {
+        /// Generate a random page of a completed
+        /// list's verification results
+        fn fake_with(faker: &mut Faker) -> Self {
+            let count = faker.rng().gen_range(1..=25);
+
+            Self {
+                status: BatchState::Success,
+                page_count: faker.rng().gen_range(1..=3),
+                results: faker.fake_many(count),
+            }
+        }
+    }
 }
 
 // </editor-fold desc="// Test Helpers & Factory Implementations ...">
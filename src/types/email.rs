@@ -0,0 +1,404 @@
+//! ## Local Email Address Syntax Validation
+//!
+//! A local, credit-free check of whether a given value is a syntactically
+//! valid email address, per the `addr-spec` grammar of
+//! [IETF RFC 5322](https://www.rfc-editor.org/rfc/rfc5322#section-3.4.1)
+//! (excluding its obsolete forms, comments, and folding whitespace) and the
+//! size limits imposed by [IETF RFC 5321](https://www.rfc-editor.org/rfc/rfc5321#section-4.5.3.1).
+//! Domains must be valid host names (or bracketed address literals), and
+//! non-ASCII characters are permitted per [IETF RFC 6532](https://www.rfc-editor.org/rfc/rfc6532).
+//!
+//! **NOTE:** A syntactically valid address isn't necessarily a deliverable
+//! one. Local validation only identifies the addresses the BriteVerify API
+//! would certainly report as `invalid_format`, so that verifying them
+//! doesn't cost any credits.
+//
+// Standard Library Imports
+use std::{fmt, str::FromStr};
+
+// Crate-Level Imports
+use super::{
+    enums::{VerificationError, VerificationStatus},
+    single::EmailVerificationArray,
+};
+use crate::errors::BriteVerifyTypeError;
+
+// <editor-fold desc="// Constants ...">
+
+/// The maximum length of an email address's "account" (local) portion
+const MAX_ACCOUNT_LENGTH: usize = 64;
+
+/// The maximum length of an email address's domain
+const MAX_DOMAIN_LENGTH: usize = 255;
+
+/// The maximum length of a single domain label
+const MAX_LABEL_LENGTH: usize = 63;
+
+/// The maximum length of a complete email address
+const MAX_ADDRESS_LENGTH: usize = 254;
+
+/// The non-alphanumeric ASCII characters
+/// permitted in an unquoted "account"
+const ATEXT_SPECIALS: &str = "!#$%&'*+-/=?^_`{|}~";
+
+// </editor-fold desc="// Constants ...">
+
+// <editor-fold desc="// EmailAddress ...">
+
+/// A syntactically valid email address, split into
+/// its "account" and "domain" portions the same way
+/// an [`EmailVerificationArray`](EmailVerificationArray) is
+///
+/// ## Basic Usage
+/// ```no_run
+/// # use briteverify_rs::types::EmailAddress;
+/// #
+/// # fn doc() -> anyhow::Result<()> {
+/// let email: EmailAddress = "test.user+tag@example.com".parse()?;
+///
+/// assert_eq!(email.account(), "test.user+tag");
+/// assert_eq!(email.domain(), "example.com");
+///
+/// assert!(!EmailAddress::is_valid("test..user@example.com"));
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct EmailAddress {
+    address: String,
+    at: usize,
+}
+
+impl EmailAddress {
+    /// Parse and validate the supplied value
+    /// (ignoring any surrounding whitespace)
+    pub fn parse<Value: AsRef<str>>(value: Value) -> Result<Self, BriteVerifyTypeError> {
+        let address = value.as_ref().trim();
+
+        match Self::_validate(address) {
+            Ok(at) => Ok(Self {
+                address: address.to_string(),
+                at,
+            }),
+            Err(reason) => Err(BriteVerifyTypeError::MalformedEmailAddress(
+                address.to_string(),
+                reason,
+            )),
+        }
+    }
+
+    /// Check whether the supplied value is
+    /// a syntactically valid email address
+    pub fn is_valid<Value: AsRef<str>>(value: Value) -> bool {
+        Self::_validate(value.as_ref().trim()).is_ok()
+    }
+
+    /// The full email address
+    pub fn address(&self) -> &str {
+        &self.address
+    }
+
+    /// The "account" portion of the email address
+    pub fn account(&self) -> &str {
+        &self.address[..self.at]
+    }
+
+    /// The "domain" portion of the email address
+    pub fn domain(&self) -> &str {
+        &self.address[self.at + 1..]
+    }
+
+    /// Build the verification result the BriteVerify API would
+    /// report for the supplied (malformed) email address
+    pub(crate) fn _invalid_format(address: &str) -> EmailVerificationArray {
+        let (account, domain) = address.rsplit_once('@').unwrap_or((address, ""));
+
+        EmailVerificationArray {
+            address: address.to_string(),
+            account: account.to_string(),
+            domain: domain.to_string(),
+            status: VerificationStatus::Invalid,
+            connected: None,
+            disposable: false,
+            role_address: false,
+            error_code: Some(VerificationError::InvalidFormat),
+            error: Some(VerificationError::InvalidFormat._message()),
+        }
+    }
+
+    // <editor-fold desc="// Internal Utility Methods ... ">
+
+    /// [internal-implementation]
+    /// Validate the supplied address, returning the index
+    /// of the `@` separating its account and domain if it's
+    /// valid, or the reason it isn't otherwise
+    fn _validate(address: &str) -> Result<usize, &'static str> {
+        if address.len() > MAX_ADDRESS_LENGTH {
+            return Err("address is too long");
+        }
+
+        let at = match address.strip_prefix('"') {
+            Some(quoted) => Self::_quoted_string_len(quoted)? + 1,
+            None => address.find('@').ok_or("missing '@'")?,
+        };
+
+        if address.as_bytes().get(at) != Some(&b'@') {
+            return Err("unexpected characters after quoted account");
+        }
+
+        let (account, domain) = (&address[..at], &address[at + 1..]);
+
+        match account.len() {
+            0 => return Err("missing account"),
+            len if len > MAX_ACCOUNT_LENGTH => return Err("account is too long"),
+            _ if account.starts_with('"') => {}
+            _ => Self::_validate_dot_atom(account)?,
+        }
+
+        Self::_validate_domain(domain)?;
+
+        Ok(at)
+    }
+
+    /// [internal-implementation]
+    /// Measure the quoted string (RFC 5322 `quoted-string`)
+    /// starting immediately after its opening quote, returning
+    /// the index of its closing quote
+    fn _quoted_string_len(quoted: &str) -> Result<usize, &'static str> {
+        let mut chars = quoted.char_indices();
+
+        while let Some((idx, char)) = chars.next() {
+            match char {
+                '"' => return Ok(idx + 1),
+                '\\' => match chars.next() {
+                    Some((_, escaped)) if escaped == '\t' || !escaped.is_control() => {}
+                    _ => return Err("invalid escape in quoted account"),
+                },
+                ' ' | '\t' => {}
+                char if char.is_ascii_graphic() || !char.is_ascii() => {}
+                _ => return Err("invalid character in quoted account"),
+            }
+        }
+
+        Err("unterminated quoted account")
+    }
+
+    /// [internal-implementation]
+    /// Validate an unquoted account (RFC 5322 `dot-atom`)
+    fn _validate_dot_atom(account: &str) -> Result<(), &'static str> {
+        if account.starts_with('.') || account.ends_with('.') {
+            return Err("account starts or ends with '.'");
+        }
+
+        if account.contains("..") {
+            return Err("account contains consecutive '.'s");
+        }
+
+        match account.chars().all(|char| {
+            char == '.'
+                || char.is_ascii_alphanumeric()
+                || ATEXT_SPECIALS.contains(char)
+                || (!char.is_ascii() && !char.is_control() && !char.is_whitespace())
+        }) {
+            true => Ok(()),
+            false => Err("invalid character in account"),
+        }
+    }
+
+    /// [internal-implementation]
+    /// Validate a domain (host name or address literal)
+    fn _validate_domain(domain: &str) -> Result<(), &'static str> {
+        if domain.is_empty() {
+            return Err("missing domain");
+        }
+
+        if domain.len() > MAX_DOMAIN_LENGTH {
+            return Err("domain is too long");
+        }
+
+        if let Some(literal) = domain.strip_prefix('[') {
+            let literal = literal
+                .strip_suffix(']')
+                .ok_or("unterminated address literal")?;
+
+            return match !literal.is_empty()
+                && literal
+                    .chars()
+                    .all(|char| char.is_ascii_graphic() && !"[]\\".contains(char))
+            {
+                true => Ok(()),
+                false => Err("invalid address literal"),
+            };
+        }
+
+        for label in domain.split('.') {
+            if label.is_empty() {
+                return Err("domain contains an empty label");
+            }
+
+            if label.len() > MAX_LABEL_LENGTH {
+                return Err("domain label is too long");
+            }
+
+            if label.starts_with('-') || label.ends_with('-') {
+                return Err("domain label starts or ends with '-'");
+            }
+
+            if !label
+                .chars()
+                .all(|char| char == '-' || char.is_alphanumeric())
+            {
+                return Err("invalid character in domain");
+            }
+        }
+
+        Ok(())
+    }
+
+    // </editor-fold desc="// Internal Utility Methods ... ">
+}
+
+impl fmt::Display for EmailAddress {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str(&self.address)
+    }
+}
+
+impl AsRef<str> for EmailAddress {
+    fn as_ref(&self) -> &str {
+        &self.address
+    }
+}
+
+impl FromStr for EmailAddress {
+    type Err = BriteVerifyTypeError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::parse(value)
+    }
+}
+
+impl TryFrom<&'_ str> for EmailAddress {
+    type Error = BriteVerifyTypeError;
+
+    fn try_from(value: &'_ str) -> Result<Self, Self::Error> {
+        Self::parse(value)
+    }
+}
+
+impl TryFrom<String> for EmailAddress {
+    type Error = BriteVerifyTypeError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::parse(value)
+    }
+}
+
+impl From<EmailAddress> for String {
+    fn from(email: EmailAddress) -> Self {
+        email.address
+    }
+}
+
+// </editor-fold desc="// EmailAddress ...">
+
+// <editor-fold desc="// I/O-Free Tests ...">
+
+#[cfg(test)]
+mod tests {
+    // Third-Party Dependencies
+    use pretty_assertions::assert_eq;
+
+    // Crate-Level Imports
+    use super::EmailAddress;
+    use crate::types::{VerificationError, VerificationStatus};
+
+    /// Test that syntactically valid email
+    /// addresses are accepted and split correctly
+    #[rstest::rstest]
+    #[case("test@example.com", "test", "example.com")]
+    #[case(
+        "first.last+tag@sub.example.co.uk",
+        "first.last+tag",
+        "sub.example.co.uk"
+    )]
+    #[case("!#$%&'*/=?^_`{|}~-@example.com", "!#$%&'*/=?^_`{|}~-", "example.com")]
+    #[case(
+        r#""john..doe @ home"@example.com"#,
+        r#""john..doe @ home""#,
+        "example.com"
+    )]
+    #[case("user@[192.168.0.1]", "user", "[192.168.0.1]")]
+    #[case("üñîçøðé@exämple.com", "üñîçøðé", "exämple.com")]
+    #[case("  padded@example.com ", "padded", "example.com")]
+    fn test_valid_email_addresses(
+        #[case] value: &str,
+        #[case] account: &str,
+        #[case] domain: &str,
+    ) {
+        let email = EmailAddress::parse(value).unwrap();
+
+        assert_eq!(account, email.account());
+        assert_eq!(domain, email.domain());
+        assert_eq!(value.trim(), email.to_string());
+    }
+
+    /// Test that malformed email addresses are rejected
+    #[rstest::rstest]
+    #[case("")]
+    #[case("example.com")]
+    #[case("@example.com")]
+    #[case("test@")]
+    #[case("test@@example.com")]
+    #[case(".test@example.com")]
+    #[case("test.@example.com")]
+    #[case("te..st@example.com")]
+    #[case("te st@example.com")]
+    #[case("test@exa mple.com")]
+    #[case("test@example..com")]
+    #[case("test@-example.com")]
+    #[case("test@example.com.")]
+    #[case("test@[192.168.0.1")]
+    #[case(r#""unterminated@example.com"#)]
+    #[case(r#""quoted"extra@example.com"#)]
+    #[case("a@b@example.com")]
+    fn test_malformed_email_addresses(#[case] value: &str) {
+        assert!(
+            !EmailAddress::is_valid(value),
+            "{value:?} should be invalid"
+        );
+        assert!(EmailAddress::parse(value).is_err());
+    }
+
+    /// Test that overlong email addresses are rejected
+    #[rstest::rstest]
+    fn test_overlong_email_addresses() {
+        assert!(!EmailAddress::is_valid(format!(
+            "{}@example.com",
+            "a".repeat(65)
+        )));
+        assert!(!EmailAddress::is_valid(format!(
+            "test@{}.com",
+            "a".repeat(64)
+        )));
+        assert!(!EmailAddress::is_valid(format!(
+            "test@{}.com",
+            vec!["a".repeat(60); 5].join(".")
+        )));
+    }
+
+    /// Test that locally-generated `invalid_format`
+    /// results match what the BriteVerify API reports
+    #[rstest::rstest]
+    fn test_invalid_format_result() {
+        let result = EmailAddress::_invalid_format("not..valid@example.com");
+
+        assert_eq!(VerificationStatus::Invalid, result.status);
+        assert_eq!(Some(VerificationError::InvalidFormat), result.error_code);
+        assert_eq!("not..valid", result.account);
+        assert_eq!("example.com", result.domain);
+    }
+}
+
+// </editor-fold desc="// I/O-Free Tests ...">
@@ -1,12 +1,12 @@
 //! ## BriteVerify API Well-Known Status & Result Enumerations
-///
+//
 // Standard Library Imports
 use std::fmt;
 
 // Conditional Imports
 #[doc(hidden)]
-#[cfg(any(test, tarpaulin))]
-#[cfg_attr(any(test, tarpaulin), allow(unused_imports))]
+#[cfg(any(test, tarpaulin, feature = "fake"))]
+#[allow(unused_imports)]
 pub use self::foundry::*;
 
 // <editor-fold desc="// BatchState ...">
@@ -207,6 +207,22 @@ impl fmt::Display for VerificationError {
     }
 }
 
+impl VerificationError {
+    /// [internal-implementation]
+    /// Render the error the way the BriteVerify API does
+    /// in the `error` field of email verifications
+    /// (e.g. `email_account_invalid` -> `Email account invalid`)
+    pub(crate) fn _message(self) -> String {
+        let message = self.to_string().replace('_', " ");
+        let mut chars = message.chars();
+
+        match chars.next() {
+            Some(first) => first.to_uppercase().chain(chars).collect(),
+            None => message,
+        }
+    }
+}
+
 // </editor-fold desc="// VerificationError ...">
 
 // <editor-fold desc="// VerificationStatus ...">
@@ -321,7 +337,76 @@ impl<T: ToString> From<Option<T>> for BulkListDirective {
 // <editor-fold desc="// Test Helpers & Factory Implementations ...">
 
 #[doc(hidden)]
-#[cfg(any(test, tarpaulin))]
-mod foundry {}
+#[cfg(any(test, tarpaulin, feature = "fake"))]
+mod foundry {
+    // Third-Party Imports
+    #[cfg(feature = "fake")]
+    use rand::Rng;
+
+    // Crate-Level Imports
+    #[cfg(feature = "fake")]
+    use crate::fake::{Fake, Faker, ADDRESS_ERRORS, EMAIL_ERRORS, PHONE_ERRORS};
+
+    #[cfg(feature = "fake")]
+    impl Fake for super::BatchState {
+        /// Generate a random (known) list state
+        fn fake_with(faker: &mut Faker) -> Self {
+            faker.pick(&[
+                Self::Open,
+                Self::Closed,
+                Self::Deleted,
+                Self::Expired,
+                Self::Pending,
+                Self::Prepped,
+                Self::Success,
+                Self::Complete,
+                Self::NotFound,
+                Self::Delivered,
+                Self::Verifying,
+                Self::Terminated,
+                Self::ImportError,
+                Self::MissingData,
+                Self::ExceedsLimit,
+                Self::InvalidState,
+                Self::DuplicateData,
+                Self::ListUploadsIncomplete,
+            ])
+        }
+    }
+
+    #[cfg(feature = "fake")]
+    impl Fake for super::BulkListDirective {
+        /// Generate a random (known) list directive
+        fn fake_with(faker: &mut Faker) -> Self {
+            faker.pick(&[Self::Start, Self::Terminate])
+        }
+    }
+
+    #[cfg(feature = "fake")]
+    impl Fake for super::VerificationError {
+        /// Generate a random (known) verification error
+        fn fake_with(faker: &mut Faker) -> Self {
+            match faker.pick(&[0, 1, 2]) {
+                0 => faker.pick(&EMAIL_ERRORS),
+                1 => faker.pick(&PHONE_ERRORS),
+                _ => faker.pick(&ADDRESS_ERRORS),
+            }
+        }
+    }
+
+    #[cfg(feature = "fake")]
+    impl Fake for super::VerificationStatus {
+        /// Generate a random verification status, weighted
+        /// towards `valid` the way real-world results are
+        fn fake_with(faker: &mut Faker) -> Self {
+            match faker.rng().gen_range(0..20) {
+                0..=13 => Self::Valid,
+                14..=16 => Self::Invalid,
+                17 => Self::AcceptAll,
+                _ => Self::Unknown,
+            }
+        }
+    }
+}
 
 // </editor-fold desc="// Test Helpers & Factory Implementations ...">
@@ -0,0 +1,363 @@
+//! ## HTTP Response Metadata Types
+//
+// Standard Library Imports
+use std::{ops::Deref, time::Duration};
+
+// Third Party Imports
+use http::{HeaderMap, StatusCode};
+
+// <editor-fold desc="// Constants ...">
+
+/// Header names commonly used to carry a
+/// server-issued request / trace identifier
+static REQUEST_ID_HEADERS: [&str; 4] = [
+    "x-request-id",
+    "x-amzn-requestid",
+    "x-correlation-id",
+    "request-id",
+];
+
+// </editor-fold desc="// Constants ...">
+
+// <editor-fold desc="// ResponseMetadata ...">
+
+/// Transport-level details of the HTTP exchange that produced
+/// a [`Response`](Response)'s deserialized data
+#[derive(Clone, Debug)]
+pub struct ResponseMetadata {
+    /// The HTTP status code of the final response
+    pub status: StatusCode,
+    /// The URL the final request was sent to
+    pub url: url::Url,
+    /// The complete set of headers included
+    /// in the final response
+    pub headers: HeaderMap,
+    /// How long the final request attempt took, as
+    /// observed by the client, from sending the request
+    /// to receiving the response's headers
+    pub latency: Duration,
+    /// How long the request took in total, as observed
+    /// by the client, including any time spent waiting
+    /// out rate limits between retries
+    pub elapsed: Duration,
+    /// The number of times the request was automatically
+    /// re-sent due to rate limiting before the final
+    /// response was received
+    pub retries: u32,
+}
+
+impl ResponseMetadata {
+    /// [internal-implementation]
+    /// Capture the metadata of the supplied response
+    pub(crate) fn from_response(
+        response: &reqwest::Response,
+        retries: u32,
+        latency: Duration,
+        elapsed: Duration,
+    ) -> Self {
+        Self {
+            status: response.status(),
+            url: response.url().clone(),
+            headers: response.headers().clone(),
+            latency,
+            elapsed,
+            retries,
+        }
+    }
+
+    /// [internal-implementation]
+    /// Capture the metadata of a response that was
+    /// produced locally rather than received over
+    /// the network (i.e. replayed or simulated)
+    pub(crate) fn from_local(
+        uri: &http::Uri,
+        response: &crate::sansio::HttpResponse,
+    ) -> Result<Self, url::ParseError> {
+        Ok(Self {
+            status: response.status(),
+            url: url::Url::parse(&uri.to_string())?,
+            headers: response.headers().clone(),
+            latency: Duration::ZERO,
+            elapsed: Duration::ZERO,
+            retries: 0,
+        })
+    }
+
+    /// [internal-implementation]
+    /// Convert the metadata of a cached response into that of
+    /// a response served from the cache, discarding everything
+    /// specific to the exchange that originally produced it
+    pub(crate) fn _from_cache(mut self) -> Self {
+        for name in REQUEST_ID_HEADERS {
+            self.headers.remove(name);
+        }
+
+        self.headers.insert(
+            crate::cache::CACHE_HEADER,
+            http::HeaderValue::from_static("hit"),
+        );
+        self.latency = Duration::ZERO;
+        self.elapsed = Duration::ZERO;
+        self.retries = 0;
+
+        self
+    }
+
+    /// [internal-implementation]
+    /// Get the value of the first of the supplied headers
+    /// present in the response, if any
+    fn _first_header<'name, Names: IntoIterator<Item = &'name str>>(
+        &self,
+        names: Names,
+    ) -> Option<&str> {
+        names
+            .into_iter()
+            .find_map(|name| self.headers.get(name))
+            .and_then(|value| value.to_str().ok())
+    }
+
+    /// [internal-implementation]
+    /// Get the value of the first of the supplied headers
+    /// present in the response as an unsigned integer
+    fn _numeric_header<'name, Names: IntoIterator<Item = &'name str>>(
+        &self,
+        names: Names,
+    ) -> Option<u64> {
+        self._first_header(names)
+            .and_then(|value| value.trim().parse::<u64>().ok())
+    }
+
+    /// The server-issued identifier of the request,
+    /// if the response included one
+    pub fn request_id(&self) -> Option<&str> {
+        self._first_header(REQUEST_ID_HEADERS)
+    }
+
+    /// The total number of requests allowed
+    /// in the current rate limit window
+    pub fn rate_limit_limit(&self) -> Option<u64> {
+        self._numeric_header(["x-ratelimit-limit", "ratelimit-limit"])
+    }
+
+    /// The number of requests remaining
+    /// in the current rate limit window
+    pub fn rate_limit_remaining(&self) -> Option<u64> {
+        self._numeric_header(["x-ratelimit-remaining", "ratelimit-remaining"])
+    }
+
+    /// The value of the response's rate limit "reset"
+    /// header (typically either the number of seconds
+    /// until the current window resets, or the Unix
+    /// timestamp at which it will)
+    pub fn rate_limit_reset(&self) -> Option<u64> {
+        self._numeric_header(["x-ratelimit-reset", "ratelimit-reset"])
+    }
+
+    /// The number of seconds the server asked the
+    /// client to wait before sending another request
+    pub fn retry_after(&self) -> Option<u64> {
+        self._numeric_header(["retry-after"])
+    }
+
+    /// Check whether the response is a synthetic one
+    /// produced by a client running in [dry-run](crate::dry_run)
+    /// mode (i.e. the request was never actually sent)
+    pub fn is_dry_run(&self) -> bool {
+        self._first_header([crate::dry_run::DRY_RUN_HEADER])
+            .is_some_and(|value| value == "true")
+    }
+
+    /// Check whether the response was answered from a client's
+    /// [verification cache](crate::cache) (i.e. the request
+    /// was never actually sent)
+    pub fn is_cached(&self) -> bool {
+        self._first_header([crate::cache::CACHE_HEADER])
+            .is_some_and(|value| value == "hit")
+    }
+}
+
+// </editor-fold desc="// ResponseMetadata ...">
+
+// <editor-fold desc="// Response ...">
+
+/// Deserialized BriteVerify API response data paired with
+/// the [`ResponseMetadata`](ResponseMetadata) of the HTTP
+/// exchange that produced it
+#[derive(Clone, Debug)]
+pub struct Response<T> {
+    /// The deserialized response data
+    pub data: T,
+    /// The response's transport-level metadata
+    pub meta: ResponseMetadata,
+}
+
+impl<T> Deref for Response<T> {
+    type Target = T;
+
+    #[cfg_attr(tarpaulin, coverage(off))]
+    fn deref(&self) -> &Self::Target {
+        &self.data
+    }
+}
+
+impl<T> Response<T> {
+    /// Create a new `Response` from the
+    /// supplied data and metadata
+    pub fn new(data: T, meta: ResponseMetadata) -> Self {
+        Self { data, meta }
+    }
+
+    /// Discard the response's metadata
+    /// and return only its data
+    pub fn into_inner(self) -> T {
+        self.data
+    }
+
+    /// Split the response into its
+    /// data and metadata
+    pub fn into_parts(self) -> (T, ResponseMetadata) {
+        (self.data, self.meta)
+    }
+
+    /// Transform the response's data while
+    /// preserving its metadata
+    pub fn map<U, Func: FnOnce(T) -> U>(self, func: Func) -> Response<U> {
+        Response {
+            data: func(self.data),
+            meta: self.meta,
+        }
+    }
+}
+
+// </editor-fold desc="// Response ...">
+
+// <editor-fold desc="// Test Helpers & Factory Implementations ...">
+
+#[doc(hidden)]
+#[cfg(feature = "fake")]
+mod foundry {
+    // Standard Library Imports
+    use std::time::Duration;
+
+    // Third Party Imports
+    use http::{HeaderMap, HeaderValue, StatusCode};
+    use rand::Rng;
+
+    // Crate-Level Imports
+    use crate::{
+        fake::{Fake, Faker},
+        sansio::{V1_API_BASE_URL, V3_API_BASE_URL},
+    };
+
+    impl Fake for super::ResponseMetadata {
+        /// Generate the metadata of a random
+        /// successful BriteVerify API exchange
+        fn fake_with(faker: &mut Faker) -> Self {
+            let url = match faker.chance(0.5) {
+                true => format!("{V1_API_BASE_URL}/fullverify"),
+                false => format!("{V3_API_BASE_URL}/lists/{}", faker.list_id()),
+            };
+            let retries = faker.pick(&[0, 0, 0, 0, 1, 2]);
+            let latency = Duration::from_millis(faker.rng().gen_range(20..800));
+            let waited = Duration::from_secs(faker.rng().gen_range(1..30) * u64::from(retries));
+
+            let mut headers = HeaderMap::new();
+
+            if let Ok(value) = HeaderValue::try_from(faker.list_id()) {
+                headers.insert("x-request-id", value);
+            }
+
+            Self {
+                status: StatusCode::OK,
+                url: url::Url::parse(&url).expect("Couldn't parse fake url"),
+                headers,
+                latency,
+                elapsed: latency + waited,
+                retries,
+            }
+        }
+    }
+
+    impl<T: Fake> Fake for super::Response<T> {
+        /// Generate random response data
+        /// along with random metadata
+        fn fake_with(faker: &mut Faker) -> Self {
+            Self {
+                data: faker.fake(),
+                meta: faker.fake(),
+            }
+        }
+    }
+}
+
+// </editor-fold desc="// Test Helpers & Factory Implementations ...">
+
+// <editor-fold desc="// I/O-Free Tests ...">
+
+#[cfg(test)]
+mod tests {
+    // Standard Library Imports
+    use std::time::Duration;
+
+    // Third-Party Dependencies
+    use http::{HeaderMap, HeaderValue, StatusCode};
+    use pretty_assertions::{assert_eq, assert_str_eq};
+
+    fn metadata(headers: &[(&'static str, &'static str)]) -> super::ResponseMetadata {
+        super::ResponseMetadata {
+            status: StatusCode::OK,
+            url: "https://bulk-api.briteverify.com/api/v3/accounts/credits"
+                .parse()
+                .unwrap(),
+            headers: HeaderMap::from_iter(
+                headers
+                    .iter()
+                    .map(|(key, value)| (*key, HeaderValue::from_static(value)))
+                    .map(|(key, value)| (key.parse().unwrap(), value)),
+            ),
+            latency: Duration::from_millis(25),
+            elapsed: Duration::from_millis(25),
+            retries: 0,
+        }
+    }
+
+    /// Test that `ResponseMetadata` extracts well-known
+    /// header values when they are present
+    #[rstest::rstest]
+    fn test_metadata_header_accessors() {
+        let meta = metadata(&[
+            ("x-amzn-requestid", "a-very-specific-request"),
+            ("x-ratelimit-limit", "100"),
+            ("x-ratelimit-remaining", "42"),
+            ("ratelimit-reset", " 30 "),
+            ("retry-after", "soon"),
+        ]);
+
+        assert_str_eq!("a-very-specific-request", meta.request_id().unwrap());
+        assert_eq!(Some(100u64), meta.rate_limit_limit());
+        assert_eq!(Some(42u64), meta.rate_limit_remaining());
+        assert_eq!(Some(30u64), meta.rate_limit_reset());
+        assert_eq!(None, meta.retry_after());
+
+        let meta = metadata(&[]);
+
+        assert!(meta.request_id().is_none());
+        assert!(meta.rate_limit_remaining().is_none());
+    }
+
+    /// Test that `Response`'s data accessors
+    /// preserve the associated metadata
+    #[rstest::rstest]
+    fn test_response_map_preserves_metadata() {
+        let response = super::Response::new(21u32, metadata(&[("x-request-id", "abc")]));
+
+        assert_eq!(21u32, *response);
+
+        let (data, meta) = response.map(|value| value * 2).into_parts();
+
+        assert_eq!(42u32, data);
+        assert_str_eq!("abc", meta.request_id().unwrap());
+    }
+}
+
+// </editor-fold desc="// I/O-Free Tests ...">
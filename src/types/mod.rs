@@ -1,8 +1,12 @@
 //! ## Type Definitions
 
 pub mod account;
+pub mod address;
 pub mod bulk;
+pub mod email;
 pub mod enums;
+pub mod metadata;
+pub mod phone;
 pub mod single;
 
 pub use self::{
@@ -13,7 +17,10 @@ pub use self::{
         CreateListResponse, DeleteListResponse, GetListStatesResponse, UpdateListResponse,
         VerificationListState,
     },
+    email::EmailAddress,
     enums::{BatchState, BulkListDirective, VerificationError, VerificationStatus},
+    metadata::{Response, ResponseMetadata},
+    phone::PhoneNumber,
     single::{
         AddressArrayBuilder, AddressVerificationArray, EmailVerificationArray,
         PhoneNumberVerificationArray, StreetAddressArray, VerificationRequest,
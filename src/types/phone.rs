@@ -0,0 +1,524 @@
+//! ## Local Phone Number Normalization
+//!
+//! A local, credit-free parser that normalizes loosely-formatted phone
+//! numbers (e.g. `"(954) 555-1234 ext. 12"`) into their
+//! [E.164](https://www.itu.int/rec/T-REC-E.164) form (e.g. `"+19545551234"`),
+//! keeping any extension separately. Numbers written without a country code are
+//! interpreted relative to a default region (an ISO 3166-1 alpha-2 code,
+//! e.g. `"US"` or `"GB"`), with that region's national trunk prefix removed.
+//!
+//! **NOTE:** Normalization is intentionally permissive. Only numbers that
+//! can't possibly be valid (e.g. with too few or too many digits, an unknown
+//! country code, or an impossible North American area code) are rejected.
+//! Whether a well-formed number is actually in service is for the BriteVerify
+//! API to decide.
+//
+// Standard Library Imports
+use std::{fmt, str::FromStr};
+
+// Crate-Level Imports
+use super::{
+    enums::{VerificationError, VerificationStatus},
+    single::PhoneNumberVerificationArray,
+};
+use crate::errors::BriteVerifyTypeError;
+
+// <editor-fold desc="// Constants ...">
+
+/// The maximum number of digits in an E.164 number
+const MAX_E164_DIGITS: usize = 15;
+
+/// The minimum number of digits in a national number
+const MIN_NATIONAL_DIGITS: usize = 4;
+
+/// The maximum number of digits in an extension
+const MAX_EXTENSION_DIGITS: usize = 7;
+
+/// The non-numeric characters permitted in a phone number
+const SEPARATORS: &str = " +().-/\t";
+
+/// The two-digit country calling codes (every other code
+/// is either one or three digits long, and no code is a
+/// prefix of any other)
+const TWO_DIGIT_CODES: [&str; 44] = [
+    "20", "27", "30", "31", "32", "33", "34", "36", "39", "40", "41", "43", "44", "45", "46", "47",
+    "48", "49", "51", "52", "53", "54", "55", "56", "57", "58", "60", "61", "62", "63", "64", "65",
+    "66", "81", "82", "84", "86", "90", "91", "92", "93", "94", "95", "98",
+];
+
+/// The assigned three-digit country calling codes
+const THREE_DIGIT_CODES: [&str; 170] = [
+    "211", "212", "213", "216", "218", "220", "221", "222", "223", "224", "225", "226", "227",
+    "228", "229", "230", "231", "232", "233", "234", "235", "236", "237", "238", "239", "240",
+    "241", "242", "243", "244", "245", "246", "247", "248", "249", "250", "251", "252", "253",
+    "254", "255", "256", "257", "258", "260", "261", "262", "263", "264", "265", "266", "267",
+    "268", "269", "290", "291", "297", "298", "299", "350", "351", "352", "353", "354", "355",
+    "356", "357", "358", "359", "370", "371", "372", "373", "374", "375", "376", "377", "378",
+    "379", "380", "381", "382", "383", "385", "386", "387", "389", "420", "421", "423", "500",
+    "501", "502", "503", "504", "505", "506", "507", "508", "509", "590", "591", "592", "593",
+    "594", "595", "596", "597", "598", "599", "670", "672", "673", "674", "675", "676", "677",
+    "678", "679", "680", "681", "682", "683", "685", "686", "687", "688", "689", "690", "691",
+    "692", "800", "808", "850", "852", "853", "855", "856", "870", "878", "880", "881", "882",
+    "883", "886", "888", "960", "961", "962", "963", "964", "965", "966", "967", "968", "970",
+    "971", "972", "973", "974", "975", "976", "977", "979", "992", "993", "994", "995", "996",
+    "998",
+];
+
+/// Supported default regions, as `(region, calling code, trunk prefix)`
+const REGIONS: [(&str, &str, Option<&str>); 40] = [
+    ("AE", "971", Some("0")),
+    ("AR", "54", Some("0")),
+    ("AT", "43", Some("0")),
+    ("AU", "61", Some("0")),
+    ("BE", "32", Some("0")),
+    ("BR", "55", Some("0")),
+    ("CA", "1", Some("1")),
+    ("CH", "41", Some("0")),
+    ("CN", "86", Some("0")),
+    ("CO", "57", None),
+    ("DE", "49", Some("0")),
+    ("DK", "45", None),
+    ("ES", "34", None),
+    ("FI", "358", Some("0")),
+    ("FR", "33", Some("0")),
+    ("GB", "44", Some("0")),
+    ("HK", "852", None),
+    ("IE", "353", Some("0")),
+    ("IL", "972", Some("0")),
+    ("IN", "91", Some("0")),
+    ("IT", "39", None),
+    ("JP", "81", Some("0")),
+    ("KR", "82", Some("0")),
+    ("MX", "52", None),
+    ("NL", "31", Some("0")),
+    ("NO", "47", None),
+    ("NZ", "64", Some("0")),
+    ("PH", "63", Some("0")),
+    ("PL", "48", None),
+    ("PR", "1", Some("1")),
+    ("PT", "351", None),
+    ("RU", "7", Some("8")),
+    ("SE", "46", Some("0")),
+    ("SG", "65", None),
+    ("TR", "90", Some("0")),
+    ("TW", "886", Some("0")),
+    ("UA", "380", Some("0")),
+    ("US", "1", Some("1")),
+    ("VI", "1", Some("1")),
+    ("ZA", "27", Some("0")),
+];
+
+// </editor-fold desc="// Constants ...">
+
+// <editor-fold desc="// PhoneNumber ...">
+
+/// A phone number normalized to E.164 form,
+/// along with its extension (if any)
+///
+/// ___
+/// **NOTE:** A number's [`Display`](fmt::Display) form (and what it's
+/// serialized as) includes its extension (e.g. `"+19545551234 ext. 12"`),
+/// which the BriteVerify API doesn't accept. Send its [`e164`](PhoneNumber::e164)
+/// form instead.
+/// ___
+///
+/// ## Basic Usage
+/// ```no_run
+/// # use briteverify_rs::types::PhoneNumber;
+/// #
+/// # fn doc() -> anyhow::Result<()> {
+/// let number = PhoneNumber::parse("(954) 555-1234 ext. 12", Some("US"))?;
+///
+/// assert_eq!(number.e164(), "+19545551234");
+/// assert_eq!(number.extension(), Some("12"));
+///
+/// let number: PhoneNumber = "+44 (0)20 7946 0958".parse()?;
+///
+/// assert_eq!(number.country_code(), "44");
+/// assert_eq!(number.national_number(), "2079460958");
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct PhoneNumber {
+    country_code: String,
+    national_number: String,
+    extension: Option<String>,
+}
+
+impl PhoneNumber {
+    /// Parse and normalize the supplied value, interpreting
+    /// numbers without a country code relative to the supplied
+    /// default region (an ISO 3166-1 alpha-2 code)
+    pub fn parse<Value: AsRef<str>>(
+        value: Value,
+        default_region: Option<&str>,
+    ) -> Result<Self, BriteVerifyTypeError> {
+        let value = value.as_ref();
+
+        match default_region {
+            Some(region) if !Self::is_supported_region(region) => {
+                Err(BriteVerifyTypeError::UnknownPhoneRegion(region.to_string()))
+            }
+            _ => Self::_parse(value, default_region).map_err(|(_, reason)| {
+                BriteVerifyTypeError::MalformedPhoneNumber(value.to_string(), reason)
+            }),
+        }
+    }
+
+    /// Check whether the supplied value can be
+    /// normalized to a plausible E.164 number
+    pub fn is_valid<Value: AsRef<str>>(value: Value, default_region: Option<&str>) -> bool {
+        Self::parse(value, default_region).is_ok()
+    }
+
+    /// Check whether the supplied ISO 3166-1 alpha-2
+    /// code is supported as a default region
+    pub fn is_supported_region(region: &str) -> bool {
+        Self::_region(region).is_some()
+    }
+
+    /// The number's country calling code (e.g. `"1"`)
+    pub fn country_code(&self) -> &str {
+        &self.country_code
+    }
+
+    /// The number's national (significant) number
+    pub fn national_number(&self) -> &str {
+        &self.national_number
+    }
+
+    /// The number's extension, if it has one
+    pub fn extension(&self) -> Option<&str> {
+        self.extension.as_deref()
+    }
+
+    /// The number in E.164 form (e.g. `"+19545551234"`)
+    pub fn e164(&self) -> String {
+        format!("+{}{}", self.country_code, self.national_number)
+    }
+
+    /// The number formatted the way the BriteVerify API reports
+    /// it (i.e. `"{country_code}{phone_number}"`, digits only)
+    pub fn digits(&self) -> String {
+        format!("{}{}", self.country_code, self.national_number)
+    }
+
+    /// Build the verification result the BriteVerify API would
+    /// report for the supplied (malformed) phone number
+    pub(crate) fn _invalid(value: &str, error: VerificationError) -> PhoneNumberVerificationArray {
+        PhoneNumberVerificationArray {
+            number: value.chars().filter(char::is_ascii_digit).collect(),
+            status: VerificationStatus::Invalid,
+            service_type: None,
+            phone_location: None,
+            errors: vec![serde_json::Value::String(error.to_string())],
+        }
+    }
+
+    // <editor-fold desc="// Internal Utility Methods ... ">
+
+    /// [internal-implementation]
+    /// Look up a supported default region
+    fn _region(region: &str) -> Option<(&'static str, Option<&'static str>)> {
+        REGIONS
+            .iter()
+            .find(|(code, ..)| code.eq_ignore_ascii_case(region.trim()))
+            .map(|(_, calling_code, trunk)| (*calling_code, *trunk))
+    }
+
+    /// [internal-implementation]
+    /// Parse and normalize the supplied value, returning the
+    /// error the BriteVerify API would report (and the reason
+    /// why) if it can't be normalized
+    pub(crate) fn _parse(
+        value: &str,
+        default_region: Option<&str>,
+    ) -> Result<Self, (VerificationError, &'static str)> {
+        let (number, extension) = Self::_split_extension(value.trim())?;
+
+        if number.is_empty() {
+            return Err((VerificationError::BlankPhoneNumber, "no phone number"));
+        }
+
+        if !number
+            .chars()
+            .all(|char| char.is_ascii_digit() || SEPARATORS.contains(char))
+        {
+            return Err((VerificationError::InvalidFormat, "invalid character"));
+        }
+
+        if number.chars().skip(1).any(|char| char == '+') {
+            return Err((VerificationError::InvalidFormat, "misplaced '+'"));
+        }
+
+        let digits: String = number.chars().filter(char::is_ascii_digit).collect();
+        let region = default_region.and_then(Self::_region);
+
+        let international = match (number.starts_with('+'), region) {
+            (true, _) => Some(digits.as_str()),
+            (false, Some(("1", _))) => digits.strip_prefix("011"),
+            (false, _) => digits.strip_prefix("00"),
+        };
+
+        let (country_code, national) = match (international, region) {
+            (Some(digits), _) => Self::_split_country_code(digits)?,
+            (None, None) => {
+                return Err((VerificationError::InvalidFormat, "missing country code"));
+            }
+            (None, Some((calling_code, trunk))) => {
+                let national = match trunk {
+                    Some("1") if digits.len() == 11 => digits.strip_prefix('1'),
+                    Some("1") => None,
+                    Some(trunk) => digits.strip_prefix(trunk),
+                    None => None,
+                };
+
+                (calling_code, national.unwrap_or(&digits))
+            }
+        };
+
+        // international numbers are often written with their
+        // trunk prefix in parentheses, e.g. "+44 (0)20 ..."
+        let national = match Self::_trunk_prefix(country_code) {
+            Some(trunk) if number.contains(&format!("({trunk})")) => {
+                national.strip_prefix(trunk).unwrap_or(national)
+            }
+            _ => national,
+        };
+
+        Self::_validate(country_code, national)?;
+
+        Ok(Self {
+            country_code: country_code.to_string(),
+            national_number: national.to_string(),
+            extension,
+        })
+    }
+
+    /// [internal-implementation]
+    /// Split the supplied value into its number and extension
+    fn _split_extension(
+        value: &str,
+    ) -> Result<(&str, Option<String>), (VerificationError, &'static str)> {
+        let Some(marker) = value.find(|char: char| char.is_alphabetic() || "#;,".contains(char))
+        else {
+            return Ok((value, None));
+        };
+
+        let tail = value[marker..].to_lowercase();
+        let extension = ["extension", ";ext=", "ext", "x", "#", ";", ","]
+            .iter()
+            .find_map(|prefix| tail.strip_prefix(prefix))
+            .map(|rest| rest.trim_start_matches(['.', ':', '=', ' ']).trim_end())
+            // letters that aren't part of an extension marker
+            // (e.g. "1-800-FLOWERS") are just invalid characters
+            .filter(|rest| !rest.contains(char::is_alphabetic))
+            .ok_or((VerificationError::InvalidFormat, "invalid character"))?;
+
+        if !(1..=MAX_EXTENSION_DIGITS).contains(&extension.len())
+            || !extension.chars().all(|char| char.is_ascii_digit())
+        {
+            return Err((VerificationError::InvalidFormat, "invalid extension"));
+        }
+
+        Ok((value[..marker].trim_end(), Some(extension.to_string())))
+    }
+
+    /// [internal-implementation]
+    /// Split the supplied international digits into
+    /// their country calling code and national number
+    fn _split_country_code(
+        digits: &str,
+    ) -> Result<(&str, &str), (VerificationError, &'static str)> {
+        let length = match digits.as_bytes().first() {
+            None | Some(b'0') => {
+                return Err((VerificationError::InvalidPrefix, "invalid country code"));
+            }
+            Some(b'1' | b'7') => 1,
+            Some(_) if digits.len() >= 2 && TWO_DIGIT_CODES.contains(&&digits[..2]) => 2,
+            Some(_) if digits.len() >= 3 && THREE_DIGIT_CODES.contains(&&digits[..3]) => 3,
+            Some(_) => {
+                return Err((VerificationError::InvalidPrefix, "unknown country code"));
+            }
+        };
+
+        match digits.len() > length {
+            true => Ok(digits.split_at(length)),
+            false => Err((VerificationError::InvalidFormat, "missing national number")),
+        }
+    }
+
+    /// [internal-implementation]
+    /// Get the national trunk prefix used with
+    /// the supplied country code, if it has one
+    fn _trunk_prefix(country_code: &str) -> Option<&'static str> {
+        REGIONS
+            .iter()
+            .find(|(_, code, _)| *code == country_code)
+            .and_then(|(.., trunk)| *trunk)
+    }
+
+    /// [internal-implementation]
+    /// Validate the supplied normalized number
+    fn _validate(
+        country_code: &str,
+        national: &str,
+    ) -> Result<(), (VerificationError, &'static str)> {
+        if country_code == "1" {
+            return match national.as_bytes() {
+                digits if digits.len() != 10 => {
+                    Err((VerificationError::InvalidFormat, "wrong number of digits"))
+                }
+                [b'0' | b'1', ..] => Err((VerificationError::InvalidPrefix, "invalid area code")),
+                [_, _, _, b'0' | b'1', ..] => {
+                    Err((VerificationError::InvalidPrefix, "invalid exchange code"))
+                }
+                _ => Ok(()),
+            };
+        }
+
+        if national.len() < MIN_NATIONAL_DIGITS
+            || country_code.len() + national.len() > MAX_E164_DIGITS
+        {
+            return Err((VerificationError::InvalidFormat, "wrong number of digits"));
+        }
+
+        Ok(())
+    }
+
+    // </editor-fold desc="// Internal Utility Methods ... ">
+}
+
+impl fmt::Display for PhoneNumber {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.extension.as_deref() {
+            None => formatter.write_str(&self.e164()),
+            Some(extension) => write!(formatter, "{} ext. {extension}", self.e164()),
+        }
+    }
+}
+
+impl FromStr for PhoneNumber {
+    type Err = BriteVerifyTypeError;
+
+    /// Parse a phone number in international format
+    /// (i.e. one that includes its country code)
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::parse(value, None)
+    }
+}
+
+impl TryFrom<&'_ str> for PhoneNumber {
+    type Error = BriteVerifyTypeError;
+
+    fn try_from(value: &'_ str) -> Result<Self, Self::Error> {
+        Self::parse(value, None)
+    }
+}
+
+impl TryFrom<String> for PhoneNumber {
+    type Error = BriteVerifyTypeError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::parse(value, None)
+    }
+}
+
+impl From<PhoneNumber> for String {
+    fn from(number: PhoneNumber) -> Self {
+        number.to_string()
+    }
+}
+
+// </editor-fold desc="// PhoneNumber ...">
+
+// <editor-fold desc="// I/O-Free Tests ...">
+
+#[cfg(test)]
+mod tests {
+    // Third-Party Dependencies
+    use pretty_assertions::assert_eq;
+
+    // Crate-Level Imports
+    use super::PhoneNumber;
+    use crate::types::VerificationError;
+
+    /// Test that plausible phone numbers are
+    /// normalized to their E.164 form
+    #[rstest::rstest]
+    #[case("(954) 555-1234", Some("US"), "+19545551234", None)]
+    #[case("1-954-555-1234", Some("us"), "+19545551234", None)]
+    #[case("954.555.1234 x12", Some("US"), "+19545551234", Some("12"))]
+    #[case("+1 954 555 1234 ext. 345", None, "+19545551234", Some("345"))]
+    #[case("011 44 20 7946 0958", Some("US"), "+442079460958", None)]
+    #[case("+44 (0)20 7946 0958", None, "+442079460958", None)]
+    #[case("020 7946 0958", Some("GB"), "+442079460958", None)]
+    #[case("0049 30 901820", Some("FR"), "+4930901820", None)]
+    #[case("06 12 34 56 78", Some("FR"), "+33612345678", None)]
+    #[case("8 (495) 123-45-67", Some("RU"), "+74951234567", None)]
+    #[case("+353 1 234 5678;ext=9", None, "+35312345678", Some("9"))]
+    fn test_normalizes_phone_numbers(
+        #[case] value: &str,
+        #[case] region: Option<&str>,
+        #[case] e164: &str,
+        #[case] extension: Option<&str>,
+    ) {
+        let number = PhoneNumber::parse(value, region).unwrap();
+
+        assert_eq!(e164, number.e164());
+        assert_eq!(extension, number.extension());
+        assert_eq!(number, number.to_string().parse::<PhoneNumber>().unwrap());
+    }
+
+    /// Test that obviously invalid phone
+    /// numbers are rejected with the error the
+    /// BriteVerify API would report for them
+    #[rstest::rstest]
+    #[case("", VerificationError::BlankPhoneNumber)]
+    #[case("555-1234", VerificationError::InvalidFormat)]
+    #[case("(954) 555-12345", VerificationError::InvalidFormat)]
+    #[case("(054) 555-1234", VerificationError::InvalidPrefix)]
+    #[case("(954) 155-1234", VerificationError::InvalidPrefix)]
+    #[case("954-555-CALL", VerificationError::InvalidFormat)]
+    #[case("+0 954 555 1234", VerificationError::InvalidPrefix)]
+    #[case("+999 1234567", VerificationError::InvalidPrefix)]
+    #[case("+44 1234 5678 9012 345", VerificationError::InvalidFormat)]
+    #[case("954-555+1234", VerificationError::InvalidFormat)]
+    fn test_rejects_invalid_phone_numbers(#[case] value: &str, #[case] error: VerificationError) {
+        assert_eq!(
+            Some(error),
+            PhoneNumber::_parse(value, Some("US"))
+                .err()
+                .map(|(error, _)| error)
+        );
+    }
+
+    /// Test that rejected phone numbers
+    /// report the reason they were rejected
+    #[rstest::rstest]
+    #[case("+999 1234567", "unknown country code")]
+    #[case("1-800-FLOWERS", "invalid character")]
+    #[case("954-555-1234 x", "invalid extension")]
+    #[case("954-555-1234 ext. 12345678", "invalid extension")]
+    fn test_reports_rejection_reasons(#[case] value: &str, #[case] reason: &str) {
+        assert_eq!(
+            Some(reason),
+            PhoneNumber::_parse(value, Some("US"))
+                .err()
+                .map(|(_, reason)| reason)
+        );
+    }
+
+    /// Test that numbers without a country code require
+    /// a (known) default region
+    #[rstest::rstest]
+    fn test_requires_a_known_region() {
+        assert!(!PhoneNumber::is_valid("954-555-1234", None));
+        assert!(PhoneNumber::parse("954-555-1234", Some("XX")).is_err());
+        assert!(PhoneNumber::is_valid("+1 954-555-1234", None));
+    }
+}
+
+// </editor-fold desc="// I/O-Free Tests ...">
@@ -1,5 +1,5 @@
 //! ## BriteVerify Real-time Single Transaction API Types ([ref](https://docs.briteverify.com/#79e00732-b734-4308-ac7f-820d62dde01f))
-///
+//
 // Standard Library Imports
 use std::time::Duration;
 
@@ -12,7 +12,7 @@ use super::enums::{VerificationError, VerificationStatus};
 use crate::errors::BriteVerifyTypeError;
 
 // Conditional Imports
-#[cfg(test)]
+#[cfg(any(test, feature = "fake"))]
 #[doc(hidden)]
 #[allow(unused_imports)]
 pub use self::foundry::*;
@@ -20,8 +20,7 @@ pub use self::foundry::*;
 // <editor-fold desc="// Request Elements ...">
 
 /// A standardized representation of a street address
-#[cfg_attr(any(test, tarpaulin, feature = "ci"), derive(Clone))]
-#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
 pub struct StreetAddressArray {
     /// The address's street number and name
     pub address1: String,
@@ -82,6 +81,7 @@ pub struct AddressArrayBuilder {
     _city: Option<String>,
     _state: Option<String>,
     _zip: Option<String>,
+    _normalize: bool,
 }
 
 impl AddressArrayBuilder {
@@ -91,22 +91,37 @@ impl AddressArrayBuilder {
     }
 
     /// Build a `StreetAddressArray` from the configured values
+    /// (normalizing it, if normalization is enabled)
     pub fn build(self) -> Result<StreetAddressArray, BriteVerifyTypeError> {
         if !self.buildable() {
             Err(BriteVerifyTypeError::UnbuildableAddressArray(Box::new(
                 self,
             )))
         } else {
-            Ok(StreetAddressArray::from_values(
+            let address = StreetAddressArray::from_values(
                 self._address1.unwrap(),
                 self._address2,
                 self._city.unwrap(),
                 self._state.unwrap(),
                 self._zip.unwrap(),
-            ))
+            );
+
+            match self._normalize {
+                true => address.normalized(),
+                false => Ok(address),
+            }
         }
     }
 
+    /// Enable or disable normalization of the
+    /// `StreetAddressArray` being built (see
+    /// [`types::address`](crate::types::address)
+    /// for details)
+    pub fn normalize(mut self, value: bool) -> Self {
+        self._normalize = value;
+        self
+    }
+
     /// Determine if a valid `StreetAddressArray` can be
     /// constructed from the current builder state
     pub fn buildable(&self) -> bool {
@@ -127,6 +142,21 @@ impl AddressArrayBuilder {
                 .is_some_and(|value| !value.trim().is_empty())
     }
 
+    /// The names of the required fields that are missing
+    /// (or blank) in the current builder state
+    pub fn missing_fields(&self) -> Vec<&'static str> {
+        [
+            ("address1", &self._address1),
+            ("city", &self._city),
+            ("state", &self._state),
+            ("zip", &self._zip),
+        ]
+        .into_iter()
+        .filter(|(_, value)| value.as_ref().is_none_or(|value| value.trim().is_empty()))
+        .map(|(field, _)| field)
+        .collect()
+    }
+
     /// Set the "zip" value of the
     /// `StreetAddressArray` being built
     pub fn zip<Displayable: ToString>(mut self, value: Displayable) -> Self {
@@ -235,7 +265,7 @@ impl PartialEq for StreetAddressArray {
 /// Request for verification made to one of the BriteVerify
 /// API's single-transaction, real-time endpoints
 #[cfg_attr(any(test, tarpaulin, feature = "ci"), derive(PartialEq))]
-#[derive(Debug, Default, serde::Serialize, serde::Deserialize)]
+#[derive(Clone, Debug, Default, serde::Serialize, serde::Deserialize)]
 pub struct VerificationRequest {
     /// The email address to be verified
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -315,6 +345,13 @@ impl TryFrom<&'_ str> for VerificationRequest {
             });
         }
 
+        if let Ok(address) = StreetAddressArray::parse(value) {
+            return Ok(Self {
+                address: Some(address),
+                ..Self::default()
+            });
+        }
+
         Err(BriteVerifyTypeError::AmbiguousTryFromValue(
             value.to_string(),
         ))
@@ -446,7 +483,7 @@ impl VerificationRequestBuilder {
 
 /// The `email` element of a verification response
 #[cfg_attr(any(test, tarpaulin, feature = "ci"), derive(PartialEq))]
-#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
 pub struct EmailVerificationArray {
     /// The full (original) [IETF RFC 532](https://www.rfc-editor.org/rfc/rfc5322)
     /// compliant email address
@@ -492,7 +529,7 @@ pub struct EmailVerificationArray {
 
 /// The `phone` element of a verification response
 #[cfg_attr(any(test, tarpaulin, feature = "ci"), derive(PartialEq))]
-#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
 pub struct PhoneNumberVerificationArray {
     /// The phone number from the originating
     /// verification request
@@ -525,7 +562,7 @@ pub struct PhoneNumberVerificationArray {
 
 /// The `address` element of a verification response
 #[cfg_attr(any(test, tarpaulin, feature = "ci"), derive(PartialEq))]
-#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
 pub struct AddressVerificationArray {
     /// The verified address's street number and name
     pub address1: String,
@@ -600,7 +637,7 @@ pub struct AddressVerificationArray {
 /// A response returned by one of the BriteVerify
 /// API's single-transaction, real-time endpoints
 #[cfg_attr(any(test, tarpaulin, feature = "ci"), derive(PartialEq))]
-#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
 pub struct VerificationResponse {
     /// Verification data for the requested
     /// email address
@@ -628,99 +665,27 @@ pub struct VerificationResponse {
 
 // <editor-fold desc="// Test Helpers & Factory Implementations ...">
 
-#[cfg(test)]
+#[cfg(any(test, feature = "fake"))]
 #[doc(hidden)]
 mod foundry {
     // Standard Library Imports
-    use std::collections::HashMap;
+    #[cfg(feature = "fake")]
+    use std::time::Duration;
 
     // Third Party Imports
-    use serde::de::Error;
-    use serde_json::{Map as JsonMap, Value};
-
-    type RawAddressMap = HashMap<String, Option<String>>;
-    type RawAddressJson = JsonMap<String, Value>;
-
-    impl TryFrom<Value> for super::StreetAddressArray {
-        type Error = serde_json::Error;
-
-        fn try_from(value: Value) -> Result<Self, Self::Error> {
-            (&value).try_into()
-        }
-    }
-
-    impl TryFrom<&Value> for super::StreetAddressArray {
-        type Error = serde_json::Error;
-
-        fn try_from(value: &Value) -> Result<Self, Self::Error> {
-            match value.as_object() {
-                None => Err(Self::Error::custom(format!(
-                    "Cannot create a `StreetAddressArray` from: {:#?}",
-                    value.as_str()
-                ))),
-                Some(data) => {
-                    if let Some(obj) = data.get("address") {
-                        return obj.try_into();
-                    }
-
-                    data.try_into()
-                }
-            }
-        }
-    }
-
-    impl TryFrom<RawAddressMap> for super::StreetAddressArray {
-        type Error = serde_json::Error;
-
-        fn try_from(data: RawAddressMap) -> Result<Self, Self::Error> {
-            (&data).try_into()
-        }
-    }
-
-    impl TryFrom<&RawAddressMap> for super::StreetAddressArray {
-        type Error = serde_json::Error;
-
-        fn try_from(data: &RawAddressMap) -> Result<Self, Self::Error> {
-            let (address1, address2, city, state, zip) = (
-                data.get("address1").unwrap().clone(),
-                data.get("address2").unwrap().clone(),
-                data.get("city").unwrap().clone(),
-                data.get("state").unwrap().clone(),
-                data.get("zip").unwrap().clone(),
-            );
-
-            match super::AddressArrayBuilder::from_values(address1, address2, city, state, zip)
-                .build()
-            {
-                Ok(address) => Ok(address),
-                Err(_) => Err(Self::Error::custom(format!(
-                    "One or more required fields missing from: {data:#?}"
-                ))),
-            }
-        }
-    }
-
-    impl TryFrom<RawAddressJson> for super::StreetAddressArray {
-        type Error = serde_json::Error;
-
-        fn try_from(value: RawAddressJson) -> Result<Self, Self::Error> {
-            (&value).try_into()
-        }
-    }
-
-    impl TryFrom<&RawAddressJson> for super::StreetAddressArray {
-        type Error = serde_json::Error;
-
-        fn try_from(data: &RawAddressJson) -> Result<Self, Self::Error> {
-            ["address1", "city", "state", "zip"]
-                .into_iter()
-                .map(|key| (key.to_string(), data.get(key)))
-                .map(|(key, value)| (key, value.map(|value| value.to_string())))
-                .collect::<HashMap<String, Option<String>>>()
-                .try_into()
-        }
-    }
-
+    #[cfg(feature = "fake")]
+    use rand::Rng;
+    #[cfg(feature = "fake")]
+    use serde_json::Value;
+
+    // Crate-Level Imports
+    #[cfg(feature = "fake")]
+    use crate::{
+        fake::{Fake, Faker, ADDRESS_ERRORS, EMAIL_ERRORS, PHONE_ERRORS},
+        types::{VerificationError, VerificationStatus},
+    };
+
+    #[cfg(test)]
     impl super::AddressArrayBuilder {
         #[cfg_attr(tarpaulin, coverage(off))]
         #[cfg_attr(tarpaulin, tarpaulin::skip)]
@@ -757,6 +722,180 @@ mod foundry {
             self._zip.as_ref()
         }
     }
+
+    #[cfg(feature = "fake")]
+    impl Fake for super::StreetAddressArray {
+        /// Generate a random (real-world shaped) US street address
+        fn fake_with(faker: &mut Faker) -> Self {
+            let (city, state, zip) = faker.locality();
+
+            Self {
+                address1: faker.street(),
+                address2: faker.chance(0.3).then(|| faker.secondary_unit()),
+                city: city.to_string(),
+                state: state.to_string(),
+                zip: zip.to_string(),
+            }
+        }
+    }
+
+    #[cfg(feature = "fake")]
+    impl Fake for super::VerificationRequest {
+        /// Generate a random request for the verification of
+        /// at least one of an email, phone number, or address
+        fn fake_with(faker: &mut Faker) -> Self {
+            let fields: u8 = faker.rng().gen_range(1..8);
+
+            Self {
+                email: (fields & 1 != 0).then(|| faker.email()),
+                phone: (fields & 2 != 0).then(|| faker.phone_number()),
+                address: (fields & 4 != 0).then(|| faker.fake()),
+            }
+        }
+    }
+
+    #[cfg(feature = "fake")]
+    impl Fake for super::EmailVerificationArray {
+        /// Generate a random email verification result
+        fn fake_with(faker: &mut Faker) -> Self {
+            let status = faker.fake();
+
+            Self::fake_with_status(faker, status)
+        }
+    }
+
+    #[cfg(feature = "fake")]
+    impl super::EmailVerificationArray {
+        /// Generate a random email verification result with
+        /// the supplied status (and, if the status is `invalid`,
+        /// a matching email-related error code)
+        pub fn fake_with_status(faker: &mut Faker, status: VerificationStatus) -> Self {
+            let error = (status == VerificationStatus::Invalid).then(|| faker.pick(&EMAIL_ERRORS));
+
+            let role_address = error == Some(VerificationError::RoleAddress)
+                || status == VerificationStatus::Valid && faker.chance(0.15);
+
+            let address = match error {
+                Some(VerificationError::Disposable) => faker.disposable_email(),
+                Some(VerificationError::InvalidFormat) => faker.email().replace('@', "."),
+                _ if role_address => faker.role_email(),
+                _ => faker.email(),
+            };
+            let (account, domain) = address.rsplit_once('@').unwrap_or((&address, ""));
+
+            Self {
+                account: account.to_string(),
+                domain: domain.to_string(),
+                status,
+                connected: None,
+                disposable: error == Some(VerificationError::Disposable),
+                role_address,
+                error_code: error,
+                error: error.map(VerificationError::_message),
+                address,
+            }
+        }
+    }
+
+    #[cfg(feature = "fake")]
+    impl Fake for super::PhoneNumberVerificationArray {
+        /// Generate a random phone number verification result
+        fn fake_with(faker: &mut Faker) -> Self {
+            let status = faker.fake();
+
+            Self::fake_with_status(faker, status)
+        }
+    }
+
+    #[cfg(feature = "fake")]
+    impl super::PhoneNumberVerificationArray {
+        /// Generate a random phone number verification result
+        /// with the supplied status (and, if the status is
+        /// `invalid`, a matching phone-related error)
+        pub fn fake_with_status(faker: &mut Faker, status: VerificationStatus) -> Self {
+            let error = (status == VerificationStatus::Invalid).then(|| faker.pick(&PHONE_ERRORS));
+
+            let number = match error {
+                Some(VerificationError::BlankPhoneNumber) => String::new(),
+                Some(VerificationError::InvalidFormat) => {
+                    faker.phone_number()[..faker.rng().gen_range(4..8)].to_string()
+                }
+                _ => faker.phone_number(),
+            };
+
+            Self {
+                number,
+                status,
+                service_type: (status == VerificationStatus::Valid)
+                    .then(|| faker.pick(&["mobile", "land", "voip"]).to_string()),
+                phone_location: None,
+                errors: error
+                    .into_iter()
+                    .map(|error| Value::String(error.to_string()))
+                    .collect(),
+            }
+        }
+    }
+
+    #[cfg(feature = "fake")]
+    impl Fake for super::AddressVerificationArray {
+        /// Generate a random street address verification result
+        fn fake_with(faker: &mut Faker) -> Self {
+            let status = faker.fake();
+
+            Self::fake_with_status(faker, status)
+        }
+    }
+
+    #[cfg(feature = "fake")]
+    impl super::AddressVerificationArray {
+        /// Generate a random street address verification result
+        /// with the supplied status (and, if the status is
+        /// `invalid`, a matching address-related error)
+        pub fn fake_with_status(faker: &mut Faker, status: VerificationStatus) -> Self {
+            let address: super::StreetAddressArray = faker.fake();
+            let error =
+                (status == VerificationStatus::Invalid).then(|| faker.pick(&ADDRESS_ERRORS));
+            let corrected = status == VerificationStatus::Valid && faker.chance(0.25);
+
+            Self {
+                address1: match address.address2 {
+                    Some(ref unit) => format!("{} {unit}", address.address1),
+                    None => address.address1,
+                },
+                address2: None,
+                city: address.city,
+                state: address.state,
+                zip: match corrected {
+                    true => format!("{}-{:04}", address.zip, faker.rng().gen_range(0..10_000)),
+                    false => address.zip,
+                },
+                status,
+                corrected,
+                errors: error
+                    .into_iter()
+                    .map(|error| Value::String(error.to_string()))
+                    .collect(),
+                secondary_status: None,
+            }
+        }
+    }
+
+    #[cfg(feature = "fake")]
+    impl Fake for super::VerificationResponse {
+        /// Generate a random response to the verification of
+        /// at least one of an email, phone number, or address
+        fn fake_with(faker: &mut Faker) -> Self {
+            let fields: u8 = faker.rng().gen_range(1..8);
+
+            Self {
+                email: (fields & 1 != 0).then(|| faker.fake()),
+                phone: (fields & 2 != 0).then(|| faker.fake()),
+                address: (fields & 4 != 0).then(|| faker.fake()),
+                duration: Duration::from_millis(faker.rng().gen_range(20..2_000)),
+            }
+        }
+    }
 }
 
 // </editor-fold desc="// Test Helpers & Factory Implementations ...">
@@ -881,9 +1020,22 @@ mod tests {
         assert!(super::VerificationRequest::try_from(format!(
             r#"{ADDRESS1}, {CITY}, {STATE} {ZIP}"#
         ))
-        .is_err_and(|error| {
-            matches!(error, super::BriteVerifyTypeError::AmbiguousTryFromValue(_))
-        }));
+        .is_ok_and(|req| req.email.is_none()
+            && req.phone.is_none()
+            && req.address.is_some_and(|address| address
+                == super::StreetAddressArray::from_values(
+                    ADDRESS1,
+                    Option::<&str>::None,
+                    CITY,
+                    STATE,
+                    ZIP
+                ))));
+
+        assert!(
+            super::VerificationRequest::try_from(format!(r#"{CITY}, {STATE} {ZIP}"#)).is_err_and(
+                |error| matches!(error, super::BriteVerifyTypeError::AmbiguousTryFromValue(_))
+            )
+        );
     }
 
     /// Test that `VerificationRequestBuilder`s properly
@@ -3,20 +3,22 @@
 // Standard Library Imports
 use std::{
     fmt::{Debug, Display},
+    sync::{mpsc, Arc, Mutex},
     time::Duration,
 };
 
 // Third Party Imports
-use anyhow::Result;
+use anyhow::{Context, Result};
 use chrono::{
     prelude::{DateTime, NaiveDateTime, Utc},
     LocalResult as ChronoResult,
 };
+use futures::channel::oneshot;
 use http::Uri;
 use serde_json::Value;
 
 // Crate-Level Imports
-use crate::types::BulkListDirective;
+use crate::{errors::BriteVerifyClientError, types::BulkListDirective};
 
 #[cfg(test)]
 #[doc(hidden)]
@@ -277,6 +279,16 @@ pub fn serialize_timestamp<S: serde::Serializer>(
     serializer.serialize_str(&timestamp)
 }
 
+/// Hash the supplied bytes with 64-bit FNV-1a, which (unlike
+/// `std`'s hashers) is stable across versions and platforms
+pub(crate) fn fnv1a<Bytes: IntoIterator<Item = u8>>(bytes: Bytes) -> u64 {
+    bytes
+        .into_iter()
+        .fold(0xcbf2_9ce4_8422_2325_u64, |hash, byte| {
+            (hash ^ u64::from(byte)).wrapping_mul(0x0000_0100_0000_01b3)
+        })
+}
+
 // </editor-fold desc="// Utility Functions ...">
 
 // <editor-fold desc="// Extension Traits ...">
@@ -324,11 +336,107 @@ impl ExtensibleUrl for url::Url {
 
 // </editor-fold desc="// Extension Traits ...">
 
+// <editor-fold desc="// Background Workers ...">
+
+/// [internal-implementation]
+/// A unit of (blocking) work, run on a worker's background thread
+type WorkerJob<Resource> = Box<dyn FnOnce(&Resource) + Send>;
+
+/// [internal-implementation]
+/// Runs blocking I/O against a shared resource on a
+/// dedicated background thread, so it doesn't block
+/// the async runtime (whichever one that may be)
+pub(crate) struct BlockingWorker<Resource: ?Sized + Send + Sync + 'static> {
+    name: &'static str,
+    resource: Arc<Resource>,
+    queue: Mutex<Option<mpsc::Sender<WorkerJob<Resource>>>>,
+}
+
+impl<Resource: ?Sized + Send + Sync + 'static> BlockingWorker<Resource> {
+    /// Create a new worker for the supplied resource, whose
+    /// background thread (named `name`) is started lazily
+    pub(crate) fn new(name: &'static str, resource: Arc<Resource>) -> Self {
+        Self {
+            name,
+            resource,
+            queue: Mutex::new(None),
+        }
+    }
+
+    /// The resource the worker runs jobs against
+    pub(crate) fn resource(&self) -> &Resource {
+        self.resource.as_ref()
+    }
+
+    /// Run the supplied job on the worker's background
+    /// thread (starting it if necessary) and wait for its result
+    pub(crate) async fn run<Output, Job>(&self, job: Job) -> Result<Output, BriteVerifyClientError>
+    where
+        Output: Send + 'static,
+        Job: FnOnce(&Resource) -> Result<Output, BriteVerifyClientError> + Send + 'static,
+    {
+        let (sender, receiver) = oneshot::channel();
+        let mut job: WorkerJob<Resource> = Box::new(move |resource| {
+            sender.send(job(resource)).ok();
+        });
+
+        {
+            let mut queue = self
+                .queue
+                .lock()
+                .unwrap_or_else(|poisoned| poisoned.into_inner());
+
+            loop {
+                let jobs = match queue.as_ref() {
+                    Some(jobs) => jobs,
+                    None => queue.insert(self._spawn()?),
+                };
+
+                match jobs.send(job) {
+                    Ok(()) => break,
+                    // the thread stopped (i.e. a job panicked),
+                    // so start a new one and hand the job to it
+                    Err(mpsc::SendError(unsent)) => {
+                        job = unsent;
+                        queue.take();
+                    }
+                }
+            }
+        }
+
+        receiver
+            .await
+            .map_err(|_| anyhow::anyhow!("The {} worker stopped before finishing", self.name))?
+    }
+
+    /// [internal-implementation]
+    /// Start the worker's background thread,
+    /// which stops once the worker is dropped
+    fn _spawn(&self) -> Result<mpsc::Sender<WorkerJob<Resource>>, BriteVerifyClientError> {
+        let resource = Arc::clone(&self.resource);
+        let (sender, receiver) = mpsc::channel::<WorkerJob<Resource>>();
+
+        std::thread::Builder::new()
+            .name(self.name.to_string())
+            .spawn(move || {
+                for job in receiver {
+                    job(resource.as_ref());
+                }
+            })
+            .with_context(|| format!("Could not start the {} worker", self.name))?;
+
+        Ok(sender)
+    }
+}
+
+// </editor-fold desc="// Background Workers ...">
+
 // <editor-fold desc="// Test Factory Utilities ...">
 
-#[cfg(test)]
+#[cfg(any(test, feature = "fake"))]
 #[doc(hidden)]
-/// Utility functions for `briteverify-rs`'s test suite and examples
+/// Utility functions for `briteverify-rs`'s test suite,
+/// examples, and fake data factories
 pub mod test_utils {
     // Third-Party Imports
     use chrono::{DateTime, Utc};
@@ -340,8 +448,8 @@ pub mod test_utils {
     }
 
     #[cfg_attr(tarpaulin, coverage(off))]
-    fn _a_few_hours_ago(now: &DateTime<Utc>) -> DateTime<Utc> {
-        let offset = rand::thread_rng().gen_range(1i64..=5i64);
+    fn _a_few_hours_ago<R: Rng + ?Sized>(rng: &mut R, now: &DateTime<Utc>) -> DateTime<Utc> {
+        let offset = rng.gen_range(1i64..=5i64);
 
         *now - chrono::Duration::hours(offset)
     }
@@ -365,38 +473,41 @@ pub mod test_utils {
         values
     }
 
-    /// Create a range of DateTime values with the specified interval
+    /// Randomly choose a DateTime value from the range
+    /// between `start` and `end` with the specified interval
     #[cfg_attr(tarpaulin, coverage(off))]
-    pub fn random_datetime_between(
+    pub fn random_datetime_between<R: Rng + ?Sized>(
+        rng: &mut R,
         start: &DateTime<Utc>,
         end: &DateTime<Utc>,
         step: chrono::Duration,
     ) -> DateTime<Utc> {
-        let pool = datetime_range(start, end, step);
-
-        loop {
-            if let Some(value) = pool.iter().choose(&mut rand::thread_rng()) {
-                break *value;
-            }
-        }
+        datetime_range(start, end, step)
+            .into_iter()
+            .choose(rng)
+            .unwrap_or(*start)
     }
 
-    /// Randomly generate a timestamp from within the past week
+    /// Randomly generate a timestamp from within the week before `now`
     #[cfg_attr(tarpaulin, coverage(off))]
-    pub fn within_the_last_week() -> DateTime<Utc> {
-        let now = Utc::now();
-        let start = _one_week_ago(&now);
+    pub fn within_the_last_week<R: Rng + ?Sized>(
+        rng: &mut R,
+        now: &DateTime<Utc>,
+    ) -> DateTime<Utc> {
+        let start = _one_week_ago(now);
 
-        random_datetime_between(&start, &now, chrono::Duration::hours(8))
+        random_datetime_between(rng, &start, now, chrono::Duration::hours(8))
     }
 
-    /// Randomly generate a timestamp from a few hours in the past
+    /// Randomly generate a timestamp from a few hours before `now`
     #[cfg_attr(tarpaulin, coverage(off))]
-    pub fn within_the_last_few_hours() -> DateTime<Utc> {
-        let now = Utc::now();
-        let start = _a_few_hours_ago(&now);
+    pub fn within_the_last_few_hours<R: Rng + ?Sized>(
+        rng: &mut R,
+        now: &DateTime<Utc>,
+    ) -> DateTime<Utc> {
+        let start = _a_few_hours_ago(rng, now);
 
-        random_datetime_between(&start, &now, chrono::Duration::minutes(15))
+        random_datetime_between(rng, &start, now, chrono::Duration::minutes(15))
     }
 }
 
@@ -423,7 +534,7 @@ mod tests {
     #[fixture]
     fn recent_datetimes() -> &'static Vec<DateTime<Utc>> {
         RECENT_DATETIMES.get_or_init(|| {
-            let start_date = super::within_the_last_week()
+            let start_date = super::within_the_last_week(&mut rand::thread_rng(), &Utc::now())
                 .with_second(0)
                 .and_then(|value| value.with_nanosecond(0))
                 .unwrap();
@@ -820,6 +931,16 @@ mod tests {
 
         assert!(result.is_err())
     }
+
+    /// Test that the `fnv1a` utility matches
+    /// the reference FNV-1a test vectors
+    #[rstest]
+    #[case("", 0xcbf2_9ce4_8422_2325)]
+    #[case("a", 0xaf63_dc4c_8601_ec8c)]
+    #[case("foobar", 0x8594_4171_f739_67e8)]
+    fn test_fnv1a(#[case] value: &str, #[case] expected: u64) {
+        assert_eq!(expected, super::fnv1a(value.bytes()));
+    }
 }
 
 // </editor-fold desc="// I/O-Free Tests ...">
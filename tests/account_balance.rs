@@ -4,15 +4,22 @@
 // Module Declarations
 pub mod utils;
 
+// Standard Library Imports
+use std::{
+    sync::atomic::{AtomicUsize, Ordering},
+    time::{Duration, Instant},
+};
+
 // Third Part Imports
 use chrono::{Datelike, Timelike};
 use http_types::Method as HttpMethod;
 use once_cell::sync::Lazy;
 use regex::Regex;
 use rstest::{fixture, rstest};
-use wiremock::{Mock, Request, ResponseTemplate};
+use wiremock::{Mock, Request, Respond, ResponseTemplate};
 
 // Crate-Level Imports
+use briteverify_rs::clock::VirtualClock;
 use utils::{official_response, BriteVerifyRequest, MockRequestResponse};
 
 // <editor-fold desc="// Constants ...">
@@ -58,6 +65,38 @@ fn account_balance_response(_: &Request) -> ResponseTemplate {
     official_response(OFFICIAL_ACCOUNT_BALANCE)
 }
 
+/// Return an account credit balance response that includes
+/// request id and rate limit headers
+fn account_balance_response_with_headers(request: &Request) -> ResponseTemplate {
+    account_balance_response(request)
+        .insert_header("x-request-id", "not-all-those-who-wander-are-lost")
+        .insert_header("x-ratelimit-limit", "100")
+        .insert_header("x-ratelimit-remaining", "99")
+        .insert_header("x-ratelimit-reset", "60")
+}
+
+/// Responds to the first request it receives with a
+/// rate limit error (asking the client to retry after
+/// the given number of seconds), and every subsequent
+/// request with an account credit balance response
+#[derive(Debug)]
+struct RateLimitedOnce(AtomicUsize, &'static str);
+
+impl RateLimitedOnce {
+    fn retry_after(seconds: &'static str) -> Self {
+        Self(AtomicUsize::new(0), seconds)
+    }
+}
+
+impl Respond for RateLimitedOnce {
+    fn respond(&self, request: &Request) -> ResponseTemplate {
+        match self.0.fetch_add(1, Ordering::SeqCst) {
+            0 => ResponseTemplate::new(429).insert_header("retry-after", self.1),
+            _ => account_balance_response(request),
+        }
+    }
+}
+
 // </editor-fold desc="// Utility Functions ...">
 
 // <editor-fold desc="// Integration Tests ...">
@@ -131,4 +170,93 @@ async fn gets_full_account_balances(#[from(mock_account_balance)] mock: Mock) {
     );
 }
 
+#[rstest]
+#[test_log::test(tokio::test)]
+/// Test that the [`get_account_balance_with_metadata`](briteverify_rs::BriteVerifyClient::get_account_balance_with_metadata)
+/// method returns the response's status and well-known headers
+/// alongside the deserialized account balance
+async fn gets_account_balance_with_metadata() {
+    let (client, server) = utils::client_and_server(None, None).await;
+
+    #[allow(unused_variables)]
+    let guard = Mock::given(is_valid_account_balance_request)
+        .respond_with(account_balance_response_with_headers)
+        .mount_as_scoped(&server)
+        .await;
+
+    let response = client
+        .get_account_balance_with_metadata()
+        .await
+        .expect("Expected Ok(Response<AccountCreditBalance>)");
+
+    assert_eq!(response.credits, 2165u32);
+    assert_eq!(response.meta.status, reqwest::StatusCode::OK);
+    assert_eq!(response.meta.retries, 0);
+    assert_eq!(
+        response.meta.request_id(),
+        Some("not-all-those-who-wander-are-lost")
+    );
+    assert_eq!(response.meta.rate_limit_limit(), Some(100));
+    assert_eq!(response.meta.rate_limit_remaining(), Some(99));
+    assert_eq!(response.meta.rate_limit_reset(), Some(60));
+    assert!(response.meta.url.path().ends_with("/accounts/credits"));
+}
+
+#[rstest]
+#[test_log::test(tokio::test)]
+/// Test that the [`ResponseMetadata`](briteverify_rs::types::ResponseMetadata)
+/// returned by the client reports automatic rate limit retries
+async fn reports_retries_in_metadata() {
+    let (client, server) = utils::client_and_server(None, Some(true)).await;
+
+    #[allow(unused_variables)]
+    let guard = Mock::given(is_valid_account_balance_request)
+        .respond_with(RateLimitedOnce::retry_after("0"))
+        .mount_as_scoped(&server)
+        .await;
+
+    let response = client
+        .get_account_balance_with_metadata()
+        .await
+        .expect("Expected Ok(Response<AccountCreditBalance>)");
+
+    assert_eq!(response.meta.status, reqwest::StatusCode::OK);
+    assert_eq!(response.meta.retries, 1);
+    assert!(response.meta.elapsed >= response.meta.latency);
+    assert!(response.meta.elapsed >= std::time::Duration::from_secs(1));
+}
+
+#[rstest]
+#[test_log::test(tokio::test)]
+/// Test that the client waits out rate limits with its
+/// configured [`Sleeper`](briteverify_rs::clock::Sleeper)
+/// and measures time with its configured [`Clock`](briteverify_rs::clock::Clock)
+async fn waits_out_rate_limits_with_virtual_time() {
+    let server = wiremock::MockServer::start().await;
+    let clock = VirtualClock::new();
+    let client = utils::builder_for_server(&server, None, true)
+        .clock(clock.clone())
+        .sleeper(clock.clone())
+        .build()
+        .unwrap();
+
+    #[allow(unused_variables)]
+    let guard = Mock::given(is_valid_account_balance_request)
+        .respond_with(RateLimitedOnce::retry_after("3600"))
+        .mount_as_scoped(&server)
+        .await;
+
+    let started = Instant::now();
+    let response = client
+        .get_account_balance_with_metadata()
+        .await
+        .expect("Expected Ok(Response<AccountCreditBalance>)");
+
+    assert!(started.elapsed() < Duration::from_secs(60));
+    assert_eq!(response.meta.retries, 1);
+    // the client pads the server's requested delay by one second
+    assert_eq!(clock.sleeps(), vec![Duration::from_secs(3601)]);
+    assert!(response.meta.elapsed >= Duration::from_secs(3600));
+}
+
 // </editor-fold desc="// Integration Tests ...">
@@ -0,0 +1,136 @@
+//! ## Integration Tests For [`BriteVerifyClient`](briteverify_rs::BriteVerifyClient)'s
+//! ## Local Street Address Normalization
+
+// Third Part Imports
+use pretty_assertions::assert_eq;
+use rstest::{fixture, rstest};
+
+// Crate-Level Imports
+use briteverify_rs::{
+    errors::BriteVerifyClientError,
+    types::{StreetAddressArray, VerificationRequest, VerificationStatus},
+    BriteVerifyClient,
+};
+
+// <editor-fold desc="// Fixtures ...">
+
+#[fixture]
+/// A dry-run client (so that any request that
+/// would have been sent is recorded instead)
+/// with street address normalization enabled
+fn normalizing() -> BriteVerifyClient {
+    BriteVerifyClient::builder()
+        .api_key("not a real api key")
+        .dry_run(true)
+        .normalize_addresses(true)
+        .build()
+        .unwrap()
+}
+
+// </editor-fold desc="// Fixtures ...">
+
+// <editor-fold desc="// Integration Tests ...">
+
+#[rstest]
+#[test_log::test(tokio::test)]
+/// Test that street addresses are normalized before being
+/// sent, and invalid ones are reported without calling the API
+async fn normalizes_single_addresses(normalizing: BriteVerifyClient) {
+    normalizing
+        .verify_street_address(
+            "123 north main street",
+            Some("suite 100"),
+            "any town",
+            "California",
+            "902101234",
+        )
+        .await
+        .unwrap();
+
+    let sent = normalizing.dry_run().unwrap().requests();
+
+    assert_eq!(
+        serde_json::json!({
+            "address1": "123 N MAIN ST",
+            "address2": "STE 100",
+            "city": "ANY TOWN",
+            "state": "CA",
+            "zip": "90210-1234",
+        }),
+        sent[0].body.as_ref().unwrap()["address"]
+    );
+
+    let invalid = normalizing
+        .verify_street_address(
+            "123 Main St",
+            Option::<&str>::None,
+            "Any Town",
+            "CA",
+            "9021",
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(VerificationStatus::Invalid, invalid.status);
+    assert_eq!(vec![serde_json::json!("zip_code_invalid")], invalid.errors);
+    assert_eq!(1, normalizing.dry_run().unwrap().len());
+}
+
+#[rstest]
+#[test_log::test(tokio::test)]
+/// Test that bulk verification lists including addresses
+/// that can't be normalized are rejected before being uploaded
+async fn rejects_lists_with_malformed_addresses(normalizing: BriteVerifyClient) {
+    let contacts =
+        [("CA", "90210"), ("Atlantis", "90210")].map(|(state, zip)| VerificationRequest {
+            address: Some(StreetAddressArray::from_values(
+                "123 Main St",
+                None,
+                "Any Town",
+                state,
+                zip,
+            )),
+            ..VerificationRequest::default()
+        });
+
+    let result = normalizing.create_list(Some(contacts), false).await;
+
+    assert!(matches!(
+        result,
+        Err(BriteVerifyClientError::MalformedAddresses(malformed)) if malformed.len() == 1
+    ));
+    assert!(normalizing.dry_run().unwrap().is_empty());
+}
+
+#[rstest]
+#[test_log::test(tokio::test)]
+/// Test that mixed free-form values (including one-line
+/// street addresses) can be verified in bulk directly
+async fn verifies_free_form_values(normalizing: BriteVerifyClient) {
+    let contacts = [
+        "test@example.com",
+        "+1 (954) 555-1234",
+        "123 north main street suite 100, any town, California 90210",
+    ]
+    .map(|value| VerificationRequest::try_from(value).unwrap());
+
+    normalizing
+        .create_list(Some(contacts), false)
+        .await
+        .unwrap();
+
+    let sent = normalizing.dry_run().unwrap().requests();
+
+    assert_eq!(
+        serde_json::json!({
+            "address1": "123 N MAIN ST",
+            "address2": "STE 100",
+            "city": "ANY TOWN",
+            "state": "CA",
+            "zip": "90210",
+        }),
+        sent[0].body.as_ref().unwrap()["contacts"][2]["address"]
+    );
+}
+
+// </editor-fold desc="// Integration Tests ...">
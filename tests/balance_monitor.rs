@@ -0,0 +1,137 @@
+//! ## Integration Tests For [`BriteVerifyClient`](briteverify_rs::BriteVerifyClient)'s
+//! ## Credit Balance Monitoring
+
+// Module Declarations
+pub mod utils;
+
+// Standard Library Imports
+use std::{ops::ControlFlow, time::Duration};
+
+// Third Party Imports
+use futures_util::StreamExt;
+use pretty_assertions::assert_eq;
+use rstest::{fixture, rstest};
+
+// Crate-Level Imports
+use briteverify_rs::{
+    clock::VirtualClock,
+    monitor::{BalanceEvent, BalanceMonitor},
+    simulator::{BriteVerifySimulator, RunningSimulator},
+    types::VerificationRequest,
+    BriteVerifyClient,
+};
+use utils::{builder_for_simulator, SIMULATOR_API_KEY};
+
+// <editor-fold desc="// Fixtures ...">
+
+#[fixture]
+fn monitor() -> BalanceMonitor {
+    BalanceMonitor::new()
+        .interval(Duration::from_secs(60))
+        .low_balance_threshold(500)
+        .reserve_growth_threshold(0)
+        .max_consumption_rate(100, Duration::from_secs(3600))
+}
+
+/// Build a client for the supplied simulator
+/// that waits (and keeps time) virtually
+fn client(server: &RunningSimulator, clock: &VirtualClock) -> BriteVerifyClient {
+    builder_for_simulator(server)
+        .clock(clock.clone())
+        .sleeper(clock.clone())
+        .build()
+        .unwrap()
+}
+
+// </editor-fold desc="// Fixtures ...">
+
+// <editor-fold desc="// Integration Tests ...">
+
+#[rstest]
+#[test_log::test(tokio::test)]
+/// Test that the balance monitor stream yields
+/// an event for each alert condition that's met
+async fn streams_balance_events(monitor: BalanceMonitor) {
+    let simulator = BriteVerifySimulator::new(SIMULATOR_API_KEY).credits(400);
+    let server = simulator.start().await.expect("Could not start simulator");
+    let clock = VirtualClock::new();
+    let client = client(&server, &clock);
+
+    let mut events = client.monitor_balance(monitor);
+
+    let event = events.next().await.unwrap().unwrap();
+
+    assert!(matches!(
+        event,
+        BalanceEvent::LowBalance {
+            credits: 400,
+            threshold: 500,
+            ..
+        }
+    ));
+
+    simulator.set_credits(300);
+
+    let event = events.next().await.unwrap().unwrap();
+
+    assert!(matches!(
+        event,
+        BalanceEvent::RapidConsumption { consumed: 100, elapsed, .. }
+            if elapsed == Duration::from_secs(60)
+    ));
+
+    let contacts = ["a@example.com", "b@example.com", "c@example.com"]
+        .map(|email| VerificationRequest::try_from(email).unwrap());
+
+    client.create_list(Some(contacts), true).await.unwrap();
+
+    let event = events.next().await.unwrap().unwrap();
+
+    assert!(matches!(
+        event,
+        BalanceEvent::ReserveGrowth {
+            previous: 0,
+            current: 3,
+            ..
+        }
+    ));
+    assert_eq!(297, event.balance().credits);
+    assert_eq!(vec![Duration::from_secs(60); 2], clock.sleeps());
+}
+
+#[rstest]
+#[test_log::test(tokio::test)]
+/// Test that the balance monitor callback receives every
+/// event until it asks for monitoring to stop
+async fn watches_balance_until_stopped(monitor: BalanceMonitor) {
+    let simulator = BriteVerifySimulator::new(SIMULATOR_API_KEY).credits(400);
+    let server = simulator.start().await.expect("Could not start simulator");
+    let clock = VirtualClock::new();
+    let client = client(&server, &clock);
+
+    let mut received: Vec<BalanceEvent> = Vec::new();
+
+    client
+        .watch_balance(monitor, |event| {
+            received.push(event.unwrap());
+
+            match received.len() {
+                1 => {
+                    simulator.set_credits(200);
+                    ControlFlow::Continue(())
+                }
+                _ => ControlFlow::Break(()),
+            }
+        })
+        .await;
+
+    assert_eq!(2, received.len());
+    assert!(matches!(received[0], BalanceEvent::LowBalance { .. }));
+    assert!(matches!(
+        received[1],
+        BalanceEvent::RapidConsumption { consumed: 200, .. }
+    ));
+    assert_eq!(vec![Duration::from_secs(60)], clock.sleeps());
+}
+
+// </editor-fold desc="// Integration Tests ...">
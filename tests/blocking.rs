@@ -0,0 +1,148 @@
+//! ## Integration Tests For [`BlockingBriteVerifyClient`](briteverify_rs::BlockingBriteVerifyClient)
+
+// Module Declarations
+pub mod utils;
+
+// Third Part Imports
+use http_types::Method as HttpMethod;
+use pretty_assertions::assert_eq;
+use rstest::rstest;
+use wiremock::{Mock, MockServer, Request};
+
+// Crate-Level Imports
+use briteverify_rs::{types::VerificationRequest, BlockingBriteVerifyClient, BriteVerifyClient};
+use utils::{official_response, BriteVerifyRequest, MockRequestResponse};
+
+// <editor-fold desc="// Constants ...">
+
+const OFFICIAL_ACCOUNT_BALANCE: MockRequestResponse = MockRequestResponse {
+    request: "",
+    response: r#"{
+  "credits": 2165,
+  "credits_in_reserve": 500,
+  "recorded_on": "2021-07-27T21:10:10.000+0000"
+}"#,
+};
+
+// </editor-fold desc="// Constants ...">
+
+// <editor-fold desc="// Utility Functions ...">
+
+/// Check if the supplied request is a valid account credit balance request
+/// [[ref](https://docs.briteverify.com/#07beceb3-2961-4d5b-93a4-9cfeb30f42fa)]
+fn is_valid_account_balance_request(request: &Request) -> bool {
+    request.method == HttpMethod::Get
+        && request.url.path().ends_with("/accounts/credits")
+        && request.has_valid_api_key()
+}
+
+// </editor-fold desc="// Utility Functions ...">
+
+// <editor-fold desc="// Integration Tests ...">
+
+#[rstest]
+#[test_log::test]
+/// Test that the [`BlockingBriteVerifyClient`](BlockingBriteVerifyClient)
+/// sends the expected request and returns the response synchronously
+/// when called from outside of any async context
+fn gets_account_balance_synchronously() {
+    // The mock server needs a runtime of its own, but
+    // the blocking client must be called from outside it
+    let runtime = tokio::runtime::Runtime::new().unwrap();
+    let server = runtime.block_on(MockServer::start());
+
+    let client =
+        BlockingBriteVerifyClient::try_from(utils::client_for_server(&server, None, false))
+            .expect("Could not create a blocking client");
+
+    #[allow(unused_variables)]
+    let guard = runtime.block_on(
+        Mock::given(is_valid_account_balance_request)
+            .respond_with(official_response(OFFICIAL_ACCOUNT_BALANCE))
+            .mount_as_scoped(&server),
+    );
+
+    let balance = client
+        .get_account_balance()
+        .expect("Expected Ok(AccountCreditBalance)");
+
+    assert_eq!(balance.credits, 2165u32);
+    assert_eq!(client.current_credits_in_reserve().unwrap(), 500u32);
+}
+
+#[rstest]
+#[test_log::test]
+/// Test that the [`BlockingBriteVerifyClient`](BlockingBriteVerifyClient)
+/// creates keyed bulk verification jobs and pairs their results with
+/// the keys of the contacts they were produced for
+fn creates_jobs_synchronously() {
+    let client = BriteVerifyClient::builder()
+        .api_key("not a real api key")
+        .dry_run(true)
+        .build_blocking()
+        .unwrap();
+
+    let contacts = ["one@example.com", "two@example.com"]
+        .into_iter()
+        .enumerate()
+        .map(|(key, email)| (key, VerificationRequest::try_from(email).unwrap()));
+
+    let job = client.create_job_with_list_size(contacts, true, 1).unwrap();
+
+    assert_eq!(2, job.list_ids().len());
+    assert_eq!(Some(2), client.dry_run().map(|log| log.requests().len()));
+
+    // dry-run lists finish verifying on the
+    // poll that precedes the results export
+    for list_id in job.list_ids() {
+        client.get_list_by_id(list_id).unwrap();
+    }
+
+    let mut keys: Vec<Option<usize>> = client
+        .get_job_results(&job)
+        .unwrap()
+        .into_iter()
+        .map(|record| record.key)
+        .collect();
+
+    keys.sort();
+
+    assert_eq!(vec![Some(0), Some(1)], keys);
+}
+
+#[rstest]
+#[test_log::test]
+/// Test that the [`BlockingBriteVerifyClient`](BlockingBriteVerifyClient)
+/// reuses the list created for a retried idempotency token
+fn creates_lists_idempotently_synchronously() {
+    let client = BriteVerifyClient::builder()
+        .api_key("not a real api key")
+        .dry_run(true)
+        .build_blocking()
+        .unwrap();
+
+    let contacts = ["one@example.com", "two@example.com"]
+        .map(|email| VerificationRequest::try_from(email).unwrap());
+
+    let first = client
+        .create_list_idempotent("job-1", contacts.clone(), false)
+        .unwrap();
+    let retried = client
+        .create_list_idempotent_with_metadata("job-1", contacts, false)
+        .unwrap();
+
+    assert_eq!(first.list.id, retried.list.id);
+    assert_eq!(
+        Some(first.list.id.as_str()),
+        client
+            .idempotent_lists()
+            .store()
+            .get("job-1")
+            .unwrap()
+            .and_then(|record| record.list_id)
+            .as_deref()
+    );
+    assert_eq!(1, client.get_lists().unwrap().lists.len());
+}
+
+// </editor-fold desc="// Integration Tests ...">
@@ -0,0 +1,125 @@
+//! ## Integration Tests For [`BriteVerifyClient`](briteverify_rs::BriteVerifyClient)'s
+//! ## Keyed Bulk Verification Jobs
+
+// Third Part Imports
+use pretty_assertions::assert_eq;
+use rstest::{fixture, rstest};
+
+// Crate-Level Imports
+use briteverify_rs::{
+    jobs::BulkJob,
+    types::{BulkVerificationResult, VerificationRequest},
+    BriteVerifyClient,
+};
+
+// <editor-fold desc="// Fixtures ...">
+
+#[fixture]
+/// Contacts keyed by (fake) CRM record ids,
+/// including duplicate email addresses
+fn contacts() -> Vec<(u32, VerificationRequest)> {
+    [
+        (1, "one@example.com"),
+        (2, "two@example.com"),
+        (3, "one@example.com"),
+        (4, "four@example.com"),
+        (5, "Two@Example.com"),
+    ]
+    .into_iter()
+    .map(|(key, email)| (key, VerificationRequest::try_from(email).unwrap()))
+    .collect()
+}
+
+/// Create a dry-run client (so that any request
+/// that would have been sent is recorded instead)
+fn dry_run_client(deduplicate: bool) -> BriteVerifyClient {
+    BriteVerifyClient::builder()
+        .api_key("not a real api key")
+        .dry_run(true)
+        .deduplicate_contacts(deduplicate)
+        .build()
+        .unwrap()
+}
+
+/// Process the supplied job's lists, then get its results
+/// as `(key, email)` pairs, ordered by key
+async fn keyed_emails(
+    client: &BriteVerifyClient,
+    job: &BulkJob<u32>,
+) -> Vec<(Option<u32>, String)> {
+    // dry-run lists advance one step through processing
+    // every time they're polled, and finish verifying
+    // on the poll that precedes the results export
+    for list_id in job.list_ids() {
+        client.get_list_by_id(list_id).await.unwrap();
+    }
+
+    let mut results: Vec<(Option<u32>, String)> = client
+        .get_job_results(job)
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|record| match record.result {
+            BulkVerificationResult::Contact(contact) => (record.key, contact.email.unwrap().email),
+            BulkVerificationResult::Email(email) => (record.key, email.email),
+        })
+        .collect();
+
+    results.sort();
+    results
+}
+
+// </editor-fold desc="// Fixtures ...">
+
+// <editor-fold desc="// Integration Tests ...">
+
+#[rstest]
+#[test_log::test(tokio::test)]
+/// Test that results are paired with the keys of the contacts
+/// they were produced for when a job is split across lists
+async fn pairs_results_across_lists(contacts: Vec<(u32, VerificationRequest)>) {
+    let client = dry_run_client(false);
+    let job = client
+        .create_job_with_list_size(contacts.clone(), true, 2)
+        .await
+        .unwrap();
+
+    assert_eq!(3, job.list_ids().len());
+    assert_eq!(5, job.len());
+
+    let expected: Vec<(Option<u32>, String)> = contacts
+        .into_iter()
+        .map(|(key, contact)| (Some(key), contact.email.unwrap()))
+        .collect();
+
+    assert_eq!(expected, keyed_emails(&client, &job).await);
+}
+
+#[rstest]
+#[test_log::test(tokio::test)]
+/// Test that every duplicate contact's key is paired with
+/// a result when duplicate contacts aren't uploaded
+async fn pairs_results_with_deduplicated_contacts(contacts: Vec<(u32, VerificationRequest)>) {
+    let client = dry_run_client(true);
+    let job = client.create_job(contacts.clone(), true).await.unwrap();
+
+    let sent = client.dry_run().unwrap().requests();
+
+    assert_eq!(
+        3,
+        sent[0].body.as_ref().unwrap()["contacts"]
+            .as_array()
+            .unwrap()
+            .len()
+    );
+
+    let keys: Vec<Option<u32>> = keyed_emails(&client, &job)
+        .await
+        .into_iter()
+        .map(|(key, _)| key)
+        .collect();
+
+    assert_eq!(vec![Some(1), Some(2), Some(3), Some(4), Some(5)], keys);
+}
+
+// </editor-fold desc="// Integration Tests ...">
@@ -114,7 +114,7 @@ pub fn list_state_by_id_response(request: &Request) -> ResponseTemplate {
     request
         .url
         .path_segments()
-        .and_then(|segments| segments.last())
+        .and_then(|mut segments| segments.next_back())
         .and_then(|list_id| {
             [
                 &mock_data::OFFICIAL_LIST_STATE_EXPIRED,
@@ -143,7 +143,7 @@ pub fn delete_list_response(request: &Request) -> ResponseTemplate {
     request
         .url
         .path_segments()
-        .and_then(|segments| segments.last())
+        .and_then(|mut segments| segments.next_back())
         .and_then(|list_id| {
             [
                 &mock_data::OFFICIAL_DELETE_PREPPED_LIST,
@@ -167,10 +167,10 @@ pub fn update_list_response(request: &Request) -> ResponseTemplate {
     request
         .url
         .path_segments()
-        .and_then(|segments| -> Option<ResponseTemplate> {
+        .and_then(|mut segments| -> Option<ResponseTemplate> {
             match (
                 segments
-                    .last()
+                    .next_back()
                     .and_then(|list_id| list_id.parse::<uuid::Uuid>().ok()),
                 serde_json::from_slice::<types::BulkVerificationRequest>(&request.body),
             ) {
@@ -256,10 +256,7 @@ fn mock_no_lists() -> Mock {
 fn mock_all_lists() -> Mock {
     Mock::given(is_get_lists_request)
         .and(move |request: &Request| -> bool {
-            request
-                .url
-                .query()
-                .map_or(true, |val| val.trim().is_empty())
+            request.url.query().is_none_or(|val| val.trim().is_empty())
         })
         .respond_with(unfiltered_lists_response)
 }
@@ -0,0 +1,152 @@
+//! ## Integration Tests For [`BriteVerifyClient`](briteverify_rs::BriteVerifyClient)'s
+//! ## Record / Replay Cassettes
+
+// Module Declarations
+pub mod utils;
+
+// Standard Library Imports
+use std::ops::Deref;
+
+// Third Part Imports
+use http::uri::Scheme;
+use pretty_assertions::{assert_eq, assert_ne};
+use rstest::rstest;
+use wiremock::{matchers, Mock, MockServer, ResponseTemplate};
+
+// Crate-Level Imports
+use briteverify_rs::{cassette::CassetteMode, errors::BriteVerifyClientError, BriteVerifyClient};
+use utils::{official_response, v1_mock_data as mock_data, BriteVerifyRequest, TEST_API_KEY};
+
+// <editor-fold desc="// Constants ...">
+
+const ACCOUNT_BALANCE: &str = r#"{
+  "credits": 2165,
+  "credits_in_reserve": 500,
+  "recorded_on": "2021-07-27T21:10:10.000+0000"
+}"#;
+
+// </editor-fold desc="// Constants ...">
+
+// <editor-fold desc="// Integration Tests ...">
+
+#[rstest]
+#[test_log::test(tokio::test)]
+/// Test that a session recorded to a cassette is redacted
+/// and can be replayed without the original server
+async fn records_and_replays_cassettes() {
+    let cassette = std::env::temp_dir().join(format!("{}.jsonl", uuid::Uuid::new_v4()));
+    let server = MockServer::start().await;
+    let server_addr = *server.address();
+
+    Mock::given(matchers::path("/api/v3/accounts/credits"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("set-cookie", "session=abc123")
+                .insert_header("x-echoed-key", TEST_API_KEY.as_str())
+                .set_body_raw(ACCOUNT_BALANCE, "application/json"),
+        )
+        .mount(&server)
+        .await;
+
+    let client = BriteVerifyClient::builder()
+        .https_only(false)
+        .set_v3_url_scheme(Scheme::HTTP)
+        .set_v3_url_port(server_addr.port())
+        .resolve_v3_url_to(server_addr)
+        .api_key(TEST_API_KEY.deref())
+        .record_cassette(&cassette)
+        .build()
+        .unwrap();
+
+    let recorded = client.get_account_balance().await.unwrap();
+
+    assert_eq!(
+        Some(CassetteMode::Record),
+        client.cassette().map(|tape| tape.mode())
+    );
+
+    drop(server);
+
+    let contents = std::fs::read_to_string(&cassette).unwrap();
+
+    assert!(!contents.contains(TEST_API_KEY.as_str()));
+    assert!(!contents.contains("abc123"));
+
+    let client = BriteVerifyClient::builder()
+        .api_key("some other key")
+        .replay_cassette(&cassette)
+        .build()
+        .unwrap();
+
+    let replayed = client.get_account_balance().await.unwrap();
+
+    assert_eq!(recorded.credits, replayed.credits);
+    assert_eq!(Some(0), client.cassette().map(|tape| tape.remaining()));
+    assert!(matches!(
+        client.get_account_balance().await,
+        Err(BriteVerifyClientError::UnmatchedCassetteRequest(_))
+    ));
+
+    std::fs::remove_file(&cassette).ok();
+}
+
+#[rstest]
+#[test_log::test(tokio::test)]
+/// Test that contact values are masked in recorded bodies,
+/// and that the masked session can still be replayed
+async fn masks_contacts_in_cassettes() {
+    let cassette = std::env::temp_dir().join(format!("{}.jsonl", uuid::Uuid::new_v4()));
+    let server = MockServer::start().await;
+
+    Mock::given(|request: &wiremock::Request| request.is_v1_verification_request())
+        .respond_with(official_response(mock_data::OFFICIAL_EMAIL_VALID))
+        .mount(&server)
+        .await;
+
+    let email = mock_data::OFFICIAL_EMAIL_VALID
+        .extract_from_request("email")
+        .unwrap();
+
+    let client = utils::builder_for_server(&server, None, false)
+        .record_cassette(&cassette)
+        .build()
+        .unwrap();
+
+    let recorded = client.verify_email(&email).await.unwrap();
+
+    drop(server);
+
+    let contents = std::fs::read_to_string(&cassette).unwrap();
+
+    assert_eq!(1, contents.lines().count());
+    assert!(!contents.contains(&email));
+
+    let client = BriteVerifyClient::builder()
+        .api_key("some other key")
+        .replay_cassette(&cassette)
+        .build()
+        .unwrap();
+
+    let replayed = client.verify_email(email.to_uppercase()).await.unwrap();
+
+    assert_eq!(recorded.status, replayed.status);
+    assert_ne!(email, replayed.address);
+    assert_eq!(Some(0), client.cassette().map(|tape| tape.remaining()));
+
+    std::fs::remove_file(&cassette).ok();
+}
+
+#[rstest]
+#[test_log::test]
+/// Test that building a client fails if its
+/// replay cassette doesn't exist
+fn missing_replay_cassette_fails_build() {
+    let result = BriteVerifyClient::builder()
+        .api_key("some key")
+        .replay_cassette(std::env::temp_dir().join("no-such-cassette.json"))
+        .build();
+
+    assert!(result.is_err());
+}
+
+// </editor-fold desc="// Integration Tests ...">
@@ -12,6 +12,7 @@ use std::{
         atomic::{AtomicU8, Ordering},
         Arc, Mutex,
     },
+    time::Duration,
 };
 
 // Third Part Imports
@@ -26,7 +27,7 @@ use wiremock::{
 };
 
 // Crate-Level Imports
-use briteverify_rs::{errors::BriteVerifyClientError, BriteVerifyClient};
+use briteverify_rs::{errors::BriteVerifyClientError, BriteVerifyClient, RequestOptions};
 use utils::BriteVerifyRequest;
 
 // <editor-fold desc="// Constants ...">
@@ -34,6 +35,7 @@ use utils::BriteVerifyRequest;
 const AUTH_KEY_ERROR: &str =
     r#"{"errors":{"user":"not authorized or over daily test limit for untrusted domains"}}"#;
 const RATE_LIMIT_BODY: &str = "wouldn't that bring about chaos?";
+const REQUEST_ID_HEADER: &str = "x-request-id";
 static REQUEST_COUNTS: Lazy<Arc<Mutex<HashMap<Url, u8>>>> =
     Lazy::new(|| Arc::new(Mutex::new(HashMap::new())));
 
@@ -73,6 +75,12 @@ pub fn unauthorized_response(_: &Request) -> ResponseTemplate {
     ResponseTemplate::new(StatusCode::Unauthorized).set_body_raw(AUTH_KEY_ERROR, &JSON.to_string())
 }
 
+/// Return a rate limit error response that instructs
+/// the client to wait one second before retrying
+pub fn rate_limited_response(_: &Request) -> ResponseTemplate {
+    ResponseTemplate::new(StatusCode::TooManyRequests).insert_header("retry-after", "1")
+}
+
 // </editor-fold desc="// Response Generators ...">
 
 // </editor-fold desc="// Utility Functions ...">
@@ -243,4 +251,97 @@ async fn handles_rate_limit_responses() -> Result<()> {
     ))
 }
 
+#[rstest]
+#[test_log::test(tokio::test)]
+/// Test that the [`BriteVerifyClient`](BriteVerifyClient)
+/// sends any extra headers supplied via [`RequestOptions`](RequestOptions)
+/// and only for the client instance the options were attached to
+async fn applies_per_request_headers() -> Result<()> {
+    let (client, server) = utils::client_and_server(None, None).await;
+
+    #[allow(unused_variables)]
+    let mock = Mock::given(|request: &Request| {
+        request.has_valid_api_key()
+            && request
+                .headers
+                .get(&REQUEST_ID_HEADER.into())
+                .is_some_and(|value| value.as_str() == "make it so")
+    })
+    .respond_with(ResponseTemplate::new(StatusCode::Ok))
+    .mount_as_scoped(&server)
+    .await;
+
+    let url = format!("{}://{}/per-request-headers", "http", server.address());
+
+    let scoped = client.with_options(RequestOptions::new().header(
+        reqwest::header::HeaderName::from_static(REQUEST_ID_HEADER),
+        reqwest::header::HeaderValue::from_static("make it so"),
+    ));
+
+    let (with_header, without_header) = (
+        scoped.build_and_send(scoped.get(&url)).await?,
+        client.build_and_send(client.get(&url)).await?,
+    );
+
+    assert_eq!(with_header.status(), reqwest::StatusCode::OK);
+    assert_eq!(without_header.status(), reqwest::StatusCode::NOT_FOUND);
+
+    Ok(())
+}
+
+#[rstest]
+#[test_log::test(tokio::test)]
+/// Test that the [`BriteVerifyClient`](BriteVerifyClient)
+/// respects a per-request override of its automatic rate
+/// limit retry behavior
+async fn applies_per_request_retry_override() -> Result<()> {
+    let server = MockServer::start().await;
+
+    // Create a `BriteVerifyClient` instance with auto-retry enabled
+    let client = utils::client_for_server(&server, None, true);
+
+    #[allow(unused_variables)]
+    let mock = Mock::given(|request: &Request| request.has_valid_api_key())
+        .respond_with(rate_limited_response)
+        .mount_as_scoped(&server)
+        .await;
+
+    let url = format!("{}://{}/no-retry", "http", server.address());
+
+    let scoped = client.with_options(RequestOptions::new().retry_enabled(false));
+    let response = scoped.build_and_send(scoped.get(url)).await?;
+
+    assert_eq!(response.status(), reqwest::StatusCode::TOO_MANY_REQUESTS);
+
+    Ok(assert_eq!(mock.received_requests().await.len(), 1))
+}
+
+#[rstest]
+#[test_log::test(tokio::test)]
+/// Test that the [`BriteVerifyClient`](BriteVerifyClient)
+/// abandons requests that exceed a per-request timeout
+async fn applies_per_request_timeout() -> Result<()> {
+    let (client, server) = utils::client_and_server(None, None).await;
+
+    #[allow(unused_variables)]
+    let mock = Mock::given(|request: &Request| request.has_valid_api_key())
+        .respond_with(ResponseTemplate::new(StatusCode::Ok).set_delay(Duration::from_secs(2)))
+        .mount_as_scoped(&server)
+        .await;
+
+    let url = format!("{}://{}/slow-response", "http", server.address());
+
+    let scoped = client.with_options(RequestOptions::new().timeout(Duration::from_millis(100)));
+    let response = scoped.build_and_send(scoped.get(url)).await;
+
+    Ok(assert!(
+        response.as_ref().is_err_and(|error| match error {
+            BriteVerifyClientError::UnbuildableRequest(inner) => inner.is_timeout(),
+            _ => false,
+        }),
+        "Expected Err(BriteVerifyClientError::UnbuildableRequest(<timeout>)), got: {:#?}",
+        response
+    ))
+}
+
 // </editor-fold desc="// Integration Tests ...">
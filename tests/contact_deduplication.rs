@@ -0,0 +1,138 @@
+//! ## Integration Tests For [`BriteVerifyClient`](briteverify_rs::BriteVerifyClient)'s
+//! ## Bulk Contact Deduplication
+
+// Third Part Imports
+use pretty_assertions::assert_eq;
+use rstest::{fixture, rstest};
+
+// Crate-Level Imports
+use briteverify_rs::{
+    dedupe::DedupePolicy,
+    types::{BulkVerificationResult, VerificationRequest},
+    BriteVerifyClient,
+};
+
+// <editor-fold desc="// Fixtures ...">
+
+#[fixture]
+/// A dry-run client (so that any request that
+/// would have been sent is recorded instead)
+/// that ignores Gmail dots and plus-tags when
+/// deduplicating contacts
+fn deduplicating() -> BriteVerifyClient {
+    BriteVerifyClient::builder()
+        .api_key("not a real api key")
+        .dry_run(true)
+        .default_phone_region("US")
+        .dedupe_policy(
+            DedupePolicy::new()
+                .ignore_gmail_dots(true)
+                .ignore_plus_tags(true),
+        )
+        .build()
+        .unwrap()
+}
+
+/// The email addresses of the supplied contacts
+fn emails(contacts: &serde_json::Value) -> Vec<&str> {
+    contacts
+        .as_array()
+        .unwrap()
+        .iter()
+        .filter_map(|contact| contact["email"].as_str())
+        .collect()
+}
+
+// </editor-fold desc="// Fixtures ...">
+
+// <editor-fold desc="// Integration Tests ...">
+
+#[rstest]
+#[test_log::test(tokio::test)]
+/// Test that duplicate contacts are only uploaded once,
+/// and their results are fanned back out to every duplicate
+async fn uploads_distinct_contacts_once(deduplicating: BriteVerifyClient) {
+    let contacts = [
+        "john.doe@gmail.com",
+        "JohnDoe+news@Gmail.com",
+        "jane@example.com",
+        "john.doe@gmail.com",
+    ];
+
+    let list = deduplicating
+        .create_list(
+            Some(contacts.map(|email| VerificationRequest::try_from(email).unwrap())),
+            true,
+        )
+        .await
+        .unwrap();
+
+    let sent = deduplicating.dry_run().unwrap().requests();
+
+    assert_eq!(
+        vec!["john.doe@gmail.com", "jane@example.com"],
+        emails(&sent[0].body.as_ref().unwrap()["contacts"])
+    );
+
+    // dry-run lists advance one step through processing
+    // every time they're polled, and finish verifying
+    // on the poll that precedes the results export
+    deduplicating.get_list_by_id(&list.list.id).await.unwrap();
+
+    let mut results: Vec<String> = deduplicating
+        .get_results_by_list_id(&list.list.id)
+        .await
+        .unwrap()
+        .into_iter()
+        .filter_map(|result| match result {
+            BulkVerificationResult::Contact(contact) => contact.email.map(|email| email.email),
+            BulkVerificationResult::Email(email) => Some(email.email),
+        })
+        .collect();
+
+    results.sort();
+
+    let mut expected = contacts.map(str::to_string).to_vec();
+    expected.sort();
+
+    assert_eq!(expected, results);
+    assert!(deduplicating.deduplicator().unwrap().is_empty());
+}
+
+#[rstest]
+#[test_log::test(tokio::test)]
+/// Test that contacts appended to a list aren't uploaded
+/// again if they duplicate contacts already in the list
+async fn skips_contacts_already_in_list(deduplicating: BriteVerifyClient) {
+    let contact = |email: &str, phone: &str| VerificationRequest {
+        email: Some(email.to_string()),
+        phone: Some(phone.to_string()),
+        ..VerificationRequest::default()
+    };
+
+    let list = deduplicating
+        .create_list(Some([contact("test@example.com", "(954) 555-1234")]), false)
+        .await
+        .unwrap();
+
+    deduplicating
+        .update_list(
+            &list.list.id,
+            [
+                contact("TEST@example.com", "+1 954-555-1234"),
+                contact("test@example.com", "954-555-9999"),
+            ],
+            false,
+        )
+        .await
+        .unwrap();
+
+    let sent = deduplicating.dry_run().unwrap().requests();
+    let appended = &sent.last().unwrap().body.as_ref().unwrap()["contacts"];
+
+    assert_eq!(1, appended.as_array().unwrap().len());
+    assert_eq!(Some("954-555-9999"), appended[0]["phone"].as_str());
+    assert_eq!(1, deduplicating.deduplicator().unwrap().len());
+}
+
+// </editor-fold desc="// Integration Tests ...">
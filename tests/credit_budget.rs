@@ -0,0 +1,98 @@
+//! ## Integration Tests For [`BriteVerifyClient`](briteverify_rs::BriteVerifyClient)'s
+//! ## Credit Budget Guard
+
+// Module Declarations
+pub mod utils;
+
+// Third Party Imports
+use pretty_assertions::assert_eq;
+use rstest::rstest;
+
+// Crate-Level Imports
+use briteverify_rs::{
+    budget::CreditBudget, errors::BriteVerifyClientError, simulator::BriteVerifySimulator,
+    types::VerificationRequest, BriteVerifyClient,
+};
+use utils::{builder_for_simulator, SIMULATOR_API_KEY};
+
+// <editor-fold desc="// Integration Tests ...">
+
+#[rstest]
+#[test_log::test(tokio::test)]
+/// Test that requests costing more than the account's
+/// live credit balance are refused before being sent
+async fn refuses_requests_exceeding_balance() {
+    let simulator = BriteVerifySimulator::new(SIMULATOR_API_KEY).credits(5);
+    let server = simulator.start().await.expect("Could not start simulator");
+    let client = builder_for_simulator(&server)
+        .credit_budget(CreditBudget::new().minimum_balance(2))
+        .build()
+        .unwrap();
+
+    client
+        .verify_contact(
+            "test@example.com",
+            "+15555555555",
+            "123 Main St",
+            Option::<&str>::None,
+            "Any Town",
+            "CA",
+            "90210",
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(2, simulator.balance().credits);
+
+    let refused = client.verify_email("test@example.com").await;
+
+    assert!(matches!(
+        refused,
+        Err(BriteVerifyClientError::InsufficientCredits {
+            required: 1,
+            available: 0
+        })
+    ));
+    assert_eq!(2, simulator.balance().credits);
+    assert_eq!(Some(3), client.budget_guard().map(|guard| guard.spent()));
+}
+
+#[rstest]
+#[test_log::test(tokio::test)]
+/// Test that requests that would exceed the budget's
+/// spend cap are refused before being sent
+async fn refuses_requests_exceeding_spend_cap() {
+    let client = BriteVerifyClient::builder()
+        .api_key("not a real api key")
+        .dry_run(true)
+        .credit_budget(CreditBudget::new().spend_cap(2).check_balance(false))
+        .build()
+        .unwrap();
+
+    let contacts = ["a@example.com", "b@example.com", "c@example.com"]
+        .map(|email| VerificationRequest::try_from(email).unwrap());
+
+    let refused = client.create_list(Some(contacts), false).await;
+
+    assert!(matches!(
+        refused,
+        Err(BriteVerifyClientError::SpendCapExceeded {
+            required: 3,
+            remaining: 2
+        })
+    ));
+    assert!(client.dry_run().unwrap().is_empty());
+
+    client.verify_email("a@example.com").await.unwrap();
+    client.verify_email("b@example.com").await.unwrap();
+
+    assert!(client.verify_email("c@example.com").await.is_err());
+    assert_eq!(Some(0), client.budget_guard().unwrap().remaining());
+
+    client.budget_guard().unwrap().reset();
+    client.verify_email("c@example.com").await.unwrap();
+
+    assert_eq!(3, client.dry_run().unwrap().len());
+}
+
+// </editor-fold desc="// Integration Tests ...">
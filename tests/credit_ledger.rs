@@ -0,0 +1,132 @@
+//! ## Integration Tests For [`BriteVerifyClient`](briteverify_rs::BriteVerifyClient)'s
+//! ## Credit Consumption Ledger
+
+// Module Declarations
+pub mod utils;
+
+// Third Party Imports
+use pretty_assertions::assert_eq;
+use rstest::rstest;
+
+// Crate-Level Imports
+use briteverify_rs::{
+    ledger::{CreditLedger, InMemoryLedger, LedgerEntryKind},
+    simulator::BriteVerifySimulator,
+    types::{BatchState, VerificationRequest},
+    BriteVerifyClient, RequestOptions,
+};
+use utils::{builder_for_simulator, SIMULATOR_API_KEY};
+
+// <editor-fold desc="// Integration Tests ...">
+
+#[rstest]
+#[test_log::test(tokio::test)]
+/// Test that single verifications and bulk lists are
+/// recorded to the ledger with their tags and balances
+async fn records_consumed_credits() {
+    let simulator = BriteVerifySimulator::new(SIMULATOR_API_KEY).credits(10);
+    let server = simulator.start().await.expect("Could not start simulator");
+    let ledger = InMemoryLedger::new();
+    let client = builder_for_simulator(&server)
+        .credit_ledger(CreditLedger::new(ledger.clone()).snapshot_balances(true))
+        .build()
+        .unwrap();
+
+    client
+        .with_options(
+            RequestOptions::new()
+                .tenant("acme")
+                .label("pipeline", "signup"),
+        )
+        .verify_contact(
+            "test@example.com",
+            "+15555555555",
+            "123 Main St",
+            Option::<&str>::None,
+            "Any Town",
+            "CA",
+            "90210",
+        )
+        .await
+        .unwrap();
+
+    let contacts = ["a@example.com", "b@example.com"]
+        .map(|email| VerificationRequest::try_from(email).unwrap());
+
+    let list = client
+        .with_options(
+            RequestOptions::new()
+                .tenant("globex")
+                .label("pipeline", "nightly"),
+        )
+        .create_list(Some(contacts), true)
+        .await
+        .unwrap()
+        .list;
+
+    assert_eq!(1, ledger.entries().len());
+
+    // completion is only recorded the first time it is observed
+    while client.get_list_by_id(&list.id).await.unwrap().state != BatchState::Complete {}
+    client.get_list_by_id(&list.id).await.unwrap();
+
+    let entries = ledger.entries();
+
+    assert_eq!(2, entries.len());
+
+    let (single, bulk) = (&entries[0], &entries[1]);
+
+    assert_eq!(LedgerEntryKind::Single, single.kind);
+    assert_eq!(Some("acme"), single.tenant.as_deref());
+    assert_eq!(Some("signup"), single.label("pipeline"));
+    assert_eq!(3, single.credits);
+    assert_eq!(
+        (Some(10), Some(7)),
+        (single.balance_before, single.balance_after)
+    );
+
+    assert_eq!(LedgerEntryKind::Bulk, bulk.kind);
+    assert_eq!(Some(list.id.as_str()), bulk.list_id.as_deref());
+    assert_eq!(Some("globex"), bulk.tenant.as_deref());
+    assert_eq!(Some("nightly"), bulk.label("pipeline"));
+    assert_eq!(2, bulk.credits);
+    assert_eq!(2, bulk.total_verified_emails);
+    assert_eq!(
+        (Some(7), Some(5)),
+        (bulk.balance_before, bulk.balance_after)
+    );
+
+    let by_pipeline = ledger.by_label("pipeline");
+
+    assert_eq!(3, by_pipeline[&Some("signup".to_string())].credits);
+    assert_eq!(2, by_pipeline[&Some("nightly".to_string())].credits);
+    assert_eq!(5, ledger.total().credits);
+    assert_eq!(5, simulator.balance().credits);
+}
+
+#[rstest]
+#[test_log::test(tokio::test)]
+/// Test that balances are only snapshotted
+/// when the ledger is configured to
+async fn skips_balance_snapshots_by_default() {
+    let ledger = InMemoryLedger::new();
+    let client = BriteVerifyClient::builder()
+        .api_key("not a real api key")
+        .dry_run(true)
+        .credit_ledger(CreditLedger::new(ledger.clone()))
+        .build()
+        .unwrap();
+
+    client.verify_email("test@example.com").await.unwrap();
+
+    let entries = ledger.entries();
+
+    assert_eq!(1, entries.len());
+    assert_eq!(
+        (None, None),
+        (entries[0].balance_before, entries[0].balance_after)
+    );
+    assert_eq!(1, client.dry_run().unwrap().len());
+}
+
+// </editor-fold desc="// Integration Tests ...">
@@ -0,0 +1,93 @@
+//! ## Integration Tests For [`BriteVerifyClient`](briteverify_rs::BriteVerifyClient)'s
+//! ## Dry-Run Mode
+
+// Third Part Imports
+use pretty_assertions::assert_eq;
+use rstest::{fixture, rstest};
+
+// Crate-Level Imports
+use briteverify_rs::{
+    errors::BriteVerifyClientError,
+    types::{BatchState, VerificationRequest, VerificationStatus},
+    BriteVerifyClient,
+};
+
+// <editor-fold desc="// Fixtures ...">
+
+#[fixture]
+fn dry_run() -> BriteVerifyClient {
+    BriteVerifyClient::builder()
+        .api_key("not a real api key")
+        .dry_run(true)
+        .build()
+        .unwrap()
+}
+
+// </editor-fold desc="// Fixtures ...">
+
+// <editor-fold desc="// Integration Tests ...">
+
+#[rstest]
+#[test_log::test(tokio::test)]
+/// Test that a dry-run client records and estimates
+/// the cost of requests instead of sending them
+async fn dry_run_records_requests(dry_run: BriteVerifyClient) {
+    let email = dry_run.verify_email("test@example.com").await.unwrap();
+
+    assert_eq!(VerificationStatus::Unknown, email.status);
+
+    let created = dry_run
+        .create_list(
+            Some(
+                ["one@example.com", "two@example.com"]
+                    .map(|email| VerificationRequest::try_from(email).unwrap()),
+            ),
+            true,
+        )
+        .await
+        .unwrap();
+
+    let mut state = created.list.state;
+
+    while state != BatchState::Complete {
+        state = dry_run
+            .get_list_by_id(&created.list.id)
+            .await
+            .unwrap()
+            .state;
+    }
+
+    let log = dry_run.dry_run().unwrap();
+
+    assert_eq!(3, log.estimated_credits());
+    assert!(log.requests().iter().all(|request| request.is_valid()));
+    assert!(log
+        .requests()
+        .iter()
+        .all(|request| request.url.as_str().starts_with("https://")));
+
+    log.clear();
+
+    assert!(log.is_empty());
+}
+
+#[rstest]
+#[test_log::test(tokio::test)]
+/// Test that a dry-run client rejects
+/// (and records) invalid requests
+async fn dry_run_rejects_invalid_requests(dry_run: BriteVerifyClient) {
+    let result = dry_run.get_list_by_id("not-a-list-id").await;
+
+    assert!(matches!(
+        result,
+        Err(BriteVerifyClientError::InvalidDryRunRequest(_))
+    ));
+
+    let log = dry_run.dry_run().unwrap();
+
+    assert_eq!(1, log.len());
+    assert_eq!(0, log.estimated_credits());
+    assert!(log.requests()[0].rejection.is_some());
+}
+
+// </editor-fold desc="// Integration Tests ...">
@@ -0,0 +1,128 @@
+//! ## Integration Tests For [`BriteVerifyClient`](briteverify_rs::BriteVerifyClient)'s
+//! ## Local Email Address Syntax Validation
+
+// Third Part Imports
+use pretty_assertions::assert_eq;
+use rstest::{fixture, rstest};
+
+// Crate-Level Imports
+use briteverify_rs::{
+    errors::BriteVerifyClientError,
+    types::{VerificationError, VerificationRequest, VerificationStatus},
+    BriteVerifyClient,
+};
+
+// <editor-fold desc="// Fixtures ...">
+
+#[fixture]
+/// A dry-run client (so that any request that
+/// would have been sent is recorded instead)
+/// with local email syntax validation enabled
+fn validating() -> BriteVerifyClient {
+    BriteVerifyClient::builder()
+        .api_key("not a real api key")
+        .dry_run(true)
+        .validate_email_syntax(true)
+        .build()
+        .unwrap()
+}
+
+// </editor-fold desc="// Fixtures ...">
+
+// <editor-fold desc="// Integration Tests ...">
+
+#[rstest]
+#[test_log::test(tokio::test)]
+/// Test that malformed email addresses are reported
+/// as `invalid_format` without calling the API
+async fn reports_malformed_emails_locally(validating: BriteVerifyClient) {
+    let email = validating
+        .verify_email("not..valid@example.com")
+        .await
+        .unwrap();
+
+    assert_eq!(VerificationStatus::Invalid, email.status);
+    assert_eq!(Some(VerificationError::InvalidFormat), email.error_code);
+    assert!(validating.dry_run().unwrap().is_empty());
+
+    let contact = validating
+        .verify_contact(
+            "not..valid@example.com",
+            "+1 (954) 555-1234",
+            "123 Main St",
+            Option::<&str>::None,
+            "Any Town",
+            "CA",
+            "90210",
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(
+        Some(VerificationError::InvalidFormat),
+        contact.email.unwrap().error_code
+    );
+    assert!(contact.phone.is_some() && contact.address.is_some());
+
+    let sent = validating.dry_run().unwrap().requests();
+
+    assert_eq!(1, sent.len());
+    assert!(sent[0].body.as_ref().unwrap().get("email").is_none());
+
+    validating.verify_email("valid@example.com").await.unwrap();
+
+    assert_eq!(2, validating.dry_run().unwrap().len());
+}
+
+#[rstest]
+#[test_log::test(tokio::test)]
+/// Test that bulk verification lists including malformed
+/// email addresses are rejected before being uploaded
+async fn rejects_lists_with_malformed_emails(validating: BriteVerifyClient) {
+    let result = validating
+        .create_list(
+            Some(
+                [
+                    "valid@example.com",
+                    "@example.com",
+                    "not..valid@example.com",
+                ]
+                .map(|email| VerificationRequest::try_from(email).unwrap()),
+            ),
+            false,
+        )
+        .await;
+
+    match result {
+        Err(BriteVerifyClientError::MalformedEmailAddresses(malformed)) => assert_eq!(
+            vec![
+                "@example.com".to_string(),
+                "not..valid@example.com".to_string()
+            ],
+            malformed
+        ),
+        other => panic!("Expected Err(MalformedEmailAddresses), got: {other:#?}"),
+    }
+
+    assert!(validating.dry_run().unwrap().is_empty());
+}
+
+#[rstest]
+#[test_log::test]
+/// Test that errors listing malformed email addresses
+/// only include the first few of them in their message
+fn truncates_malformed_email_messages() {
+    let malformed = (0..10).map(|idx| format!("{idx}@")).collect::<Vec<_>>();
+    let error = BriteVerifyClientError::MalformedEmailAddresses(malformed.clone());
+
+    assert_eq!(
+        r#"Contacts include 10 malformed email address(es): "0@", "1@", "2@" (and 7 more)"#,
+        error.to_string()
+    );
+    assert!(matches!(
+        error,
+        BriteVerifyClientError::MalformedEmailAddresses(values) if values == malformed
+    ));
+}
+
+// </editor-fold desc="// Integration Tests ...">
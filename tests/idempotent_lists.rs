@@ -0,0 +1,316 @@
+//! ## Integration Tests For [`BriteVerifyClient`](briteverify_rs::BriteVerifyClient)'s
+//! ## Idempotent List Creation
+
+// Module Declarations
+pub mod utils;
+
+// Standard Library Imports
+use std::{path::Path, time::Duration};
+
+// Third Party Imports
+use chrono::Utc;
+use pretty_assertions::assert_eq;
+use rstest::{fixture, rstest};
+
+// Crate-Level Imports
+use briteverify_rs::{
+    errors::BriteVerifyClientError,
+    idempotency::{
+        fingerprint, FileIdempotencyStore, IdempotencyRecord, IdempotencyStore, IdempotentLists,
+    },
+    simulator::{BriteVerifySimulator, RunningSimulator},
+    types::{BatchState, VerificationRequest},
+    BriteVerifyClient,
+};
+use utils::{builder_for_simulator, TempPath, SIMULATOR_API_KEY};
+
+// <editor-fold desc="// Fixtures ...">
+
+#[fixture]
+fn store_path() -> TempPath {
+    TempPath::new("json")
+}
+
+#[fixture]
+fn contacts() -> Vec<VerificationRequest> {
+    ["one@example.com", "two@example.com", "three@example.com"]
+        .into_iter()
+        .map(|email| VerificationRequest::try_from(email).unwrap())
+        .collect()
+}
+
+/// Build a client for the supplied simulator,
+/// backed by the store at the supplied path
+fn client(server: &RunningSimulator, path: &Path) -> BriteVerifyClient {
+    builder_for_simulator(server)
+        .idempotent_lists(IdempotentLists::new(
+            FileIdempotencyStore::open(path).unwrap(),
+        ))
+        .build()
+        .unwrap()
+}
+
+// </editor-fold desc="// Fixtures ...">
+
+// <editor-fold desc="// Integration Tests ...">
+
+#[rstest]
+#[test_log::test(tokio::test)]
+/// Test that retried creations with the same token reuse
+/// the same list, even across clients sharing a store
+async fn reuses_lists_for_retried_tokens(store_path: TempPath, contacts: Vec<VerificationRequest>) {
+    let simulator = BriteVerifySimulator::new(SIMULATOR_API_KEY).credits(10);
+    let server = simulator.start().await.expect("Could not start simulator");
+
+    let first = client(&server, &store_path)
+        .create_list_idempotent("job-1", contacts.clone(), true)
+        .await
+        .unwrap();
+
+    let retried = client(&server, &store_path)
+        .create_list_idempotent("job-1", contacts.iter().rev().cloned(), true)
+        .await
+        .unwrap();
+
+    assert_eq!(first.list.id, retried.list.id);
+    assert_eq!(3, simulator.balance().credits_in_reserve);
+
+    let other = client(&server, &store_path)
+        .create_list_idempotent("job-2", contacts.clone(), true)
+        .await
+        .unwrap();
+
+    assert_ne!(first.list.id, other.list.id);
+    assert_eq!(6, simulator.balance().credits_in_reserve);
+}
+
+#[rstest]
+#[test_log::test(tokio::test)]
+/// Test that a retried creation whose original response
+/// was lost finds and reuses the list it created
+async fn recovers_lists_with_lost_responses(
+    store_path: TempPath,
+    contacts: Vec<VerificationRequest>,
+) {
+    let simulator = BriteVerifySimulator::new(SIMULATOR_API_KEY).credits(10);
+    let server = simulator.start().await.expect("Could not start simulator");
+    let client = client(&server, &store_path);
+
+    // an earlier, unrelated list that must not be mistaken for the lost one
+    let unrelated = client
+        .create_list_idempotent("job-0", contacts[..1].to_vec(), false)
+        .await
+        .unwrap();
+
+    // simulate an attempt whose response never arrived
+    client
+        .idempotent_lists()
+        .store()
+        .put(IdempotencyRecord {
+            token: "job-1".to_string(),
+            fingerprint: fingerprint(&contacts, None),
+            attempted_on: Utc::now(),
+            contacts: Some(contacts.len()),
+            list_id: None,
+            failed_on: None,
+        })
+        .unwrap();
+
+    let lost = client
+        .create_list(Some(contacts.clone()), true)
+        .await
+        .unwrap();
+
+    let retried = client
+        .create_list_idempotent("job-1", contacts.clone(), true)
+        .await
+        .unwrap();
+
+    assert_eq!(lost.list.id, retried.list.id);
+    assert_ne!(unrelated.list.id, retried.list.id);
+    assert_eq!(2, client.get_lists().await.unwrap().lists.len());
+    assert_eq!(
+        Some(lost.list.id),
+        client
+            .idempotent_lists()
+            .store()
+            .get("job-1")
+            .unwrap()
+            .and_then(|record| record.list_id)
+    );
+}
+
+#[rstest]
+#[test_log::test(tokio::test)]
+/// Test that a retried creation whose original response was
+/// lost still finds the list it created when duplicate
+/// contacts were removed before uploading it
+async fn recovers_deduplicated_lists_with_lost_responses(
+    store_path: TempPath,
+    contacts: Vec<VerificationRequest>,
+) {
+    let simulator = BriteVerifySimulator::new(SIMULATOR_API_KEY).credits(10);
+    let server = simulator.start().await.expect("Could not start simulator");
+    let client = builder_for_simulator(&server)
+        .deduplicate_contacts(true)
+        .idempotent_lists(IdempotentLists::new(
+            FileIdempotencyStore::open(&store_path).unwrap(),
+        ))
+        .build()
+        .unwrap();
+
+    let mut duplicated = contacts.clone();
+    duplicated.push(VerificationRequest::try_from("ONE@example.com").unwrap());
+
+    let created = client
+        .create_list_idempotent("job-1", duplicated.clone(), true)
+        .await
+        .unwrap();
+
+    while client.get_list_by_id(&created.list.id).await.unwrap().state != BatchState::Complete {}
+
+    // simulate the creation response never having arrived
+    let store = client.idempotent_lists().store();
+    let record = store.get("job-1").unwrap().unwrap();
+
+    assert_eq!(Some(contacts.len()), record.contacts);
+
+    store
+        .put(IdempotencyRecord {
+            list_id: None,
+            ..record
+        })
+        .unwrap();
+
+    let retried = client
+        .create_list_idempotent("job-1", duplicated, true)
+        .await
+        .unwrap();
+
+    assert_eq!(created.list.id, retried.list.id);
+    assert_eq!(1, client.get_lists().await.unwrap().lists.len());
+}
+
+#[rstest]
+#[test_log::test(tokio::test)]
+/// Test that lists created after an attempt failed aren't
+/// mistaken for the list the attempt (might have) created
+async fn ignores_lists_created_after_failed_attempts(
+    store_path: TempPath,
+    contacts: Vec<VerificationRequest>,
+) {
+    let simulator = BriteVerifySimulator::new(SIMULATOR_API_KEY).credits(10);
+    let server = simulator.start().await.expect("Could not start simulator");
+    let client = client(&server, &store_path);
+    let now = Utc::now();
+
+    // simulate an attempt that failed well before the unrelated list was created
+    client
+        .idempotent_lists()
+        .store()
+        .put(IdempotencyRecord {
+            token: "job-1".to_string(),
+            fingerprint: fingerprint(&contacts, None),
+            attempted_on: now - chrono::Duration::minutes(10),
+            contacts: Some(contacts.len()),
+            list_id: None,
+            failed_on: Some(now - chrono::Duration::minutes(9)),
+        })
+        .unwrap();
+
+    let unrelated = client
+        .create_list(Some(contacts.clone()), true)
+        .await
+        .unwrap();
+
+    let retried = client
+        .create_list_idempotent("job-1", contacts.clone(), true)
+        .await
+        .unwrap();
+
+    assert_ne!(unrelated.list.id, retried.list.id);
+    assert_eq!(2, client.get_lists().await.unwrap().lists.len());
+}
+
+#[rstest]
+#[test_log::test(tokio::test)]
+/// Test that concurrent creations with the same
+/// token don't both create a list
+async fn refuses_concurrent_attempts(store_path: TempPath, contacts: Vec<VerificationRequest>) {
+    let simulator = BriteVerifySimulator::new(SIMULATOR_API_KEY).credits(10);
+    let server = simulator.start().await.expect("Could not start simulator");
+    let client = client(&server, &store_path);
+
+    let (first, second) = tokio::join!(
+        client.create_list_idempotent("job-1", contacts.clone(), false),
+        client.create_list_idempotent("job-1", contacts.clone(), false),
+    );
+
+    assert!(first.is_ok());
+    assert!(matches!(
+        second,
+        Err(BriteVerifyClientError::IdempotentRequestInProgress(token)) if token == "job-1"
+    ));
+    assert_eq!(1, client.get_lists().await.unwrap().lists.len());
+}
+
+#[rstest]
+#[test_log::test(tokio::test)]
+/// Test that tokens can't be reused
+/// for lists with different contents
+async fn refuses_reused_tokens(store_path: TempPath, contacts: Vec<VerificationRequest>) {
+    let simulator = BriteVerifySimulator::new(SIMULATOR_API_KEY).credits(10);
+    let server = simulator.start().await.expect("Could not start simulator");
+    let client = client(&server, &store_path);
+
+    client
+        .create_list_idempotent("job-1", contacts.clone(), false)
+        .await
+        .unwrap();
+
+    let result = client
+        .create_list_idempotent("job-1", contacts[1..].to_vec(), false)
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(BriteVerifyClientError::ReusedIdempotencyToken(token)) if token == "job-1"
+    ));
+    assert_eq!(1, client.get_lists().await.unwrap().lists.len());
+}
+
+#[rstest]
+#[test_log::test(tokio::test)]
+/// Test that file-backed stores discard records
+/// older than their retention period
+async fn prunes_expired_records(store_path: TempPath, contacts: Vec<VerificationRequest>) {
+    let record = |token: &str, age: chrono::Duration| IdempotencyRecord {
+        token: token.to_string(),
+        fingerprint: fingerprint(&contacts, None),
+        attempted_on: Utc::now() - age,
+        contacts: Some(contacts.len()),
+        list_id: Some(format!("list-{token}")),
+        failed_on: None,
+    };
+
+    let store = FileIdempotencyStore::open(&*store_path).unwrap();
+
+    store
+        .put(record("old", chrono::Duration::days(45)))
+        .unwrap();
+    store
+        .put(record("recent", chrono::Duration::days(1)))
+        .unwrap();
+
+    assert_eq!(None, store.get("old").unwrap());
+    assert!(store.get("recent").unwrap().is_some());
+
+    let store = FileIdempotencyStore::open(&*store_path)
+        .unwrap()
+        .retention(Duration::from_secs(3600));
+
+    assert_eq!(None, store.get("recent").unwrap());
+    assert!(store.claimed().unwrap().is_empty());
+}
+
+// </editor-fold desc="// Integration Tests ...">
@@ -0,0 +1,189 @@
+//! ## Integration Tests For [`BriteVerifyClient`](briteverify_rs::BriteVerifyClient)'s
+//! ## Persistent Verification Result Store
+
+// Module Declarations
+pub mod utils;
+
+// Standard Library Imports
+use std::{path::Path, time::Duration};
+
+// Third Party Imports
+use chrono::Utc;
+use pretty_assertions::assert_eq;
+use rstest::{fixture, rstest};
+use rusqlite::Connection;
+
+// Crate-Level Imports
+use briteverify_rs::{
+    simulator::{BriteVerifySimulator, RunningSimulator},
+    store::{
+        PersistentCache, ResultStore, SqliteResultStore, StoredResult, StoredSource,
+        StoredValueKind, SCHEMA_VERSION,
+    },
+    types::{BatchState, VerificationRequest, VerificationStatus},
+    BriteVerifyClient,
+};
+use utils::{builder_for_simulator, TempPath, SIMULATOR_API_KEY};
+
+// <editor-fold desc="// Fixtures ...">
+
+#[fixture]
+fn store_path() -> TempPath {
+    TempPath::new("sqlite3")
+}
+
+/// Build a client for the supplied simulator,
+/// backed by the store at the supplied path
+fn client(server: &RunningSimulator, path: &Path, freshness: Duration) -> BriteVerifyClient {
+    builder_for_simulator(server)
+        .persistent_cache(
+            PersistentCache::new(SqliteResultStore::open(path).unwrap()).freshness(freshness),
+        )
+        .build()
+        .unwrap()
+}
+
+// </editor-fold desc="// Fixtures ...">
+
+// <editor-fold desc="// Integration Tests ...">
+
+#[rstest]
+#[test_log::test]
+/// Test that new stores are migrated to the current schema
+/// version and stores with newer schema versions are refused
+fn migrates_result_stores(store_path: TempPath) {
+    SqliteResultStore::open(&store_path).unwrap();
+
+    let version = || -> u32 {
+        Connection::open(&store_path)
+            .unwrap()
+            .pragma_query_value(None, "user_version", |row| row.get(0))
+            .unwrap()
+    };
+
+    assert_eq!(SCHEMA_VERSION, version());
+
+    // reopening an up-to-date store leaves it untouched
+    SqliteResultStore::open(&store_path).unwrap();
+
+    assert_eq!(SCHEMA_VERSION, version());
+
+    Connection::open(&store_path)
+        .unwrap()
+        .pragma_update(None, "user_version", SCHEMA_VERSION + 1)
+        .unwrap();
+
+    assert!(SqliteResultStore::open(&store_path).is_err());
+}
+
+#[rstest]
+#[test_log::test]
+/// Test that the latest matching result is returned, that
+/// older results never replace newer ones from the same
+/// source, and that stale results are pruned
+fn queries_and_prunes_results(store_path: TempPath) {
+    let store = SqliteResultStore::open(&store_path).unwrap();
+    let now = Utc::now();
+
+    let result = |status, source, age: i64| StoredResult {
+        kind: StoredValueKind::Email,
+        value: "test@example.com".to_string(),
+        status,
+        secondary_status: None,
+        source,
+        list_id: None,
+        recorded_on: now - chrono::Duration::days(age),
+        data: serde_json::Value::Null,
+    };
+
+    store
+        .insert(vec![
+            result(VerificationStatus::Valid, StoredSource::Single, 10),
+            result(VerificationStatus::Invalid, StoredSource::Bulk, 1),
+            result(VerificationStatus::Unknown, StoredSource::Single, 5),
+        ])
+        .unwrap();
+
+    store
+        .insert(vec![result(
+            VerificationStatus::AcceptAll,
+            StoredSource::Single,
+            8,
+        )])
+        .unwrap();
+
+    let latest = |kind, source| {
+        store
+            .latest(kind, "test@example.com", source)
+            .unwrap()
+            .map(|result| result.status)
+    };
+
+    assert_eq!(
+        Some(VerificationStatus::Invalid),
+        latest(StoredValueKind::Email, None)
+    );
+    assert_eq!(
+        Some(VerificationStatus::Unknown),
+        latest(StoredValueKind::Email, Some(StoredSource::Single))
+    );
+    assert_eq!(None, latest(StoredValueKind::Phone, None));
+
+    assert_eq!(1, store.prune(now - chrono::Duration::days(3)).unwrap());
+    assert_eq!(0, store.prune(now - chrono::Duration::days(3)).unwrap());
+
+    // a second handle on the same database sees the same results
+    let reopened = SqliteResultStore::open(&store_path).unwrap();
+
+    assert_eq!(1, reopened.prune(now).unwrap());
+}
+
+#[rstest]
+#[test_log::test(tokio::test)]
+/// Test that stored results are shared between clients
+/// and reused (without spending credits) while fresh
+async fn reuses_fresh_results(store_path: TempPath) {
+    let simulator = BriteVerifySimulator::new(SIMULATOR_API_KEY).credits(10);
+    let server = simulator.start().await.expect("Could not start simulator");
+
+    let first = client(&server, &store_path, Duration::from_secs(3600));
+    let second = client(&server, &store_path, Duration::from_secs(3600));
+    let stale = client(&server, &store_path, Duration::ZERO);
+
+    let verified = first.verify_email("test@example.com").await.unwrap();
+
+    assert_eq!(9, simulator.balance().credits);
+
+    let reused = second.verify_email("TEST@example.com").await.unwrap();
+
+    assert_eq!(verified.address, reused.address);
+    assert_eq!(verified.status, reused.status);
+    assert_eq!(9, simulator.balance().credits);
+
+    stale.verify_email("test@example.com").await.unwrap();
+
+    assert_eq!(8, simulator.balance().credits);
+
+    let contacts = ["bulk@example.com"].map(|email| VerificationRequest::try_from(email).unwrap());
+    let list = first.create_list(Some(contacts), true).await.unwrap().list;
+
+    while first.get_list_by_id(&list.id).await.unwrap().state != BatchState::Complete {}
+
+    first.get_results_by_list_id(&list.id).await.unwrap();
+
+    let known = second
+        .persistent_cache()
+        .unwrap()
+        .last_known_email("Bulk@Example.com")
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(StoredSource::Bulk, known.source);
+    assert_eq!(Some(list.id.as_str()), known.list_id.as_deref());
+    assert_eq!(VerificationStatus::Valid, known.status);
+
+    assert_eq!(0, second.persistent_cache().unwrap().prune().unwrap());
+    assert_eq!(2, stale.persistent_cache().unwrap().prune().unwrap());
+}
+
+// </editor-fold desc="// Integration Tests ...">
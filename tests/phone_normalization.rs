@@ -0,0 +1,113 @@
+//! ## Integration Tests For [`BriteVerifyClient`](briteverify_rs::BriteVerifyClient)'s
+//! ## Local Phone Number Normalization
+
+// Third Part Imports
+use pretty_assertions::assert_eq;
+use rstest::{fixture, rstest};
+
+// Crate-Level Imports
+use briteverify_rs::{
+    errors::BriteVerifyClientError,
+    types::{VerificationRequest, VerificationStatus},
+    BriteVerifyClient,
+};
+
+// <editor-fold desc="// Fixtures ...">
+
+#[fixture]
+/// A dry-run client (so that any request that
+/// would have been sent is recorded instead)
+/// with US-based phone number normalization enabled
+fn normalizing() -> BriteVerifyClient {
+    BriteVerifyClient::builder()
+        .api_key("not a real api key")
+        .dry_run(true)
+        .normalize_phone_numbers(true)
+        .default_phone_region("US")
+        .build()
+        .unwrap()
+}
+
+// </editor-fold desc="// Fixtures ...">
+
+// <editor-fold desc="// Integration Tests ...">
+
+#[rstest]
+#[test_log::test(tokio::test)]
+/// Test that phone numbers are normalized (without their extensions)
+/// before being sent, and invalid ones are reported without calling the API
+async fn normalizes_single_phone_numbers(normalizing: BriteVerifyClient) {
+    normalizing
+        .verify_phone_number("(954) 555-1234 x12")
+        .await
+        .unwrap();
+
+    let sent = normalizing.dry_run().unwrap().requests();
+
+    assert_eq!(
+        Some("+19545551234"),
+        sent[0].body.as_ref().unwrap()["phone"].as_str()
+    );
+
+    let invalid = normalizing
+        .verify_phone_number("(054) 555-1234")
+        .await
+        .unwrap();
+
+    assert_eq!(VerificationStatus::Invalid, invalid.status);
+    assert_eq!(vec![serde_json::json!("invalid_prefix")], invalid.errors);
+    assert_eq!(1, normalizing.dry_run().unwrap().len());
+}
+
+#[rstest]
+#[test_log::test(tokio::test)]
+/// Test that bulk verification lists are normalized, and
+/// rejected if they include invalid phone numbers
+async fn normalizes_bulk_phone_numbers(normalizing: BriteVerifyClient) {
+    let contacts = |numbers: [&str; 2]| {
+        numbers.map(|number| VerificationRequest {
+            phone: Some(number.to_string()),
+            ..VerificationRequest::default()
+        })
+    };
+
+    let result = normalizing
+        .create_list(Some(contacts(["954-555-1234", "555-1234"])), false)
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(BriteVerifyClientError::MalformedPhoneNumbers(malformed)) if malformed == ["555-1234"]
+    ));
+
+    normalizing
+        .create_list(Some(contacts(["954-555-1234", "+44 20 7946 0958"])), false)
+        .await
+        .unwrap();
+
+    let sent = normalizing.dry_run().unwrap().requests();
+    let phones: Vec<&str> = sent[0].body.as_ref().unwrap()["contacts"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .filter_map(|contact| contact["phone"].as_str())
+        .collect();
+
+    assert_eq!(vec!["+19545551234", "+442079460958"], phones);
+}
+
+#[rstest]
+#[test_log::test]
+/// Test that unsupported default regions are
+/// reported when the client is built
+fn rejects_unknown_regions() {
+    let client = BriteVerifyClient::builder()
+        .api_key("not a real api key")
+        .normalize_phone_numbers(true)
+        .default_phone_region("XX")
+        .build();
+
+    assert!(client.is_err());
+}
+
+// </editor-fold desc="// Integration Tests ...">
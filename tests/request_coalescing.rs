@@ -0,0 +1,109 @@
+//! ## Integration Tests For [`BriteVerifyClient`](briteverify_rs::BriteVerifyClient)'s
+//! ## In-Flight Request Coalescing
+
+// Module Declarations
+pub mod utils;
+
+// Standard Library Imports
+use std::time::Duration;
+
+// Third Party Imports
+use futures::future::join_all;
+use pretty_assertions::assert_eq;
+use rstest::rstest;
+
+// Crate-Level Imports
+use briteverify_rs::{
+    budget::CreditBudget, errors::BriteVerifyClientError, simulator::BriteVerifySimulator,
+    RequestOptions,
+};
+use utils::{builder_for_simulator, SIMULATOR_API_KEY};
+
+// <editor-fold desc="// Integration Tests ...">
+
+#[rstest]
+#[test_log::test(tokio::test)]
+/// Test that concurrent verifications of the same
+/// (normalized) values share a single request
+async fn coalesces_concurrent_verifications() {
+    let simulator = BriteVerifySimulator::new(SIMULATOR_API_KEY).credits(10);
+    let server = simulator.start().await.expect("Could not start simulator");
+    let client = builder_for_simulator(&server)
+        .coalesce_requests(true)
+        .build()
+        .unwrap();
+
+    let emails = [
+        "test@example.com",
+        "TEST@example.com",
+        " test@example.com ",
+        "Test@Example.com",
+        "test@EXAMPLE.COM",
+    ];
+
+    let responses = join_all(emails.map(|email| client.verify_email(email))).await;
+
+    assert!(responses.iter().all(|response| response.is_ok()));
+    assert_eq!(9, simulator.balance().credits);
+
+    let inflight = client.inflight_verifications().unwrap();
+
+    assert_eq!(4, inflight.coalesced());
+    assert!(inflight.is_empty());
+
+    client.verify_email("test@example.com").await.unwrap();
+
+    assert_eq!(8, simulator.balance().credits);
+}
+
+#[rstest]
+#[test_log::test(tokio::test)]
+/// Test that every caller sharing a failed
+/// request receives a copy of its error
+async fn shares_errors_with_every_caller() {
+    let simulator = BriteVerifySimulator::new(SIMULATOR_API_KEY).credits(0);
+    let server = simulator.start().await.expect("Could not start simulator");
+    let client = builder_for_simulator(&server)
+        .credit_budget(CreditBudget::new())
+        .coalesce_requests(true)
+        .build()
+        .unwrap();
+
+    let responses = join_all((0..5).map(|_| client.verify_email("test@example.com"))).await;
+
+    assert!(responses.iter().all(|response| matches!(
+        response,
+        Err(BriteVerifyClientError::InsufficientCredits {
+            required: 1,
+            available: 0,
+        })
+    )));
+    assert_eq!(4, client.inflight_verifications().unwrap().coalesced());
+}
+
+#[rstest]
+#[test_log::test(tokio::test)]
+/// Test that verifications made with per-call
+/// request options are never coalesced
+async fn sends_verifications_with_options_separately() {
+    let simulator = BriteVerifySimulator::new(SIMULATOR_API_KEY).credits(10);
+    let server = simulator.start().await.expect("Could not start simulator");
+    let client = builder_for_simulator(&server)
+        .coalesce_requests(true)
+        .build()
+        .unwrap();
+    let tenant = client.with_options(RequestOptions::new().tenant("acme"));
+    let hurried = client.with_options(RequestOptions::new().timeout(Duration::from_secs(5)));
+
+    let (first, second, third) = futures::join!(
+        client.verify_email("test@example.com"),
+        tenant.verify_email("test@example.com"),
+        hurried.verify_email("test@example.com"),
+    );
+
+    assert!(first.is_ok() && second.is_ok() && third.is_ok());
+    assert_eq!(7, simulator.balance().credits);
+    assert_eq!(0, client.inflight_verifications().unwrap().coalesced());
+}
+
+// </editor-fold desc="// Integration Tests ...">
@@ -0,0 +1,120 @@
+//! ## Integration Tests For [`BriteVerifyClient`](briteverify_rs::BriteVerifyClient)'s
+//! ## Credit-Free Sandbox Mode
+
+// Third Part Imports
+use pretty_assertions::assert_eq;
+use rstest::{fixture, rstest};
+
+// Crate-Level Imports
+use briteverify_rs::{
+    types::{
+        BatchState, BulkVerificationResult, VerificationError, VerificationRequest,
+        VerificationStatus,
+    },
+    BriteVerifyClient,
+};
+
+// <editor-fold desc="// Fixtures ...">
+
+#[fixture]
+fn sandbox() -> BriteVerifyClient {
+    BriteVerifyClient::builder()
+        .api_key("not a real api key")
+        .sandbox(true)
+        .build()
+        .unwrap()
+}
+
+// </editor-fold desc="// Fixtures ...">
+
+// <editor-fold desc="// Integration Tests ...">
+
+#[rstest]
+#[test_log::test(tokio::test)]
+/// Test that a sandboxed client answers single-transaction
+/// verifications locally based on their "magic" values
+async fn sandbox_answers_single_transactions(sandbox: BriteVerifyClient) {
+    assert!(sandbox.is_sandbox());
+
+    let email = sandbox
+        .verify_email("mailboxfull@example.com")
+        .await
+        .unwrap();
+
+    assert_eq!(VerificationStatus::Invalid, email.status);
+    assert_eq!(
+        Some(VerificationError::MailboxFullInvalid),
+        email.error_code
+    );
+
+    let phone = sandbox.verify_phone_number("555-555-0002").await.unwrap();
+
+    assert_eq!(VerificationStatus::Invalid, phone.status);
+    assert_eq!(vec![serde_json::json!("invalid_prefix")], phone.errors);
+
+    let address = sandbox
+        .verify_street_address(
+            "123 Main St",
+            Option::<&str>::None,
+            "Any Town",
+            "CA",
+            "00016",
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(VerificationStatus::Valid, address.status);
+    assert!(address.corrected);
+
+    let balance = sandbox.get_account_balance().await.unwrap();
+
+    assert!(balance.credits > 0);
+}
+
+#[rstest]
+#[test_log::test(tokio::test)]
+/// Test that a sandboxed client walks bulk verification
+/// lists through their lifecycle locally
+async fn sandbox_answers_bulk_lists(sandbox: BriteVerifyClient) {
+    let created = sandbox
+        .create_list(
+            Some(
+                ["valid@example.com", "disposable@example.com"]
+                    .map(|email| VerificationRequest::try_from(email).unwrap()),
+            ),
+            true,
+        )
+        .await
+        .unwrap();
+
+    let list_id = created.list.id;
+    let mut state = created.list.state;
+
+    while state != BatchState::Complete {
+        state = sandbox.get_list_by_id(&list_id).await.unwrap().state;
+    }
+
+    let results = sandbox.get_results_by_list_id(&list_id).await.unwrap();
+    let statuses: Vec<(String, VerificationStatus)> = results
+        .into_iter()
+        .filter_map(|result| match result {
+            BulkVerificationResult::Email(email) => Some((email.email, email.status)),
+            BulkVerificationResult::Contact(contact) => {
+                contact.email.map(|email| (email.email, email.status))
+            }
+        })
+        .collect();
+
+    assert_eq!(
+        vec![
+            ("valid@example.com".to_string(), VerificationStatus::Valid),
+            (
+                "disposable@example.com".to_string(),
+                VerificationStatus::Invalid
+            ),
+        ],
+        statuses
+    );
+}
+
+// </editor-fold desc="// Integration Tests ...">
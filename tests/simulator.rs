@@ -0,0 +1,94 @@
+//! ## Integration Tests For [`BriteVerifySimulator`](briteverify_rs::simulator::BriteVerifySimulator)
+
+// Third Party Imports
+use pretty_assertions::assert_eq;
+use rstest::rstest;
+
+// Crate-Level Imports
+use briteverify_rs::{
+    errors::BriteVerifyClientError,
+    simulator::BriteVerifySimulator,
+    types::{BatchState, VerificationRequest},
+};
+
+// <editor-fold desc="// Integration Tests ...">
+
+#[rstest]
+#[test_log::test(tokio::test)]
+/// Test that a [`BriteVerifyClient`](briteverify_rs::BriteVerifyClient)
+/// can run a complete bulk verification job against the simulator
+async fn runs_bulk_job_against_simulator() {
+    let simulator = BriteVerifySimulator::new("a-very-secret-key")
+        .credits(100)
+        .page_size(2);
+    let server = simulator.start().await.expect("Could not start simulator");
+    let client = server.client().expect("Could not build client");
+
+    let contacts: Vec<VerificationRequest> = ["a@example.com", "b@example.com", "+15555555555"]
+        .into_iter()
+        .map(VerificationRequest::try_from)
+        .collect::<Result<_, _>>()
+        .unwrap();
+
+    let list = client
+        .create_list(Some(contacts), true)
+        .await
+        .expect("Expected Ok(CreateListResponse)")
+        .list;
+
+    assert_eq!(BatchState::Pending, list.state);
+    assert_eq!(97u32, client.current_credits().await.unwrap());
+
+    let mut state = list.state;
+
+    while state != BatchState::Complete {
+        state = client.get_list_by_id(&list.id).await.unwrap().state;
+    }
+
+    let results = client
+        .get_results_by_list_id(&list.id)
+        .await
+        .expect("Expected Ok(Vec<BulkVerificationResult>)");
+
+    assert_eq!(3, results.len());
+    assert_eq!(
+        BatchState::Delivered,
+        client.get_list_by_id(&list.id).await.unwrap().state
+    );
+    assert_eq!(0u32, client.current_credits_in_reserve().await.unwrap());
+
+    let email = client.verify_email("c@example.com").await.unwrap();
+
+    assert_eq!("example.com", email.domain);
+    assert_eq!(96u32, simulator.balance().credits);
+}
+
+#[rstest]
+#[test_log::test(tokio::test)]
+/// Test that a bulk verification job that fails partway through
+/// reports the lists it already created instead of orphaning them
+async fn reports_lists_of_incomplete_jobs() {
+    let simulator = BriteVerifySimulator::new("a-very-secret-key").credits(2);
+    let server = simulator.start().await.expect("Could not start simulator");
+    let client = server.client().expect("Could not build client");
+
+    let contacts: Vec<(u32, VerificationRequest)> =
+        ["a@example.com", "b@example.com", "c@example.com"]
+            .into_iter()
+            .enumerate()
+            .map(|(key, email)| (key as u32, VerificationRequest::try_from(email).unwrap()))
+            .collect();
+
+    let result = client.create_job_with_list_size(contacts, true, 2).await;
+
+    let Err(BriteVerifyClientError::IncompleteBulkJob { list_ids, .. }) = result else {
+        panic!("Expected Err(IncompleteBulkJob), got: {result:?}");
+    };
+
+    let created: Vec<String> = simulator.lists().into_iter().map(|list| list.id).collect();
+
+    assert_eq!(created, list_ids);
+    assert_eq!(1, list_ids.len());
+}
+
+// </editor-fold desc="// Integration Tests ...">
@@ -5,7 +5,10 @@ pub mod v1_mock_data;
 pub mod v3_mock_data;
 
 // Standard Library Imports
-use std::ops::Deref;
+use std::{
+    ops::Deref,
+    path::{Path, PathBuf},
+};
 
 // Third Part Imports
 use anyhow::Result;
@@ -22,13 +25,19 @@ use uuid::Uuid;
 use wiremock::{Match, MockServer, Request, Respond, ResponseTemplate};
 
 // Crate-Level Imports
-use briteverify_rs::BriteVerifyClient;
+use briteverify_rs::{BriteVerifyClient, BriteVerifyClientBuilder};
+
+#[cfg(feature = "simulator")]
+use briteverify_rs::simulator::RunningSimulator;
 
 // <editor-fold desc="// Constants ...">
 
 pub static TEST_API_KEY: Lazy<String> = Lazy::new(|| Uuid::new_v4().to_string());
 pub static API_KEY_HEADER: Lazy<String> = Lazy::new(|| format!("ApiKey: {}", TEST_API_KEY.deref()));
 
+/// The API key accepted by simulators started for tests
+pub const SIMULATOR_API_KEY: &str = "a-very-secret-key";
+
 // <editor-fold desc="// Endpoint URL Regexes ...">
 
 // Common "Parts"
@@ -76,6 +85,18 @@ pub fn client_for_server(
     api_key: Option<&str>,
     enable_retry: bool,
 ) -> BriteVerifyClient {
+    builder_for_server(server, api_key, enable_retry)
+        .build()
+        .unwrap()
+}
+
+/// Create a `BriteVerifyClientBuilder` instance pre-configured
+/// for use with the supplied `wiremock::MockServer` instance
+pub fn builder_for_server(
+    server: &MockServer,
+    api_key: Option<&str>,
+    enable_retry: bool,
+) -> BriteVerifyClientBuilder {
     let server_addr = *server.address();
 
     BriteVerifyClient::builder()
@@ -88,8 +109,6 @@ pub fn client_for_server(
         .resolve_v1_url_to(server_addr)
         .resolve_v3_url_to(server_addr)
         .api_key(api_key.unwrap_or(TEST_API_KEY.deref()))
-        .build()
-        .unwrap()
 }
 
 /// Create a `BriteVerifyClient` instance pre-configured for use
@@ -104,6 +123,16 @@ pub async fn client_and_server(
     (client, server)
 }
 
+/// Create a `BriteVerifyClientBuilder` instance pre-configured
+/// for use with the supplied running simulator
+#[cfg(feature = "simulator")]
+pub fn builder_for_simulator(server: &RunningSimulator) -> BriteVerifyClientBuilder {
+    BriteVerifyClient::builder()
+        .api_key(SIMULATOR_API_KEY)
+        .v1_base_url(server.v1_base_url().as_str())
+        .v3_base_url(server.v3_base_url().as_str())
+}
+
 /// Create a [`ResponseTemplate`](ResponseTemplate) from the supplied JSON blob
 pub fn official_response(from: MockRequestResponse) -> ResponseTemplate {
     ResponseTemplate::new(StatusCode::Ok).set_body_raw(from.response, &JSON.to_string())
@@ -111,6 +140,47 @@ pub fn official_response(from: MockRequestResponse) -> ResponseTemplate {
 
 // </editor-fold desc="// Utility Functions ...">
 
+// <editor-fold desc="// Temporary Files ...">
+
+/// A unique path in the system's temporary directory, whose file
+/// (along with any SQLite journal files) is deleted when dropped
+#[derive(Debug)]
+pub struct TempPath(PathBuf);
+
+impl TempPath {
+    /// Create a new, unique path with the supplied extension
+    pub fn new(extension: &str) -> Self {
+        Self(std::env::temp_dir().join(format!("{}.{extension}", Uuid::new_v4())))
+    }
+}
+
+impl Deref for TempPath {
+    type Target = Path;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl AsRef<Path> for TempPath {
+    fn as_ref(&self) -> &Path {
+        &self.0
+    }
+}
+
+impl Drop for TempPath {
+    fn drop(&mut self) {
+        for suffix in ["", "-wal", "-shm", "-journal"] {
+            let mut path = self.0.clone().into_os_string();
+            path.push(suffix);
+
+            let _ = std::fs::remove_file(path);
+        }
+    }
+}
+
+// </editor-fold desc="// Temporary Files ...">
+
 // <editor-fold desc="// `wiremock` Extension Traits ...">
 
 pub trait BriteVerifyRequest {
@@ -135,13 +205,13 @@ impl BriteVerifyRequest for Request {
     fn has_json_content(&self) -> bool {
         self.headers
             .get(&CONTENT_TYPE)
-            .map_or(false, |value| value.as_str() == JSON.to_string())
+            .is_some_and(|value| value.as_str() == JSON.to_string())
     }
 
     fn has_valid_api_key(&self) -> bool {
         self.headers
             .get(&AUTHORIZATION)
-            .map_or(false, |value| value.as_str() == API_KEY_HEADER.deref())
+            .is_some_and(|value| value.as_str() == API_KEY_HEADER.deref())
     }
 
     fn body_json_matches_value(&self, value: &Value) -> bool {
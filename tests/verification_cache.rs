@@ -0,0 +1,63 @@
+//! ## Integration Tests For [`BriteVerifyClient`](briteverify_rs::BriteVerifyClient)'s
+//! ## Verification Result Cache
+
+// Module Declarations
+pub mod utils;
+
+// Standard Library Imports
+use std::time::Duration;
+
+// Third Party Imports
+use pretty_assertions::assert_eq;
+use rstest::rstest;
+
+// Crate-Level Imports
+use briteverify_rs::{
+    cache::CachePolicy, clock::VirtualClock, simulator::BriteVerifySimulator,
+    types::VerificationStatus,
+};
+use utils::{builder_for_simulator, SIMULATOR_API_KEY};
+
+// <editor-fold desc="// Integration Tests ...">
+
+#[rstest]
+#[test_log::test(tokio::test)]
+/// Test that repeated verifications of the same values are
+/// answered from the cache until their responses expire
+async fn caches_verification_responses() {
+    let simulator = BriteVerifySimulator::new(SIMULATOR_API_KEY).credits(10);
+    let server = simulator.start().await.expect("Could not start simulator");
+    let clock = VirtualClock::new();
+    let client = builder_for_simulator(&server)
+        .clock(clock.clone())
+        .verification_cache(
+            CachePolicy::new().ttl(VerificationStatus::Valid, Duration::from_secs(60)),
+        )
+        .build()
+        .unwrap();
+
+    let first = client.verify_email("test@example.com").await.unwrap();
+    let second = client.verify_email(" TEST@example.com ").await.unwrap();
+
+    assert_eq!(first.address, second.address);
+    assert_eq!(9, simulator.balance().credits);
+
+    client.verify_email("other@example.com").await.unwrap();
+    client.verify_email("test@example.com").await.unwrap();
+
+    assert_eq!(8, simulator.balance().credits);
+
+    clock.advance(Duration::from_secs(60));
+    client.verify_email("test@example.com").await.unwrap();
+
+    assert_eq!(7, simulator.balance().credits);
+
+    let stats = client.verification_cache().unwrap().stats();
+
+    assert_eq!(
+        (2, 3, 1, 2),
+        (stats.hits, stats.misses, stats.expirations, stats.len)
+    );
+}
+
+// </editor-fold desc="// Integration Tests ...">